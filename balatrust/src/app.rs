@@ -1,25 +1,79 @@
+use std::io;
+use std::path::Path;
+
 use crossterm::event::{KeyCode, KeyEvent, KeyModifiers, MouseEvent};
+use ratatui::layout::{Alignment, Rect};
+use ratatui::style::{Modifier, Style};
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{Block, BorderType, Borders, Clear, Paragraph};
 use ratatui::Frame;
+use serde::{Deserialize, Serialize};
 use tachyonfx::Duration;
 
-use balatrust_core::RunState;
+use balatrust_core::run::AntePhase;
+use balatrust_core::{BossBlind, DeckType, RunState, Stake};
+use balatrust_widgets::sidebar::MoneyDisplay;
+use balatrust_widgets::theme::{ColorScheme, Theme};
 
 use crate::effects::{self, FxManager};
 use crate::screens::blind_select::BlindSelectScreen;
 use crate::screens::game_over::GameOverScreen;
 use crate::screens::main_menu::MainMenuScreen;
 use crate::screens::play_round::PlayRoundScreen;
+use crate::screens::sandbox::SandboxScreen;
 use crate::screens::shop::ShopScreen;
 use crate::screens::Screen;
+use crate::settings::{AnimationSpeed, Settings};
+use crate::storage;
 
 /// Top-level game phase
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum GamePhase {
     MainMenu,
+    /// Building a custom deck and jokers before a sandbox run
+    Sandbox,
     BlindSelect,
     Playing,
     Shop,
-    GameOver { won: bool },
+    GameOver {
+        won: bool,
+    },
+}
+
+/// The seed, deck and game-affecting actions of a run, enough to replay it exactly
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Recorder {
+    pub seed: u64,
+    /// Seed text the run was started from, if it was typed in
+    #[serde(default)]
+    pub seed_string: Option<String>,
+    pub deck_type: DeckType,
+    #[serde(default)]
+    pub stake: Stake,
+    pub actions: Vec<ScreenAction>,
+}
+
+impl Recorder {
+    /// Start recording a run that has just been created from its seed
+    pub fn new(game: &RunState) -> Self {
+        Self {
+            seed: game.seed,
+            seed_string: game.seed_string.clone(),
+            deck_type: game.deck_type,
+            stake: game.stake,
+            actions: Vec::new(),
+        }
+    }
+
+    pub fn load_from_path(path: &Path) -> io::Result<Self> {
+        let json = std::fs::read_to_string(path)?;
+        serde_json::from_str(&json).map_err(io::Error::other)
+    }
+
+    pub fn save_to_path(&self, path: &Path) -> io::Result<()> {
+        let json = serde_json::to_string(self).map_err(io::Error::other)?;
+        storage::write_file(path, &json)
+    }
 }
 
 /// Main application state
@@ -29,9 +83,21 @@ pub struct App {
     pub tick: u64,
     pub fx: FxManager,
     prev_phase: Option<GamePhase>,
+    pub settings: Settings,
+    /// Game-affecting actions since the last autosave
+    actions_since_save: u32,
+    /// Sidebar money, counting toward the run's real money each tick
+    money_display: MoneyDisplay,
+    /// "Quit run?" prompt is open (every quit outside the main menu asks first)
+    quit_prompt: bool,
+    /// Actions of the run in progress, if it was started from a seed
+    recorder: Option<Recorder>,
+    /// Off while a replay fast-forwards, so it never touches the autosave
+    writes_saves: bool,
 
     // Screens
     pub main_menu: MainMenuScreen,
+    pub sandbox: SandboxScreen,
     pub blind_select: BlindSelectScreen,
     pub play_round: PlayRoundScreen,
     pub shop: ShopScreen,
@@ -44,16 +110,41 @@ impl App {
         // Title shimmer runs forever on main menu
         fx.add_unique_effect("title_shimmer", effects::title_shimmer());
 
+        let settings = Settings::load_from_path(&storage::settings_path());
+        let mut main_menu = MainMenuScreen::new(storage::save_path().exists());
+        main_menu.animation_speed = settings.animation_speed;
+        main_menu.confirm_leave_shop = settings.confirm_leave_shop;
+        main_menu.mouse_capture = settings.mouse_capture;
+        main_menu.last_hand_warning = settings.last_hand_warning;
+        main_menu.color_scheme = settings.color_scheme;
+        Theme::set_scheme(settings.color_scheme);
+        main_menu.accessible_suits = settings.accessible_suits;
+        Theme::set_accessible_suits(settings.accessible_suits);
+        main_menu.play_hints = settings.play_hints;
+        let mut play_round = PlayRoundScreen::new();
+        play_round.animation_speed = settings.animation_speed;
+        play_round.last_hand_warning = settings.last_hand_warning;
+        play_round.play_hints = settings.play_hints;
+        let mut shop = ShopScreen::new();
+        shop.confirm_leave = settings.confirm_leave_shop;
+
         Self {
             phase: GamePhase::MainMenu,
             game: None,
             tick: 0,
             fx,
             prev_phase: None,
-            main_menu: MainMenuScreen::new(),
+            settings,
+            actions_since_save: 0,
+            money_display: MoneyDisplay::default(),
+            quit_prompt: false,
+            recorder: None,
+            writes_saves: true,
+            main_menu,
+            sandbox: SandboxScreen::new(),
             blind_select: BlindSelectScreen::new(),
-            play_round: PlayRoundScreen::new(),
-            shop: ShopScreen::new(),
+            play_round,
+            shop,
             game_over: GameOverScreen::new(),
         }
     }
@@ -69,8 +160,14 @@ impl App {
         let frame_border = balatrust_widgets::background::FrameWidget::new(self.tick);
         frame.render_widget(frame_border, area);
 
+        self.blind_select.money_display = self.money_display;
+        self.play_round.money_display = self.money_display;
+        self.play_round.tick = self.tick;
+        self.shop.money_display = self.money_display;
+
         match self.phase {
             GamePhase::MainMenu => self.main_menu.render(frame, &self.game),
+            GamePhase::Sandbox => self.sandbox.render(frame, &self.game),
             GamePhase::BlindSelect => self.blind_select.render(frame, &self.game),
             GamePhase::Playing => self.play_round.render(frame, &self.game),
             GamePhase::Shop => self.shop.render(frame, &self.game),
@@ -80,12 +177,68 @@ impl App {
             }
         }
 
+        if self.quit_prompt {
+            self.render_quit_prompt(frame, area);
+        }
+
         // Apply all tachyonfx effects on top of rendered content
         let tick_duration = Duration::from_millis(33); // ~30fps
         let buf = frame.buffer_mut();
         self.fx.process_effects(tick_duration, buf, area);
     }
 
+    fn render_quit_prompt(&self, frame: &mut Frame, area: Rect) {
+        let mut keys = vec![
+            Span::styled("[", Style::default().fg(Theme::dim_text())),
+            Span::styled("Y", Style::default().fg(Theme::gold())),
+            Span::styled("] Quit  [", Style::default().fg(Theme::dim_text())),
+        ];
+        if self.can_save() {
+            keys.push(Span::styled("S", Style::default().fg(Theme::gold())));
+            keys.push(Span::styled(
+                "] Save & Quit  [",
+                Style::default().fg(Theme::dim_text()),
+            ));
+        }
+        keys.push(Span::styled("N", Style::default().fg(Theme::gold())));
+        keys.push(Span::styled(
+            "] Stay",
+            Style::default().fg(Theme::dim_text()),
+        ));
+
+        let lines = vec![
+            Line::from(Span::styled(
+                "Quit run?",
+                Style::default()
+                    .fg(Theme::bright_text())
+                    .add_modifier(Modifier::BOLD),
+            )),
+            Line::from(""),
+            Line::from(keys),
+        ];
+
+        let popup_width = 42u16.min(area.width);
+        let popup_height = (lines.len() as u16 + 2).min(area.height);
+        let popup_area = Rect::new(
+            area.x + area.width.saturating_sub(popup_width) / 2,
+            area.y + area.height.saturating_sub(popup_height) / 2,
+            popup_width,
+            popup_height,
+        );
+
+        frame.render_widget(Clear, popup_area);
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .border_type(BorderType::Rounded)
+            .border_style(Style::default().fg(Theme::mult_color()));
+        frame.render_widget(
+            Paragraph::new(lines)
+                .alignment(Alignment::Center)
+                .block(block),
+            popup_area,
+        );
+    }
+
     /// Handle key event. Returns true if should quit.
     pub fn handle_key(&mut self, key: KeyEvent) -> bool {
         // Ctrl+C quits from anywhere
@@ -93,13 +246,45 @@ impl App {
             return true;
         }
 
-        // 'q' quits from main menu
-        if key.code == KeyCode::Char('q') && self.phase == GamePhase::MainMenu {
+        // The quit prompt takes every key: y quits, s saves first, anything else stays
+        if self.quit_prompt {
+            self.quit_prompt = false;
+            return match key.code {
+                KeyCode::Char('y') | KeyCode::Char('Y') => true,
+                KeyCode::Char('s') | KeyCode::Char('S') if self.can_save() => {
+                    self.autosave();
+                    true
+                }
+                _ => false,
+            };
+        }
+
+        // 'q' quits from main menu (unless it's being typed into the seed)
+        if key.code == KeyCode::Char('q')
+            && self.phase == GamePhase::MainMenu
+            && !self.is_text_input_active()
+        {
             return true;
         }
 
+        // 'M' (Shift+m) switches between mouse capture and keyboard-only input
+        if key.code == KeyCode::Char('M') && !self.is_text_input_active() {
+            return self.process_action(Some(ScreenAction::SetMouseCapture(
+                !self.settings.mouse_capture,
+            )));
+        }
+
+        // 'A' (Shift+a) dumps a plain-text description of the run for external tools
+        if key.code == KeyCode::Char('A') && !self.is_text_input_active() {
+            if let Some(game) = &self.game {
+                let _ = storage::write_file(&storage::announce_path(), &game.describe_text());
+                return false;
+            }
+        }
+
         let action = match self.phase {
             GamePhase::MainMenu => self.main_menu.handle_key(key),
+            GamePhase::Sandbox => self.sandbox.handle_key(key),
             GamePhase::BlindSelect => self.blind_select.handle_key(key),
             GamePhase::Playing => self.play_round.handle_key(key),
             GamePhase::Shop => self.shop.handle_key(key),
@@ -109,7 +294,24 @@ impl App {
         self.process_action(action)
     }
 
+    /// Whether the current screen is taking typed text (seed entry, deck viewer
+    /// filter), so global letter hotkeys must let keystrokes through
+    fn is_text_input_active(&self) -> bool {
+        match self.phase {
+            GamePhase::MainMenu => self.main_menu.is_editing_seed(),
+            GamePhase::BlindSelect => self.blind_select.is_editing_filter(),
+            GamePhase::Playing => self.play_round.is_editing_filter(),
+            GamePhase::Shop => self.shop.is_editing_filter(),
+            GamePhase::Sandbox | GamePhase::GameOver { .. } => false,
+        }
+    }
+
+    /// Handle a mouse event. Ignored in keyboard-only mode, in case one slips
+    /// through before the terminal stops capturing.
     pub fn handle_mouse(&mut self, mouse: MouseEvent) {
+        if !self.settings.mouse_capture || self.quit_prompt {
+            return;
+        }
         match self.phase {
             GamePhase::Playing => {
                 let action = self.play_round.handle_mouse(mouse, &self.game);
@@ -133,6 +335,9 @@ impl App {
 
     pub fn tick(&mut self) {
         self.tick += 1;
+        if let Some(game) = &self.game {
+            self.money_display.tick(game.money);
+        }
 
         // Detect phase changes and trigger transition effects
         if self.prev_phase != Some(self.phase) {
@@ -164,30 +369,241 @@ impl App {
             self.prev_phase = Some(self.phase);
         }
 
-        match self.phase {
-            GamePhase::Playing => {
-                self.play_round.tick(&mut self.game);
+        if self.phase == GamePhase::Playing {
+            self.play_round.tick(&mut self.game);
 
-                // Drive the scoring animation state machine
-                if self.play_round.is_scoring() {
-                    if let Some(action) = self.play_round.tick_scoring(&mut self.fx) {
-                        self.process_action(Some(action));
-                    }
+            // Drive the scoring animation state machine
+            if self.play_round.is_scoring() {
+                if let Some(action) = self.play_round.tick_scoring(&mut self.fx) {
+                    self.process_action(Some(action));
                 }
             }
-            _ => {}
+        }
+    }
+
+    /// Count a game-affecting action and autosave once the configured interval is reached
+    fn note_action(&mut self) {
+        self.actions_since_save += 1;
+        let interval = self.settings.autosave_interval;
+        if interval > 0 && self.actions_since_save >= interval {
+            self.autosave();
+        }
+    }
+
+    /// Whether there is a run worth saving right now. Not mid-animation, when the
+    /// played cards are out of the hand but their score has not been applied yet.
+    fn can_save(&self) -> bool {
+        self.writes_saves
+            && self.game.is_some()
+            && !self.play_round.is_scoring()
+            && !matches!(self.phase, GamePhase::MainMenu | GamePhase::GameOver { .. })
+    }
+
+    /// Save the run in progress (see `can_save`)
+    fn autosave(&mut self) {
+        if !self.can_save() {
+            return;
+        }
+        if let Some(game) = &self.game {
+            let _ = game.save_to_path(&storage::save_path());
+            self.actions_since_save = 0;
+        }
+    }
+
+    /// Make `game` the current run, with the sidebar money showing its amount
+    /// rather than counting over from the previous run's
+    fn install_game(&mut self, game: RunState) {
+        self.money_display.snap(game.money);
+        self.game = Some(game);
+    }
+
+    /// Begin a fresh run from its seed, recording its actions for a replay
+    fn start_recorded_run(&mut self, game: RunState) {
+        self.recorder = Some(Recorder::new(&game));
+        self.install_game(game);
+    }
+
+    /// Rebuild a recorded run by re-applying its actions to a fresh run from the same seed
+    pub fn replay(&mut self, recording: &Recorder) {
+        let mut game = RunState::with_seed_and_deck(recording.seed, recording.deck_type)
+            .with_stake(recording.stake);
+        game.seed_string = recording.seed_string.clone();
+        self.start_recorded_run(game);
+        self.blind_select.cursor = 0;
+        self.play_round.reset();
+        self.shop.reset();
+        self.phase = GamePhase::BlindSelect;
+
+        self.writes_saves = false;
+        for action in &recording.actions {
+            self.process_action(Some(action.clone()));
+        }
+        self.writes_saves = true;
+        if let Some(game) = &self.game {
+            self.money_display.snap(game.money);
         }
     }
 
     /// Process a screen action. Returns true if should quit.
     fn process_action(&mut self, action: Option<ScreenAction>) -> bool {
+        let counts_for_autosave = matches!(
+            action,
+            Some(
+                ScreenAction::StartBlind
+                    | ScreenAction::SkipBlind
+                    | ScreenAction::RerollBoss
+                    | ScreenAction::FinishScoring
+                    | ScreenAction::Discard
+                    | ScreenAction::Undo
+                    | ScreenAction::ToggleCard(_)
+                    | ScreenAction::BuyShopItem(_)
+                    | ScreenAction::SellJoker(_)
+                    | ScreenAction::SellAllJokers
+                    | ScreenAction::MoveJoker { .. }
+                    | ScreenAction::SellConsumable(_)
+                    | ScreenAction::RerollShop
+                    | ScreenAction::PickFromPack(_)
+                    | ScreenAction::SkipPack
+                    | ScreenAction::UseConsumable(_)
+                    | ScreenAction::SortByRank
+                    | ScreenAction::SortBySuit
+                    | ScreenAction::SelectAll
+                    | ScreenAction::ClearSelection
+            )
+        );
+
+        // Starting or skipping a blind only works with the cursor on the active blind,
+        // and a replay always has it there, so record only the ones that took effect
+        let on_active_blind = self
+            .game
+            .as_ref()
+            .is_none_or(|g| self.blind_select.cursor == g.current_blind_index());
+        let records = counts_for_autosave
+            || matches!(
+                action,
+                Some(
+                    ScreenAction::PlayHand
+                        | ScreenAction::BeatBlind
+                        | ScreenAction::LeaveShop
+                        | ScreenAction::ContinueEndless
+                )
+            );
+        if let (Some(recorder), Some(action)) = (&mut self.recorder, &action) {
+            let skipped = !on_active_blind
+                && matches!(action, ScreenAction::StartBlind | ScreenAction::SkipBlind);
+            if records && !skipped {
+                recorder.actions.push(action.clone());
+            }
+        }
+        if action.is_some() {
+            self.play_round.invalidate_hint();
+        }
+
         match action {
-            Some(ScreenAction::Quit) => return true,
+            // Only the main menu quits straight away
+            Some(ScreenAction::Quit) if self.phase == GamePhase::MainMenu => return true,
+            Some(ScreenAction::Quit) => self.quit_prompt = true,
             Some(ScreenAction::NewGame) => {
-                self.game = Some(RunState::new());
+                self.start_recorded_run(
+                    RunState::new_with_deck(self.main_menu.deck_type)
+                        .with_stake(self.main_menu.stake),
+                );
                 self.blind_select.cursor = 0; // Active blind is always 0 at start
                 self.phase = GamePhase::BlindSelect;
             }
+            Some(ScreenAction::RestartRun) => {
+                if let Some(game) = self.game.as_ref().map(RunState::restarted) {
+                    self.start_recorded_run(game);
+                    self.blind_select.cursor = 0;
+                    self.phase = GamePhase::BlindSelect;
+                }
+            }
+            Some(ScreenAction::NewSeedRun) => {
+                let (deck_type, stake) = self
+                    .game
+                    .as_ref()
+                    .map_or((self.main_menu.deck_type, self.main_menu.stake), |g| {
+                        (g.deck_type, g.stake)
+                    });
+                self.start_recorded_run(RunState::new_with_deck(deck_type).with_stake(stake));
+                self.blind_select.cursor = 0;
+                self.phase = GamePhase::BlindSelect;
+            }
+            Some(ScreenAction::SeededGame(seed)) => {
+                self.start_recorded_run(
+                    RunState::with_seed_string_and_deck(&seed, self.main_menu.deck_type)
+                        .with_stake(self.main_menu.stake),
+                );
+                self.blind_select.cursor = 0;
+                self.phase = GamePhase::BlindSelect;
+            }
+            Some(ScreenAction::ContinueRun) => {
+                match RunState::load_from_path(&storage::save_path()) {
+                    Ok(game) => {
+                        self.phase = match game.ante_phase {
+                            AntePhase::BlindSelect => {
+                                self.blind_select.cursor = game.current_blind_index();
+                                GamePhase::BlindSelect
+                            }
+                            AntePhase::Playing => {
+                                self.play_round.reset();
+                                GamePhase::Playing
+                            }
+                            AntePhase::Shop => {
+                                self.shop.reset();
+                                GamePhase::Shop
+                            }
+                        };
+                        self.install_game(game);
+                        self.recorder = None;
+                        self.main_menu.status = None;
+                    }
+                    Err(e) => {
+                        self.main_menu.status = Some(format!("Could not load save: {}", e));
+                    }
+                }
+            }
+            Some(ScreenAction::PracticeBoss { seed, ante, boss }) => {
+                self.install_game(RunState::practice_boss(seed, ante, boss));
+                self.recorder = None;
+                self.play_round.reset();
+                self.phase = GamePhase::Playing;
+            }
+            Some(ScreenAction::OpenSandbox) => {
+                self.phase = GamePhase::Sandbox;
+            }
+            Some(ScreenAction::StartSandbox) => {
+                self.install_game(RunState::sandbox(
+                    self.sandbox.seed,
+                    self.sandbox.setup.clone(),
+                ));
+                self.recorder = None;
+                self.blind_select.cursor = 0;
+                self.phase = GamePhase::BlindSelect;
+            }
+            Some(ScreenAction::SaveRecap) => {
+                if let Some(game) = &self.game {
+                    let path = storage::recap_path();
+                    let mut text = game.recap_card_lines().join("\n");
+                    text.push('\n');
+                    self.game_over.recap_status = Some(match storage::write_file(&path, &text) {
+                        Ok(()) => format!("Recap saved to {}", path.display()),
+                        Err(e) => format!("Could not save recap: {}", e),
+                    });
+                }
+            }
+            Some(ScreenAction::SaveReplay) => {
+                if let Some(recorder) = &self.recorder {
+                    let path = storage::replay_path();
+                    self.game_over.recap_status = Some(match recorder.save_to_path(&path) {
+                        Ok(()) => format!("Replay saved to {}", path.display()),
+                        Err(e) => format!("Could not save replay: {}", e),
+                    });
+                } else {
+                    self.game_over.recap_status =
+                        Some("Only runs started from a seed can be replayed".to_string());
+                }
+            }
             Some(ScreenAction::StartBlind) => {
                 if let Some(game) = &mut self.game {
                     // Only allow starting the currently active blind
@@ -210,18 +626,28 @@ impl App {
                     }
                 }
             }
+            Some(ScreenAction::RerollBoss) => {
+                if let Some(game) = &mut self.game {
+                    self.blind_select.notice = Some(match game.boss_reroll_problem() {
+                        Some(problem) => problem,
+                        None => {
+                            game.reroll_boss();
+                            format!("The boss is now {}", game.boss_blind)
+                        }
+                    });
+                }
+            }
             Some(ScreenAction::PlayHand) => {
                 if let Some(game) = &mut self.game {
                     if game.can_play() && !self.play_round.is_scoring() {
                         // Remove cards from hand and compute score, but DON'T apply yet
+                        let money_before = game.money;
                         let played = game.play_selected();
-                        let score_result = balatrust_core::scoring::calculate_score_with_jokers(
-                            &played,
-                            &game.hand_levels,
-                            &game.jokers,
-                            &game.hand, // remaining hand = held cards
-                            game.discards_remaining,
-                        );
+                        let toll = money_before - game.money;
+                        if toll > 0 {
+                            self.play_round.notice = Some(format!("The Tooth took ${}", toll));
+                        }
+                        let score_result = game.score_played(&played);
 
                         // Use a hand charge (decrements hands_remaining)
                         game.use_hand();
@@ -236,7 +662,7 @@ impl App {
                 self.play_round.finish_scoring();
                 if let Some(game) = &mut self.game {
                     if let Some(result) = self.play_round.scoring_result.take() {
-                        game.add_score(result.final_score);
+                        game.resolve_played_hand(&self.play_round.last_played, &result);
 
                         // Store for the "last score" display
                         self.play_round.last_score = Some(result);
@@ -253,11 +679,16 @@ impl App {
 
                         // Check win/lose
                         if game.blind_beaten() {
-                            self.play_round.blind_just_beaten = true;
+                            self.play_round
+                                .start_cashout(&game.calculate_reward_breakdown());
                             self.fx
                                 .add_unique_effect("celebration", effects::celebration_shimmer());
                         } else if game.round_lost() {
+                            self.game_over.recap_status = None;
                             self.phase = GamePhase::GameOver { won: false };
+                            if self.writes_saves {
+                                storage::clear_save();
+                            }
                         }
                     }
                 }
@@ -265,8 +696,17 @@ impl App {
             Some(ScreenAction::Discard) => {
                 if let Some(game) = &mut self.game {
                     if game.can_discard() {
-                        game.use_discard();
+                        // Discard first so an undo snapshot still has the discard
                         game.discard_selected();
+                        game.use_discard();
+                        // Discarding the last cards of a small deck ends the round
+                        if game.round_lost() {
+                            self.game_over.recap_status = None;
+                            self.phase = GamePhase::GameOver { won: false };
+                            if self.writes_saves {
+                                storage::clear_save();
+                            }
+                        }
                     }
                 }
             }
@@ -274,10 +714,25 @@ impl App {
                 if let Some(game) = &mut self.game {
                     game.beat_blind();
                     if game.run_won() {
+                        self.game_over.recap_status = None;
                         self.phase = GamePhase::GameOver { won: true };
+                        if self.writes_saves {
+                            storage::clear_save();
+                        }
                     } else {
                         self.phase = GamePhase::Shop;
                         self.shop.reset();
+                        self.autosave();
+                    }
+                }
+            }
+            Some(ScreenAction::ContinueEndless) => {
+                if let Some(game) = &mut self.game {
+                    if game.run_won() {
+                        game.continue_endless();
+                        self.phase = GamePhase::Shop;
+                        self.shop.reset();
+                        self.autosave();
                     }
                 }
             }
@@ -291,6 +746,8 @@ impl App {
             }
             Some(ScreenAction::BackToMenu) => {
                 self.game = None;
+                self.recorder = None;
+                self.main_menu.set_has_save(storage::save_path().exists());
                 self.phase = GamePhase::MainMenu;
             }
             Some(ScreenAction::ToggleCard(idx)) => {
@@ -308,16 +765,50 @@ impl App {
                     game.sell_joker(idx);
                 }
             }
+            Some(ScreenAction::SellAllJokers) => {
+                if let Some(game) = &mut self.game {
+                    let gained = game.sell_all_jokers();
+                    self.shop.notice = Some(format!("Sold every joker for ${}", gained));
+                }
+            }
+            Some(ScreenAction::MoveJoker { from, to }) => {
+                if let Some(game) = &mut self.game {
+                    game.move_joker(from, to);
+                }
+            }
+            Some(ScreenAction::SellConsumable(idx)) => {
+                if let Some(game) = &mut self.game {
+                    game.sell_consumable(idx);
+                }
+            }
             Some(ScreenAction::RerollShop) => {
                 if let Some(game) = &mut self.game {
                     game.reroll_shop();
                 }
             }
+            Some(ScreenAction::PickFromPack(idx)) => {
+                if let Some(game) = &mut self.game {
+                    game.pick_from_pack(idx);
+                }
+            }
+            Some(ScreenAction::SkipPack) => {
+                if let Some(game) = &mut self.game {
+                    game.skip_pack();
+                }
+            }
             Some(ScreenAction::UseConsumable(idx)) => {
                 if let Some(game) = &mut self.game {
-                    // Try planet first, then tarot
-                    if !game.use_planet(idx) {
-                        game.use_tarot(idx);
+                    let notice = match game.consumable_use_problem(idx) {
+                        Some(problem) => Some(problem),
+                        None if game.use_consumable(idx) => {
+                            self.play_round.consumable_cursor = None;
+                            None
+                        }
+                        None => Some("That card can't be used right now".to_string()),
+                    };
+                    match self.phase {
+                        GamePhase::Shop => self.shop.notice = notice,
+                        _ => self.play_round.notice = notice,
                     }
                 }
             }
@@ -333,10 +824,45 @@ impl App {
                     self.play_round.cursor = 0;
                 }
             }
+            Some(ScreenAction::SetAnimationSpeed(speed)) => {
+                self.settings.animation_speed = speed;
+                self.play_round.animation_speed = speed;
+                let _ = self.settings.save_to_path(&storage::settings_path());
+            }
+            Some(ScreenAction::SetConfirmLeaveShop(confirm)) => {
+                self.settings.confirm_leave_shop = confirm;
+                self.shop.confirm_leave = confirm;
+                let _ = self.settings.save_to_path(&storage::settings_path());
+            }
+            Some(ScreenAction::SetMouseCapture(capture)) => {
+                self.settings.mouse_capture = capture;
+                self.main_menu.mouse_capture = capture;
+                let _ = self.settings.save_to_path(&storage::settings_path());
+            }
+            Some(ScreenAction::SetColorScheme(scheme)) => {
+                self.settings.color_scheme = scheme;
+                Theme::set_scheme(scheme);
+                let _ = self.settings.save_to_path(&storage::settings_path());
+            }
+            Some(ScreenAction::SetAccessibleSuits(on)) => {
+                self.settings.accessible_suits = on;
+                Theme::set_accessible_suits(on);
+                let _ = self.settings.save_to_path(&storage::settings_path());
+            }
+            Some(ScreenAction::SetPlayHints(on)) => {
+                self.settings.play_hints = on;
+                self.play_round.play_hints = on;
+                let _ = self.settings.save_to_path(&storage::settings_path());
+            }
+            Some(ScreenAction::SetLastHandWarning(warn)) => {
+                self.settings.last_hand_warning = warn;
+                self.play_round.last_hand_warning = warn;
+                let _ = self.settings.save_to_path(&storage::settings_path());
+            }
             Some(ScreenAction::SelectAll) => {
                 if let Some(game) = &mut self.game {
                     game.selected_indices.clear();
-                    let max = game.hand.len().min(5);
+                    let max = game.hand.len().min(game.max_selected as usize);
                     for i in 0..max {
                         game.selected_indices.push(i);
                     }
@@ -347,6 +873,17 @@ impl App {
                     game.selected_indices.clear();
                 }
             }
+            Some(ScreenAction::Undo) => {
+                if let Some(game) = &mut self.game {
+                    if game.undo() {
+                        self.play_round.cancel_scoring();
+                    } else if game.undo_used {
+                        self.play_round.notice = Some("Undo already used this round".to_string());
+                    } else {
+                        self.play_round.notice = Some("Nothing to undo".to_string());
+                    }
+                }
+            }
             Some(ScreenAction::OpenDeckViewer) => {
                 if let Some(game) = &self.game {
                     let full_deck = game.full_deck();
@@ -367,18 +904,42 @@ impl App {
             None => {}
         }
 
+        if counts_for_autosave {
+            self.note_action();
+        }
+
         false
     }
 }
 
 /// Actions that screens can return
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 #[allow(dead_code)]
 pub enum ScreenAction {
     Quit,
     NewGame,
+    /// Start the finished run over with the same seed and deck
+    RestartRun,
+    /// Start a new run with a random seed and the finished run's deck
+    NewSeedRun,
+    /// Start a new run from player-typed seed text (empty = random)
+    SeededGame(String),
+    /// Resume the run saved at `storage::save_path()`
+    ContinueRun,
+    /// Start a practice run directly at a chosen boss blind
+    PracticeBoss {
+        seed: u64,
+        ante: u8,
+        boss: BossBlind,
+    },
+    /// Open the sandbox deck and joker editor
+    OpenSandbox,
+    /// Start a run with the deck and jokers built in the sandbox editor
+    StartSandbox,
     StartBlind,
     SkipBlind,
+    /// Pay to replace the upcoming boss (Director's Cut / Retcon)
+    RerollBoss,
     PlayHand,
     /// Scoring animation is complete — apply the score and resolve the round
     FinishScoring,
@@ -389,11 +950,264 @@ pub enum ScreenAction {
     ToggleCard(usize),
     BuyShopItem(usize),
     SellJoker(usize),
+    /// Sell every owned joker (confirmed in the shop first)
+    SellAllJokers,
+    /// Move a joker to another slot, shifting the ones in between
+    MoveJoker {
+        from: usize,
+        to: usize,
+    },
+    SellConsumable(usize),
     RerollShop,
+    /// Take an option from the booster pack being opened
+    PickFromPack(usize),
+    /// Close the open booster pack without taking anything
+    SkipPack,
     UseConsumable(usize),
     SortByRank,
     SortBySuit,
     SelectAll,
     ClearSelection,
     OpenDeckViewer,
+    /// Take back the last play or discard of the round
+    Undo,
+    /// Write the game-over recap card to a text file
+    SaveRecap,
+    /// Write the run's seed and recorded actions to a replay file
+    SaveReplay,
+    /// Keep playing a won run past ante 8
+    ContinueEndless,
+    /// Change the scoring animation speed (saved to the settings file)
+    SetAnimationSpeed(AnimationSpeed),
+    /// Turn the leave-shop confirmation on or off (saved to the settings file)
+    SetConfirmLeaveShop(bool),
+    /// Capture the mouse, or leave it to the terminal for keyboard-only play (saved to the settings file)
+    SetMouseCapture(bool),
+    /// Turn the last-hand shortfall warning on or off (saved to the settings file)
+    SetLastHandWarning(bool),
+    /// Repaint the UI with another color scheme (saved to the settings file)
+    SetColorScheme(ColorScheme),
+    /// Give each suit its own color and letter (saved to the settings file)
+    SetAccessibleSuits(bool),
+    /// Outline the best play in the hand (saved to the settings file)
+    SetPlayHints(bool),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crossterm::event::{MouseButton, MouseEventKind};
+
+    fn left_click() -> MouseEvent {
+        MouseEvent {
+            kind: MouseEventKind::Down(MouseButton::Left),
+            column: 0,
+            row: 0,
+            modifiers: KeyModifiers::NONE,
+        }
+    }
+
+    fn app_in_round() -> App {
+        let mut app = App::new();
+        let mut game = RunState::with_seed(1);
+        game.start_blind();
+        app.game = Some(game);
+        app.phase = GamePhase::Playing;
+        app.play_round.score_log.open = true;
+        app
+    }
+
+    fn press(app: &mut App, code: KeyCode) -> bool {
+        app.handle_key(KeyEvent::new(code, KeyModifiers::NONE))
+    }
+
+    #[test]
+    fn test_quitting_a_run_asks_first() {
+        let mut app = app_in_round();
+        app.play_round.score_log.open = false;
+
+        assert!(!press(&mut app, KeyCode::Char('q')));
+        assert!(app.quit_prompt);
+        assert!(!press(&mut app, KeyCode::Char('n')));
+        assert!(!app.quit_prompt);
+
+        assert!(!press(&mut app, KeyCode::Esc));
+        assert!(press(&mut app, KeyCode::Char('y')));
+    }
+
+    #[test]
+    fn test_main_menu_quits_without_asking() {
+        let mut app = App::new();
+        assert!(press(&mut app, KeyCode::Char('q')));
+        assert!(!app.quit_prompt);
+    }
+
+    #[test]
+    fn test_clicks_reach_the_screen_with_mouse_capture() {
+        let mut app = app_in_round();
+        app.settings.mouse_capture = true;
+        app.handle_mouse(left_click());
+        assert!(!app.play_round.score_log.open);
+    }
+
+    #[test]
+    fn test_clicks_are_ignored_in_keyboard_only_mode() {
+        let mut app = app_in_round();
+        app.settings.mouse_capture = false;
+        app.handle_mouse(left_click());
+        assert!(app.play_round.score_log.open);
+    }
+
+    /// Play a seeded run by script: a discard, then hands until the run ends,
+    /// visiting the shop whenever a blind falls
+    fn play_scripted_run(app: &mut App) {
+        app.writes_saves = false;
+        app.process_action(Some(ScreenAction::SeededGame("GHOST".to_string())));
+        app.process_action(Some(ScreenAction::StartBlind));
+        app.process_action(Some(ScreenAction::ToggleCard(0)));
+        app.process_action(Some(ScreenAction::Discard));
+
+        for _ in 0..20 {
+            if app.phase != GamePhase::Playing {
+                break;
+            }
+            app.process_action(Some(ScreenAction::SortByRank));
+            for idx in 0..5 {
+                app.process_action(Some(ScreenAction::ToggleCard(idx)));
+            }
+            app.process_action(Some(ScreenAction::PlayHand));
+            app.process_action(Some(ScreenAction::FinishScoring));
+
+            if app.game.as_ref().is_some_and(|g| g.blind_beaten()) {
+                app.process_action(Some(ScreenAction::BeatBlind));
+                app.process_action(Some(ScreenAction::BuyShopItem(0)));
+                app.process_action(Some(ScreenAction::RerollShop));
+                app.process_action(Some(ScreenAction::LeaveShop));
+                app.process_action(Some(ScreenAction::StartBlind));
+            }
+        }
+    }
+
+    #[test]
+    fn test_replay_reproduces_the_run() {
+        let mut original = App::new();
+        play_scripted_run(&mut original);
+        let recording = original.recorder.clone().unwrap();
+        assert!(recording.actions.len() > 10);
+
+        let mut ghost = App::new();
+        ghost.replay(&recording);
+
+        let (played, replayed) = (original.game.unwrap(), ghost.game.unwrap());
+        assert!(played.round_score > 0);
+        assert_eq!(replayed.round_score, played.round_score);
+        assert_eq!(replayed.money, played.money);
+        assert_eq!(ghost.money_display.shown, replayed.money);
+        assert_eq!(replayed.ante, played.ante);
+        assert_eq!(replayed.hand, played.hand);
+        assert_eq!(replayed.seed_string.as_deref(), Some("GHOST"));
+        assert_eq!(ghost.phase, original.phase);
+        assert_eq!(
+            ghost.recorder.unwrap().actions.len(),
+            recording.actions.len()
+        );
+    }
+
+    #[test]
+    fn test_new_run_shows_its_money_without_counting() {
+        let mut app = app_in_round();
+        app.writes_saves = false;
+        app.money_display.shown = 250;
+
+        app.process_action(Some(ScreenAction::NewGame));
+        let money = app.game.as_ref().unwrap().money;
+        assert_eq!(app.money_display.shown, money);
+
+        app.money_display.shown = 250;
+        app.process_action(Some(ScreenAction::RestartRun));
+        assert_eq!(app.money_display.shown, money);
+    }
+
+    #[test]
+    fn test_blind_actions_off_the_active_blind_are_not_recorded() {
+        let mut app = App::new();
+        app.writes_saves = false;
+        app.process_action(Some(ScreenAction::SeededGame("GHOST".to_string())));
+        app.blind_select.cursor = 2;
+        app.process_action(Some(ScreenAction::StartBlind));
+        assert_eq!(app.phase, GamePhase::BlindSelect);
+        assert!(app.recorder.as_ref().unwrap().actions.is_empty());
+    }
+
+    #[test]
+    fn test_a_won_run_can_continue_in_endless_mode() {
+        let mut app = App::new();
+        app.writes_saves = false;
+        app.game = Some(RunState::practice_boss(
+            1,
+            balatrust_core::run::WINNING_ANTE,
+            BossBlind::TheHook,
+        ));
+        app.phase = GamePhase::Playing;
+
+        app.process_action(Some(ScreenAction::BeatBlind));
+        assert_eq!(app.phase, GamePhase::GameOver { won: true });
+
+        app.process_action(Some(ScreenAction::ContinueEndless));
+        assert_eq!(app.phase, GamePhase::Shop);
+        app.process_action(Some(ScreenAction::LeaveShop));
+        assert_eq!(app.phase, GamePhase::BlindSelect);
+        assert_eq!(app.game.as_ref().unwrap().ante, 9);
+    }
+
+    #[test]
+    fn test_v_opens_the_deck_viewer_from_the_shop() {
+        let mut app = app_in_round();
+        let game = app.game.as_mut().unwrap();
+        game.beat_blind();
+        let deck_size = game.full_deck().len();
+        app.phase = GamePhase::Shop;
+
+        press(&mut app, KeyCode::Char('v'));
+        assert!(app.shop.deck_viewer.open);
+        assert_eq!(app.shop.deck_viewer.cached_deck.len(), deck_size);
+        press(&mut app, KeyCode::Esc);
+        assert!(!app.shop.deck_viewer.open);
+    }
+
+    #[test]
+    fn test_v_opens_the_deck_viewer_at_cash_out() {
+        let mut app = app_in_round();
+        app.play_round.score_log.open = false;
+        app.play_round.blind_just_beaten = true;
+
+        press(&mut app, KeyCode::Char('v'));
+        assert!(app.play_round.deck_viewer.open);
+    }
+
+    /// Open the round's deck viewer and start typing a filter
+    fn app_typing_deck_filter() -> App {
+        let mut app = app_in_round();
+        app.play_round.score_log.open = false;
+        press(&mut app, KeyCode::Char('v'));
+        press(&mut app, KeyCode::Char('/'));
+        assert!(app.play_round.deck_viewer.filter_editing);
+        app
+    }
+
+    #[test]
+    fn test_capital_m_types_into_the_deck_filter() {
+        let mut app = app_typing_deck_filter();
+        let mouse_capture = app.settings.mouse_capture;
+        press(&mut app, KeyCode::Char('M'));
+        assert_eq!(app.settings.mouse_capture, mouse_capture);
+        assert_eq!(app.play_round.deck_viewer.filter, "M");
+    }
+
+    #[test]
+    fn test_capital_a_types_into_the_deck_filter() {
+        let mut app = app_typing_deck_filter();
+        press(&mut app, KeyCode::Char('A'));
+        assert_eq!(app.play_round.deck_viewer.filter, "A");
+    }
 }
@@ -1,25 +1,23 @@
 #![allow(dead_code)]
 
-use ratatui::style::Color;
 use tachyonfx::fx;
 use tachyonfx::{Effect, EffectManager, Interpolation, IntoEffect, Motion};
 
+use balatrust_widgets::theme::Theme;
+
 /// Our keyed effect manager using tachyonfx's built-in EffectManager
 /// Using String keys so we can generate dynamic keys like "card_score_0"
 pub type FxManager = EffectManager<String>;
 
 // ─── Effect Factories ────────────────────────────────────────────────
 
-const DARK: Color = Color::Rgb(13, 17, 23);
-const FELT: Color = Color::Rgb(22, 22, 52);
-
 /// Screen transition: content sweeps in from the left
 pub fn screen_transition() -> Effect {
     fx::sweep_in(
         Motion::LeftToRight,
         8,
         2,
-        DARK,
+        Theme::bg(),
         (400, Interpolation::CubicOut),
     )
 }
@@ -35,7 +33,7 @@ pub fn score_sweep() -> Effect {
         Motion::LeftToRight,
         4,
         1,
-        DARK,
+        Theme::bg(),
         (300, Interpolation::QuadOut),
     )
 }
@@ -69,12 +67,18 @@ pub fn dissolve_out() -> Effect {
 
 /// Slide in from below for cards being dealt
 pub fn card_deal_slide() -> Effect {
-    fx::slide_in(Motion::DownToUp, 3, 1, FELT, (350, Interpolation::CubicOut))
+    fx::slide_in(
+        Motion::DownToUp,
+        3,
+        1,
+        Theme::felt(),
+        (350, Interpolation::CubicOut),
+    )
 }
 
 /// Fade foreground to gold (for score number highlight)
 pub fn score_highlight() -> Effect {
-    let gold = Color::Rgb(255, 214, 10);
+    let gold = Theme::score_color();
     let shift = fx::fade_to_fg(gold, (200, Interpolation::QuadOut));
     let shift_back = fx::fade_from_fg(gold, (600, Interpolation::QuadIn));
     fx::sequence(&[shift, shift_back])
@@ -82,7 +86,7 @@ pub fn score_highlight() -> Effect {
 
 /// Brief bright glow on a card being scored
 pub fn card_score_glow() -> Effect {
-    let white = Color::Rgb(255, 255, 255);
+    let white = Theme::bright_text();
     let bright = fx::fade_to_fg(white, (100, Interpolation::QuadOut));
     let dim = fx::fade_from_fg(white, (200, Interpolation::QuadIn));
     fx::sequence(&[bright, dim])
@@ -90,7 +94,7 @@ pub fn card_score_glow() -> Effect {
 
 /// Pulse effect on a joker when it activates during scoring
 pub fn joker_activate_pulse() -> Effect {
-    let gold = Color::Rgb(255, 214, 10);
+    let gold = Theme::score_color();
     let bright = fx::fade_to_fg(gold, (120, Interpolation::QuadOut));
     let dim = fx::fade_from_fg(gold, (250, Interpolation::QuadIn));
     fx::sequence(&[bright, dim])
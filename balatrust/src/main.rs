@@ -1,8 +1,11 @@
 mod app;
 mod effects;
 mod screens;
+mod settings;
+mod storage;
 
 use std::io;
+use std::path::Path;
 use std::time::Duration;
 
 use crossterm::event::{self, Event, KeyEventKind};
@@ -13,20 +16,27 @@ use crossterm::terminal::{
 };
 use ratatui::prelude::*;
 
-use app::App;
+use app::{App, Recorder};
 
 fn main() -> color_eyre::Result<()> {
     color_eyre::install()?;
 
+    // `--replay <file>` rebuilds a run saved from the game-over screen
+    let mut args = std::env::args().skip(1);
+    let replay = match (args.next().as_deref(), args.next()) {
+        (Some("--replay"), Some(path)) => Some(Recorder::load_from_path(Path::new(&path))?),
+        _ => None,
+    };
+
     // Setup terminal
     enable_raw_mode()?;
     let mut stdout = io::stdout();
-    execute!(stdout, EnterAlternateScreen, EnableMouseCapture)?;
+    execute!(stdout, EnterAlternateScreen)?;
     let backend = CrosstermBackend::new(stdout);
     let mut terminal = Terminal::new(backend)?;
 
     // Run app
-    let result = run_app(&mut terminal);
+    let result = run_app(&mut terminal, replay.as_ref());
 
     // Restore terminal
     disable_raw_mode()?;
@@ -44,10 +54,27 @@ fn main() -> color_eyre::Result<()> {
     Ok(())
 }
 
-fn run_app(terminal: &mut Terminal<CrosstermBackend<io::Stdout>>) -> color_eyre::Result<()> {
+fn run_app(
+    terminal: &mut Terminal<CrosstermBackend<io::Stdout>>,
+    replay: Option<&Recorder>,
+) -> color_eyre::Result<()> {
     let mut app = App::new();
+    if let Some(recording) = replay {
+        app.replay(recording);
+    }
+    let mut mouse_captured = false;
 
     loop {
+        // Follow the mouse capture setting, which can change at runtime
+        if app.settings.mouse_capture != mouse_captured {
+            mouse_captured = app.settings.mouse_capture;
+            if mouse_captured {
+                execute!(terminal.backend_mut(), EnableMouseCapture)?;
+            } else {
+                execute!(terminal.backend_mut(), DisableMouseCapture)?;
+            }
+        }
+
         terminal.draw(|frame| {
             app.render(frame);
         })?;
@@ -55,12 +82,8 @@ fn run_app(terminal: &mut Terminal<CrosstermBackend<io::Stdout>>) -> color_eyre:
         // Poll with ~30fps tick for animations
         if event::poll(Duration::from_millis(33))? {
             match event::read()? {
-                Event::Key(key) => {
-                    if key.kind == KeyEventKind::Press {
-                        if app.handle_key(key) {
-                            break; // Quit signal
-                        }
-                    }
+                Event::Key(key) if key.kind == KeyEventKind::Press && app.handle_key(key) => {
+                    break; // Quit signal
                 }
                 Event::Mouse(mouse) => {
                     app.handle_mouse(mouse);
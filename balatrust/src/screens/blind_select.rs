@@ -6,17 +6,19 @@ use ratatui::widgets::Paragraph;
 use ratatui::Frame;
 
 use balatrust_core::blind::BlindType;
-use balatrust_core::run::BlindOutcome;
+use balatrust_core::run::{BlindOutcome, BOSS_REROLL_COST};
 use balatrust_core::RunState;
 use balatrust_widgets::blind_select::BlindSelectWidget;
 use balatrust_widgets::consumable_slots::ConsumableSlotsWidget;
-use balatrust_widgets::deck_viewer::DeckViewerState;
+use balatrust_widgets::deck_viewer::{DeckViewerState, PileCounts};
+use balatrust_widgets::help::{HelpContext, HelpState};
 use balatrust_widgets::joker_bar::JokerBarWidget;
-use balatrust_widgets::sidebar::SidebarWidget;
+use balatrust_widgets::run_info::RunInfoState;
+use balatrust_widgets::sidebar::{MoneyDisplay, SidebarWidget, SlotStats};
 use balatrust_widgets::theme::Theme;
 
 use crate::app::ScreenAction;
-use crate::screens::Screen;
+use crate::screens::{joker_edition_line, Screen};
 
 // ─── Constants ────────────────────────────────────────────────────────
 
@@ -38,6 +40,18 @@ pub struct BlindSelectScreen {
 
     // Deck viewer
     pub deck_viewer: DeckViewerState,
+
+    // Run info overlay
+    pub run_info: RunInfoState,
+
+    // Key binding help overlay
+    pub help: HelpState,
+
+    // Sidebar money (tweened by the app each tick)
+    pub money_display: MoneyDisplay,
+
+    /// Feedback shown in place of the help line, e.g. after a boss reroll
+    pub notice: Option<String>,
 }
 
 impl BlindSelectScreen {
@@ -51,23 +65,32 @@ impl BlindSelectScreen {
             panel_rect: Rect::default(),
             inspected_joker: None,
             deck_viewer: DeckViewerState::new(),
+            run_info: RunInfoState::new(),
+            help: HelpState::new(),
+            money_display: MoneyDisplay::default(),
+            notice: None,
         }
     }
 
+    /// True while the deck viewer filter is taking keystrokes
+    pub fn is_editing_filter(&self) -> bool {
+        self.deck_viewer.filter_editing
+    }
+
     // ─── Sidebar Data ─────────────────────────────────────────────────
 
     fn sidebar_data(&self, game: &RunState) -> SidebarWidget {
         let blind_color = match game.blind_type {
-            BlindType::Small => Theme::SMALL_BLIND,
-            BlindType::Big => Theme::BIG_BLIND,
-            BlindType::Boss(_) => Theme::BOSS_BLIND,
+            BlindType::Small => Theme::small_blind(),
+            BlindType::Big => Theme::big_blind(),
+            BlindType::Boss(_) => Theme::boss_blind(),
         };
 
         SidebarWidget::new(
             game.blind_type.name(),
             blind_color,
             game.score_target,
-            game.blind_type.reward(),
+            game.blind_reward(),
             game.round_score,
             String::new(), // No hand type on blind select
             1,
@@ -81,6 +104,9 @@ impl BlindSelectScreen {
             game.round_number(),
         )
         .blind_select(true)
+        .money_display(self.money_display)
+        .slots(SlotStats::from_run(game))
+        .stake(game.stake)
     }
 
     // ─── Rendering ────────────────────────────────────────────────────
@@ -122,8 +148,7 @@ impl BlindSelectScreen {
             self.deck_viewer.render_preview(
                 frame,
                 right_parts[1],
-                game.deck.total() + game.hand.len(),
-                game.deck.remaining(),
+                PileCounts::new(&game.deck, game.hand.len()),
             );
         }
 
@@ -138,6 +163,13 @@ impl BlindSelectScreen {
 
         // Deck viewer overlay
         self.deck_viewer.render_overlay(frame, area);
+
+        // Run info overlay
+        self.run_info
+            .render_overlay(frame, area, &game.hand_levels, &game.stats.hand_counts);
+
+        // Help overlay (above every other overlay)
+        self.help.render_overlay(frame, area);
     }
 
     fn render_center(&mut self, frame: &mut Frame, game: &RunState, center: Rect) {
@@ -158,7 +190,9 @@ impl BlindSelectScreen {
         let active_index = game.current_blind_index();
 
         let widget =
-            BlindSelectWidget::new(game.ante, game.boss_blind, self.cursor, game.blind_outcomes);
+            BlindSelectWidget::new(game.ante, game.boss_blind, self.cursor, game.blind_outcomes)
+                .skip_tags(game.skip_tags)
+                .stake(game.stake);
 
         // Cache hit-test rects
         for i in 0..3 {
@@ -169,19 +203,38 @@ impl BlindSelectScreen {
 
         frame.render_widget(widget, rows[1]);
 
-        // === Help line ===
+        // === Help line (or reroll feedback) ===
+        if let Some(notice) = &self.notice {
+            let line = Line::from(Span::styled(
+                notice.as_str(),
+                Style::default().fg(Theme::mult_color()),
+            ));
+            frame.render_widget(Paragraph::new(line).alignment(Alignment::Center), rows[2]);
+            return;
+        }
         let can_skip = active_index < 2; // Can skip Small/Big only
         let mut help_spans = vec![
-            Span::styled("[", Style::default().fg(Theme::DIM_TEXT)),
-            Span::styled("\u{2190}\u{2192}", Style::default().fg(Theme::GOLD)),
-            Span::styled("] Move  [", Style::default().fg(Theme::DIM_TEXT)),
-            Span::styled("Enter", Style::default().fg(Theme::GOLD)),
-            Span::styled("] Select", Style::default().fg(Theme::DIM_TEXT)),
+            Span::styled("[", Style::default().fg(Theme::dim_text())),
+            Span::styled("\u{2190}\u{2192}", Style::default().fg(Theme::gold())),
+            Span::styled("] Move  [", Style::default().fg(Theme::dim_text())),
+            Span::styled("Enter", Style::default().fg(Theme::gold())),
+            Span::styled("] Select", Style::default().fg(Theme::dim_text())),
         ];
         if can_skip {
-            help_spans.push(Span::styled("  [", Style::default().fg(Theme::DIM_TEXT)));
-            help_spans.push(Span::styled("S", Style::default().fg(Theme::GOLD)));
-            help_spans.push(Span::styled("] Skip", Style::default().fg(Theme::DIM_TEXT)));
+            help_spans.push(Span::styled("  [", Style::default().fg(Theme::dim_text())));
+            help_spans.push(Span::styled("S", Style::default().fg(Theme::gold())));
+            help_spans.push(Span::styled(
+                "] Skip",
+                Style::default().fg(Theme::dim_text()),
+            ));
+        }
+        if game.has_boss_reroll() {
+            help_spans.push(Span::styled("  [", Style::default().fg(Theme::dim_text())));
+            help_spans.push(Span::styled("R", Style::default().fg(Theme::gold())));
+            help_spans.push(Span::styled(
+                format!("] Reroll Boss ${}", BOSS_REROLL_COST),
+                Style::default().fg(Theme::dim_text()),
+            ));
         }
         let help = Paragraph::new(Line::from(help_spans)).alignment(Alignment::Center);
         frame.render_widget(help, rows[2]);
@@ -203,11 +256,11 @@ impl BlindSelectScreen {
         }
         frame.render_widget(joker_bar, parts[0]);
 
-        let counter = format!("{}/{}", game.jokers.len(), game.max_jokers);
+        let counter = format!("{}/{}", game.joker_slots_used(), game.max_jokers);
         frame.render_widget(
             Paragraph::new(Line::from(Span::styled(
                 counter,
-                Style::default().fg(Theme::MUTED_TEXT),
+                Style::default().fg(Theme::muted_text()),
             )))
             .alignment(Alignment::Center),
             parts[1],
@@ -234,7 +287,7 @@ impl BlindSelectScreen {
         };
 
         let jt = &joker.joker_type;
-        let name = jt.name();
+        let name = joker.name();
         let desc = jt.description();
         let rarity = jt.rarity();
         let sell_value = joker.total_sell_value();
@@ -246,24 +299,24 @@ impl BlindSelectScreen {
             balatrust_core::joker::JokerRarity::Legendary => "Legendary",
         };
         let rarity_color = match rarity {
-            balatrust_core::joker::JokerRarity::Common => Theme::COMMON,
-            balatrust_core::joker::JokerRarity::Uncommon => Theme::UNCOMMON,
-            balatrust_core::joker::JokerRarity::Rare => Theme::RARE,
-            balatrust_core::joker::JokerRarity::Legendary => Theme::LEGENDARY,
+            balatrust_core::joker::JokerRarity::Common => Theme::common(),
+            balatrust_core::joker::JokerRarity::Uncommon => Theme::uncommon(),
+            balatrust_core::joker::JokerRarity::Rare => Theme::rare(),
+            balatrust_core::joker::JokerRarity::Legendary => Theme::legendary(),
         };
 
-        let lines: Vec<Line> = vec![
+        let mut lines: Vec<Line> = vec![
             Line::from(Span::styled(
                 name,
                 Style::default()
-                    .fg(Theme::BRIGHT_TEXT)
+                    .fg(Theme::bright_text())
                     .add_modifier(Modifier::BOLD),
             )),
             Line::from(""),
-            Line::from(Span::styled(desc, Style::default().fg(Theme::MUTED_TEXT))),
+            Line::from(Span::styled(desc, Style::default().fg(Theme::muted_text()))),
             Line::from(""),
             Line::from(vec![
-                Span::styled("Rarity: ", Style::default().fg(Theme::DIM_TEXT)),
+                Span::styled("Rarity: ", Style::default().fg(Theme::dim_text())),
                 Span::styled(
                     rarity_str,
                     Style::default()
@@ -272,16 +325,26 @@ impl BlindSelectScreen {
                 ),
             ]),
             Line::from(vec![
-                Span::styled("Sell: ", Style::default().fg(Theme::DIM_TEXT)),
+                Span::styled("Sell: ", Style::default().fg(Theme::dim_text())),
                 Span::styled(
                     format!("${}", sell_value),
                     Style::default()
-                        .fg(Theme::MONEY_COLOR)
+                        .fg(Theme::money_color())
                         .add_modifier(Modifier::BOLD),
                 ),
             ]),
         ];
 
+        if let Some(line) = joker_edition_line(joker) {
+            lines.push(line);
+        }
+        if let Some(preview) = joker.live_preview(game.money) {
+            lines.push(Line::from(Span::styled(
+                preview,
+                Style::default().fg(Theme::mult_color()),
+            )));
+        }
+
         let content_width = lines.iter().map(|l| l.width() as u16).max().unwrap_or(10) + 4;
         let popup_width = content_width.clamp(20, 40);
         let popup_height = (lines.len() as u16) + 3;
@@ -333,7 +396,14 @@ impl BlindSelectScreen {
             None => return None,
         };
 
+        if self.deck_viewer.handle_mouse_scroll(mouse.kind) {
+            return None;
+        }
+
         if let MouseEventKind::Down(MouseButton::Left) = mouse.kind {
+            if self.help.handle_mouse_click() || self.run_info.handle_mouse_click() {
+                return None;
+            }
             let col = mouse.column;
             let row = mouse.row;
 
@@ -423,10 +493,22 @@ impl Screen for BlindSelectScreen {
     }
 
     fn handle_key(&mut self, key: KeyEvent) -> Option<ScreenAction> {
+        // '?' help sits above everything, including the deck viewer (unless typing a filter)
+        let help_context = if self.deck_viewer.open {
+            HelpContext::DeckViewer
+        } else {
+            HelpContext::BlindSelect
+        };
+        if !self.deck_viewer.filter_editing && self.help.handle_key(key.code, help_context) {
+            return None;
+        }
         // Deck viewer overlay intercepts all keys when open
         if self.deck_viewer.handle_key(key.code) {
             return None;
         }
+        if self.run_info.handle_key(key.code) {
+            return None;
+        }
 
         // Dismiss joker popup first
         if self.inspected_joker.is_some() {
@@ -436,16 +518,14 @@ impl Screen for BlindSelectScreen {
             return None;
         }
 
+        self.notice = None;
+
         match key.code {
-            KeyCode::Left | KeyCode::Char('h') => {
-                if self.cursor > 0 {
-                    self.cursor -= 1;
-                }
+            KeyCode::Left | KeyCode::Char('h') if self.cursor > 0 => {
+                self.cursor -= 1;
             }
-            KeyCode::Right | KeyCode::Char('l') => {
-                if self.cursor < 2 {
-                    self.cursor += 1;
-                }
+            KeyCode::Right | KeyCode::Char('l') if self.cursor < 2 => {
+                self.cursor += 1;
             }
             KeyCode::Enter | KeyCode::Char(' ') => {
                 return Some(ScreenAction::StartBlind);
@@ -453,9 +533,18 @@ impl Screen for BlindSelectScreen {
             KeyCode::Char('s') | KeyCode::Char('S') => {
                 return Some(ScreenAction::SkipBlind);
             }
+            KeyCode::Char('r') | KeyCode::Char('R') => {
+                return Some(ScreenAction::RerollBoss);
+            }
             KeyCode::Char('v') | KeyCode::Char('V') => {
                 return Some(ScreenAction::OpenDeckViewer);
             }
+            KeyCode::Char('i') | KeyCode::Char('I') => {
+                self.run_info.toggle();
+            }
+            KeyCode::Char('q') | KeyCode::Char('Q') | KeyCode::Esc => {
+                return Some(ScreenAction::Quit);
+            }
             _ => {}
         }
         None
@@ -1,8 +1,8 @@
 use crossterm::event::{KeyCode, KeyEvent};
-use ratatui::layout::{Alignment, Constraint, Layout};
+use ratatui::layout::{Alignment, Constraint, Layout, Rect};
 use ratatui::style::{Modifier, Style};
 use ratatui::text::{Line, Span};
-use ratatui::widgets::Paragraph;
+use ratatui::widgets::{Block, BorderType, Borders, Clear, Padding, Paragraph};
 use ratatui::Frame;
 
 use balatrust_core::RunState;
@@ -13,66 +13,152 @@ use crate::screens::Screen;
 
 pub struct GameOverScreen {
     pub won: bool,
+    /// Result of the last "save recap" request, shown under the card
+    pub recap_status: Option<String>,
 }
 
 impl GameOverScreen {
     pub fn new() -> Self {
-        Self { won: false }
+        Self {
+            won: false,
+            recap_status: None,
+        }
+    }
+
+    /// Recap and run stats cards side by side, centered in `area`
+    fn render_summary_cards(&self, frame: &mut Frame, game: &RunState, area: Rect) {
+        const GAP: u16 = 2;
+        let recap = game.recap_card_lines();
+        let stats = game.stats_card_lines();
+
+        let recap_width = card_width(&recap);
+        let stats_width = card_width(&stats);
+        let total = recap_width + GAP + stats_width;
+        let x = area.x + area.width.saturating_sub(total) / 2;
+
+        let recap_area = Rect::new(x, area.y, recap_width.min(area.width), area.height);
+        self.render_card(frame, &recap, recap_area);
+
+        let stats_x = x + recap_width + GAP;
+        if stats_x + stats_width <= area.x + area.width {
+            let stats_area = Rect::new(stats_x, area.y, stats_width, area.height);
+            self.render_card(frame, &stats, stats_area);
+        }
+    }
+
+    /// Bordered card sized to its content, at the top-left of `area`
+    fn render_card(&self, frame: &mut Frame, text: &[String], area: Rect) {
+        let border_color = if self.won {
+            Theme::gold()
+        } else {
+            Theme::mult_color()
+        };
+
+        let lines: Vec<Line> = text
+            .iter()
+            .enumerate()
+            .map(|(i, text)| {
+                let style = if i == 0 {
+                    Style::default()
+                        .fg(border_color)
+                        .add_modifier(Modifier::BOLD)
+                } else if text.starts_with("  ") {
+                    Style::default().fg(Theme::muted_text())
+                } else {
+                    Style::default().fg(Theme::bright_text())
+                };
+                Line::from(Span::styled(text.clone(), style))
+            })
+            .collect();
+
+        let height = (lines.len() as u16 + 2).min(area.height);
+        let card = Rect::new(area.x, area.y, area.width, height);
+
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .border_type(BorderType::Rounded)
+            .border_style(Style::default().fg(border_color))
+            .padding(Padding::horizontal(1));
+
+        frame.render_widget(Clear, card);
+        frame.render_widget(Paragraph::new(lines).block(block), card);
     }
 }
 
+/// Width of a card holding `text`, borders and padding included
+fn card_width(text: &[String]) -> u16 {
+    text.iter().map(|l| l.chars().count()).max().unwrap_or(0) as u16 + 4
+}
+
 impl Screen for GameOverScreen {
     fn render(&mut self, frame: &mut Frame, game: &Option<RunState>) {
         let area = frame.area();
 
         let chunks = Layout::vertical([
-            Constraint::Percentage(30),
-            Constraint::Length(10),
-            Constraint::Percentage(30),
-            Constraint::Min(3),
+            Constraint::Percentage(20),
+            Constraint::Length(2),  // Title
+            Constraint::Length(2),  // Outcome
+            Constraint::Length(16), // Recap + stats cards
+            Constraint::Length(3),  // Status + key hints
+            Constraint::Min(0),
         ])
         .split(area);
 
         let (title, title_color) = if self.won {
-            ("YOU WIN!", Theme::GOLD)
+            ("YOU WIN!", Theme::gold())
         } else {
-            ("GAME OVER", Theme::MULT_COLOR)
+            ("GAME OVER", Theme::mult_color())
         };
 
-        let mut lines = vec![
-            Line::from(Span::styled(
-                title,
-                Style::default()
-                    .fg(title_color)
-                    .add_modifier(Modifier::BOLD),
-            )),
-            Line::from(""),
-        ];
+        let title = Paragraph::new(Line::from(Span::styled(
+            title,
+            Style::default()
+                .fg(title_color)
+                .add_modifier(Modifier::BOLD),
+        )))
+        .alignment(Alignment::Center);
+        frame.render_widget(title, chunks[1]);
 
         if let Some(game) = game {
-            lines.push(Line::from(Span::styled(
-                format!("Ante: {}", game.ante),
-                Style::default().fg(Theme::BRIGHT_TEXT),
-            )));
-            lines.push(Line::from(Span::styled(
-                format!("Money: ${}", game.money),
-                Style::default().fg(Theme::MONEY_COLOR),
-            )));
+            let outcome = Paragraph::new(Line::from(Span::styled(
+                game.outcome_line(),
+                Style::default().fg(Theme::bright_text()),
+            )))
+            .alignment(Alignment::Center);
+            frame.render_widget(outcome, chunks[2]);
+            self.render_summary_cards(frame, game, chunks[3]);
         }
 
-        lines.push(Line::from(""));
-        lines.push(Line::from(Span::styled(
-            "[Enter] New Game  [Q] Quit",
-            Style::default().fg(Theme::GOLD),
+        let mut footer = Vec::new();
+        if let Some(status) = &self.recap_status {
+            footer.push(Line::from(Span::styled(
+                status.as_str(),
+                Style::default().fg(Theme::muted_text()),
+            )));
+        }
+        footer.push(Line::from(""));
+        let endless = if self.won { "[E] Endless  " } else { "" };
+        footer.push(Line::from(Span::styled(
+            format!(
+                "{}[Enter] New Game  [R] Restart Seed  [N] New Seed  [C] Save Recap  [P] Save Replay  [Q] Quit",
+                endless
+            ),
+            Style::default().fg(Theme::gold()),
         )));
-
-        let content = Paragraph::new(lines).alignment(Alignment::Center);
-        frame.render_widget(content, chunks[1]);
+        let footer = Paragraph::new(footer).alignment(Alignment::Center);
+        frame.render_widget(footer, chunks[4]);
     }
 
     fn handle_key(&mut self, key: KeyEvent) -> Option<ScreenAction> {
         match key.code {
             KeyCode::Enter => Some(ScreenAction::NewGame),
+            KeyCode::Char('r') | KeyCode::Char('R') => Some(ScreenAction::RestartRun),
+            KeyCode::Char('n') | KeyCode::Char('N') => Some(ScreenAction::NewSeedRun),
+            KeyCode::Char('c') | KeyCode::Char('C') => Some(ScreenAction::SaveRecap),
+            KeyCode::Char('p') | KeyCode::Char('P') => Some(ScreenAction::SaveReplay),
+            KeyCode::Char('e') | KeyCode::Char('E') if self.won => {
+                Some(ScreenAction::ContinueEndless)
+            }
             KeyCode::Char('q') | KeyCode::Char('Q') => Some(ScreenAction::Quit),
             KeyCode::Esc => Some(ScreenAction::BackToMenu),
             _ => None,
@@ -5,19 +5,215 @@ use ratatui::text::{Line, Span};
 use ratatui::widgets::Paragraph;
 use ratatui::Frame;
 
-use balatrust_core::RunState;
-use balatrust_widgets::theme::Theme;
+use balatrust_core::run::{normalize_seed_string, MAX_SEED_LEN};
+use balatrust_core::{BossBlind, DeckType, RunState, Stake};
+use balatrust_widgets::theme::{ColorScheme, Theme};
 
 use crate::app::ScreenAction;
 use crate::screens::Screen;
+use crate::settings::AnimationSpeed;
+
+/// Menu rows, top to bottom
+const MENU_CONTINUE: usize = 0;
+const MENU_NEW_GAME: usize = 1;
+const MENU_DECK: usize = 2;
+const MENU_STAKE: usize = 3;
+const MENU_SEEDED: usize = 4;
+const MENU_PRACTICE: usize = 5;
+const MENU_SANDBOX: usize = 6;
+const MENU_SPEED: usize = 7;
+const MENU_CONFIRM_LEAVE: usize = 8;
+const MENU_MOUSE: usize = 9;
+const MENU_LAST_HAND_WARNING: usize = 10;
+const MENU_COLOR_SCHEME: usize = 11;
+const MENU_ACCESSIBLE_SUITS: usize = 12;
+const MENU_PLAY_HINTS: usize = 13;
+const MENU_QUIT: usize = 14;
+
+/// Highest ante selectable for boss practice
+const MAX_PRACTICE_ANTE: u8 = 8;
 
 pub struct MainMenuScreen {
     pub selected: usize,
+    /// Index into `BossBlind::ALL` for the practice launcher
+    pub practice_boss: usize,
+    pub practice_ante: u8,
+    /// Seed for the practice run, so a setup can be replayed exactly
+    pub practice_seed: u64,
+    /// Whether a saved run exists (enables "Continue")
+    has_save: bool,
+    /// Shown under the menu, e.g. when loading the save failed
+    pub status: Option<String>,
+    /// Seed text typed for a seeded run
+    pub seed_input: String,
+    /// Whether keystrokes currently go into `seed_input`
+    editing_seed: bool,
+    /// Current scoring animation speed (mirrors the app settings)
+    pub animation_speed: AnimationSpeed,
+    /// Whether leaving a shop with affordable items asks first (mirrors the app settings)
+    pub confirm_leave_shop: bool,
+    /// Whether clicks are captured, or the keyboard is the only input (mirrors the app settings)
+    pub mouse_capture: bool,
+    /// Whether the round warns when the last hand falls short (mirrors the app settings)
+    pub last_hand_warning: bool,
+    /// Active color scheme (mirrors the app settings)
+    pub color_scheme: ColorScheme,
+    /// Whether suits get their own colors and letters (mirrors the app settings)
+    pub accessible_suits: bool,
+    /// Whether the round outlines the best play (mirrors the app settings)
+    pub play_hints: bool,
+    /// Starting deck for new and seeded runs
+    pub deck_type: DeckType,
+    /// Stake for new and seeded runs
+    pub stake: Stake,
 }
 
 impl MainMenuScreen {
-    pub fn new() -> Self {
-        Self { selected: 0 }
+    pub fn new(has_save: bool) -> Self {
+        let mut menu = Self {
+            selected: MENU_NEW_GAME,
+            practice_boss: 0,
+            practice_ante: 1,
+            practice_seed: 1,
+            has_save: false,
+            status: None,
+            seed_input: String::new(),
+            editing_seed: false,
+            animation_speed: AnimationSpeed::Normal,
+            confirm_leave_shop: true,
+            mouse_capture: true,
+            last_hand_warning: true,
+            color_scheme: ColorScheme::Dark,
+            accessible_suits: false,
+            play_hints: false,
+            deck_type: DeckType::default(),
+            stake: Stake::default(),
+        };
+        menu.set_has_save(has_save);
+        menu
+    }
+
+    /// Update save availability, moving the cursor to "Continue" when one appears
+    pub fn set_has_save(&mut self, has_save: bool) {
+        self.has_save = has_save;
+        self.selected = if has_save {
+            MENU_CONTINUE
+        } else {
+            MENU_NEW_GAME
+        };
+    }
+
+    /// Topmost selectable row ("Continue" is skipped without a save)
+    fn first_row(&self) -> usize {
+        if self.has_save {
+            MENU_CONTINUE
+        } else {
+            MENU_NEW_GAME
+        }
+    }
+
+    /// True while the player is typing a seed (global hotkeys must not fire)
+    pub fn is_editing_seed(&self) -> bool {
+        self.editing_seed
+    }
+
+    fn seeded_label(&self) -> String {
+        let shown = if self.seed_input.is_empty() && !self.editing_seed {
+            "random".to_string()
+        } else {
+            format!("{:_<width$}", self.seed_input, width = MAX_SEED_LEN)
+        };
+        let cursor = if self.editing_seed { "\u{258f}" } else { "" };
+        format!("Seeded Run: [{}{}]", shown, cursor)
+    }
+
+    fn handle_seed_key(&mut self, key: KeyEvent) -> Option<ScreenAction> {
+        match key.code {
+            KeyCode::Enter => {
+                self.editing_seed = false;
+                return Some(ScreenAction::SeededGame(self.seed_input.clone()));
+            }
+            KeyCode::Esc => self.editing_seed = false,
+            KeyCode::Backspace => {
+                self.seed_input.pop();
+            }
+            KeyCode::Char(c) => {
+                let mut text = self.seed_input.clone();
+                text.push(c);
+                self.seed_input = normalize_seed_string(&text);
+            }
+            _ => {}
+        }
+        None
+    }
+
+    fn deck_label(&self) -> String {
+        format!(
+            "Deck: \u{25c2} {} \u{25b8}  {}",
+            self.deck_type.name(),
+            self.deck_type.description()
+        )
+    }
+
+    fn stake_label(&self) -> String {
+        format!(
+            "Stake: \u{25c2} {} \u{25b8}  {}",
+            self.stake.name(),
+            self.stake.description()
+        )
+    }
+
+    fn speed_label(&self) -> String {
+        format!(
+            "Animation Speed: \u{25c2} {} \u{25b8}",
+            self.animation_speed.label()
+        )
+    }
+
+    fn confirm_leave_label(&self) -> String {
+        let state = if self.confirm_leave_shop { "On" } else { "Off" };
+        format!("Confirm Leaving Shop: \u{25c2} {} \u{25b8}", state)
+    }
+
+    fn mouse_label(&self) -> String {
+        let state = if self.mouse_capture {
+            "On"
+        } else {
+            "Off (keyboard only)"
+        };
+        format!("Mouse: \u{25c2} {} \u{25b8}", state)
+    }
+
+    fn last_hand_warning_label(&self) -> String {
+        let state = if self.last_hand_warning { "On" } else { "Off" };
+        format!("Last Hand Warning: \u{25c2} {} \u{25b8}", state)
+    }
+
+    fn color_scheme_label(&self) -> String {
+        format!("Theme: \u{25c2} {} \u{25b8}", self.color_scheme.label())
+    }
+
+    fn accessible_suits_label(&self) -> String {
+        let state = if self.accessible_suits {
+            "On (\u{2660}S \u{2665}H \u{2666}D \u{2663}C)"
+        } else {
+            "Off"
+        };
+        format!("Accessible Suits: \u{25c2} {} \u{25b8}", state)
+    }
+
+    fn play_hints_label(&self) -> String {
+        let state = if self.play_hints { "On" } else { "Off" };
+        format!("Play Hints: \u{25c2} {} \u{25b8}", state)
+    }
+
+    fn practice_label(&self) -> String {
+        format!(
+            "Practice: \u{25c2} {} \u{25b8}  Ante {}  Seed {}",
+            BossBlind::ALL[self.practice_boss],
+            self.practice_ante,
+            self.practice_seed
+        )
     }
 }
 
@@ -38,37 +234,37 @@ impl Screen for MainMenuScreen {
             Line::from(Span::styled(
                 " ____        _       _                   _   ",
                 Style::default()
-                    .fg(Theme::GOLD)
+                    .fg(Theme::gold())
                     .add_modifier(Modifier::BOLD),
             )),
             Line::from(Span::styled(
                 "| __ )  __ _| | __ _| |_ _ __ _   _ ___| |_ ",
                 Style::default()
-                    .fg(Theme::GOLD)
+                    .fg(Theme::gold())
                     .add_modifier(Modifier::BOLD),
             )),
             Line::from(Span::styled(
                 "|  _ \\ / _` | |/ _` | __| '__| | | / __| __|",
                 Style::default()
-                    .fg(Theme::GOLD)
+                    .fg(Theme::gold())
                     .add_modifier(Modifier::BOLD),
             )),
             Line::from(Span::styled(
                 "| |_) | (_| | | (_| | |_| |  | |_| \\__ \\ |_ ",
                 Style::default()
-                    .fg(Theme::GOLD)
+                    .fg(Theme::gold())
                     .add_modifier(Modifier::BOLD),
             )),
             Line::from(Span::styled(
                 "|____/ \\__,_|_|\\__,_|\\__|_|   \\__,_|___/\\__|",
                 Style::default()
-                    .fg(Theme::GOLD)
+                    .fg(Theme::gold())
                     .add_modifier(Modifier::BOLD),
             )),
             Line::from(""),
             Line::from(Span::styled(
                 "A terminal Balatro experience",
-                Style::default().fg(Theme::MUTED_TEXT),
+                Style::default().fg(Theme::muted_text()),
             )),
         ];
 
@@ -76,15 +272,33 @@ impl Screen for MainMenuScreen {
         frame.render_widget(title, chunks[1]);
 
         // Menu options
-        let menu_items = vec!["New Game", "Quit"];
+        let menu_items = [
+            "Continue".to_string(),
+            "New Game".to_string(),
+            self.deck_label(),
+            self.stake_label(),
+            self.seeded_label(),
+            self.practice_label(),
+            "Sandbox: Build a Deck".to_string(),
+            self.speed_label(),
+            self.confirm_leave_label(),
+            self.mouse_label(),
+            self.last_hand_warning_label(),
+            self.color_scheme_label(),
+            self.accessible_suits_label(),
+            self.play_hints_label(),
+            "Quit".to_string(),
+        ];
         let mut menu_lines = Vec::new();
         for (i, item) in menu_items.iter().enumerate() {
-            let style = if i == self.selected {
+            let style = if i == MENU_CONTINUE && !self.has_save {
+                Style::default().fg(Theme::dim_text())
+            } else if i == self.selected {
                 Style::default()
-                    .fg(Theme::CARD_SELECTED)
+                    .fg(Theme::card_selected())
                     .add_modifier(Modifier::BOLD)
             } else {
-                Style::default().fg(Theme::MUTED_TEXT)
+                Style::default().fg(Theme::muted_text())
             };
             let prefix = if i == self.selected { "> " } else { "  " };
             menu_lines.push(Line::from(Span::styled(
@@ -93,39 +307,171 @@ impl Screen for MainMenuScreen {
             )));
         }
 
+        if let Some(status) = &self.status {
+            menu_lines.push(Line::from(""));
+            menu_lines.push(Line::from(Span::styled(
+                status.clone(),
+                Style::default().fg(Theme::mult_color()),
+            )));
+        }
+
         let menu = Paragraph::new(menu_lines).alignment(Alignment::Center);
         frame.render_widget(menu, chunks[2]);
 
         // Footer
         let footer = Paragraph::new(Line::from(vec![
-            Span::styled("[", Style::default().fg(Theme::DIM_TEXT)),
-            Span::styled("\u{2191}\u{2193}", Style::default().fg(Theme::GOLD)),
-            Span::styled("] Navigate  [", Style::default().fg(Theme::DIM_TEXT)),
-            Span::styled("Enter", Style::default().fg(Theme::GOLD)),
-            Span::styled("] Select  [", Style::default().fg(Theme::DIM_TEXT)),
-            Span::styled("q", Style::default().fg(Theme::GOLD)),
-            Span::styled("] Quit", Style::default().fg(Theme::DIM_TEXT)),
+            Span::styled("[", Style::default().fg(Theme::dim_text())),
+            Span::styled("\u{2191}\u{2193}", Style::default().fg(Theme::gold())),
+            Span::styled("] Navigate  [", Style::default().fg(Theme::dim_text())),
+            Span::styled("Enter", Style::default().fg(Theme::gold())),
+            Span::styled("] Select  [", Style::default().fg(Theme::dim_text())),
+            Span::styled("\u{2190}\u{2192} +/- s", Style::default().fg(Theme::gold())),
+            Span::styled(
+                "] Deck / stake / practice setup / options  [",
+                Style::default().fg(Theme::dim_text()),
+            ),
+            Span::styled("q", Style::default().fg(Theme::gold())),
+            Span::styled("] Quit", Style::default().fg(Theme::dim_text())),
         ]))
         .alignment(Alignment::Center);
         frame.render_widget(footer, chunks[3]);
     }
 
     fn handle_key(&mut self, key: KeyEvent) -> Option<ScreenAction> {
+        if self.editing_seed {
+            return self.handle_seed_key(key);
+        }
+
         match key.code {
-            KeyCode::Up | KeyCode::Char('k') => {
-                if self.selected > 0 {
-                    self.selected -= 1;
-                }
+            KeyCode::Up | KeyCode::Char('k') if self.selected > self.first_row() => {
+                self.selected -= 1;
+            }
+            KeyCode::Down | KeyCode::Char('j') if self.selected < MENU_QUIT => {
+                self.selected += 1;
+            }
+            KeyCode::Left | KeyCode::Char('h') if self.selected == MENU_PRACTICE => {
+                let len = BossBlind::ALL.len();
+                self.practice_boss = (self.practice_boss + len - 1) % len;
+            }
+            KeyCode::Right | KeyCode::Char('l') if self.selected == MENU_PRACTICE => {
+                self.practice_boss = (self.practice_boss + 1) % BossBlind::ALL.len();
+            }
+            KeyCode::Left | KeyCode::Char('h') | KeyCode::Right | KeyCode::Char('l')
+                if self.selected == MENU_DECK =>
+            {
+                let forward = matches!(key.code, KeyCode::Right | KeyCode::Char('l'));
+                self.deck_type = self.deck_type.cycle(forward);
+            }
+            KeyCode::Left | KeyCode::Char('h') | KeyCode::Right | KeyCode::Char('l')
+                if self.selected == MENU_STAKE =>
+            {
+                let forward = matches!(key.code, KeyCode::Right | KeyCode::Char('l'));
+                self.stake = self.stake.cycle(forward);
+            }
+            KeyCode::Left | KeyCode::Char('h') | KeyCode::Right | KeyCode::Char('l')
+                if self.selected == MENU_SPEED =>
+            {
+                let forward = matches!(key.code, KeyCode::Right | KeyCode::Char('l'));
+                self.animation_speed = self.animation_speed.cycle(forward);
+                return Some(ScreenAction::SetAnimationSpeed(self.animation_speed));
+            }
+            KeyCode::Left | KeyCode::Char('h') | KeyCode::Right | KeyCode::Char('l')
+                if self.selected == MENU_CONFIRM_LEAVE =>
+            {
+                self.confirm_leave_shop = !self.confirm_leave_shop;
+                return Some(ScreenAction::SetConfirmLeaveShop(self.confirm_leave_shop));
+            }
+            KeyCode::Left | KeyCode::Char('h') | KeyCode::Right | KeyCode::Char('l')
+                if self.selected == MENU_MOUSE =>
+            {
+                self.mouse_capture = !self.mouse_capture;
+                return Some(ScreenAction::SetMouseCapture(self.mouse_capture));
+            }
+            KeyCode::Left | KeyCode::Char('h') | KeyCode::Right | KeyCode::Char('l')
+                if self.selected == MENU_LAST_HAND_WARNING =>
+            {
+                self.last_hand_warning = !self.last_hand_warning;
+                return Some(ScreenAction::SetLastHandWarning(self.last_hand_warning));
+            }
+            KeyCode::Left | KeyCode::Char('h') | KeyCode::Right | KeyCode::Char('l')
+                if self.selected == MENU_COLOR_SCHEME =>
+            {
+                let forward = matches!(key.code, KeyCode::Right | KeyCode::Char('l'));
+                self.color_scheme = self.color_scheme.cycle(forward);
+                return Some(ScreenAction::SetColorScheme(self.color_scheme));
+            }
+            KeyCode::Left | KeyCode::Char('h') | KeyCode::Right | KeyCode::Char('l')
+                if self.selected == MENU_ACCESSIBLE_SUITS =>
+            {
+                self.accessible_suits = !self.accessible_suits;
+                return Some(ScreenAction::SetAccessibleSuits(self.accessible_suits));
+            }
+            KeyCode::Left | KeyCode::Char('h') | KeyCode::Right | KeyCode::Char('l')
+                if self.selected == MENU_PLAY_HINTS =>
+            {
+                self.play_hints = !self.play_hints;
+                return Some(ScreenAction::SetPlayHints(self.play_hints));
+            }
+            KeyCode::Char('+') | KeyCode::Char('=') if self.selected == MENU_PRACTICE => {
+                self.practice_ante = (self.practice_ante + 1).min(MAX_PRACTICE_ANTE);
+            }
+            KeyCode::Char('-') if self.selected == MENU_PRACTICE => {
+                self.practice_ante = self.practice_ante.saturating_sub(1).max(1);
             }
-            KeyCode::Down | KeyCode::Char('j') => {
-                if self.selected < 1 {
-                    self.selected += 1;
-                }
+            KeyCode::Char('s') if self.selected == MENU_PRACTICE => {
+                self.practice_seed = self.practice_seed.wrapping_add(1);
             }
             KeyCode::Enter => {
                 return match self.selected {
-                    0 => Some(ScreenAction::NewGame),
-                    1 => Some(ScreenAction::Quit),
+                    MENU_CONTINUE => Some(ScreenAction::ContinueRun),
+                    MENU_NEW_GAME => Some(ScreenAction::NewGame),
+                    MENU_DECK => {
+                        self.deck_type = self.deck_type.cycle(true);
+                        None
+                    }
+                    MENU_STAKE => {
+                        self.stake = self.stake.cycle(true);
+                        None
+                    }
+                    MENU_SEEDED => {
+                        self.editing_seed = true;
+                        None
+                    }
+                    MENU_PRACTICE => Some(ScreenAction::PracticeBoss {
+                        seed: self.practice_seed,
+                        ante: self.practice_ante,
+                        boss: BossBlind::ALL[self.practice_boss],
+                    }),
+                    MENU_SANDBOX => Some(ScreenAction::OpenSandbox),
+                    MENU_SPEED => {
+                        self.animation_speed = self.animation_speed.cycle(true);
+                        Some(ScreenAction::SetAnimationSpeed(self.animation_speed))
+                    }
+                    MENU_CONFIRM_LEAVE => {
+                        self.confirm_leave_shop = !self.confirm_leave_shop;
+                        Some(ScreenAction::SetConfirmLeaveShop(self.confirm_leave_shop))
+                    }
+                    MENU_MOUSE => {
+                        self.mouse_capture = !self.mouse_capture;
+                        Some(ScreenAction::SetMouseCapture(self.mouse_capture))
+                    }
+                    MENU_LAST_HAND_WARNING => {
+                        self.last_hand_warning = !self.last_hand_warning;
+                        Some(ScreenAction::SetLastHandWarning(self.last_hand_warning))
+                    }
+                    MENU_COLOR_SCHEME => {
+                        self.color_scheme = self.color_scheme.cycle(true);
+                        Some(ScreenAction::SetColorScheme(self.color_scheme))
+                    }
+                    MENU_ACCESSIBLE_SUITS => {
+                        self.accessible_suits = !self.accessible_suits;
+                        Some(ScreenAction::SetAccessibleSuits(self.accessible_suits))
+                    }
+                    MENU_PLAY_HINTS => {
+                        self.play_hints = !self.play_hints;
+                        Some(ScreenAction::SetPlayHints(self.play_hints))
+                    }
+                    MENU_QUIT => Some(ScreenAction::Quit),
                     _ => None,
                 };
             }
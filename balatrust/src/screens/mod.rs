@@ -2,16 +2,35 @@ pub mod blind_select;
 pub mod game_over;
 pub mod main_menu;
 pub mod play_round;
+pub mod sandbox;
 pub mod shop;
 
 use crossterm::event::KeyEvent;
+use ratatui::style::{Modifier, Style};
+use ratatui::text::{Line, Span};
 use ratatui::Frame;
 
 use crate::app::ScreenAction;
+use balatrust_core::joker::Joker;
 use balatrust_core::RunState;
+use balatrust_widgets::theme::Theme;
 
 /// Trait for game screens
 pub trait Screen {
     fn render(&mut self, frame: &mut Frame, game: &Option<RunState>);
     fn handle_key(&mut self, key: KeyEvent) -> Option<ScreenAction>;
 }
+
+/// "Edition: Foil (+50 Chips)" for inspect popups, if the joker has one
+pub(crate) fn joker_edition_line(joker: &Joker) -> Option<Line<'static>> {
+    let effect = joker.edition.joker_effect()?;
+    Some(Line::from(vec![
+        Span::styled("Edition: ", Style::default().fg(Theme::dim_text())),
+        Span::styled(
+            format!("{} ({})", joker.edition.name(), effect),
+            Style::default()
+                .fg(Theme::bright_text())
+                .add_modifier(Modifier::BOLD),
+        ),
+    ]))
+}
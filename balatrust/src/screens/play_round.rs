@@ -6,23 +6,29 @@ use ratatui::widgets::{Block, Borders, Paragraph};
 use ratatui::Frame;
 
 use balatrust_core::blind::BlindType;
-use balatrust_core::hand::detect_hand;
+use balatrust_core::format::format_number;
+use balatrust_core::run::RewardBreakdown;
 use balatrust_core::scoring::{ScoreResult, ScoreStep};
-use balatrust_core::PlayingCard;
 use balatrust_core::RunState;
+use balatrust_core::{Joker, PlayingCard};
 use balatrust_widgets::action_buttons::{ActionButtonsWidget, ButtonHit};
-use balatrust_widgets::cashout_panel::CashOutPanel;
+use balatrust_widgets::cashout_panel::{CashOutPanel, CashOutTally};
 use balatrust_widgets::consumable_slots::ConsumableSlotsWidget;
-use balatrust_widgets::deck_viewer::DeckViewerState;
+use balatrust_widgets::deck_viewer::{DeckViewerState, PileCounts};
 use balatrust_widgets::hand::HandWidget;
+use balatrust_widgets::help::{HelpContext, HelpState};
 use balatrust_widgets::joker_bar::JokerBarWidget;
 use balatrust_widgets::played_cards::PlayedCardsWidget;
+use balatrust_widgets::run_info::RunInfoState;
+use balatrust_widgets::score_log::ScoreLogState;
 use balatrust_widgets::score_popup::{ScorePopup, ScorePopupKind};
-use balatrust_widgets::sidebar::SidebarWidget;
+use balatrust_widgets::sidebar::{MoneyDisplay, SidebarWidget, SlotStats};
 use balatrust_widgets::theme::Theme;
 
 use crate::app::ScreenAction;
 use crate::effects::FxManager;
+use crate::screens::joker_edition_line;
+use crate::settings::AnimationSpeed;
 
 // ─── Constants ────────────────────────────────────────────────────────
 
@@ -50,7 +56,7 @@ enum ScoringPhase {
     Done,
 }
 
-/// Ticks per animation phase
+/// Ticks per animation phase at 1x speed
 const TICKS_SHOW_PLAYED: u8 = 10;
 const TICKS_SHOW_HAND_TYPE: u8 = 12;
 const TICKS_PER_STEP: u8 = 8;
@@ -91,8 +97,38 @@ pub struct PlayRoundScreen {
     action_buttons_rect: Rect,
     /// Cached rect for the cash-out panel area (for mouse hit-testing in recap mode)
     cashout_panel_rect: Rect,
+    /// Reward lines counting up on the cash-out panel (recap mode)
+    cashout_tally: Option<CashOutTally>,
     /// Deck viewer state (preview + overlay)
     pub deck_viewer: DeckViewerState,
+    /// Poker hand levels overlay
+    pub run_info: RunInfoState,
+    /// Recently scored hands overlay
+    pub score_log: ScoreLogState,
+    /// Full key binding overlay ('?')
+    pub help: HelpState,
+    /// Sidebar money (tweened by the app each tick)
+    pub money_display: MoneyDisplay,
+    /// App tick, for the pulsing boss banner
+    pub tick: u64,
+    /// Scoring animation speed (from settings)
+    pub animation_speed: AnimationSpeed,
+    /// Warn when the last hand's selection can't beat the blind (from settings)
+    pub last_hand_warning: bool,
+    /// Outline the cards that would score the most (mirrors the app settings)
+    pub play_hints: bool,
+    /// Hand and jokers the last play hint was searched for, and its suggestion
+    hint_cache: Option<(Vec<PlayingCard>, Vec<Joker>, Vec<usize>)>,
+    /// Consumable slot picked for use (Tab or click)
+    pub consumable_cursor: Option<usize>,
+    /// Cards in hand (updated each tick, for number-key selection)
+    pub hand_len: usize,
+    /// Consumables held (updated each tick, for Tab cycling)
+    pub consumable_count: usize,
+    /// Cached consumable slot rects (for mouse hit-testing)
+    consumable_rects: Vec<Rect>,
+    /// One-line feedback shown above the hand, cleared on the next key press
+    pub notice: Option<String>,
 }
 
 impl PlayRoundScreen {
@@ -117,7 +153,22 @@ impl PlayRoundScreen {
             inspected_joker: None,
             action_buttons_rect: Rect::default(),
             cashout_panel_rect: Rect::default(),
+            cashout_tally: None,
             deck_viewer: DeckViewerState::new(),
+            run_info: RunInfoState::new(),
+            score_log: ScoreLogState::new(),
+            help: HelpState::new(),
+            money_display: MoneyDisplay::default(),
+            tick: 0,
+            animation_speed: AnimationSpeed::Normal,
+            last_hand_warning: true,
+            play_hints: false,
+            hint_cache: None,
+            consumable_cursor: None,
+            hand_len: 0,
+            consumable_count: 0,
+            consumable_rects: Vec::new(),
+            notice: None,
         }
     }
 
@@ -141,7 +192,45 @@ impl PlayRoundScreen {
         self.inspected_joker = None;
         self.action_buttons_rect = Rect::default();
         self.cashout_panel_rect = Rect::default();
+        self.cashout_tally = None;
         self.deck_viewer = DeckViewerState::new();
+        self.run_info = RunInfoState::new();
+        self.score_log = ScoreLogState::new();
+        self.help = HelpState::new();
+        self.consumable_cursor = None;
+        self.consumable_rects.clear();
+        self.notice = None;
+        self.hint_cache = None;
+    }
+
+    /// Enter recap mode and start tallying the reward breakdown
+    pub fn start_cashout(&mut self, breakdown: &RewardBreakdown) {
+        self.blind_just_beaten = true;
+        self.cashout_tally = Some(CashOutTally::new(breakdown));
+    }
+
+    /// True while the cash-out lines are still counting up
+    fn is_tallying(&self) -> bool {
+        self.cashout_tally.as_ref().is_some_and(|t| !t.is_done())
+    }
+
+    /// Leave recap mode once the reward has been collected
+    fn cash_out(&mut self) -> Option<ScreenAction> {
+        self.blind_just_beaten = false;
+        self.cashout_tally = None;
+        self.last_score = None;
+        Some(ScreenAction::BeatBlind)
+    }
+
+    /// True while the deck viewer filter is taking keystrokes
+    pub fn is_editing_filter(&self) -> bool {
+        self.deck_viewer.filter_editing
+    }
+
+    /// Search for the best play again on the next frame. Planets, money and
+    /// held consumables change what scores best without touching the hand.
+    pub fn invalidate_hint(&mut self) {
+        self.hint_cache = None;
     }
 
     /// Returns true if we're currently in a scoring animation
@@ -162,6 +251,18 @@ impl PlayRoundScreen {
         self.popup = None;
     }
 
+    /// Drop an in-progress scoring animation without applying it (undo)
+    pub fn cancel_scoring(&mut self) {
+        self.scoring_phase = ScoringPhase::NotScoring;
+        self.scoring_result = None;
+        self.played_cards.clear();
+        self.anim_chips = 0;
+        self.anim_mult = 0.0;
+        self.active_card_index = None;
+        self.active_joker_index = None;
+        self.popup = None;
+    }
+
     /// Start the scoring animation with the given result and played cards
     pub fn start_scoring(&mut self, result: ScoreResult, played_cards: Vec<PlayingCard>) {
         self.anim_hand_name = format!("{}", result.hand_type);
@@ -171,12 +272,19 @@ impl PlayRoundScreen {
         self.active_card_index = None;
         self.active_joker_index = None;
         self.popup = None;
+        // Scoring takes over the joker bar; close any inspect popup
+        self.inspected_joker = None;
         self.scoring_result = Some(result);
         self.scoring_phase = ScoringPhase::ShowingPlayedCards {
-            timer: TICKS_SHOW_PLAYED,
+            timer: self.ticks(TICKS_SHOW_PLAYED),
         };
     }
 
+    /// Length of an animation phase at the current speed
+    fn ticks(&self, base: u8) -> u8 {
+        self.animation_speed.scale_ticks(base)
+    }
+
     /// Skip the entire animation and jump to Done
     fn skip_animation(&mut self) {
         if let Some(result) = &self.scoring_result {
@@ -192,6 +300,11 @@ impl PlayRoundScreen {
     /// Advance the scoring state machine by one tick.
     /// Returns Some(ScreenAction::FinishScoring) when animation is complete.
     pub fn tick_scoring(&mut self, fx: &mut FxManager) -> Option<ScreenAction> {
+        if self.animation_speed == AnimationSpeed::Instant && self.is_scoring() {
+            self.skip_animation();
+            return Some(ScreenAction::FinishScoring);
+        }
+
         let phase = self.scoring_phase.clone();
 
         match phase {
@@ -200,7 +313,7 @@ impl PlayRoundScreen {
             ScoringPhase::ShowingPlayedCards { timer } => {
                 if timer == 0 {
                     self.scoring_phase = ScoringPhase::ShowingHandType {
-                        timer: TICKS_SHOW_HAND_TYPE,
+                        timer: self.ticks(TICKS_SHOW_HAND_TYPE),
                     };
                 } else {
                     self.scoring_phase = ScoringPhase::ShowingPlayedCards { timer: timer - 1 };
@@ -221,15 +334,15 @@ impl PlayRoundScreen {
                     let has_more = self
                         .scoring_result
                         .as_ref()
-                        .map_or(false, |r| next_index < r.steps.len());
+                        .is_some_and(|r| next_index < r.steps.len());
                     if has_more {
                         self.scoring_phase = ScoringPhase::ScoringStep {
                             step_index: next_index,
-                            timer: TICKS_PER_STEP,
+                            timer: self.ticks(TICKS_PER_STEP),
                         };
                     } else {
                         self.scoring_phase = ScoringPhase::FinalScore {
-                            timer: TICKS_FINAL_SCORE,
+                            timer: self.ticks(TICKS_FINAL_SCORE),
                         };
                     }
                 } else {
@@ -239,7 +352,7 @@ impl PlayRoundScreen {
             }
 
             ScoringPhase::ScoringStep { step_index, timer } => {
-                if timer == TICKS_PER_STEP {
+                if timer == self.ticks(TICKS_PER_STEP) {
                     self.apply_step(step_index, fx);
                 }
 
@@ -252,15 +365,15 @@ impl PlayRoundScreen {
                     let has_more = self
                         .scoring_result
                         .as_ref()
-                        .map_or(false, |r| next < r.steps.len());
+                        .is_some_and(|r| next < r.steps.len());
                     if has_more {
                         self.scoring_phase = ScoringPhase::ScoringStep {
                             step_index: next,
-                            timer: TICKS_PER_STEP,
+                            timer: self.ticks(TICKS_PER_STEP),
                         };
                     } else {
                         self.scoring_phase = ScoringPhase::FinalScore {
-                            timer: TICKS_FINAL_SCORE,
+                            timer: self.ticks(TICKS_FINAL_SCORE),
                         };
                     }
                 } else {
@@ -303,6 +416,7 @@ impl PlayRoundScreen {
             "chips" => ScorePopupKind::Chips,
             "mult" => ScorePopupKind::Mult,
             "xmult" => ScorePopupKind::XMult,
+            "money" => ScorePopupKind::Money,
             _ => ScorePopupKind::Chips,
         };
 
@@ -335,6 +449,76 @@ impl PlayRoundScreen {
                     );
                 }
             }
+            ScoreStep::HeldCardXMult { held_index, x_mult } => {
+                // Held cards live in the hand row, not the played zone
+                self.active_card_index = None;
+                self.active_joker_index = None;
+                self.anim_mult *= x_mult;
+                if let Some(rect) = self.hand_card_rects.get(*held_index).copied() {
+                    self.popup = Some((popup_text, popup_kind, rect));
+                    fx.add_unique_effect(
+                        format!("held_card_score_{}", held_index),
+                        crate::effects::card_score_glow().with_area(rect),
+                    );
+                }
+            }
+            ScoreStep::CardEdition {
+                card_index,
+                edition,
+            } => {
+                self.active_card_index = Some(*card_index);
+                self.active_joker_index = None;
+                self.anim_chips += edition.chips();
+                self.anim_mult += edition.mult() as f64;
+                self.anim_mult *= edition.x_mult();
+                self.set_popup_at_card(*card_index, popup_text, popup_kind);
+                if let Some(rect) = self.played_card_rects.get(*card_index).copied() {
+                    fx.add_unique_effect(
+                        format!("card_score_{}", card_index),
+                        crate::effects::card_score_glow().with_area(rect),
+                    );
+                }
+            }
+            ScoreStep::LuckyMult { card_index, mult } => {
+                self.active_card_index = Some(*card_index);
+                self.active_joker_index = None;
+                self.anim_mult += *mult as f64;
+                self.set_popup_at_card(*card_index, popup_text, popup_kind);
+                if let Some(rect) = self.played_card_rects.get(*card_index).copied() {
+                    fx.add_unique_effect(
+                        format!("card_score_{}", card_index),
+                        crate::effects::card_score_glow().with_area(rect),
+                    );
+                }
+            }
+            ScoreStep::SealRetrigger { card_index }
+            | ScoreStep::CardMoney { card_index, .. }
+            | ScoreStep::LuckyMoney { card_index, .. } => {
+                // No effect on the running totals, just a beat over the card
+                self.active_card_index = Some(*card_index);
+                self.active_joker_index = None;
+                self.set_popup_at_card(*card_index, popup_text, popup_kind);
+                if let Some(rect) = self.played_card_rects.get(*card_index).copied() {
+                    fx.add_unique_effect(
+                        format!("card_score_{}", card_index),
+                        crate::effects::card_score_glow().with_area(rect),
+                    );
+                }
+            }
+            ScoreStep::JokerRetrigger {
+                joker_index,
+                card_index,
+            } => {
+                self.active_card_index = Some(*card_index);
+                self.active_joker_index = Some(*joker_index);
+                self.set_popup_at_card(*card_index, popup_text, popup_kind);
+                if let Some(rect) = self.joker_rects.get(*joker_index).copied() {
+                    fx.add_unique_effect(
+                        format!("joker_activate_{}", joker_index),
+                        crate::effects::joker_activate_pulse().with_area(rect),
+                    );
+                }
+            }
             ScoreStep::JokerChips {
                 joker_index, chips, ..
             } => {
@@ -376,6 +560,35 @@ impl PlayRoundScreen {
                     );
                 }
             }
+            ScoreStep::PlanetXMult {
+                consumable_index,
+                x_mult,
+            } => {
+                // The Planet sits in the consumable slots
+                self.active_card_index = None;
+                self.active_joker_index = None;
+                self.anim_mult *= x_mult;
+                if let Some(rect) = self.consumable_rects.get(*consumable_index).copied() {
+                    self.popup = Some((popup_text, popup_kind, rect));
+                }
+            }
+            ScoreStep::JokerEdition {
+                joker_index,
+                edition,
+            } => {
+                self.active_card_index = None;
+                self.active_joker_index = Some(*joker_index);
+                self.anim_chips += edition.chips();
+                self.anim_mult += edition.mult() as f64;
+                self.anim_mult *= edition.x_mult();
+                self.set_popup_at_joker(*joker_index, popup_text, popup_kind);
+                if let Some(rect) = self.joker_rects.get(*joker_index).copied() {
+                    fx.add_unique_effect(
+                        format!("joker_activate_{}", joker_index),
+                        crate::effects::joker_activate_pulse().with_area(rect),
+                    );
+                }
+            }
             ScoreStep::JokerCardChips {
                 joker_index,
                 card_index,
@@ -408,6 +621,22 @@ impl PlayRoundScreen {
                     );
                 }
             }
+            ScoreStep::JokerCardXMult {
+                joker_index,
+                card_index,
+                x_mult,
+            } => {
+                self.active_card_index = Some(*card_index);
+                self.active_joker_index = Some(*joker_index);
+                self.anim_mult *= x_mult;
+                self.set_popup_at_card(*card_index, popup_text, popup_kind);
+                if let Some(rect) = self.joker_rects.get(*joker_index).copied() {
+                    fx.add_unique_effect(
+                        format!("joker_activate_{}", joker_index),
+                        crate::effects::joker_activate_pulse().with_area(rect),
+                    );
+                }
+            }
             ScoreStep::BaseHand { .. } => {
                 // Already handled in ShowingHandType phase
             }
@@ -431,9 +660,9 @@ impl PlayRoundScreen {
     /// Compute the sidebar data from game state and animation state
     fn sidebar_data(&self, game: &RunState) -> SidebarWidget {
         let blind_color = match game.blind_type {
-            BlindType::Small => Theme::SMALL_BLIND,
-            BlindType::Big => Theme::BIG_BLIND,
-            BlindType::Boss(_) => Theme::BOSS_BLIND,
+            BlindType::Small => Theme::small_blind(),
+            BlindType::Big => Theme::big_blind(),
+            BlindType::Boss(_) => Theme::boss_blind(),
         };
 
         let (hand_name, hand_level, chips, mult) = self.current_score_display(game);
@@ -442,7 +671,7 @@ impl PlayRoundScreen {
             game.blind_type.name(),
             blind_color,
             game.score_target,
-            game.blind_type.reward(),
+            game.blind_reward(),
             game.round_score,
             hand_name,
             hand_level,
@@ -455,6 +684,12 @@ impl PlayRoundScreen {
             8, // max_ante
             game.round_number(),
         )
+        .money_display(self.money_display)
+        .slots(SlotStats::from_run(game))
+        .stake(game.stake)
+        .projected_reward(game)
+        .boss_effect(game)
+        .tick(self.tick)
     }
 
     /// Get current hand name, level, chips, mult for display (sidebar + animation)
@@ -463,23 +698,26 @@ impl PlayRoundScreen {
             // During animation, show the animated running totals
             (
                 self.anim_hand_name.clone(),
-                game.hand_levels.get_level(&result.hand_type),
+                game.scoring_levels(result.hand_type)
+                    .get_level(&result.hand_type),
                 self.anim_chips,
                 self.anim_mult.max(0.0).ceil() as u64,
             )
         } else if let Some(result) = &self.last_score {
             (
                 format!("{}", result.hand_type),
-                game.hand_levels.get_level(&result.hand_type),
+                game.scoring_levels(result.hand_type)
+                    .get_level(&result.hand_type),
                 result.total_chips,
                 result.total_mult,
             )
-        } else if !game.selected_indices.is_empty() {
+        } else if !game.selected_indices.is_empty() && !game.selection_hidden() {
             let selected = game.selected_cards();
-            let hand_result = detect_hand(&selected);
-            let level = game.hand_levels.get_level(&hand_result.hand_type);
-            let base_chips = game.hand_levels.chips_for(&hand_result.hand_type);
-            let base_mult = game.hand_levels.mult_for(&hand_result.hand_type);
+            let hand_result = game.detect_hand(&selected);
+            let levels = game.scoring_levels(hand_result.hand_type);
+            let level = levels.get_level(&hand_result.hand_type);
+            let base_chips = levels.chips_for(&hand_result.hand_type);
+            let base_mult = levels.mult_for(&hand_result.hand_type);
             (
                 format!("{}", hand_result.hand_type),
                 level,
@@ -527,16 +765,26 @@ impl PlayRoundScreen {
             ])
             .split(right_col);
 
+            if self
+                .consumable_cursor
+                .is_some_and(|i| i >= game.consumables.len())
+            {
+                self.consumable_cursor = None;
+            }
+            self.consumable_rects = ConsumableSlotsWidget::slot_rects(
+                right_parts[0],
+                ConsumableSlotsWidget::slot_count(&game.consumables, game.max_consumables),
+            );
             frame.render_widget(
-                ConsumableSlotsWidget::new(&game.consumables, game.max_consumables),
+                ConsumableSlotsWidget::new(&game.consumables, game.max_consumables)
+                    .selected(self.consumable_cursor),
                 right_parts[0],
             );
 
             self.deck_viewer.render_preview(
                 frame,
                 right_parts[1],
-                game.deck.total() + game.hand.len(),
-                game.deck.remaining(),
+                PileCounts::new(&game.deck, game.hand.len()),
             );
         }
 
@@ -567,6 +815,16 @@ impl PlayRoundScreen {
 
         // Deck viewer overlay (on top of everything else)
         self.deck_viewer.render_overlay(frame, area);
+
+        // Run info overlay
+        self.run_info
+            .render_overlay(frame, area, &game.hand_levels, &game.stats.hand_counts);
+
+        // Score log overlay
+        self.score_log.render_overlay(frame, area, &game.score_log);
+
+        // Help overlay (above every other overlay)
+        self.help.render_overlay(frame, area);
     }
 
     /// Render center area (unified layout — played cards zone always visible)
@@ -578,7 +836,7 @@ impl PlayRoundScreen {
         // │ Joker slots row (6)                  │
         // │ Played cards zone (8)  <- always     │
         // │ Score info line (2)                  │
-        // │ Hand type preview (1)                │
+        // │ Hand type + projected score (2)      │
         // │ Player's hand cards (flex)           │
         // │ Card counter (1)                     │
         // │ Action buttons (3)                   │
@@ -589,7 +847,7 @@ impl PlayRoundScreen {
             Constraint::Length(6),  // Joker bar + counter
             Constraint::Length(12), // Played cards zone (always present)
             Constraint::Length(2),  // Score info / last score
-            Constraint::Length(1),  // Hand type preview
+            Constraint::Length(2),  // Hand type + projected score
             Constraint::Min(0),     // Hand cards
             Constraint::Length(1),  // Card counter
             Constraint::Length(3),  // Action buttons
@@ -633,20 +891,53 @@ impl PlayRoundScreen {
             self.render_last_score(frame, rows[2]);
         }
 
-        // === Hand type preview ===
-        if !is_scoring && !game.selected_indices.is_empty() {
-            let selected_cards = game.selected_cards();
-            let hand_result = detect_hand(&selected_cards);
-            let preview = Line::from(vec![Span::styled(
-                format!("{}", hand_result.hand_type),
+        // === Hand type preview (or consumable feedback) ===
+        if let Some(notice) = self.notice.as_ref().filter(|_| !is_scoring) {
+            let line = Line::from(Span::styled(
+                notice.as_str(),
+                Style::default().fg(Theme::mult_color()),
+            ));
+            frame.render_widget(Paragraph::new(line).alignment(Alignment::Center), rows[3]);
+        } else if let Some(preview) = game
+            .preview_selected()
+            .filter(|_| !is_scoring && !game.selection_hidden())
+        {
+            let dim = Style::default().fg(Theme::dim_text());
+            let mut hand_line = Line::from(Span::styled(
+                format!("{}", preview.hand_type),
                 Style::default()
-                    .fg(Theme::GOLD)
+                    .fg(Theme::hand_color(preview.hand_type))
                     .add_modifier(Modifier::BOLD),
-            )]);
-            frame.render_widget(
-                Paragraph::new(preview).alignment(Alignment::Center),
-                rows[3],
-            );
+            ));
+            if let Some(short) = game
+                .last_hand_shortfall()
+                .filter(|_| self.last_hand_warning)
+            {
+                hand_line.push_span(Span::styled(
+                    format!("  This won't be enough: need {} more", format_number(short)),
+                    Style::default().fg(Theme::mult_color()),
+                ));
+            }
+            let lines = vec![
+                hand_line,
+                Line::from(vec![
+                    Span::styled(
+                        format_number(preview.total_chips),
+                        Style::default().fg(Theme::chips_color()),
+                    ),
+                    Span::styled(" X ", dim),
+                    Span::styled(
+                        format_number(preview.total_mult),
+                        Style::default().fg(Theme::mult_color()),
+                    ),
+                    Span::styled(" = ", dim),
+                    Span::styled(
+                        format_number(preview.final_score),
+                        Style::default().fg(Theme::bright_text()),
+                    ),
+                ]),
+            ];
+            frame.render_widget(Paragraph::new(lines).alignment(Alignment::Center), rows[3]);
         }
 
         // === Hand cards ===
@@ -662,7 +953,7 @@ impl PlayRoundScreen {
         frame.render_widget(
             Paragraph::new(Line::from(Span::styled(
                 counter_text,
-                Style::default().fg(Theme::MUTED_TEXT),
+                Style::default().fg(Theme::muted_text()),
             )))
             .alignment(Alignment::Center),
             rows[5],
@@ -685,27 +976,33 @@ impl PlayRoundScreen {
         // === Help line ===
         let help = if is_scoring {
             Paragraph::new(Line::from(vec![
-                Span::styled("[", Style::default().fg(Theme::DIM_TEXT)),
-                Span::styled("Space/Enter", Style::default().fg(Theme::GOLD)),
-                Span::styled("] Skip Animation", Style::default().fg(Theme::DIM_TEXT)),
+                Span::styled("[", Style::default().fg(Theme::dim_text())),
+                Span::styled("Space/Enter", Style::default().fg(Theme::gold())),
+                Span::styled("] Skip Animation", Style::default().fg(Theme::dim_text())),
             ]))
         } else {
             Paragraph::new(Line::from(vec![
-                Span::styled("[", Style::default().fg(Theme::DIM_TEXT)),
-                Span::styled("\u{2190}\u{2192}", Style::default().fg(Theme::GOLD)),
-                Span::styled("] Move  [", Style::default().fg(Theme::DIM_TEXT)),
-                Span::styled("Space", Style::default().fg(Theme::GOLD)),
-                Span::styled("] Select  [", Style::default().fg(Theme::DIM_TEXT)),
-                Span::styled("P", Style::default().fg(Theme::GOLD)),
-                Span::styled("] Play  [", Style::default().fg(Theme::DIM_TEXT)),
-                Span::styled("D", Style::default().fg(Theme::GOLD)),
-                Span::styled("] Discard  [", Style::default().fg(Theme::DIM_TEXT)),
-                Span::styled("S", Style::default().fg(Theme::GOLD)),
-                Span::styled("] Rank  [", Style::default().fg(Theme::DIM_TEXT)),
-                Span::styled("T", Style::default().fg(Theme::GOLD)),
-                Span::styled("] Suit  [", Style::default().fg(Theme::DIM_TEXT)),
-                Span::styled("V", Style::default().fg(Theme::GOLD)),
-                Span::styled("] Deck", Style::default().fg(Theme::DIM_TEXT)),
+                Span::styled("[", Style::default().fg(Theme::dim_text())),
+                Span::styled("\u{2190}\u{2192}", Style::default().fg(Theme::gold())),
+                Span::styled("] Move  [", Style::default().fg(Theme::dim_text())),
+                Span::styled("Space", Style::default().fg(Theme::gold())),
+                Span::styled("] Select  [", Style::default().fg(Theme::dim_text())),
+                Span::styled("P", Style::default().fg(Theme::gold())),
+                Span::styled("] Play  [", Style::default().fg(Theme::dim_text())),
+                Span::styled("D", Style::default().fg(Theme::gold())),
+                Span::styled("] Discard  [", Style::default().fg(Theme::dim_text())),
+                Span::styled("S", Style::default().fg(Theme::gold())),
+                Span::styled("] Rank  [", Style::default().fg(Theme::dim_text())),
+                Span::styled("T", Style::default().fg(Theme::gold())),
+                Span::styled("] Suit  [", Style::default().fg(Theme::dim_text())),
+                Span::styled("V", Style::default().fg(Theme::gold())),
+                Span::styled("] Deck  [", Style::default().fg(Theme::dim_text())),
+                Span::styled("I", Style::default().fg(Theme::gold())),
+                Span::styled("] Info  [", Style::default().fg(Theme::dim_text())),
+                Span::styled("Z", Style::default().fg(Theme::gold())),
+                Span::styled("] Undo  [", Style::default().fg(Theme::dim_text())),
+                Span::styled("Tab U", Style::default().fg(Theme::gold())),
+                Span::styled("] Use Card", Style::default().fg(Theme::dim_text())),
             ]))
         };
         frame.render_widget(help.alignment(Alignment::Center), rows[7]);
@@ -733,14 +1030,22 @@ impl PlayRoundScreen {
         // === Cash-out panel ===
         self.cashout_panel_rect = rows[1];
         let breakdown = game.calculate_reward_breakdown();
-        let panel = CashOutPanel::new(game.blind_type.name(), game.score_target, breakdown);
+        let mut panel = CashOutPanel::new(game.blind_type.name(), game.score_target, breakdown);
+        if let Some(tally) = &self.cashout_tally {
+            panel = panel.tally(tally);
+        }
         frame.render_widget(panel, rows[1]);
 
         // === Help line ===
+        let (keys, label) = if self.is_tallying() {
+            ("Space/Enter", "] Skip")
+        } else {
+            ("Enter", "] Cash Out")
+        };
         let help = Paragraph::new(Line::from(vec![
-            Span::styled("[", Style::default().fg(Theme::DIM_TEXT)),
-            Span::styled("Enter", Style::default().fg(Theme::GOLD)),
-            Span::styled("] Cash Out", Style::default().fg(Theme::DIM_TEXT)),
+            Span::styled("[", Style::default().fg(Theme::dim_text())),
+            Span::styled(keys, Style::default().fg(Theme::gold())),
+            Span::styled(label, Style::default().fg(Theme::dim_text())),
         ]))
         .alignment(Alignment::Center);
         frame.render_widget(help, rows[2]);
@@ -756,8 +1061,10 @@ impl PlayRoundScreen {
         ])
         .split(area);
 
-        let joker_bar =
-            JokerBarWidget::new(&game.jokers, game.max_jokers).activated(self.active_joker_index);
+        // The scoring glow takes precedence over the inspected joker's outline
+        let joker_bar = JokerBarWidget::new(&game.jokers, game.max_jokers)
+            .selected(self.inspected_joker)
+            .activated(self.active_joker_index);
 
         // Cache joker rects
         self.joker_rects.clear();
@@ -771,11 +1078,11 @@ impl PlayRoundScreen {
         frame.render_widget(joker_bar, parts[0]);
 
         // Joker slot counter
-        let counter = format!("{}/{}", game.jokers.len(), game.max_jokers);
+        let counter = format!("{}/{}", game.joker_slots_used(), game.max_jokers);
         frame.render_widget(
             Paragraph::new(Line::from(Span::styled(
                 counter,
-                Style::default().fg(Theme::MUTED_TEXT),
+                Style::default().fg(Theme::muted_text()),
             )))
             .alignment(Alignment::Center),
             parts[1],
@@ -818,28 +1125,31 @@ impl PlayRoundScreen {
             Span::styled(
                 format!("  {} ", self.anim_hand_name),
                 Style::default()
-                    .fg(Theme::BRIGHT_TEXT)
+                    .fg(Theme::bright_text())
                     .add_modifier(Modifier::BOLD),
             ),
-            Span::styled("\u{2502} ", Style::default().fg(Theme::CARD_BORDER)),
+            Span::styled("\u{2502} ", Style::default().fg(Theme::card_border())),
             Span::styled(
-                format!("{}", chips_display),
+                format_number(chips_display),
                 Style::default()
-                    .fg(Theme::CHIPS_COLOR)
+                    .fg(Theme::chips_color())
                     .add_modifier(Modifier::BOLD),
             ),
-            Span::styled(" \u{00d7} ", Style::default().fg(Theme::BRIGHT_TEXT)),
+            Span::styled(" \u{00d7} ", Style::default().fg(Theme::bright_text())),
             Span::styled(
-                format!("{}", mult_display),
+                format_number(mult_display),
                 Style::default()
-                    .fg(Theme::MULT_COLOR)
+                    .fg(Theme::mult_color())
                     .add_modifier(Modifier::BOLD),
             ),
             if is_final {
                 Span::styled(
-                    format!(" = {}", chips_display * mult_display),
+                    format!(
+                        " = {}",
+                        format_number(chips_display.saturating_mul(mult_display))
+                    ),
                     Style::default()
-                        .fg(Theme::SCORE_COLOR)
+                        .fg(Theme::score_color())
                         .add_modifier(Modifier::BOLD),
                 )
             } else {
@@ -855,27 +1165,27 @@ impl PlayRoundScreen {
                 Span::styled(
                     format!("  {} ", result.hand_type),
                     Style::default()
-                        .fg(Theme::BRIGHT_TEXT)
+                        .fg(Theme::bright_text())
                         .add_modifier(Modifier::BOLD),
                 ),
-                Span::styled("\u{2502} ", Style::default().fg(Theme::CARD_BORDER)),
+                Span::styled("\u{2502} ", Style::default().fg(Theme::card_border())),
                 Span::styled(
-                    format!("{}", result.total_chips),
+                    format_number(result.total_chips),
                     Style::default()
-                        .fg(Theme::CHIPS_COLOR)
+                        .fg(Theme::chips_color())
                         .add_modifier(Modifier::BOLD),
                 ),
-                Span::styled(" \u{00d7} ", Style::default().fg(Theme::BRIGHT_TEXT)),
+                Span::styled(" \u{00d7} ", Style::default().fg(Theme::bright_text())),
                 Span::styled(
-                    format!("{}", result.total_mult),
+                    format_number(result.total_mult),
                     Style::default()
-                        .fg(Theme::MULT_COLOR)
+                        .fg(Theme::mult_color())
                         .add_modifier(Modifier::BOLD),
                 ),
                 Span::styled(
-                    format!(" = {}", result.final_score),
+                    format!(" = {}", format_number(result.final_score)),
                     Style::default()
-                        .fg(Theme::SCORE_COLOR)
+                        .fg(Theme::score_color())
                         .add_modifier(Modifier::BOLD),
                 ),
             ]);
@@ -893,9 +1203,32 @@ impl PlayRoundScreen {
         }
     }
 
+    /// The best play for `game`, searched again when the hand or jokers change
+    /// or after any action (see `invalidate_hint`)
+    fn suggested_play(&mut self, game: &RunState) -> Vec<usize> {
+        let stale = !self
+            .hint_cache
+            .as_ref()
+            .is_some_and(|(hand, jokers, _)| *hand == game.hand && *jokers == game.jokers);
+        if stale {
+            let best = game.best_play().unwrap_or_default();
+            self.hint_cache = Some((game.hand.clone(), game.jokers.clone(), best));
+        }
+        self.hint_cache
+            .as_ref()
+            .map(|(_, _, best)| best.clone())
+            .unwrap_or_default()
+    }
+
     fn render_hand(&mut self, frame: &mut Frame, game: &RunState, area: Rect) {
-        let hand_widget =
-            HandWidget::new(&game.hand, &game.selected_indices).cursor(Some(self.cursor));
+        let suggested = if self.play_hints {
+            self.suggested_play(game)
+        } else {
+            Vec::new()
+        };
+        let hand_widget = HandWidget::new(&game.hand, &game.selected_indices)
+            .cursor(Some(self.cursor))
+            .suggested(&suggested);
 
         // Store card rects for mouse hit-testing
         self.hand_card_rects.clear();
@@ -928,7 +1261,7 @@ impl PlayRoundScreen {
         };
 
         let jt = &joker.joker_type;
-        let name = jt.name();
+        let name = joker.name();
         let desc = jt.description();
         let rarity = jt.rarity();
         let sell_value = joker.total_sell_value();
@@ -940,24 +1273,24 @@ impl PlayRoundScreen {
             balatrust_core::joker::JokerRarity::Legendary => "Legendary",
         };
         let rarity_color = match rarity {
-            balatrust_core::joker::JokerRarity::Common => Theme::COMMON,
-            balatrust_core::joker::JokerRarity::Uncommon => Theme::UNCOMMON,
-            balatrust_core::joker::JokerRarity::Rare => Theme::RARE,
-            balatrust_core::joker::JokerRarity::Legendary => Theme::LEGENDARY,
+            balatrust_core::joker::JokerRarity::Common => Theme::common(),
+            balatrust_core::joker::JokerRarity::Uncommon => Theme::uncommon(),
+            balatrust_core::joker::JokerRarity::Rare => Theme::rare(),
+            balatrust_core::joker::JokerRarity::Legendary => Theme::legendary(),
         };
 
-        let lines: Vec<Line> = vec![
+        let mut lines: Vec<Line> = vec![
             Line::from(Span::styled(
                 name,
                 Style::default()
-                    .fg(Theme::BRIGHT_TEXT)
+                    .fg(Theme::bright_text())
                     .add_modifier(Modifier::BOLD),
             )),
             Line::from(""),
-            Line::from(Span::styled(desc, Style::default().fg(Theme::MUTED_TEXT))),
+            Line::from(Span::styled(desc, Style::default().fg(Theme::muted_text()))),
             Line::from(""),
             Line::from(vec![
-                Span::styled("Rarity: ", Style::default().fg(Theme::DIM_TEXT)),
+                Span::styled("Rarity: ", Style::default().fg(Theme::dim_text())),
                 Span::styled(
                     rarity_str,
                     Style::default()
@@ -966,18 +1299,28 @@ impl PlayRoundScreen {
                 ),
             ]),
             Line::from(vec![
-                Span::styled("Sell: ", Style::default().fg(Theme::DIM_TEXT)),
+                Span::styled("Sell: ", Style::default().fg(Theme::dim_text())),
                 Span::styled(
                     format!("${}", sell_value),
                     Style::default()
-                        .fg(Theme::MONEY_COLOR)
+                        .fg(Theme::money_color())
                         .add_modifier(Modifier::BOLD),
                 ),
             ]),
         ];
 
+        if let Some(line) = joker_edition_line(joker) {
+            lines.push(line);
+        }
+        if let Some(preview) = joker.live_preview(game.money) {
+            lines.push(Line::from(Span::styled(
+                preview,
+                Style::default().fg(Theme::mult_color()),
+            )));
+        }
+
         let content_width = lines.iter().map(|l| l.width() as u16).max().unwrap_or(10) + 4;
-        let popup_width = content_width.max(20).min(40);
+        let popup_width = content_width.clamp(20, 40);
         let popup_height = (lines.len() as u16) + 3;
 
         let popup_x = joker_rect
@@ -1018,17 +1361,47 @@ impl PlayRoundScreen {
     // ─── Input Handling ──────────────────────────────────────────────
 
     pub fn handle_key(&mut self, key: KeyEvent) -> Option<ScreenAction> {
+        // '?' help sits above everything, including the deck viewer (unless typing a filter)
+        let help_context = if self.deck_viewer.open {
+            HelpContext::DeckViewer
+        } else {
+            HelpContext::Play
+        };
+        if !self.deck_viewer.filter_editing && self.help.handle_key(key.code, help_context) {
+            return None;
+        }
         // Deck viewer overlay intercepts all keys when open
         if self.deck_viewer.handle_key(key.code) {
             return None;
         }
+        if self.run_info.handle_key(key.code) {
+            return None;
+        }
+        if self.score_log.handle_key(key.code) {
+            return None;
+        }
+        // The score log also opens from the cash out screen, but not mid-animation
+        if matches!(key.code, KeyCode::Char('g') | KeyCode::Char('G')) && !self.is_scoring() {
+            self.score_log.toggle();
+            return None;
+        }
+        // So does the deck viewer, like clicking the sidebar deck preview
+        if matches!(key.code, KeyCode::Char('v') | KeyCode::Char('V')) && !self.is_scoring() {
+            return Some(ScreenAction::OpenDeckViewer);
+        }
 
         // If blind is beaten, wait for enter
         if self.blind_just_beaten {
+            if self.is_tallying() {
+                if matches!(key.code, KeyCode::Enter | KeyCode::Char(' ')) {
+                    if let Some(tally) = &mut self.cashout_tally {
+                        tally.skip();
+                    }
+                }
+                return None;
+            }
             if key.code == KeyCode::Enter {
-                self.blind_just_beaten = false;
-                self.last_score = None;
-                return Some(ScreenAction::BeatBlind);
+                return self.cash_out();
             }
             return None;
         }
@@ -1041,8 +1414,11 @@ impl PlayRoundScreen {
             return None;
         }
 
-        // During scoring animation, only Space/Enter to skip
+        // During scoring animation, only Space/Enter to skip (or undo the play)
         if self.is_scoring() {
+            if matches!(key.code, KeyCode::Char('z') | KeyCode::Char('Z')) {
+                return Some(ScreenAction::Undo);
+            }
             if matches!(
                 key.code,
                 KeyCode::Char(' ') | KeyCode::Enter | KeyCode::Char('p') | KeyCode::Char('P')
@@ -1053,11 +1429,36 @@ impl PlayRoundScreen {
             return None;
         }
 
+        self.notice = None;
+
         match key.code {
-            KeyCode::Left | KeyCode::Char('h') => {
-                if self.cursor > 0 {
-                    self.cursor -= 1;
+            KeyCode::Left | KeyCode::Char('h') if self.cursor > 0 => {
+                self.cursor -= 1;
+            }
+            KeyCode::Char(c @ '1'..='9') => {
+                // Toggle the card at that hand position, like clicking it
+                let idx = c as usize - '1' as usize;
+                if idx < self.hand_len {
+                    self.cursor = idx;
+                    return Some(ScreenAction::ToggleCard(idx));
+                }
+            }
+            KeyCode::Tab => {
+                // Pick the next consumable slot, then none
+                self.consumable_cursor = match self.consumable_cursor {
+                    None if self.consumable_count > 0 => Some(0),
+                    Some(slot) if slot + 1 < self.consumable_count => Some(slot + 1),
+                    _ => None,
+                };
+            }
+            KeyCode::Char('z') | KeyCode::Char('Z') => {
+                return Some(ScreenAction::Undo);
+            }
+            KeyCode::Char('u') | KeyCode::Char('U') => {
+                if let Some(slot) = self.consumable_cursor {
+                    return Some(ScreenAction::UseConsumable(slot));
                 }
+                self.notice = Some("Pick a consumable with Tab first".to_string());
             }
             KeyCode::Right | KeyCode::Char('l') => {
                 self.cursor += 1;
@@ -1080,14 +1481,17 @@ impl PlayRoundScreen {
                 // Sort by suit
                 return Some(ScreenAction::SortBySuit);
             }
-            KeyCode::Char('a') | KeyCode::Char('A') => {
+            KeyCode::Char('a') => {
                 return Some(ScreenAction::SelectAll);
             }
             KeyCode::Char('c') | KeyCode::Char('C') => {
                 return Some(ScreenAction::ClearSelection);
             }
-            KeyCode::Char('v') | KeyCode::Char('V') => {
-                return Some(ScreenAction::OpenDeckViewer);
+            KeyCode::Char('i') | KeyCode::Char('I') => {
+                self.run_info.toggle();
+            }
+            KeyCode::Char('q') | KeyCode::Char('Q') | KeyCode::Esc => {
+                return Some(ScreenAction::Quit);
             }
             _ => {}
         }
@@ -1099,55 +1503,51 @@ impl PlayRoundScreen {
         mouse: MouseEvent,
         _game: &Option<RunState>,
     ) -> Option<ScreenAction> {
-        match mouse.kind {
-            MouseEventKind::Down(MouseButton::Left) => {
-                let col = mouse.column;
-                let row = mouse.row;
-
-                // Deck viewer overlay/preview click handling
-                if let Some(consumed) = self.deck_viewer.handle_mouse_click(col, row) {
-                    if !consumed {
-                        // Preview was clicked — open the deck viewer
-                        return Some(ScreenAction::OpenDeckViewer);
-                    }
-                    return None;
-                }
+        if self.deck_viewer.handle_mouse_scroll(mouse.kind) {
+            return None;
+        }
 
-                // In recap mode, check cash-out button click
-                if self.blind_just_beaten {
-                    // Joker inspect still works during recap
-                    for (i, rect) in self.joker_rects.iter().enumerate() {
-                        if rect.width > 0
-                            && col >= rect.x
-                            && col < rect.x + rect.width
-                            && row >= rect.y
-                            && row < rect.y + rect.height
-                        {
-                            if self.inspected_joker == Some(i) {
-                                self.inspected_joker = None;
-                            } else {
-                                self.inspected_joker = Some(i);
-                            }
-                            return None;
-                        }
-                    }
+        if let MouseEventKind::Down(MouseButton::Left) = mouse.kind {
+            if self.help.handle_mouse_click()
+                || self.run_info.handle_mouse_click()
+                || self.score_log.handle_mouse_click()
+            {
+                return None;
+            }
+            let col = mouse.column;
+            let row = mouse.row;
+
+            // Deck viewer overlay/preview click handling
+            if let Some(consumed) = self.deck_viewer.handle_mouse_click(col, row) {
+                if !consumed {
+                    // Preview was clicked — open the deck viewer
+                    return Some(ScreenAction::OpenDeckViewer);
+                }
+                return None;
+            }
 
-                    if self.inspected_joker.is_some() {
-                        self.inspected_joker = None;
+            // Consumable slots (toggle selection)
+            if !self.is_scoring() {
+                for (i, rect) in self.consumable_rects.iter().enumerate() {
+                    if rect.width > 0
+                        && col >= rect.x
+                        && col < rect.x + rect.width
+                        && row >= rect.y
+                        && row < rect.y + rect.height
+                    {
+                        self.consumable_cursor = if self.consumable_cursor == Some(i) {
+                            None
+                        } else {
+                            Some(i)
+                        };
                         return None;
                     }
-
-                    // Check cash-out button
-                    if CashOutPanel::hit_test_cashout(self.cashout_panel_rect, col, row) {
-                        self.blind_just_beaten = false;
-                        self.last_score = None;
-                        return Some(ScreenAction::BeatBlind);
-                    }
-
-                    return None;
                 }
+            }
 
-                // Check if click is on a joker (works during scoring too)
+            // In recap mode, check cash-out button click
+            if self.blind_just_beaten {
+                // Joker inspect still works during recap
                 for (i, rect) in self.joker_rects.iter().enumerate() {
                     if rect.width > 0
                         && col >= rect.x
@@ -1164,51 +1564,126 @@ impl PlayRoundScreen {
                     }
                 }
 
-                // Click elsewhere dismisses the joker popup
                 if self.inspected_joker.is_some() {
                     self.inspected_joker = None;
                     return None;
                 }
 
-                // Don't process other clicks during scoring
-                if self.is_scoring() {
-                    return None;
+                // Check cash-out button
+                if CashOutPanel::hit_test_cashout(self.cashout_panel_rect, col, row) {
+                    if self.is_tallying() {
+                        if let Some(tally) = &mut self.cashout_tally {
+                            tally.skip();
+                        }
+                        return None;
+                    }
+                    return self.cash_out();
                 }
 
-                // Check if click is on an action button
-                if let Some(hit) = ActionButtonsWidget::hit_test(self.action_buttons_rect, col, row)
+                return None;
+            }
+
+            // Check if click is on a joker (works during scoring too)
+            for (i, rect) in self.joker_rects.iter().enumerate() {
+                if rect.width > 0
+                    && col >= rect.x
+                    && col < rect.x + rect.width
+                    && row >= rect.y
+                    && row < rect.y + rect.height
                 {
-                    return match hit {
-                        ButtonHit::PlayHand => Some(ScreenAction::PlayHand),
-                        ButtonHit::SortRank => Some(ScreenAction::SortByRank),
-                        ButtonHit::SortSuit => Some(ScreenAction::SortBySuit),
-                        ButtonHit::Discard => Some(ScreenAction::Discard),
-                    };
+                    if self.inspected_joker == Some(i) {
+                        self.inspected_joker = None;
+                    } else {
+                        self.inspected_joker = Some(i);
+                    }
+                    return None;
                 }
+            }
 
-                // Check if click is on a hand card
-                for (i, rect) in self.hand_card_rects.iter().enumerate() {
-                    if col >= rect.x
-                        && col < rect.x + rect.width
-                        && row >= rect.y
-                        && row < rect.y + rect.height
-                    {
-                        self.cursor = i;
-                        return Some(ScreenAction::ToggleCard(i));
-                    }
+            // Click elsewhere dismisses the joker popup
+            if self.inspected_joker.is_some() {
+                self.inspected_joker = None;
+                return None;
+            }
+
+            // Don't process other clicks during scoring
+            if self.is_scoring() {
+                return None;
+            }
+
+            // Check if click is on an action button
+            if let Some(hit) = ActionButtonsWidget::hit_test(self.action_buttons_rect, col, row) {
+                return match hit {
+                    ButtonHit::PlayHand => Some(ScreenAction::PlayHand),
+                    ButtonHit::SortRank => Some(ScreenAction::SortByRank),
+                    ButtonHit::SortSuit => Some(ScreenAction::SortBySuit),
+                    ButtonHit::Discard => Some(ScreenAction::Discard),
+                };
+            }
+
+            // Check if click is on a hand card
+            for (i, rect) in self.hand_card_rects.iter().enumerate() {
+                if col >= rect.x
+                    && col < rect.x + rect.width
+                    && row >= rect.y
+                    && row < rect.y + rect.height
+                {
+                    self.cursor = i;
+                    return Some(ScreenAction::ToggleCard(i));
                 }
             }
-            _ => {}
         }
         None
     }
 
     pub fn tick(&mut self, game: &mut Option<RunState>) {
+        if let Some(tally) = &mut self.cashout_tally {
+            tally.tick();
+        }
+
         // Clamp cursor
         if let Some(game) = game {
             if !game.hand.is_empty() && self.cursor >= game.hand.len() {
                 self.cursor = game.hand.len() - 1;
             }
+            self.hand_len = game.hand.len();
+            self.consumable_count = game.consumables.len();
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crossterm::event::KeyModifiers;
+
+    fn press(screen: &mut PlayRoundScreen, code: KeyCode) -> Option<ScreenAction> {
+        screen.handle_key(KeyEvent::new(code, KeyModifiers::NONE))
+    }
+
+    #[test]
+    fn test_number_keys_toggle_cards_in_range() {
+        let mut screen = PlayRoundScreen::new();
+        screen.hand_len = 5;
+        assert!(matches!(
+            press(&mut screen, KeyCode::Char('3')),
+            Some(ScreenAction::ToggleCard(2))
+        ));
+        assert_eq!(screen.cursor, 2);
+        assert!(press(&mut screen, KeyCode::Char('9')).is_none());
+        assert!(press(&mut screen, KeyCode::Char('6')).is_none());
+        assert_eq!(screen.cursor, 2);
+    }
+
+    #[test]
+    fn test_tab_cycles_consumable_slots_then_none() {
+        let mut screen = PlayRoundScreen::new();
+        screen.consumable_count = 2;
+        press(&mut screen, KeyCode::Tab);
+        assert_eq!(screen.consumable_cursor, Some(0));
+        press(&mut screen, KeyCode::Tab);
+        assert_eq!(screen.consumable_cursor, Some(1));
+        press(&mut screen, KeyCode::Tab);
+        assert_eq!(screen.consumable_cursor, None);
+    }
+}
@@ -0,0 +1,359 @@
+use crossterm::event::{KeyCode, KeyEvent};
+use ratatui::layout::{Alignment, Constraint, Layout};
+use ratatui::style::{Modifier, Style};
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{Paragraph, Wrap};
+use ratatui::Frame;
+
+use balatrust_core::card::{Edition, Enhancement, PlayingCard, Rank, Seal, Suit};
+use balatrust_core::deck::Deck;
+use balatrust_core::joker::{Joker, JokerType};
+use balatrust_core::{RunState, SandboxSetup};
+use balatrust_widgets::theme::Theme;
+
+use crate::app::ScreenAction;
+use crate::screens::Screen;
+
+/// Editor rows, top to bottom
+const ROW_RANK: usize = 0;
+const ROW_SUIT: usize = 1;
+const ROW_ENHANCEMENT: usize = 2;
+const ROW_SEAL: usize = 3;
+const ROW_EDITION: usize = 4;
+const ROW_JOKER: usize = 5;
+
+/// Step an index through `0..len`, wrapping at both ends
+fn cycle_index(index: usize, len: usize, forward: bool) -> usize {
+    if forward {
+        (index + 1) % len
+    } else {
+        (index + len - 1) % len
+    }
+}
+
+/// Step an optional index through `None, 0..len`, wrapping at both ends
+fn cycle_option(index: Option<usize>, len: usize, forward: bool) -> Option<usize> {
+    let slot = index.map_or(0, |i| i + 1);
+    match cycle_index(slot, len + 1, forward) {
+        0 => None,
+        n => Some(n - 1),
+    }
+}
+
+/// Builds a starting deck and joker set for a sandbox run
+pub struct SandboxScreen {
+    pub row: usize,
+    /// Indices into `Rank::ALL`, `Suit::ALL`, ... for the card being built
+    pub rank: usize,
+    pub suit: usize,
+    pub enhancement: Option<usize>,
+    pub seal: Option<usize>,
+    pub edition: usize,
+    /// Index into `JokerType::ALL` for the joker being added
+    pub joker: usize,
+    /// Deck and jokers the run will start with
+    pub setup: SandboxSetup,
+    /// Seed for the sandbox run's shuffles and shops
+    pub seed: u64,
+    /// Feedback shown under the editor
+    pub notice: Option<String>,
+}
+
+impl SandboxScreen {
+    pub fn new() -> Self {
+        Self {
+            row: ROW_RANK,
+            rank: 0,
+            suit: 0,
+            enhancement: None,
+            seal: None,
+            edition: 0,
+            joker: 0,
+            setup: SandboxSetup::standard(),
+            seed: 1,
+            notice: None,
+        }
+    }
+
+    /// The card described by the editor rows
+    pub fn draft_card(&self) -> PlayingCard {
+        let mut card = PlayingCard::new(Rank::ALL[self.rank], Suit::ALL[self.suit]);
+        card.enhancement = self.enhancement.map(|i| Enhancement::ALL[i]);
+        card.seal = self.seal.map(|i| Seal::ALL[i]);
+        card.edition = Edition::ALL[self.edition];
+        card
+    }
+
+    fn draft_joker(&self) -> JokerType {
+        JokerType::ALL[self.joker]
+    }
+
+    fn cycle_row(&mut self, forward: bool) {
+        match self.row {
+            ROW_RANK => self.rank = cycle_index(self.rank, Rank::ALL.len(), forward),
+            ROW_SUIT => self.suit = cycle_index(self.suit, Suit::ALL.len(), forward),
+            ROW_ENHANCEMENT => {
+                self.enhancement = cycle_option(self.enhancement, Enhancement::ALL.len(), forward)
+            }
+            ROW_SEAL => self.seal = cycle_option(self.seal, Seal::ALL.len(), forward),
+            ROW_EDITION => self.edition = cycle_index(self.edition, Edition::ALL.len(), forward),
+            ROW_JOKER => self.joker = cycle_index(self.joker, JokerType::ALL.len(), forward),
+            _ => {}
+        }
+    }
+
+    /// Add the draft card, or the draft joker on the joker row
+    fn add(&mut self) {
+        if self.row == ROW_JOKER {
+            self.setup.jokers.push(Joker::new(self.draft_joker()));
+            self.notice = Some(format!("Added {}", self.draft_joker().name()));
+        } else {
+            let card = self.draft_card();
+            self.setup.deck.add_card(card);
+            self.notice = Some(format!("Added {}", card_label(&card)));
+        }
+    }
+
+    /// Remove one copy of the draft card, or the last draft joker on the joker row
+    fn remove(&mut self) {
+        if self.row == ROW_JOKER {
+            let joker_type = self.draft_joker();
+            let pos = self
+                .setup
+                .jokers
+                .iter()
+                .rposition(|j| j.joker_type == joker_type);
+            self.notice = Some(match pos {
+                Some(pos) => {
+                    self.setup.jokers.remove(pos);
+                    format!("Removed {}", joker_type.name())
+                }
+                None => format!("No {} to remove", joker_type.name()),
+            });
+        } else {
+            let card = self.draft_card();
+            self.notice = Some(if self.setup.deck.remove_card(&card) {
+                format!("Removed {}", card_label(&card))
+            } else {
+                format!("No {} in the deck", card_label(&card))
+            });
+        }
+    }
+
+    fn row_lines(&self) -> Vec<(String, String)> {
+        let card = self.draft_card();
+        vec![
+            ("Rank".to_string(), card.rank.name().to_string()),
+            ("Suit".to_string(), card.suit.name().to_string()),
+            (
+                "Enhancement".to_string(),
+                card.enhancement.map_or("None", |e| e.name()).to_string(),
+            ),
+            (
+                "Seal".to_string(),
+                card.seal.map_or("None", |s| s.name()).to_string(),
+            ),
+            ("Edition".to_string(), card.edition.name().to_string()),
+            (
+                "Joker".to_string(),
+                format!(
+                    "{} ({})",
+                    self.draft_joker().name(),
+                    self.draft_joker().description()
+                ),
+            ),
+        ]
+    }
+}
+
+/// "K♠" plus any modifiers, e.g. "K♠ Glass Red Foil"
+fn card_label(card: &PlayingCard) -> String {
+    let mut label = format!("{}{}", card.rank.short_name(), card.suit.symbol());
+    if let Some(enhancement) = card.enhancement {
+        label.push(' ');
+        label.push_str(enhancement.name());
+    }
+    if let Some(seal) = card.seal {
+        label.push(' ');
+        label.push_str(seal.name());
+    }
+    if card.edition != Edition::Base {
+        label.push(' ');
+        label.push_str(card.edition.name());
+    }
+    label
+}
+
+impl Screen for SandboxScreen {
+    fn render(&mut self, frame: &mut Frame, _game: &Option<RunState>) {
+        let area = frame.area();
+        let chunks = Layout::vertical([
+            Constraint::Length(3),
+            Constraint::Length(8),
+            Constraint::Min(4),
+            Constraint::Length(2),
+        ])
+        .margin(2)
+        .split(area);
+
+        let title = Paragraph::new(Line::from(Span::styled(
+            format!("Sandbox  \u{2022}  Seed {}", self.seed),
+            Style::default()
+                .fg(Theme::gold())
+                .add_modifier(Modifier::BOLD),
+        )))
+        .alignment(Alignment::Center);
+        frame.render_widget(title, chunks[0]);
+
+        let mut rows = Vec::new();
+        for (i, (label, value)) in self.row_lines().into_iter().enumerate() {
+            let selected = i == self.row;
+            let style = if selected {
+                Style::default()
+                    .fg(Theme::card_selected())
+                    .add_modifier(Modifier::BOLD)
+            } else {
+                Style::default().fg(Theme::muted_text())
+            };
+            let prefix = if selected { "> " } else { "  " };
+            rows.push(Line::from(Span::styled(
+                format!("{}{}: \u{25c2} {} \u{25b8}", prefix, label, value),
+                style,
+            )));
+        }
+        if let Some(notice) = &self.notice {
+            rows.push(Line::from(Span::styled(
+                notice.clone(),
+                Style::default().fg(Theme::bright_text()),
+            )));
+        }
+        frame.render_widget(Paragraph::new(rows).alignment(Alignment::Center), chunks[1]);
+
+        let mut cards = self.setup.deck.all_cards();
+        cards.sort_by_key(PlayingCard::display_order_key);
+        let deck_text = cards.iter().map(card_label).collect::<Vec<_>>().join("  ");
+        let jokers_text = if self.setup.jokers.is_empty() {
+            "none".to_string()
+        } else {
+            self.setup
+                .jokers
+                .iter()
+                .map(|j| j.name())
+                .collect::<Vec<_>>()
+                .join(", ")
+        };
+        let contents = vec![
+            Line::from(Span::styled(
+                format!("Jokers: {}", jokers_text),
+                Style::default().fg(Theme::bright_text()),
+            )),
+            Line::from(Span::styled(
+                format!("Deck ({} cards):", cards.len()),
+                Style::default().fg(Theme::bright_text()),
+            )),
+            Line::from(Span::styled(
+                deck_text,
+                Style::default().fg(Theme::muted_text()),
+            )),
+        ];
+        frame.render_widget(
+            Paragraph::new(contents).wrap(Wrap { trim: true }),
+            chunks[2],
+        );
+
+        let footer = Paragraph::new(Line::from(Span::styled(
+            "[\u{2191}\u{2193}] Row  [\u{2190}\u{2192}] Change  [a] Add  [x] Remove  \
+             [r] Standard deck  [c] Clear  [s] Seed  [Enter] Start  [Esc] Back",
+            Style::default().fg(Theme::dim_text()),
+        )))
+        .alignment(Alignment::Center);
+        frame.render_widget(footer, chunks[3]);
+    }
+
+    fn handle_key(&mut self, key: KeyEvent) -> Option<ScreenAction> {
+        match key.code {
+            KeyCode::Up | KeyCode::Char('k') => self.row = self.row.saturating_sub(1),
+            KeyCode::Down | KeyCode::Char('j') => self.row = (self.row + 1).min(ROW_JOKER),
+            KeyCode::Left | KeyCode::Char('h') => self.cycle_row(false),
+            KeyCode::Right | KeyCode::Char('l') => self.cycle_row(true),
+            KeyCode::Char('a') => self.add(),
+            KeyCode::Char('x') => self.remove(),
+            KeyCode::Char('r') => {
+                self.setup.deck = Deck::standard();
+                self.notice = Some("Deck reset to the 52 standard cards".to_string());
+            }
+            KeyCode::Char('c') => {
+                self.setup = SandboxSetup::new();
+                self.notice = Some("Deck and jokers cleared".to_string());
+            }
+            KeyCode::Char('s') => self.seed = self.seed.wrapping_add(1),
+            KeyCode::Enter => {
+                if self.setup.deck.is_empty() {
+                    self.notice = Some("Add at least one card first".to_string());
+                } else {
+                    self.notice = None;
+                    return Some(ScreenAction::StartSandbox);
+                }
+            }
+            KeyCode::Esc => return Some(ScreenAction::BackToMenu),
+            _ => {}
+        }
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crossterm::event::KeyModifiers;
+
+    fn press(screen: &mut SandboxScreen, code: KeyCode) -> Option<ScreenAction> {
+        screen.handle_key(KeyEvent::new(code, KeyModifiers::NONE))
+    }
+
+    #[test]
+    fn test_add_and_remove_the_draft_card() {
+        let mut screen = SandboxScreen::new();
+        press(&mut screen, KeyCode::Char('c'));
+        press(&mut screen, KeyCode::Down); // Suit
+        press(&mut screen, KeyCode::Right);
+        press(&mut screen, KeyCode::Down); // Enhancement
+        press(&mut screen, KeyCode::Right);
+        press(&mut screen, KeyCode::Char('a'));
+        press(&mut screen, KeyCode::Char('a'));
+
+        let card = screen.draft_card();
+        assert_eq!(card.suit, Suit::ALL[1]);
+        assert_eq!(card.enhancement, Some(Enhancement::ALL[0]));
+        assert_eq!(screen.setup.deck.all_cards(), vec![card, card]);
+
+        press(&mut screen, KeyCode::Char('x'));
+        assert_eq!(screen.setup.deck.len(), 1);
+    }
+
+    #[test]
+    fn test_joker_row_adds_jokers() {
+        let mut screen = SandboxScreen::new();
+        screen.row = ROW_JOKER;
+        press(&mut screen, KeyCode::Left);
+        press(&mut screen, KeyCode::Char('a'));
+
+        let last = *JokerType::ALL.last().unwrap();
+        assert_eq!(screen.setup.jokers.len(), 1);
+        assert_eq!(screen.setup.jokers[0].joker_type, last);
+    }
+
+    #[test]
+    fn test_empty_deck_cannot_start() {
+        let mut screen = SandboxScreen::new();
+        press(&mut screen, KeyCode::Char('c'));
+        assert!(press(&mut screen, KeyCode::Enter).is_none());
+        assert!(screen.notice.is_some());
+
+        press(&mut screen, KeyCode::Char('a'));
+        assert!(matches!(
+            press(&mut screen, KeyCode::Enter),
+            Some(ScreenAction::StartSandbox)
+        ));
+    }
+}
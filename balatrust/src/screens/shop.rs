@@ -2,23 +2,27 @@ use crossterm::event::{KeyCode, KeyEvent, MouseButton, MouseEvent, MouseEventKin
 use ratatui::layout::{Alignment, Constraint, Layout, Rect};
 use ratatui::style::{Modifier, Style};
 use ratatui::text::{Line, Span};
-use ratatui::widgets::{Block, BorderType, Borders, Padding, Paragraph};
+use ratatui::widgets::{Block, BorderType, Borders, Padding, Paragraph, Wrap};
 use ratatui::Frame;
 
 use balatrust_core::blind::BlindType;
 use balatrust_core::consumable::ConsumableType;
 use balatrust_core::joker::JokerRarity;
+use balatrust_core::pack::PackOption;
 use balatrust_core::shop::ShopItem;
 use balatrust_core::RunState;
 use balatrust_widgets::consumable_slots::ConsumableSlotsWidget;
-use balatrust_widgets::deck_viewer::DeckViewerState;
+use balatrust_widgets::deck_viewer::{DeckViewerState, PileCounts};
+use balatrust_widgets::help::{HelpContext, HelpState};
 use balatrust_widgets::joker_bar::JokerBarWidget;
+use balatrust_widgets::purchase_log::PurchaseLogState;
+use balatrust_widgets::run_info::RunInfoState;
 use balatrust_widgets::shop_panel::ShopPanelWidget;
-use balatrust_widgets::sidebar::SidebarWidget;
+use balatrust_widgets::sidebar::{MoneyDisplay, SidebarWidget, SlotStats};
 use balatrust_widgets::theme::Theme;
 
 use crate::app::ScreenAction;
-use crate::screens::Screen;
+use crate::screens::{joker_edition_line, Screen};
 
 // ─── Constants ────────────────────────────────────────────────────────
 
@@ -26,32 +30,72 @@ use crate::screens::Screen;
 const SIDEBAR_WIDTH: u16 = 30;
 /// Width of the right sidebar (consumable slots) in columns
 const RIGHT_SIDEBAR_WIDTH: u16 = 14;
+/// Size of one option card in the booster pack overlay
+const PACK_OPTION_WIDTH: u16 = 24;
+const PACK_OPTION_HEIGHT: u16 = 8;
 
 /// Focus area in the shop
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub(crate) enum ShopFocus {
     Items,
     Jokers,
+    Consumables,
 }
 
 pub struct ShopScreen {
     pub cursor: usize,
     pub focus: ShopFocus,
     pub joker_cursor: usize,
+    pub consumable_cursor: usize,
 
     // Cached rects for mouse hit-testing
     joker_rects: Vec<Rect>,
+    consumable_rects: Vec<Rect>,
     item_rects: Vec<Rect>,
     next_round_rect: Rect,
     reroll_rect: Rect,
     shop_panel_rect: Rect,
+    buy_button_rect: Rect,
+
+    // Entry counts cached at render time, for keeping cursors in range
+    item_count: usize,
+    joker_count: usize,
+    consumable_count: usize,
 
     // Popup state
     inspected_item: Option<usize>,
     inspected_joker: Option<usize>,
 
+    // Booster pack overlay (option count is cached at render time)
+    pack_cursor: usize,
+    pack_option_count: usize,
+    pack_option_rects: Vec<Rect>,
+
     // Deck viewer
     pub deck_viewer: DeckViewerState,
+
+    // Run info overlay
+    pub run_info: RunInfoState,
+    /// Purchase log overlay
+    pub purchase_log: PurchaseLogState,
+
+    // Key binding help overlay
+    pub help: HelpState,
+
+    // Sidebar money (tweened by the app each tick)
+    pub money_display: MoneyDisplay,
+
+    /// One-line feedback shown in place of the help line, cleared on the next key press
+    pub notice: Option<String>,
+
+    /// Ask before leaving while something is still affordable (mirrors the app settings)
+    pub confirm_leave: bool,
+    /// Shop items that could be bought right now (cached at render time)
+    affordable_items: usize,
+    /// "Leave the shop?" prompt is open
+    leave_prompt: bool,
+    /// "Sell every joker?" prompt is open
+    sell_all_prompt: bool,
 }
 
 impl ShopScreen {
@@ -60,45 +104,83 @@ impl ShopScreen {
             cursor: 0,
             focus: ShopFocus::Items,
             joker_cursor: 0,
+            consumable_cursor: 0,
             joker_rects: Vec::new(),
+            consumable_rects: Vec::new(),
             item_rects: Vec::new(),
             next_round_rect: Rect::default(),
             reroll_rect: Rect::default(),
             shop_panel_rect: Rect::default(),
+            buy_button_rect: Rect::default(),
+            item_count: 0,
+            joker_count: 0,
+            consumable_count: 0,
             inspected_item: None,
             inspected_joker: None,
+            pack_cursor: 0,
+            pack_option_count: 0,
+            pack_option_rects: Vec::new(),
             deck_viewer: DeckViewerState::new(),
+            run_info: RunInfoState::new(),
+            purchase_log: PurchaseLogState::new(),
+            help: HelpState::new(),
+            money_display: MoneyDisplay::default(),
+            notice: None,
+            confirm_leave: true,
+            affordable_items: 0,
+            leave_prompt: false,
+            sell_all_prompt: false,
         }
     }
 
+    /// True while the deck viewer filter is taking keystrokes
+    pub fn is_editing_filter(&self) -> bool {
+        self.deck_viewer.filter_editing
+    }
+
     pub fn reset(&mut self) {
         self.cursor = 0;
         self.focus = ShopFocus::Items;
         self.joker_cursor = 0;
+        self.consumable_cursor = 0;
         self.joker_rects.clear();
+        self.consumable_rects.clear();
         self.item_rects.clear();
         self.next_round_rect = Rect::default();
         self.reroll_rect = Rect::default();
         self.shop_panel_rect = Rect::default();
+        self.item_count = 0;
+        self.joker_count = 0;
+        self.consumable_count = 0;
         self.inspected_item = None;
         self.inspected_joker = None;
+        self.pack_cursor = 0;
+        self.pack_option_count = 0;
+        self.pack_option_rects.clear();
         self.deck_viewer = DeckViewerState::new();
+        self.run_info = RunInfoState::new();
+        self.purchase_log = PurchaseLogState::new();
+        self.help = HelpState::new();
+        self.notice = None;
+        self.affordable_items = 0;
+        self.leave_prompt = false;
+        self.sell_all_prompt = false;
     }
 
     // ─── Sidebar Data ─────────────────────────────────────────────────
 
     fn sidebar_data(&self, game: &RunState) -> SidebarWidget {
         let blind_color = match game.blind_type {
-            BlindType::Small => Theme::SMALL_BLIND,
-            BlindType::Big => Theme::BIG_BLIND,
-            BlindType::Boss(_) => Theme::BOSS_BLIND,
+            BlindType::Small => Theme::small_blind(),
+            BlindType::Big => Theme::big_blind(),
+            BlindType::Boss(_) => Theme::boss_blind(),
         };
 
         SidebarWidget::new(
             game.blind_type.name(),
             blind_color,
             game.score_target,
-            game.blind_type.reward(),
+            game.blind_reward(),
             game.round_score,
             String::new(), // No hand type in shop
             1,
@@ -112,6 +194,9 @@ impl ShopScreen {
             game.round_number(),
         )
         .shop(true)
+        .money_display(self.money_display)
+        .slots(SlotStats::from_run(game))
+        .stake(game.stake)
     }
 
     // ─── Rendering ────────────────────────────────────────────────────
@@ -148,22 +233,31 @@ impl ShopScreen {
             ])
             .split(right_col);
 
+            self.consumable_count = game.consumables.len();
+            self.consumable_cursor = clamp_cursor(self.consumable_cursor, self.consumable_count);
+            self.consumable_rects = ConsumableSlotsWidget::slot_rects(
+                right_parts[0],
+                ConsumableSlotsWidget::slot_count(&game.consumables, game.max_consumables),
+            );
+            let selected = (self.focus == ShopFocus::Consumables && !game.consumables.is_empty())
+                .then_some(self.consumable_cursor);
             frame.render_widget(
-                ConsumableSlotsWidget::new(&game.consumables, game.max_consumables),
+                ConsumableSlotsWidget::new(&game.consumables, game.max_consumables)
+                    .selected(selected),
                 right_parts[0],
             );
 
             self.deck_viewer.render_preview(
                 frame,
                 right_parts[1],
-                game.deck.total() + game.hand.len(),
-                game.deck.remaining(),
+                PileCounts::new(&game.deck, game.hand.len()),
             );
         }
 
         // ═══ CENTER AREA ═══
         let center = columns[1];
         self.render_center(frame, game, center);
+        self.affordable_items = game.affordable_shop_items();
 
         // ═══ OVERLAYS ═══
 
@@ -177,8 +271,143 @@ impl ShopScreen {
             self.render_joker_inspect(frame, game, ji, area);
         }
 
+        // Booster pack overlay
+        self.pack_option_count = game.pack_options.len();
+        self.pack_option_rects.clear();
+        if self.pack_option_count > 0 {
+            self.pack_cursor = self.pack_cursor.min(self.pack_option_count - 1);
+            self.render_pack_overlay(frame, game, columns[1]);
+        } else {
+            self.pack_cursor = 0;
+        }
+
+        // Leave confirmation
+        if self.leave_prompt {
+            self.render_leave_prompt(frame, columns[1]);
+        }
+
+        // Sell-all confirmation
+        if self.sell_all_prompt {
+            self.render_sell_all_prompt(frame, game, columns[1]);
+        }
+
         // Deck viewer overlay
         self.deck_viewer.render_overlay(frame, area);
+
+        // Run info overlay
+        self.run_info
+            .render_overlay(frame, area, &game.hand_levels, &game.stats.hand_counts);
+
+        // Purchase log overlay
+        self.purchase_log
+            .render_overlay(frame, area, &game.purchases);
+
+        // Help overlay (above every other overlay)
+        self.help.render_overlay(frame, area);
+    }
+
+    /// Leave right away, or open the confirmation if something is still affordable
+    fn request_leave(&mut self) -> Option<ScreenAction> {
+        if self.confirm_leave && self.affordable_items > 0 {
+            self.leave_prompt = true;
+            None
+        } else {
+            Some(ScreenAction::LeaveShop)
+        }
+    }
+
+    fn render_leave_prompt(&self, frame: &mut Frame, center: Rect) {
+        let plural = if self.affordable_items == 1 { "" } else { "s" };
+        let lines = vec![
+            Line::from(Span::styled(
+                "Leave the shop?",
+                Style::default()
+                    .fg(Theme::bright_text())
+                    .add_modifier(Modifier::BOLD),
+            )),
+            Line::from(Span::styled(
+                format!(
+                    "You can still afford {} item{}",
+                    self.affordable_items, plural
+                ),
+                Style::default().fg(Theme::money_color()),
+            )),
+            Line::from(""),
+            Line::from(vec![
+                Span::styled("[", Style::default().fg(Theme::dim_text())),
+                Span::styled("Y", Style::default().fg(Theme::gold())),
+                Span::styled("] Leave  [", Style::default().fg(Theme::dim_text())),
+                Span::styled("N", Style::default().fg(Theme::gold())),
+                Span::styled("] Stay", Style::default().fg(Theme::dim_text())),
+            ]),
+        ];
+
+        let popup_width = 36u16.min(center.width);
+        let popup_height = (lines.len() as u16 + 2).min(center.height);
+        let popup_area = Rect::new(
+            center.x + center.width.saturating_sub(popup_width) / 2,
+            center.y + center.height.saturating_sub(popup_height) / 2,
+            popup_width,
+            popup_height,
+        );
+
+        frame.render_widget(ratatui::widgets::Clear, popup_area);
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .border_type(BorderType::Rounded)
+            .border_style(Style::default().fg(Theme::card_selected()));
+        frame.render_widget(
+            Paragraph::new(lines)
+                .alignment(Alignment::Center)
+                .block(block),
+            popup_area,
+        );
+    }
+
+    fn render_sell_all_prompt(&self, frame: &mut Frame, game: &RunState, center: Rect) {
+        let total: u32 = game.jokers.iter().map(|j| j.total_sell_value()).sum();
+        let plural = if game.jokers.len() == 1 { "" } else { "s" };
+        let lines = vec![
+            Line::from(Span::styled(
+                "Sell every joker?",
+                Style::default()
+                    .fg(Theme::bright_text())
+                    .add_modifier(Modifier::BOLD),
+            )),
+            Line::from(Span::styled(
+                format!("{} joker{} for ${}", game.jokers.len(), plural, total),
+                Style::default().fg(Theme::money_color()),
+            )),
+            Line::from(""),
+            Line::from(vec![
+                Span::styled("[", Style::default().fg(Theme::dim_text())),
+                Span::styled("Y", Style::default().fg(Theme::gold())),
+                Span::styled("] Sell all  [", Style::default().fg(Theme::dim_text())),
+                Span::styled("N", Style::default().fg(Theme::gold())),
+                Span::styled("] Keep", Style::default().fg(Theme::dim_text())),
+            ]),
+        ];
+
+        let popup_width = 36u16.min(center.width);
+        let popup_height = (lines.len() as u16 + 2).min(center.height);
+        let popup_area = Rect::new(
+            center.x + center.width.saturating_sub(popup_width) / 2,
+            center.y + center.height.saturating_sub(popup_height) / 2,
+            popup_width,
+            popup_height,
+        );
+
+        frame.render_widget(ratatui::widgets::Clear, popup_area);
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .border_type(BorderType::Rounded)
+            .border_style(Style::default().fg(Theme::mult_color()));
+        frame.render_widget(
+            Paragraph::new(lines)
+                .alignment(Alignment::Center)
+                .block(block),
+            popup_area,
+        );
     }
 
     fn render_center(&mut self, frame: &mut Frame, game: &RunState, center: Rect) {
@@ -205,7 +434,17 @@ impl ShopScreen {
             .as_ref()
             .map(|s| s.items.as_slice())
             .unwrap_or(&[]);
-        let reroll_cost = game.shop.as_ref().map_or(5, |s| s.reroll_cost);
+        let reroll_cost = game
+            .shop
+            .as_ref()
+            .map_or(game.reroll_base, |s| s.reroll_cost);
+        let free_items = game
+            .shop
+            .as_ref()
+            .map(|s| s.free_items.as_slice())
+            .unwrap_or(&[]);
+        self.item_count = items.len();
+        self.cursor = clamp_cursor(self.cursor, self.item_count);
 
         let selected_item = if self.focus == ShopFocus::Items {
             Some(self.cursor)
@@ -213,34 +452,48 @@ impl ShopScreen {
             None
         };
 
-        let panel = ShopPanelWidget::new(items, game.money, reroll_cost, selected_item);
+        let panel = ShopPanelWidget::new(items, game.money, reroll_cost, selected_item)
+            .discount(game.shop_discount_percent)
+            .free_items(free_items);
 
         // Cache hit-test rects
         self.next_round_rect = ShopPanelWidget::next_round_rect(rows[1]);
         self.reroll_rect = ShopPanelWidget::reroll_rect(rows[1]);
-        self.item_rects = ShopPanelWidget::item_rects(rows[1], items.len());
+        self.item_rects = ShopPanelWidget::item_rects(rows[1], items);
 
         frame.render_widget(panel, rows[1]);
 
-        // === Help line ===
+        // === Help line (or consumable feedback) ===
+        if let Some(notice) = &self.notice {
+            let line = Line::from(Span::styled(
+                notice.as_str(),
+                Style::default().fg(Theme::mult_color()),
+            ));
+            frame.render_widget(Paragraph::new(line).alignment(Alignment::Center), rows[2]);
+            return;
+        }
         let reroll_cost_str = format!("${}", reroll_cost);
         let help = Paragraph::new(Line::from(vec![
-            Span::styled("[", Style::default().fg(Theme::DIM_TEXT)),
-            Span::styled("N", Style::default().fg(Theme::GOLD)),
-            Span::styled("] Next Round  [", Style::default().fg(Theme::DIM_TEXT)),
-            Span::styled("R", Style::default().fg(Theme::GOLD)),
+            Span::styled("[", Style::default().fg(Theme::dim_text())),
+            Span::styled("N", Style::default().fg(Theme::gold())),
+            Span::styled("] Next Round  [", Style::default().fg(Theme::dim_text())),
+            Span::styled("R", Style::default().fg(Theme::gold())),
             Span::styled(
                 format!("] Reroll ({})  [", reroll_cost_str),
-                Style::default().fg(Theme::DIM_TEXT),
+                Style::default().fg(Theme::dim_text()),
             ),
-            Span::styled("S", Style::default().fg(Theme::GOLD)),
-            Span::styled("] Sell Joker  [", Style::default().fg(Theme::DIM_TEXT)),
-            Span::styled("Tab", Style::default().fg(Theme::GOLD)),
-            Span::styled("] Switch  [", Style::default().fg(Theme::DIM_TEXT)),
-            Span::styled("\u{2190}\u{2192}", Style::default().fg(Theme::GOLD)),
-            Span::styled("] Move  [", Style::default().fg(Theme::DIM_TEXT)),
-            Span::styled("Enter", Style::default().fg(Theme::GOLD)),
-            Span::styled("] Select", Style::default().fg(Theme::DIM_TEXT)),
+            Span::styled("B", Style::default().fg(Theme::gold())),
+            Span::styled("] Buy  [", Style::default().fg(Theme::dim_text())),
+            Span::styled("S", Style::default().fg(Theme::gold())),
+            Span::styled("] Sell  [", Style::default().fg(Theme::dim_text())),
+            Span::styled("<>", Style::default().fg(Theme::gold())),
+            Span::styled("] Reorder  [", Style::default().fg(Theme::dim_text())),
+            Span::styled("Tab", Style::default().fg(Theme::gold())),
+            Span::styled("] Switch  [", Style::default().fg(Theme::dim_text())),
+            Span::styled("\u{2190}\u{2192}", Style::default().fg(Theme::gold())),
+            Span::styled("] Move  [", Style::default().fg(Theme::dim_text())),
+            Span::styled("Enter", Style::default().fg(Theme::gold())),
+            Span::styled("] Select", Style::default().fg(Theme::dim_text())),
         ]))
         .alignment(Alignment::Center);
         frame.render_widget(help, rows[2]);
@@ -253,13 +506,23 @@ impl ShopScreen {
         ])
         .split(area);
 
-        let joker_bar = JokerBarWidget::new(&game.jokers, game.max_jokers).selected(
-            if self.focus == ShopFocus::Jokers {
+        // Outline owned jokers that work with the inspected shop item
+        let synergy = self
+            .inspected_item
+            .and_then(|idx| game.shop.as_ref()?.items.get(idx))
+            .map(|item| item.synergy_targets(game))
+            .unwrap_or_default();
+
+        self.joker_count = game.jokers.len();
+        self.joker_cursor = clamp_cursor(self.joker_cursor, self.joker_count);
+
+        let joker_bar = JokerBarWidget::new(&game.jokers, game.max_jokers)
+            .selected(if self.focus == ShopFocus::Jokers {
                 Some(self.joker_cursor)
             } else {
                 None
-            },
-        );
+            })
+            .highlighted(&synergy.joker_indices);
 
         // Cache joker rects
         self.joker_rects.clear();
@@ -273,11 +536,11 @@ impl ShopScreen {
         frame.render_widget(joker_bar, parts[0]);
 
         // Joker slot counter
-        let counter = format!("{}/{}", game.jokers.len(), game.max_jokers);
+        let counter = format!("{}/{}", game.joker_slots_used(), game.max_jokers);
         frame.render_widget(
             Paragraph::new(Line::from(Span::styled(
                 counter,
-                Style::default().fg(Theme::MUTED_TEXT),
+                Style::default().fg(Theme::muted_text()),
             )))
             .alignment(Alignment::Center),
             parts[1],
@@ -287,14 +550,19 @@ impl ShopScreen {
     // ─── Popup Overlays ───────────────────────────────────────────────
 
     fn render_item_inspect(
-        &self,
+        &mut self,
         frame: &mut Frame,
         game: &RunState,
         item_index: usize,
         _screen_area: Rect,
     ) {
-        let items = match &game.shop {
-            Some(shop) => &shop.items,
+        self.buy_button_rect = Rect::default();
+
+        let (items, price) = match &game.shop {
+            Some(shop) => (
+                &shop.items,
+                shop.price(item_index, game.shop_discount_percent),
+            ),
             None => return,
         };
 
@@ -310,23 +578,26 @@ impl ShopScreen {
 
         let name = item.name();
         let desc = item.description();
-        let price = item.price();
+        let price = price.unwrap_or_default();
         let can_afford = game.money >= price;
 
         let (type_label, name_color) = match item {
             ShopItem::JokerItem(j) => {
                 let color = match j.joker_type.rarity() {
-                    JokerRarity::Common => Theme::COMMON,
-                    JokerRarity::Uncommon => Theme::UNCOMMON,
-                    JokerRarity::Rare => Theme::RARE,
-                    JokerRarity::Legendary => Theme::LEGENDARY,
+                    JokerRarity::Common => Theme::common(),
+                    JokerRarity::Uncommon => Theme::uncommon(),
+                    JokerRarity::Rare => Theme::rare(),
+                    JokerRarity::Legendary => Theme::legendary(),
                 };
                 ("Joker", color)
             }
             ShopItem::ConsumableItem(c) => match c.consumable_type {
-                ConsumableType::Planet(_) => ("Planet", Theme::CHIPS_COLOR),
-                ConsumableType::Tarot(_) => ("Tarot", Theme::LEGENDARY),
+                ConsumableType::Planet(_) => ("Planet", Theme::chips_color()),
+                ConsumableType::Tarot(_) => ("Tarot", Theme::legendary()),
+                ConsumableType::Spectral(_) => ("Spectral", Theme::spectral()),
             },
+            ShopItem::VoucherItem(_) => ("Voucher", Theme::gold()),
+            ShopItem::PackItem(_) => ("Booster Pack", Theme::money_color()),
         };
 
         let mut lines: Vec<Line> = vec![
@@ -335,45 +606,69 @@ impl ShopScreen {
                 Style::default().fg(name_color).add_modifier(Modifier::BOLD),
             )),
             Line::from(""),
-            Line::from(Span::styled(desc, Style::default().fg(Theme::MUTED_TEXT))),
+            Line::from(Span::styled(desc, Style::default().fg(Theme::muted_text()))),
             Line::from(""),
             Line::from(vec![
-                Span::styled("Type: ", Style::default().fg(Theme::DIM_TEXT)),
+                Span::styled("Type: ", Style::default().fg(Theme::dim_text())),
                 Span::styled(
                     type_label,
                     Style::default()
-                        .fg(Theme::BRIGHT_TEXT)
+                        .fg(Theme::bright_text())
                         .add_modifier(Modifier::BOLD),
                 ),
             ]),
             Line::from(vec![
-                Span::styled("Price: ", Style::default().fg(Theme::DIM_TEXT)),
+                Span::styled("Price: ", Style::default().fg(Theme::dim_text())),
                 Span::styled(
                     format!("${}", price),
                     Style::default()
                         .fg(if can_afford {
-                            Theme::MONEY_COLOR
+                            Theme::money_color()
                         } else {
-                            Theme::MULT_COLOR
+                            Theme::mult_color()
                         })
                         .add_modifier(Modifier::BOLD),
                 ),
             ]),
-            Line::from(""),
         ];
 
+        if let ShopItem::JokerItem(j) = item {
+            if let Some(line) = joker_edition_line(j) {
+                lines.push(line);
+            }
+            if let Some(preview) = j.joker_type.live_preview(game.money) {
+                lines.push(Line::from(Span::styled(
+                    preview,
+                    Style::default().fg(Theme::mult_color()),
+                )));
+            }
+        }
+
+        // Synergy notes (owned jokers are outlined in the joker bar)
+        let synergy = item.synergy_targets(game);
+        if !synergy.notes.is_empty() {
+            lines.push(Line::from(""));
+            for note in synergy.notes {
+                lines.push(Line::from(Span::styled(
+                    note,
+                    Style::default().fg(Theme::gold()),
+                )));
+            }
+        }
+        lines.push(Line::from(""));
+
         // Buy button line
         if can_afford {
             lines.push(Line::from(Span::styled(
                 format!("   Buy ${}   ", price),
                 Style::default()
-                    .fg(Theme::GOLD)
+                    .fg(Theme::gold())
                     .add_modifier(Modifier::BOLD),
             )));
         } else {
             lines.push(Line::from(Span::styled(
                 "  Can't afford  ",
-                Style::default().fg(Theme::DIM_TEXT),
+                Style::default().fg(Theme::dim_text()),
             )));
         }
 
@@ -404,6 +699,14 @@ impl ShopScreen {
         let inner = block.inner(popup_area);
         frame.render_widget(block, popup_area);
 
+        // The buy button is always the last line
+        let buy_y = inner.y + lines.len() as u16 - 1;
+        self.buy_button_rect = if can_afford && buy_y < inner.bottom() {
+            Rect::new(inner.x, buy_y, inner.width, 1)
+        } else {
+            Rect::default()
+        };
+
         for (i, line) in lines.iter().enumerate() {
             let y = inner.y + i as u16;
             if y >= inner.bottom() {
@@ -434,7 +737,7 @@ impl ShopScreen {
         };
 
         let jt = &joker.joker_type;
-        let name = jt.name();
+        let name = joker.name();
         let desc = jt.description();
         let rarity = jt.rarity();
         let sell_value = joker.total_sell_value();
@@ -446,24 +749,24 @@ impl ShopScreen {
             JokerRarity::Legendary => "Legendary",
         };
         let rarity_color = match rarity {
-            JokerRarity::Common => Theme::COMMON,
-            JokerRarity::Uncommon => Theme::UNCOMMON,
-            JokerRarity::Rare => Theme::RARE,
-            JokerRarity::Legendary => Theme::LEGENDARY,
+            JokerRarity::Common => Theme::common(),
+            JokerRarity::Uncommon => Theme::uncommon(),
+            JokerRarity::Rare => Theme::rare(),
+            JokerRarity::Legendary => Theme::legendary(),
         };
 
-        let lines: Vec<Line> = vec![
+        let mut lines: Vec<Line> = vec![
             Line::from(Span::styled(
                 name,
                 Style::default()
-                    .fg(Theme::BRIGHT_TEXT)
+                    .fg(Theme::bright_text())
                     .add_modifier(Modifier::BOLD),
             )),
             Line::from(""),
-            Line::from(Span::styled(desc, Style::default().fg(Theme::MUTED_TEXT))),
+            Line::from(Span::styled(desc, Style::default().fg(Theme::muted_text()))),
             Line::from(""),
             Line::from(vec![
-                Span::styled("Rarity: ", Style::default().fg(Theme::DIM_TEXT)),
+                Span::styled("Rarity: ", Style::default().fg(Theme::dim_text())),
                 Span::styled(
                     rarity_str,
                     Style::default()
@@ -472,16 +775,26 @@ impl ShopScreen {
                 ),
             ]),
             Line::from(vec![
-                Span::styled("Sell: ", Style::default().fg(Theme::DIM_TEXT)),
+                Span::styled("Sell: ", Style::default().fg(Theme::dim_text())),
                 Span::styled(
                     format!("${}", sell_value),
                     Style::default()
-                        .fg(Theme::MONEY_COLOR)
+                        .fg(Theme::money_color())
                         .add_modifier(Modifier::BOLD),
                 ),
             ]),
         ];
 
+        if let Some(line) = joker_edition_line(joker) {
+            lines.push(line);
+        }
+        if let Some(preview) = joker.live_preview(game.money) {
+            lines.push(Line::from(Span::styled(
+                preview,
+                Style::default().fg(Theme::mult_color()),
+            )));
+        }
+
         let content_width = lines.iter().map(|l| l.width() as u16).max().unwrap_or(10) + 4;
         let popup_width = content_width.clamp(20, 40);
         let popup_height = (lines.len() as u16) + 3;
@@ -521,55 +834,149 @@ impl ShopScreen {
         }
     }
 
-    // ─── Buy Button Hit Test ──────────────────────────────────────────
-
-    /// Check if a click hits the "Buy" button in the item inspect popup.
-    /// The buy button is the last line content in the popup, positioned below the item card.
-    fn hit_test_buy_button(&self, game: &RunState, col: u16, row: u16) -> bool {
-        let item_index = match self.inspected_item {
-            Some(idx) => idx,
-            None => return false,
-        };
+    fn render_pack_overlay(&mut self, frame: &mut Frame, game: &RunState, center: Rect) {
+        let count = game.pack_options.len() as u16;
+        let spacing = 2u16;
+        let popup_width =
+            (count * PACK_OPTION_WIDTH + count.saturating_sub(1) * spacing + 4).min(center.width);
+        let popup_height = (PACK_OPTION_HEIGHT + 5).min(center.height);
+        let popup_area = Rect::new(
+            center.x + center.width.saturating_sub(popup_width) / 2,
+            center.y + center.height.saturating_sub(popup_height) / 2,
+            popup_width,
+            popup_height,
+        );
 
-        let items = match &game.shop {
-            Some(shop) => &shop.items,
-            None => return false,
-        };
+        frame.render_widget(ratatui::widgets::Clear, popup_area);
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .border_type(BorderType::Double)
+            .border_style(Style::default().fg(Theme::money_color()))
+            .title(Span::styled(
+                " Booster Pack - Choose 1 ",
+                Style::default()
+                    .fg(Theme::bright_text())
+                    .add_modifier(Modifier::BOLD),
+            ))
+            .title_alignment(Alignment::Center)
+            .padding(Padding::horizontal(1));
+        let inner = block.inner(popup_area);
+        frame.render_widget(block, popup_area);
 
-        let item = match items.get(item_index) {
-            Some(i) => i,
-            None => return false,
-        };
+        let rows = Layout::vertical([
+            Constraint::Length(1),
+            Constraint::Length(PACK_OPTION_HEIGHT),
+            Constraint::Min(0),
+            Constraint::Length(1),
+        ])
+        .split(inner);
 
-        if game.money < item.price() {
-            return false;
+        for (i, option) in game.pack_options.iter().enumerate() {
+            let x = rows[1].x + i as u16 * (PACK_OPTION_WIDTH + spacing);
+            if x + PACK_OPTION_WIDTH > rows[1].right() {
+                break;
+            }
+            let rect = Rect::new(x, rows[1].y, PACK_OPTION_WIDTH, rows[1].height);
+            self.pack_option_rects.push(rect);
+            self.render_pack_option(
+                frame,
+                option,
+                rect,
+                i == self.pack_cursor,
+                game.can_pick_from_pack(i),
+            );
         }
 
-        let item_rect = match self.item_rects.get(item_index).copied() {
-            Some(r) if r.width > 0 => r,
-            _ => return false,
+        let help = Paragraph::new(Line::from(vec![
+            Span::styled("[", Style::default().fg(Theme::dim_text())),
+            Span::styled("\u{2190}\u{2192}", Style::default().fg(Theme::gold())),
+            Span::styled("] Move  [", Style::default().fg(Theme::dim_text())),
+            Span::styled("Enter", Style::default().fg(Theme::gold())),
+            Span::styled("] Pick  [", Style::default().fg(Theme::dim_text())),
+            Span::styled("S", Style::default().fg(Theme::gold())),
+            Span::styled("] Skip", Style::default().fg(Theme::dim_text())),
+        ]))
+        .alignment(Alignment::Center);
+        frame.render_widget(help, rows[3]);
+    }
+
+    fn render_pack_option(
+        &self,
+        frame: &mut Frame,
+        option: &PackOption,
+        area: Rect,
+        selected: bool,
+        can_pick: bool,
+    ) {
+        let (type_label, name_color) = match option {
+            PackOption::Joker(j) => {
+                let color = match j.joker_type.rarity() {
+                    JokerRarity::Common => Theme::common(),
+                    JokerRarity::Uncommon => Theme::uncommon(),
+                    JokerRarity::Rare => Theme::rare(),
+                    JokerRarity::Legendary => Theme::legendary(),
+                };
+                ("Joker", color)
+            }
+            PackOption::Consumable(c) => match c.consumable_type {
+                ConsumableType::Planet(_) => ("Planet", Theme::chips_color()),
+                ConsumableType::Tarot(_) => ("Tarot", Theme::legendary()),
+                ConsumableType::Spectral(_) => ("Spectral", Theme::spectral()),
+            },
+            PackOption::Card(_) => ("Playing Card", Theme::bright_text()),
         };
 
-        // Reconstruct the popup area to check if click is on the buy button region
-        // The popup has ~11 lines of content + 2 border = ~13 height
-        let popup_height = 11u16 + 3;
-        let popup_width = 28u16; // approximate
+        let border_color = if selected {
+            Theme::card_selected()
+        } else {
+            Theme::card_border()
+        };
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .border_type(if selected {
+                BorderType::Double
+            } else {
+                BorderType::Rounded
+            })
+            .border_style(Style::default().fg(border_color));
 
-        let popup_x = item_rect
-            .x
-            .saturating_add(item_rect.width / 2)
-            .saturating_sub(popup_width / 2);
-        let popup_y = item_rect.bottom();
+        let mut lines = vec![
+            Line::from(Span::styled(
+                option.name(),
+                Style::default().fg(name_color).add_modifier(Modifier::BOLD),
+            )),
+            Line::from(Span::styled(
+                type_label,
+                Style::default().fg(Theme::dim_text()),
+            )),
+            Line::from(Span::styled(
+                option.description(),
+                Style::default().fg(Theme::muted_text()),
+            )),
+        ];
+        if !can_pick {
+            lines.push(Line::from(Span::styled(
+                "Slots full",
+                Style::default().fg(Theme::mult_color()),
+            )));
+        }
+
+        frame.render_widget(
+            Paragraph::new(lines).block(block).wrap(Wrap { trim: true }),
+            area,
+        );
+    }
 
-        // Buy button is roughly at the bottom of the popup content
-        // Inside the popup: border(1) + 7 content lines + buy button line = row at y+9
-        let buy_y = popup_y + popup_height - 3; // approximate row of "Buy" button
+    // ─── Buy Button Hit Test ──────────────────────────────────────────
 
-        // Check if click is roughly in the popup area on the buy button row
-        col >= popup_x
-            && col < popup_x + popup_width
-            && row >= buy_y.saturating_sub(1)
-            && row <= buy_y + 1
+    /// Check if a click hits the "Buy" button in the item inspect popup.
+    /// The buy button is the last line content in the popup, positioned below the item card.
+    fn hit_test_buy_button(&self, col: u16, row: u16) -> bool {
+        self.inspected_item.is_some()
+            && self.buy_button_rect.width > 0
+            && self
+                .buy_button_rect
+                .contains(ratatui::layout::Position::new(col, row))
     }
 
     // ─── Input Handling ───────────────────────────────────────────────
@@ -579,12 +986,25 @@ impl ShopScreen {
         mouse: MouseEvent,
         game: &Option<RunState>,
     ) -> Option<ScreenAction> {
-        let game = match game {
-            Some(g) => g,
-            None => return None,
-        };
+        game.as_ref()?;
+
+        if self.deck_viewer.handle_mouse_scroll(mouse.kind) {
+            return None;
+        }
 
         if let MouseEventKind::Down(MouseButton::Left) = mouse.kind {
+            if self.help.handle_mouse_click()
+                || self.run_info.handle_mouse_click()
+                || self.purchase_log.handle_mouse_click()
+            {
+                return None;
+            }
+            // Clicking away from the leave prompt means "stay"
+            if self.leave_prompt || self.sell_all_prompt {
+                self.leave_prompt = false;
+                self.sell_all_prompt = false;
+                return None;
+            }
             let col = mouse.column;
             let row = mouse.row;
 
@@ -596,9 +1016,20 @@ impl ShopScreen {
                 return None;
             }
 
+            // An open booster pack takes every click until an option is picked or skipped
+            if self.pack_option_count > 0 {
+                for (i, rect) in self.pack_option_rects.iter().enumerate() {
+                    if rect.contains(ratatui::layout::Position::new(col, row)) {
+                        self.pack_cursor = i;
+                        return Some(ScreenAction::PickFromPack(i));
+                    }
+                }
+                return None;
+            }
+
             // If item inspect popup is open, check buy button first
             if let Some(idx) = self.inspected_item {
-                if self.hit_test_buy_button(game, col, row) {
+                if self.hit_test_buy_button(col, row) {
                     self.inspected_item = None;
                     return Some(ScreenAction::BuyShopItem(idx));
                 }
@@ -620,7 +1051,7 @@ impl ShopScreen {
                 && row >= self.next_round_rect.y
                 && row < self.next_round_rect.y + self.next_round_rect.height
             {
-                return Some(ScreenAction::LeaveShop);
+                return self.request_leave();
             }
 
             // Check "Reroll" button
@@ -648,6 +1079,20 @@ impl ShopScreen {
                 }
             }
 
+            // Check consumable slots (select for selling)
+            for (i, rect) in self.consumable_rects.iter().enumerate() {
+                if rect.width > 0
+                    && col >= rect.x
+                    && col < rect.x + rect.width
+                    && row >= rect.y
+                    && row < rect.y + rect.height
+                {
+                    self.focus = ShopFocus::Consumables;
+                    self.consumable_cursor = i;
+                    return None;
+                }
+            }
+
             // Check jokers (toggle inspect popup)
             for (i, rect) in self.joker_rects.iter().enumerate() {
                 if rect.width > 0
@@ -669,6 +1114,52 @@ impl ShopScreen {
     }
 }
 
+impl ShopScreen {
+    /// Step the focused cursor left or right, wrapping at either end
+    fn move_cursor(&mut self, forward: bool) {
+        let (cursor, count) = match self.focus {
+            ShopFocus::Items => (&mut self.cursor, self.item_count),
+            ShopFocus::Jokers => (&mut self.joker_cursor, self.joker_count),
+            ShopFocus::Consumables => (&mut self.consumable_cursor, self.consumable_count),
+        };
+        *cursor = wrap_cursor(*cursor, count, forward);
+    }
+
+    /// Swap the selected joker with its neighbour; the cursor follows it.
+    /// Does nothing at either end.
+    fn move_joker(&mut self, right: bool) -> Option<ScreenAction> {
+        let from = clamp_cursor(self.joker_cursor, self.joker_count);
+        let to = if right {
+            from + 1
+        } else {
+            from.checked_sub(1)?
+        };
+        if to >= self.joker_count {
+            return None;
+        }
+        self.joker_cursor = to;
+        Some(ScreenAction::MoveJoker { from, to })
+    }
+}
+
+/// Keep `cursor` on an existing entry (0 when there are none)
+fn clamp_cursor(cursor: usize, count: usize) -> usize {
+    cursor.min(count.saturating_sub(1))
+}
+
+/// Next or previous entry, wrapping around
+fn wrap_cursor(cursor: usize, count: usize, forward: bool) -> usize {
+    if count == 0 {
+        return 0;
+    }
+    let cursor = clamp_cursor(cursor, count);
+    if forward {
+        (cursor + 1) % count
+    } else {
+        (cursor + count - 1) % count
+    }
+}
+
 impl Screen for ShopScreen {
     fn render(&mut self, frame: &mut Frame, game: &Option<RunState>) {
         // Delegate to the inherent method
@@ -676,10 +1167,71 @@ impl Screen for ShopScreen {
     }
 
     fn handle_key(&mut self, key: KeyEvent) -> Option<ScreenAction> {
+        // '?' help sits above everything, including the deck viewer (unless typing a filter)
+        let help_context = if self.deck_viewer.open {
+            HelpContext::DeckViewer
+        } else {
+            HelpContext::Shop
+        };
+        if !self.deck_viewer.filter_editing && self.help.handle_key(key.code, help_context) {
+            return None;
+        }
         // Deck viewer overlay intercepts all keys when open
         if self.deck_viewer.handle_key(key.code) {
             return None;
         }
+        if self.run_info.handle_key(key.code) {
+            return None;
+        }
+        if self.purchase_log.handle_key(key.code) {
+            return None;
+        }
+
+        self.notice = None;
+
+        // Booster pack overlay captures input while open
+        if self.pack_option_count > 0 {
+            match key.code {
+                KeyCode::Left | KeyCode::Char('h') => {
+                    self.pack_cursor = self.pack_cursor.saturating_sub(1);
+                }
+                KeyCode::Right | KeyCode::Char('l') => {
+                    self.pack_cursor = (self.pack_cursor + 1).min(self.pack_option_count - 1);
+                }
+                KeyCode::Enter | KeyCode::Char(' ') => {
+                    return Some(ScreenAction::PickFromPack(self.pack_cursor));
+                }
+                KeyCode::Esc | KeyCode::Char('s') | KeyCode::Char('S') => {
+                    return Some(ScreenAction::SkipPack);
+                }
+                // Check the deck before taking a card or a Tarot
+                KeyCode::Char('v') | KeyCode::Char('V') => {
+                    return Some(ScreenAction::OpenDeckViewer);
+                }
+                _ => {}
+            }
+            return None;
+        }
+
+        if self.leave_prompt {
+            self.leave_prompt = false;
+            if matches!(
+                key.code,
+                KeyCode::Char('y') | KeyCode::Char('Y') | KeyCode::Enter
+            ) {
+                return Some(ScreenAction::LeaveShop);
+            }
+            return None;
+        }
+
+        // Only an explicit yes sells; anything else is treated as a misclick
+        if self.sell_all_prompt {
+            self.sell_all_prompt = false;
+            if matches!(key.code, KeyCode::Char('y') | KeyCode::Char('Y')) {
+                return Some(ScreenAction::SellAllJokers);
+            }
+            return None;
+        }
 
         // Dismiss popups first
         if let Some(idx) = self.inspected_item {
@@ -705,30 +1257,26 @@ impl Screen for ShopScreen {
 
         match key.code {
             KeyCode::Char('n') | KeyCode::Char('N') => {
-                return Some(ScreenAction::LeaveShop);
+                return self.request_leave();
             }
             KeyCode::Tab => {
                 self.focus = match self.focus {
                     ShopFocus::Items => ShopFocus::Jokers,
-                    ShopFocus::Jokers => ShopFocus::Items,
+                    ShopFocus::Jokers => ShopFocus::Consumables,
+                    ShopFocus::Consumables => ShopFocus::Items,
                 };
+                self.cursor = clamp_cursor(self.cursor, self.item_count);
+                self.joker_cursor = clamp_cursor(self.joker_cursor, self.joker_count);
+                self.consumable_cursor =
+                    clamp_cursor(self.consumable_cursor, self.consumable_count);
+            }
+            KeyCode::Left | KeyCode::Char('h') => self.move_cursor(false),
+            KeyCode::Right | KeyCode::Char('l') => self.move_cursor(true),
+            KeyCode::Char('b') | KeyCode::Char('B')
+                if self.focus == ShopFocus::Items && self.item_count > 0 =>
+            {
+                return Some(ScreenAction::BuyShopItem(self.cursor));
             }
-            KeyCode::Left | KeyCode::Char('h') => match self.focus {
-                ShopFocus::Items => {
-                    if self.cursor > 0 {
-                        self.cursor -= 1;
-                    }
-                }
-                ShopFocus::Jokers => {
-                    if self.joker_cursor > 0 {
-                        self.joker_cursor -= 1;
-                    }
-                }
-            },
-            KeyCode::Right | KeyCode::Char('l') => match self.focus {
-                ShopFocus::Items => self.cursor += 1,
-                ShopFocus::Jokers => self.joker_cursor += 1,
-            },
             KeyCode::Enter | KeyCode::Char(' ') => match self.focus {
                 ShopFocus::Items => {
                     // Open the inspect popup for the current item
@@ -738,20 +1286,205 @@ impl Screen for ShopScreen {
                     // Open joker inspect
                     self.inspected_joker = Some(self.joker_cursor);
                 }
+                ShopFocus::Consumables => {
+                    return Some(ScreenAction::UseConsumable(self.consumable_cursor));
+                }
             },
+            KeyCode::Char('u') | KeyCode::Char('U') if self.focus == ShopFocus::Consumables => {
+                return Some(ScreenAction::UseConsumable(self.consumable_cursor));
+            }
             KeyCode::Char('r') | KeyCode::Char('R') => {
                 return Some(ScreenAction::RerollShop);
             }
-            KeyCode::Char('s') | KeyCode::Char('S') => {
-                if self.focus == ShopFocus::Jokers {
-                    return Some(ScreenAction::SellJoker(self.joker_cursor));
-                }
+            KeyCode::Char('s') | KeyCode::Char('S') if self.focus == ShopFocus::Jokers => {
+                return Some(ScreenAction::SellJoker(self.joker_cursor));
+            }
+            KeyCode::Char('X') if self.joker_count > 0 => {
+                self.sell_all_prompt = true;
+            }
+            KeyCode::Char('<') | KeyCode::Char(',') if self.focus == ShopFocus::Jokers => {
+                return self.move_joker(false);
+            }
+            KeyCode::Char('>') | KeyCode::Char('.') if self.focus == ShopFocus::Jokers => {
+                return self.move_joker(true);
+            }
+            KeyCode::Char('s') | KeyCode::Char('S') if self.focus == ShopFocus::Consumables => {
+                return Some(ScreenAction::SellConsumable(self.consumable_cursor));
             }
             KeyCode::Char('v') | KeyCode::Char('V') => {
                 return Some(ScreenAction::OpenDeckViewer);
             }
+            KeyCode::Char('i') | KeyCode::Char('I') => {
+                self.run_info.toggle();
+            }
+            KeyCode::Char('p') | KeyCode::Char('P') => {
+                self.purchase_log.toggle();
+            }
+            KeyCode::Char('q') | KeyCode::Char('Q') | KeyCode::Esc => {
+                return Some(ScreenAction::Quit);
+            }
             _ => {}
         }
         None
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crossterm::event::KeyModifiers;
+
+    fn press(shop: &mut ShopScreen, code: KeyCode) -> Option<ScreenAction> {
+        Screen::handle_key(shop, KeyEvent::new(code, KeyModifiers::NONE))
+    }
+
+    fn shop_with_items(count: usize) -> ShopScreen {
+        let mut shop = ShopScreen::new();
+        shop.item_count = count;
+        shop
+    }
+
+    #[test]
+    fn test_cursor_with_no_items_stays_put_and_cannot_buy() {
+        let mut shop = shop_with_items(0);
+        press(&mut shop, KeyCode::Right);
+        press(&mut shop, KeyCode::Left);
+        assert_eq!(shop.cursor, 0);
+        assert!(press(&mut shop, KeyCode::Char('b')).is_none());
+    }
+
+    #[test]
+    fn test_cursor_with_one_item_wraps_onto_itself() {
+        let mut shop = shop_with_items(1);
+        press(&mut shop, KeyCode::Right);
+        assert_eq!(shop.cursor, 0);
+        press(&mut shop, KeyCode::Left);
+        assert_eq!(shop.cursor, 0);
+        assert!(matches!(
+            press(&mut shop, KeyCode::Char('b')),
+            Some(ScreenAction::BuyShopItem(0))
+        ));
+    }
+
+    #[test]
+    fn test_cursor_with_three_items_wraps_both_ways() {
+        let mut shop = shop_with_items(3);
+        press(&mut shop, KeyCode::Left);
+        assert_eq!(shop.cursor, 2);
+        press(&mut shop, KeyCode::Right);
+        assert_eq!(shop.cursor, 0);
+        for _ in 0..4 {
+            press(&mut shop, KeyCode::Right);
+        }
+        assert_eq!(shop.cursor, 1);
+
+        // A stale cursor past the end is pulled back in range
+        shop.cursor = 7;
+        press(&mut shop, KeyCode::Right);
+        assert_eq!(shop.cursor, 0);
+        assert!(matches!(
+            press(&mut shop, KeyCode::Char('b')),
+            Some(ScreenAction::BuyShopItem(0))
+        ));
+    }
+
+    #[test]
+    fn test_leave_asks_first_only_while_something_is_affordable() {
+        let mut shop = shop_with_items(3);
+        shop.affordable_items = 1;
+        assert!(press(&mut shop, KeyCode::Char('n')).is_none());
+        assert!(shop.leave_prompt);
+        assert!(press(&mut shop, KeyCode::Char('n')).is_none());
+        assert!(!shop.leave_prompt);
+
+        press(&mut shop, KeyCode::Char('n'));
+        assert!(matches!(
+            press(&mut shop, KeyCode::Char('y')),
+            Some(ScreenAction::LeaveShop)
+        ));
+
+        shop.affordable_items = 0;
+        assert!(matches!(
+            press(&mut shop, KeyCode::Char('n')),
+            Some(ScreenAction::LeaveShop)
+        ));
+
+        // The setting turns the guard off
+        shop.affordable_items = 2;
+        shop.confirm_leave = false;
+        assert!(matches!(
+            press(&mut shop, KeyCode::Char('n')),
+            Some(ScreenAction::LeaveShop)
+        ));
+    }
+
+    #[test]
+    fn test_sell_all_jokers_needs_an_explicit_yes() {
+        let mut shop = shop_with_items(3);
+        assert!(press(&mut shop, KeyCode::Char('X')).is_none());
+        assert!(!shop.sell_all_prompt);
+
+        shop.joker_count = 2;
+        assert!(press(&mut shop, KeyCode::Char('X')).is_none());
+        assert!(shop.sell_all_prompt);
+        assert!(press(&mut shop, KeyCode::Enter).is_none());
+        assert!(!shop.sell_all_prompt);
+
+        press(&mut shop, KeyCode::Char('X'));
+        assert!(matches!(
+            press(&mut shop, KeyCode::Char('y')),
+            Some(ScreenAction::SellAllJokers)
+        ));
+    }
+
+    #[test]
+    fn test_purchase_log_opens_with_p_and_takes_keys_until_closed() {
+        let mut shop = shop_with_items(2);
+        assert!(press(&mut shop, KeyCode::Char('p')).is_none());
+        assert!(shop.purchase_log.open);
+        // Buying is blocked while the log is open
+        assert!(press(&mut shop, KeyCode::Char('b')).is_none());
+        assert!(press(&mut shop, KeyCode::Esc).is_none());
+        assert!(!shop.purchase_log.open);
+    }
+
+    #[test]
+    fn test_deck_viewer_opens_while_picking_from_a_pack() {
+        let mut shop = shop_with_items(2);
+        shop.pack_option_count = 3;
+        assert!(matches!(
+            press(&mut shop, KeyCode::Char('v')),
+            Some(ScreenAction::OpenDeckViewer)
+        ));
+    }
+
+    #[test]
+    fn test_tab_to_empty_jokers_keeps_cursor_in_range() {
+        let mut shop = shop_with_items(3);
+        shop.joker_cursor = 4;
+        press(&mut shop, KeyCode::Tab);
+        assert_eq!(shop.focus, ShopFocus::Jokers);
+        assert_eq!(shop.joker_cursor, 0);
+        press(&mut shop, KeyCode::Right);
+        assert_eq!(shop.joker_cursor, 0);
+    }
+
+    #[test]
+    fn test_reorder_keys_move_the_selected_joker() {
+        let mut shop = ShopScreen::new();
+        shop.focus = ShopFocus::Jokers;
+        shop.joker_count = 3;
+
+        assert!(press(&mut shop, KeyCode::Char('<')).is_none());
+        assert!(matches!(
+            press(&mut shop, KeyCode::Char('>')),
+            Some(ScreenAction::MoveJoker { from: 0, to: 1 })
+        ));
+        assert!(matches!(
+            press(&mut shop, KeyCode::Char('>')),
+            Some(ScreenAction::MoveJoker { from: 1, to: 2 })
+        ));
+        assert_eq!(shop.joker_cursor, 2);
+        assert!(press(&mut shop, KeyCode::Char('>')).is_none());
+    }
+}
@@ -0,0 +1,136 @@
+use std::io;
+use std::path::Path;
+
+use serde::{Deserialize, Serialize};
+
+use balatrust_widgets::theme::ColorScheme;
+
+/// User-adjustable options
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Settings {
+    /// Autosave after this many game-affecting actions (0 = only at shop entry)
+    pub autosave_interval: u32,
+    /// Playback speed of the scoring animation
+    pub animation_speed: AnimationSpeed,
+    /// Ask before leaving a shop that still has affordable items
+    pub confirm_leave_shop: bool,
+    /// Capture the mouse for clicks; off leaves the terminal's own text selection working
+    pub mouse_capture: bool,
+    /// Warn when the selection can't beat the blind on the last hand
+    pub last_hand_warning: bool,
+    /// Colors the whole UI draws with
+    pub color_scheme: ColorScheme,
+    /// One color and a letter per suit, instead of red / black alone
+    pub accessible_suits: bool,
+    /// Outline the hand cards that would score the most (beginner assist)
+    pub play_hints: bool,
+}
+
+impl Default for Settings {
+    fn default() -> Self {
+        Self {
+            autosave_interval: 5,
+            animation_speed: AnimationSpeed::Normal,
+            confirm_leave_shop: true,
+            mouse_capture: true,
+            last_hand_warning: true,
+            color_scheme: ColorScheme::Dark,
+            accessible_suits: false,
+            play_hints: false,
+        }
+    }
+}
+
+impl Settings {
+    /// Load settings from `path`, falling back to defaults when missing or unreadable
+    pub fn load_from_path(path: &Path) -> Self {
+        std::fs::read_to_string(path)
+            .ok()
+            .and_then(|json| serde_json::from_str(&json).ok())
+            .unwrap_or_default()
+    }
+
+    pub fn save_to_path(&self, path: &Path) -> io::Result<()> {
+        let json = serde_json::to_string_pretty(self).map_err(io::Error::other)?;
+        crate::storage::write_file(path, &json)
+    }
+}
+
+/// Scoring animation speed multiplier
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum AnimationSpeed {
+    Half,
+    Normal,
+    Double,
+    /// No animation: the score is applied right away
+    Instant,
+}
+
+impl AnimationSpeed {
+    pub const ALL: [AnimationSpeed; 4] = [
+        AnimationSpeed::Half,
+        AnimationSpeed::Normal,
+        AnimationSpeed::Double,
+        AnimationSpeed::Instant,
+    ];
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            AnimationSpeed::Half => "0.5x",
+            AnimationSpeed::Normal => "1x",
+            AnimationSpeed::Double => "2x",
+            AnimationSpeed::Instant => "Instant",
+        }
+    }
+
+    /// Next speed in `ALL`, wrapping around (`forward = false` goes back)
+    pub fn cycle(&self, forward: bool) -> Self {
+        let len = Self::ALL.len();
+        let idx = Self::ALL.iter().position(|s| s == self).unwrap_or(0);
+        let next = if forward {
+            (idx + 1) % len
+        } else {
+            (idx + len - 1) % len
+        };
+        Self::ALL[next]
+    }
+
+    /// Scale a phase length in ticks. Faster speeds round up and never go below 1.
+    pub fn scale_ticks(&self, ticks: u8) -> u8 {
+        match self {
+            AnimationSpeed::Half => ticks.saturating_mul(2),
+            AnimationSpeed::Normal => ticks,
+            AnimationSpeed::Double => ticks.div_ceil(2).max(1),
+            AnimationSpeed::Instant => 0,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_double_speed_halves_ticks_rounding_up() {
+        let speed = AnimationSpeed::Double;
+        assert_eq!(speed.scale_ticks(8), 4);
+        assert_eq!(speed.scale_ticks(12), 6);
+        assert_eq!(speed.scale_ticks(7), 4);
+        assert_eq!(speed.scale_ticks(1), 1);
+        assert_eq!(speed.scale_ticks(0), 1);
+    }
+
+    #[test]
+    fn test_settings_missing_mouse_capture_default_to_on() {
+        let settings: Settings = serde_json::from_str(r#"{"confirm_leave_shop": false}"#).unwrap();
+        assert!(settings.mouse_capture);
+        assert!(!settings.confirm_leave_shop);
+    }
+
+    #[test]
+    fn test_half_speed_doubles_ticks() {
+        assert_eq!(AnimationSpeed::Half.scale_ticks(10), 20);
+        assert_eq!(AnimationSpeed::Normal.scale_ticks(10), 10);
+    }
+}
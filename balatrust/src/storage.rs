@@ -0,0 +1,55 @@
+use std::io;
+use std::path::{Path, PathBuf};
+
+/// Directory where Balatrust keeps its files
+/// (`$XDG_DATA_HOME/balatrust`, falling back to `~/.local/share/balatrust`)
+pub fn data_dir() -> PathBuf {
+    if let Some(dir) = std::env::var_os("XDG_DATA_HOME").filter(|d| !d.is_empty()) {
+        return PathBuf::from(dir).join("balatrust");
+    }
+    match std::env::var_os("HOME") {
+        Some(home) => PathBuf::from(home)
+            .join(".local")
+            .join("share")
+            .join("balatrust"),
+        None => std::env::temp_dir().join("balatrust"),
+    }
+}
+
+/// File that receives the plain-text "announce state" dump
+pub fn announce_path() -> PathBuf {
+    data_dir().join("state.txt")
+}
+
+/// Autosave file for the run in progress
+pub fn save_path() -> PathBuf {
+    data_dir().join("save.json")
+}
+
+/// User settings, kept next to the autosave
+pub fn settings_path() -> PathBuf {
+    data_dir().join("settings.json")
+}
+
+/// Delete the autosave once its run is over, so "Continue" can't resume it
+pub fn clear_save() {
+    let _ = std::fs::remove_file(save_path());
+}
+
+/// Plain-text copy of the last run's recap card
+pub fn recap_path() -> PathBuf {
+    data_dir().join("recap.txt")
+}
+
+/// Seed and recorded actions of the last run, for `--replay`
+pub fn replay_path() -> PathBuf {
+    data_dir().join("replay.json")
+}
+
+/// Write `contents` to `path`, creating parent directories as needed
+pub fn write_file(path: &Path, contents: &str) -> io::Result<()> {
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+    std::fs::write(path, contents)
+}
@@ -1,7 +1,12 @@
 use std::fmt;
 
+use serde::{Deserialize, Serialize};
+
+use crate::card::{PlayingCard, Suit};
+use crate::stake::Stake;
+
 /// The type of blind within an ante
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum BlindType {
     Small,
     Big,
@@ -35,6 +40,14 @@ impl BlindType {
         }
     }
 
+    /// Money reward for beating this blind at `stake`
+    pub fn reward_at(&self, stake: Stake) -> u32 {
+        match self {
+            BlindType::Small if !stake.pays_small_blind() => 0,
+            _ => self.reward(),
+        }
+    }
+
     /// Can this blind be skipped?
     pub fn can_skip(&self) -> bool {
         matches!(self, BlindType::Small | BlindType::Big)
@@ -48,7 +61,7 @@ impl fmt::Display for BlindType {
 }
 
 /// Boss blind effects
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum BossBlind {
     /// Discards 2 random cards per hand played
     TheHook,
@@ -66,10 +79,24 @@ pub enum BossBlind {
     TheWindow,
     /// All Heart cards debuffed
     TheHead,
+    /// Cards drawn after playing a hand are face down
+    TheFish,
+    /// -1 hand size
+    TheManacle,
+    /// No repeat hand types this round
+    TheEye,
+    /// Only one hand type can be played this round
+    TheMouth,
+    /// All face cards debuffed
+    ThePlant,
+    /// Played poker hand scores one level lower
+    TheArm,
+    /// Lose $1 per card played
+    TheTooth,
 }
 
 impl BossBlind {
-    pub const ALL: [BossBlind; 8] = [
+    pub const ALL: [BossBlind; 15] = [
         BossBlind::TheHook,
         BossBlind::TheWall,
         BossBlind::ThePsychic,
@@ -78,6 +105,13 @@ impl BossBlind {
         BossBlind::TheGoad,
         BossBlind::TheWindow,
         BossBlind::TheHead,
+        BossBlind::TheFish,
+        BossBlind::TheManacle,
+        BossBlind::TheEye,
+        BossBlind::TheMouth,
+        BossBlind::ThePlant,
+        BossBlind::TheArm,
+        BossBlind::TheTooth,
     ];
 
     pub fn score_multiplier(&self) -> f64 {
@@ -97,6 +131,31 @@ impl BossBlind {
             BossBlind::TheGoad => "All Spade cards are debuffed",
             BossBlind::TheWindow => "All Diamond cards are debuffed",
             BossBlind::TheHead => "All Heart cards are debuffed",
+            BossBlind::TheFish => "Cards drawn after a hand are face down",
+            BossBlind::TheManacle => "-1 hand size",
+            BossBlind::TheEye => "No repeat hand types this round",
+            BossBlind::TheMouth => "Play only one hand type this round",
+            BossBlind::ThePlant => "All face cards are debuffed",
+            BossBlind::TheArm => "Played hand scores one level lower",
+            BossBlind::TheTooth => "Lose $1 per card played",
+        }
+    }
+}
+
+impl BossBlind {
+    /// Whether this boss debuffs `card` for the round. Stone cards have no
+    /// rank or suit, so they are never debuffed.
+    pub fn debuffs(&self, card: &PlayingCard) -> bool {
+        if card.is_stone() {
+            return false;
+        }
+        match self {
+            BossBlind::TheClub => card.suit == Suit::Clubs,
+            BossBlind::TheGoad => card.suit == Suit::Spades,
+            BossBlind::TheWindow => card.suit == Suit::Diamonds,
+            BossBlind::TheHead => card.suit == Suit::Hearts,
+            BossBlind::ThePlant => card.rank.is_face(),
+            _ => false,
         }
     }
 }
@@ -112,28 +171,52 @@ impl fmt::Display for BossBlind {
             BossBlind::TheGoad => "The Goad",
             BossBlind::TheWindow => "The Window",
             BossBlind::TheHead => "The Head",
+            BossBlind::TheFish => "The Fish",
+            BossBlind::TheManacle => "The Manacle",
+            BossBlind::TheEye => "The Eye",
+            BossBlind::TheMouth => "The Mouth",
+            BossBlind::ThePlant => "The Plant",
+            BossBlind::TheArm => "The Arm",
+            BossBlind::TheTooth => "The Tooth",
         };
         write!(f, "{}", name)
     }
 }
 
-/// Base chip requirements per ante
-pub fn ante_base_chips(ante: u8) -> u64 {
+/// Base chip requirements for antes 1-8, per stake score scaling (see `Stake::score_scaling`)
+const ANTE_BASE_CHIPS: [[u64; 8]; 3] = [
+    [300, 800, 2_000, 5_000, 11_000, 20_000, 35_000, 50_000],
+    [300, 900, 2_600, 8_000, 20_000, 36_000, 60_000, 100_000],
+    [300, 1_000, 3_200, 9_000, 25_000, 60_000, 110_000, 200_000],
+];
+
+/// Base chip requirements per ante at `stake`
+pub fn ante_base_chips(ante: u8, stake: Stake) -> u64 {
+    let table = &ANTE_BASE_CHIPS[stake.score_scaling() as usize - 1];
     match ante {
-        1 => 300,
-        2 => 800,
-        3 => 2_000,
-        4 => 5_000,
-        5 => 11_000,
-        6 => 20_000,
-        7 => 35_000,
-        8 => 50_000,
-        _ => 50_000 + (ante as u64 - 8) * 25_000, // Endless scaling
+        0 => 100,
+        1..=8 => table[ante as usize - 1],
+        _ => endless_base_chips(ante, table[7]),
+    }
+}
+
+/// Balatro's endless curve past ante 8, growing from ante 8's `base` and
+/// kept to two significant digits. Saturates at `u64::MAX` once the curve outgrows it.
+fn endless_base_chips(ante: u8, base: u64) -> u64 {
+    let c = (ante - 8) as f64;
+    let d = 1.0 + 0.2 * c;
+    let amount = base as f64 * (1.6 + (0.75 * c).powf(d)).powf(c);
+    if !amount.is_finite() || amount >= u64::MAX as f64 {
+        return u64::MAX;
     }
+    let amount = amount as u64;
+    let step = 10u64.pow(amount.ilog10().saturating_sub(1));
+    amount - amount % step
 }
 
-/// Calculate the score target for a given ante and blind type
-pub fn score_target(ante: u8, blind_type: &BlindType) -> u64 {
-    let base = ante_base_chips(ante) as f64;
+/// Calculate the score target for a given ante, blind type and stake
+pub fn score_target(ante: u8, blind_type: &BlindType, stake: Stake) -> u64 {
+    let base = ante_base_chips(ante, stake) as f64;
+    // Float to int casts saturate, so huge endless targets cap at u64::MAX
     (base * blind_type.score_multiplier()) as u64
 }
@@ -1,6 +1,8 @@
 use std::fmt;
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
 pub enum Suit {
     Spades,
     Hearts,
@@ -20,9 +22,29 @@ impl Suit {
         }
     }
 
+    /// First letter of the suit, shown next to the symbol with accessible suits on
+    pub fn letter(&self) -> char {
+        match self {
+            Suit::Spades => 'S',
+            Suit::Hearts => 'H',
+            Suit::Diamonds => 'D',
+            Suit::Clubs => 'C',
+        }
+    }
+
     pub fn is_red(&self) -> bool {
         matches!(self, Suit::Hearts | Suit::Diamonds)
     }
+
+    /// Spelled-out name (e.g. "Spades"), for plain-text output
+    pub fn name(&self) -> &'static str {
+        match self {
+            Suit::Spades => "Spades",
+            Suit::Hearts => "Hearts",
+            Suit::Diamonds => "Diamonds",
+            Suit::Clubs => "Clubs",
+        }
+    }
 }
 
 impl fmt::Display for Suit {
@@ -31,7 +53,7 @@ impl fmt::Display for Suit {
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
 pub enum Rank {
     Two = 2,
     Three = 3,
@@ -81,6 +103,31 @@ impl Rank {
         }
     }
 
+    /// Balatro's odd ranks: A, 9, 7, 5, 3. Face cards are neither odd nor even,
+    /// so this can't be read off the enum discriminant.
+    pub fn is_odd(&self) -> bool {
+        matches!(
+            self,
+            Rank::Ace | Rank::Nine | Rank::Seven | Rank::Five | Rank::Three
+        )
+    }
+
+    /// Balatro's even ranks: 10, 8, 6, 4, 2
+    pub fn is_even(&self) -> bool {
+        matches!(
+            self,
+            Rank::Ten | Rank::Eight | Rank::Six | Rank::Four | Rank::Two
+        )
+    }
+
+    /// Ranks in the Fibonacci sequence: A, 2, 3, 5, 8
+    pub fn is_fibonacci(&self) -> bool {
+        matches!(
+            self,
+            Rank::Ace | Rank::Two | Rank::Three | Rank::Five | Rank::Eight
+        )
+    }
+
     pub fn short_name(&self) -> &'static str {
         match self {
             Rank::Two => "2",
@@ -99,6 +146,25 @@ impl Rank {
         }
     }
 
+    /// Spelled-out name (e.g. "Queen"), for plain-text output
+    pub fn name(&self) -> &'static str {
+        match self {
+            Rank::Two => "Two",
+            Rank::Three => "Three",
+            Rank::Four => "Four",
+            Rank::Five => "Five",
+            Rank::Six => "Six",
+            Rank::Seven => "Seven",
+            Rank::Eight => "Eight",
+            Rank::Nine => "Nine",
+            Rank::Ten => "Ten",
+            Rank::Jack => "Jack",
+            Rank::Queen => "Queen",
+            Rank::King => "King",
+            Rank::Ace => "Ace",
+        }
+    }
+
     pub fn is_face(&self) -> bool {
         matches!(self, Rank::Jack | Rank::Queen | Rank::King)
     }
@@ -110,33 +176,130 @@ impl fmt::Display for Rank {
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
 pub enum Enhancement {
     Bonus, // +30 chips
     Mult,  // +4 mult
     Wild,  // Counts as all suits
-    Glass, // x2 mult, 1/4 chance to destroy
+    Glass, // x1.5 mult, 1/4 chance to shatter after scoring
     Steel, // x1.5 mult while in hand
     Stone, // +50 chips, no rank/suit, always scores
     Gold,  // $3 if held at end of round
     Lucky, // 1/5 +20 mult, 1/15 $20
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+impl Enhancement {
+    pub const ALL: [Enhancement; 8] = [
+        Enhancement::Bonus,
+        Enhancement::Mult,
+        Enhancement::Wild,
+        Enhancement::Glass,
+        Enhancement::Steel,
+        Enhancement::Stone,
+        Enhancement::Gold,
+        Enhancement::Lucky,
+    ];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Enhancement::Bonus => "Bonus",
+            Enhancement::Mult => "Mult",
+            Enhancement::Wild => "Wild",
+            Enhancement::Glass => "Glass",
+            Enhancement::Steel => "Steel",
+            Enhancement::Stone => "Stone",
+            Enhancement::Gold => "Gold",
+            Enhancement::Lucky => "Lucky",
+        }
+    }
+}
+
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default, Serialize, Deserialize,
+)]
 pub enum Edition {
+    #[default]
     Base,
     Foil,        // +50 chips
     Holographic, // +10 mult
     Polychrome,  // x1.5 mult
+    Negative,    // +1 joker slot (jokers only)
 }
 
-impl Default for Edition {
-    fn default() -> Self {
-        Edition::Base
+impl Edition {
+    pub const ALL: [Edition; 5] = [
+        Edition::Base,
+        Edition::Foil,
+        Edition::Holographic,
+        Edition::Polychrome,
+        Edition::Negative,
+    ];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Edition::Base => "Base",
+            Edition::Foil => "Foil",
+            Edition::Holographic => "Holographic",
+            Edition::Polychrome => "Polychrome",
+            Edition::Negative => "Negative",
+        }
+    }
+
+    /// Whether the edition changes chips or mult when it triggers
+    pub fn scores(&self) -> bool {
+        matches!(
+            self,
+            Edition::Foil | Edition::Holographic | Edition::Polychrome
+        )
+    }
+
+    /// What the edition does on a joker, for inspect popups
+    pub fn joker_effect(&self) -> Option<&'static str> {
+        match self {
+            Edition::Base => None,
+            Edition::Foil => Some("+50 Chips"),
+            Edition::Holographic => Some("+10 Mult"),
+            Edition::Polychrome => Some("X1.5 Mult"),
+            Edition::Negative => Some("+1 Joker slot"),
+        }
+    }
+
+    /// Added to a joker's shop price
+    pub fn joker_extra_cost(&self) -> u32 {
+        match self {
+            Edition::Base => 0,
+            Edition::Foil => 2,
+            Edition::Holographic => 3,
+            Edition::Polychrome | Edition::Negative => 5,
+        }
+    }
+
+    /// Flat chips this edition adds when the card scores
+    pub fn chips(&self) -> u64 {
+        match self {
+            Edition::Foil => 50,
+            _ => 0,
+        }
+    }
+
+    /// Flat mult this edition adds when the card scores
+    pub fn mult(&self) -> u64 {
+        match self {
+            Edition::Holographic => 10,
+            _ => 0,
+        }
+    }
+
+    /// Multiplicative mult this edition applies when the card scores
+    pub fn x_mult(&self) -> f64 {
+        match self {
+            Edition::Polychrome => 1.5,
+            _ => 1.0,
+        }
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
 pub enum Seal {
     Gold,   // $3 when played
     Red,    // Retrigger 1x
@@ -144,7 +307,20 @@ pub enum Seal {
     Purple, // Creates tarot when discarded
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+impl Seal {
+    pub const ALL: [Seal; 4] = [Seal::Gold, Seal::Red, Seal::Blue, Seal::Purple];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Seal::Gold => "Gold",
+            Seal::Red => "Red",
+            Seal::Blue => "Blue",
+            Seal::Purple => "Purple",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub struct PlayingCard {
     pub rank: Rank,
     pub suit: Suit,
@@ -152,9 +328,37 @@ pub struct PlayingCard {
     pub edition: Edition,
     pub seal: Option<Seal>,
     pub debuffed: bool,
+    /// Hidden from the player until revealed (The Fish)
+    #[serde(default)]
+    pub face_down: bool,
 }
 
 impl PlayingCard {
+    /// Sort key for a stable, deterministic card order: suit (Spades, Hearts,
+    /// Diamonds, Clubs), rank high to low, then modifiers
+    #[allow(clippy::type_complexity)]
+    pub fn display_order_key(
+        &self,
+    ) -> (
+        Suit,
+        std::cmp::Reverse<Rank>,
+        Option<Enhancement>,
+        Edition,
+        Option<Seal>,
+        bool,
+        bool,
+    ) {
+        (
+            self.suit,
+            std::cmp::Reverse(self.rank),
+            self.enhancement,
+            self.edition,
+            self.seal,
+            self.debuffed,
+            self.face_down,
+        )
+    }
+
     pub fn new(rank: Rank, suit: Suit) -> Self {
         Self {
             rank,
@@ -163,11 +367,41 @@ impl PlayingCard {
             edition: Edition::default(),
             seal: None,
             debuffed: false,
+            face_down: false,
         }
     }
 
-    /// Effective chip value considering enhancements
+    pub fn with_enhancement(mut self, enhancement: Enhancement) -> Self {
+        self.enhancement = Some(enhancement);
+        self
+    }
+
+    pub fn with_seal(mut self, seal: Seal) -> Self {
+        self.seal = Some(seal);
+        self
+    }
+
+    pub fn with_edition(mut self, edition: Edition) -> Self {
+        self.edition = edition;
+        self
+    }
+
+    /// Mark the card debuffed: it still forms hands but scores nothing
+    pub fn debuffed(mut self) -> Self {
+        self.debuffed = true;
+        self
+    }
+
+    /// Effective chip value considering enhancements and edition
     pub fn chip_value(&self) -> u64 {
+        if self.debuffed {
+            return 0;
+        }
+        self.enhanced_chip_value() + self.edition.chips()
+    }
+
+    /// Chips from rank and enhancement only (the edition scores as its own step)
+    pub fn enhanced_chip_value(&self) -> u64 {
         if self.debuffed {
             return 0;
         }
@@ -179,11 +413,7 @@ impl PlayingCard {
             Some(Enhancement::Bonus) => 30,
             _ => 0,
         };
-        let edition_bonus = match self.edition {
-            Edition::Foil => 50,
-            _ => 0,
-        };
-        base + bonus + edition_bonus
+        base + bonus
     }
 
     /// Additional mult from this card's enhancement/edition
@@ -191,15 +421,15 @@ impl PlayingCard {
         if self.debuffed {
             return 0;
         }
-        let enh = match self.enhancement {
-            Some(Enhancement::Mult) => 4,
-            _ => 0,
-        };
-        let ed = match self.edition {
-            Edition::Holographic => 10,
+        self.enhancement_mult() + self.edition.mult()
+    }
+
+    /// Flat mult from the enhancement only
+    pub fn enhancement_mult(&self) -> u64 {
+        match self.enhancement {
+            Some(Enhancement::Mult) if !self.debuffed => 4,
             _ => 0,
-        };
-        enh + ed
+        }
     }
 
     /// Multiplicative mult from this card
@@ -207,15 +437,32 @@ impl PlayingCard {
         if self.debuffed {
             return 1.0;
         }
-        let enh = match self.enhancement {
-            Some(Enhancement::Glass) => 2.0,
-            _ => 1.0,
-        };
-        let ed = match self.edition {
-            Edition::Polychrome => 1.5,
+        self.enhancement_x_mult() * self.edition.x_mult()
+    }
+
+    /// Multiplicative mult from the enhancement only
+    pub fn enhancement_x_mult(&self) -> f64 {
+        match self.enhancement {
+            Some(Enhancement::Glass) if !self.debuffed => 1.5,
             _ => 1.0,
-        };
-        enh * ed
+        }
+    }
+
+    /// Whether this card counts as a face card. With Pareidolia every card
+    /// with a rank does (Stone cards never count).
+    pub fn is_face_with(&self, pareidolia: bool) -> bool {
+        !self.is_stone() && (pareidolia || self.rank.is_face())
+    }
+
+    /// Stone cards have no rank or suit
+    pub fn is_stone(&self) -> bool {
+        matches!(self.enhancement, Some(Enhancement::Stone))
+    }
+
+    /// Whether this card counts as `suit` (Wild cards count as every suit,
+    /// Stone cards as none)
+    pub fn is_suit(&self, suit: Suit) -> bool {
+        !self.is_stone() && (self.suit == suit || self.is_wild())
     }
 
     /// Whether this card acts as a wild (all suits)
@@ -223,9 +470,43 @@ impl PlayingCard {
         matches!(self.enhancement, Some(Enhancement::Wild))
     }
 
+    /// Multiplicative mult while this card stays in hand (Steel: x1.5)
+    pub fn held_x_mult(&self) -> f64 {
+        match self.enhancement {
+            Some(Enhancement::Steel) if !self.debuffed => 1.5,
+            _ => 1.0,
+        }
+    }
+
+    /// Whether this card rolls for bonus Mult/money when scored (Lucky)
+    pub fn is_lucky(&self) -> bool {
+        matches!(self.enhancement, Some(Enhancement::Lucky)) && !self.debuffed
+    }
+
+    /// Whether this card can shatter after scoring (Glass)
+    pub fn is_glass(&self) -> bool {
+        matches!(self.enhancement, Some(Enhancement::Glass))
+    }
+
+    /// Extra times this card is scored from its seal (Red seal retriggers once)
+    pub fn seal_retriggers(&self) -> usize {
+        match self.seal {
+            Some(Seal::Red) if !self.debuffed => 1,
+            _ => 0,
+        }
+    }
+
+    /// Money paid each time this card is scored (Gold seal: $3)
+    pub fn seal_money(&self) -> u32 {
+        match self.seal {
+            Some(Seal::Gold) if !self.debuffed => 3,
+            _ => 0,
+        }
+    }
+
     /// Whether this card always scores regardless of hand
     pub fn always_scores(&self) -> bool {
-        matches!(self.enhancement, Some(Enhancement::Stone))
+        self.is_stone()
     }
 }
 
@@ -234,3 +515,45 @@ impl fmt::Display for PlayingCard {
         write!(f, "{}{}", self.rank, self.suit)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_builders_combine_enhancement_and_edition() {
+        let card = PlayingCard::new(Rank::King, Suit::Hearts)
+            .with_enhancement(Enhancement::Bonus)
+            .with_edition(Edition::Foil)
+            .with_seal(Seal::Red);
+        assert_eq!(card.chip_value(), 10 + 30 + 50);
+        assert_eq!(card.seal, Some(Seal::Red));
+        assert_eq!(card.seal_retriggers(), 1);
+
+        let card = PlayingCard::new(Rank::Two, Suit::Clubs)
+            .with_enhancement(Enhancement::Mult)
+            .with_edition(Edition::Holographic);
+        assert_eq!(card.mult_bonus(), 4 + 10);
+        assert_eq!(card.x_mult(), 1.0);
+
+        let card = PlayingCard::new(Rank::Ace, Suit::Spades)
+            .with_enhancement(Enhancement::Glass)
+            .with_edition(Edition::Polychrome);
+        assert_eq!(card.x_mult(), 1.5 * 1.5);
+        assert!(card.is_glass());
+    }
+
+    #[test]
+    fn test_debuffed_builder_zeroes_the_scoring() {
+        let card = PlayingCard::new(Rank::Queen, Suit::Diamonds)
+            .with_enhancement(Enhancement::Wild)
+            .with_edition(Edition::Holographic)
+            .debuffed();
+        assert!(card.debuffed);
+        assert_eq!(card.chip_value(), 0);
+        assert_eq!(card.mult_bonus(), 0);
+        assert_eq!(card.x_mult(), 1.0);
+        // Still a Wild for hand detection
+        assert!(card.is_wild() && card.is_suit(Suit::Spades));
+    }
+}
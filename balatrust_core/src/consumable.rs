@@ -1,12 +1,17 @@
 use std::fmt;
 
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+
+use crate::card::Suit;
 use crate::hand::PokerHand;
 
 /// Type of consumable
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum ConsumableType {
     Planet(PlanetCard),
     Tarot(TarotCard),
+    Spectral(SpectralCard),
 }
 
 impl ConsumableType {
@@ -14,6 +19,7 @@ impl ConsumableType {
         match self {
             ConsumableType::Planet(p) => p.name(),
             ConsumableType::Tarot(t) => t.name(),
+            ConsumableType::Spectral(s) => s.name(),
         }
     }
 
@@ -21,6 +27,7 @@ impl ConsumableType {
         match self {
             ConsumableType::Planet(p) => p.description(),
             ConsumableType::Tarot(t) => t.description().to_string(),
+            ConsumableType::Spectral(s) => s.description().to_string(),
         }
     }
 
@@ -28,10 +35,65 @@ impl ConsumableType {
         match self {
             ConsumableType::Planet(_) => 3,
             ConsumableType::Tarot(_) => 3,
+            ConsumableType::Spectral(_) => 4,
+        }
+    }
+
+    /// Money refunded when sold: half the price, at least $1
+    pub fn sell_value(&self) -> u32 {
+        (self.price() / 2).max(1)
+    }
+
+    /// Roll a random consumable: the kind by `weights`, then a card of that kind
+    /// uniformly (planets from `PlanetCard::COMMON`). All-zero weights give a planet.
+    pub fn random<R: Rng>(rng: &mut R, weights: &ConsumableWeights) -> Self {
+        let roll = rng.gen_range(0..weights.total().max(1));
+        if roll < weights.tarot {
+            let idx = rng.gen_range(0..TarotCard::ALL.len());
+            ConsumableType::Tarot(TarotCard::ALL[idx])
+        } else if roll < weights.tarot + weights.spectral {
+            let idx = rng.gen_range(0..SpectralCard::ALL.len());
+            ConsumableType::Spectral(SpectralCard::ALL[idx])
+        } else {
+            let idx = rng.gen_range(0..PlanetCard::COMMON.len());
+            ConsumableType::Planet(PlanetCard::COMMON[idx])
         }
     }
 }
 
+/// Relative odds of each consumable kind in `ConsumableType::random`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ConsumableWeights {
+    pub planet: u32,
+    pub tarot: u32,
+    pub spectral: u32,
+}
+
+impl ConsumableWeights {
+    /// Shop card slots that didn't roll a joker
+    pub const SHOP: ConsumableWeights = ConsumableWeights {
+        planet: 15,
+        tarot: 12,
+        spectral: 3,
+    };
+
+    pub const PLANET_ONLY: ConsumableWeights = ConsumableWeights {
+        planet: 1,
+        tarot: 0,
+        spectral: 0,
+    };
+
+    pub const TAROT_ONLY: ConsumableWeights = ConsumableWeights {
+        planet: 0,
+        tarot: 1,
+        spectral: 0,
+    };
+
+    pub fn total(&self) -> u32 {
+        self.planet + self.tarot + self.spectral
+    }
+}
+
 impl fmt::Display for ConsumableType {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(f, "{}", self.name())
@@ -39,14 +101,29 @@ impl fmt::Display for ConsumableType {
 }
 
 /// A consumable instance
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Consumable {
     pub consumable_type: ConsumableType,
+    /// Negative copies don't use a consumable slot
+    #[serde(default)]
+    pub negative: bool,
 }
 
 impl Consumable {
     pub fn new(consumable_type: ConsumableType) -> Self {
-        Self { consumable_type }
+        Self {
+            consumable_type,
+            negative: false,
+        }
+    }
+
+    pub fn with_negative(mut self) -> Self {
+        self.negative = true;
+        self
+    }
+
+    pub fn takes_slot(&self) -> bool {
+        !self.negative
     }
 
     pub fn planet(card: PlanetCard) -> Self {
@@ -56,10 +133,14 @@ impl Consumable {
     pub fn tarot(card: TarotCard) -> Self {
         Self::new(ConsumableType::Tarot(card))
     }
+
+    pub fn spectral(card: SpectralCard) -> Self {
+        Self::new(ConsumableType::Spectral(card))
+    }
 }
 
 /// Planet cards level up specific poker hands
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum PlanetCard {
     Pluto,   // High Card
     Mercury, // Pair
@@ -121,6 +202,24 @@ impl PlanetCard {
         }
     }
 
+    /// The planet that levels up `hand` (Royal Flush shares Neptune)
+    pub fn for_hand(hand: PokerHand) -> PlanetCard {
+        match hand {
+            PokerHand::HighCard => PlanetCard::Pluto,
+            PokerHand::Pair => PlanetCard::Mercury,
+            PokerHand::TwoPair => PlanetCard::Uranus,
+            PokerHand::ThreeOfAKind => PlanetCard::Venus,
+            PokerHand::Straight => PlanetCard::Saturn,
+            PokerHand::Flush => PlanetCard::Jupiter,
+            PokerHand::FullHouse => PlanetCard::Earth,
+            PokerHand::FourOfAKind => PlanetCard::Mars,
+            PokerHand::StraightFlush | PokerHand::RoyalFlush => PlanetCard::Neptune,
+            PokerHand::FiveOfAKind => PlanetCard::PlanetX,
+            PokerHand::FlushHouse => PlanetCard::Ceres,
+            PokerHand::FlushFive => PlanetCard::Eris,
+        }
+    }
+
     pub fn hand_type(&self) -> PokerHand {
         match self {
             PlanetCard::Pluto => PokerHand::HighCard,
@@ -150,7 +249,7 @@ impl PlanetCard {
 }
 
 /// Tarot cards modify playing cards
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum TarotCard {
     TheFool,          // Create copy of last Tarot/Planet used
     TheMagician,      // Enhance 1-2 cards to Lucky
@@ -164,10 +263,16 @@ pub enum TarotCard {
     TheHermit,        // Double money (max $20)
     Death,            // Convert left card to right card (2 selected)
     Temperance,       // Gain money equal to sell value of jokers (max $50)
+    Justice,          // Enhance 1 card to Glass
+    TheTower,         // Enhance 1 card to Stone
+    TheStar,          // Convert up to 3 cards to Diamonds
+    TheMoon,          // Convert up to 3 cards to Clubs
+    TheSun,           // Convert up to 3 cards to Hearts
+    TheWorld,         // Convert up to 3 cards to Spades
 }
 
 impl TarotCard {
-    pub const ALL: [TarotCard; 12] = [
+    pub const ALL: [TarotCard; 18] = [
         TarotCard::TheFool,
         TarotCard::TheMagician,
         TarotCard::TheHighPriestess,
@@ -180,6 +285,12 @@ impl TarotCard {
         TarotCard::TheHermit,
         TarotCard::Death,
         TarotCard::Temperance,
+        TarotCard::Justice,
+        TarotCard::TheTower,
+        TarotCard::TheStar,
+        TarotCard::TheMoon,
+        TarotCard::TheSun,
+        TarotCard::TheWorld,
     ];
 
     pub fn name(&self) -> &'static str {
@@ -196,6 +307,12 @@ impl TarotCard {
             TarotCard::TheHermit => "The Hermit",
             TarotCard::Death => "Death",
             TarotCard::Temperance => "Temperance",
+            TarotCard::Justice => "Justice",
+            TarotCard::TheTower => "The Tower",
+            TarotCard::TheStar => "The Star",
+            TarotCard::TheMoon => "The Moon",
+            TarotCard::TheSun => "The Sun",
+            TarotCard::TheWorld => "The World",
         }
     }
 
@@ -213,6 +330,23 @@ impl TarotCard {
             TarotCard::TheHermit => "Double money (max $20)",
             TarotCard::Death => "Convert left card to right card",
             TarotCard::Temperance => "Gain $ equal to joker sell value",
+            TarotCard::Justice => "Enhance 1 card to Glass",
+            TarotCard::TheTower => "Enhance 1 card to Stone",
+            TarotCard::TheStar => "Convert 1-3 cards to Diamonds",
+            TarotCard::TheMoon => "Convert 1-3 cards to Clubs",
+            TarotCard::TheSun => "Convert 1-3 cards to Hearts",
+            TarotCard::TheWorld => "Convert 1-3 cards to Spades",
+        }
+    }
+
+    /// Suit this tarot converts selected cards to, if any
+    pub fn converts_to_suit(&self) -> Option<Suit> {
+        match self {
+            TarotCard::TheStar => Some(Suit::Diamonds),
+            TarotCard::TheMoon => Some(Suit::Clubs),
+            TarotCard::TheSun => Some(Suit::Hearts),
+            TarotCard::TheWorld => Some(Suit::Spades),
+            _ => None,
         }
     }
 
@@ -224,9 +358,121 @@ impl TarotCard {
             TarotCard::TheEmperor => (0, 0),
             TarotCard::TheHermit => (0, 0),
             TarotCard::Temperance => (0, 0),
-            TarotCard::TheLover | TarotCard::TheChariot => (1, 1),
+            TarotCard::TheLover
+            | TarotCard::TheChariot
+            | TarotCard::Justice
+            | TarotCard::TheTower => (1, 1),
             TarotCard::Death => (2, 2),
+            TarotCard::TheStar | TarotCard::TheMoon | TarotCard::TheSun | TarotCard::TheWorld => {
+                (1, 3)
+            }
             _ => (1, 2), // 1-2 cards
         }
     }
 }
+
+/// Spectral cards are rare, powerful effects on the deck or run
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum SpectralCard {
+    Familiar,    // Destroy 1 random card in hand, add 3 enhanced face cards
+    Grim,        // Destroy 1 random card in hand, add 2 enhanced Aces
+    Incantation, // Destroy 1 random card in hand, add 4 enhanced number cards
+    Talisman,    // Add a Gold seal to 1 card
+    AuraFoil,    // Add Foil edition to 1 card
+    Ectoplasm,   // +1 joker slot, -1 hand size
+}
+
+impl SpectralCard {
+    pub const ALL: [SpectralCard; 6] = [
+        SpectralCard::Familiar,
+        SpectralCard::Grim,
+        SpectralCard::Incantation,
+        SpectralCard::Talisman,
+        SpectralCard::AuraFoil,
+        SpectralCard::Ectoplasm,
+    ];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            SpectralCard::Familiar => "Familiar",
+            SpectralCard::Grim => "Grim",
+            SpectralCard::Incantation => "Incantation",
+            SpectralCard::Talisman => "Talisman",
+            SpectralCard::AuraFoil => "Aura",
+            SpectralCard::Ectoplasm => "Ectoplasm",
+        }
+    }
+
+    pub fn description(&self) -> &'static str {
+        match self {
+            SpectralCard::Familiar => "Destroy 1 random card, add 3 enhanced face cards",
+            SpectralCard::Grim => "Destroy 1 random card, add 2 enhanced Aces",
+            SpectralCard::Incantation => "Destroy 1 random card, add 4 enhanced numbers",
+            SpectralCard::Talisman => "Add a Gold seal to 1 card",
+            SpectralCard::AuraFoil => "Add Foil edition to 1 card",
+            SpectralCard::Ectoplasm => "+1 Joker slot, -1 hand size",
+        }
+    }
+
+    /// How many cards this spectral needs selected
+    pub fn cards_needed(&self) -> (usize, usize) {
+        match self {
+            SpectralCard::Talisman | SpectralCard::AuraFoil => (1, 1),
+            _ => (0, 0),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::SeedableRng;
+
+    #[test]
+    fn test_constructors_set_type_name_and_description() {
+        let pluto = Consumable::planet(PlanetCard::Pluto);
+        assert_eq!(
+            pluto.consumable_type,
+            ConsumableType::Planet(PlanetCard::Pluto)
+        );
+        assert_eq!(pluto.consumable_type.name(), "Pluto");
+        assert!(pluto.consumable_type.description().contains("High Card"));
+        assert!(pluto.takes_slot());
+
+        let emperor = Consumable::tarot(TarotCard::TheEmperor);
+        assert_eq!(emperor.consumable_type.name(), TarotCard::TheEmperor.name());
+        assert_eq!(
+            emperor.consumable_type.description(),
+            "Create up to 2 Tarot cards"
+        );
+
+        let grim = Consumable::spectral(SpectralCard::Grim).with_negative();
+        assert_eq!(
+            grim.consumable_type,
+            ConsumableType::Spectral(SpectralCard::Grim)
+        );
+        assert!(!grim.takes_slot());
+    }
+
+    #[test]
+    fn test_random_never_rolls_a_zero_weight_kind() {
+        let mut rng = rand_chacha::ChaCha12Rng::seed_from_u64(3);
+        let weights = ConsumableWeights {
+            spectral: 0,
+            ..ConsumableWeights::SHOP
+        };
+        let rolls: Vec<ConsumableType> = (0..1000)
+            .map(|_| ConsumableType::random(&mut rng, &weights))
+            .collect();
+        assert!(!rolls
+            .iter()
+            .any(|c| matches!(c, ConsumableType::Spectral(_))));
+        assert!(rolls.iter().any(|c| matches!(c, ConsumableType::Planet(_))));
+        assert!(rolls.iter().any(|c| matches!(c, ConsumableType::Tarot(_))));
+
+        for _ in 0..100 {
+            let tarot = ConsumableType::random(&mut rng, &ConsumableWeights::TAROT_ONLY);
+            assert!(matches!(tarot, ConsumableType::Tarot(_)));
+        }
+    }
+}
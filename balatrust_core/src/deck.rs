@@ -1,12 +1,79 @@
 use rand::seq::SliceRandom;
 use rand::Rng;
+use serde::{Deserialize, Serialize};
 
 use crate::card::{PlayingCard, Rank, Suit};
 
-#[derive(Debug, Clone)]
+/// Starting deck chosen for a run
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum DeckType {
+    #[default]
+    Standard,
+    Red,       // +1 discard every round
+    Blue,      // +1 hand every round
+    Abandoned, // No face cards
+    Checkered, // 26 Spades and 26 Hearts
+}
+
+impl DeckType {
+    pub const ALL: [DeckType; 5] = [
+        DeckType::Standard,
+        DeckType::Red,
+        DeckType::Blue,
+        DeckType::Abandoned,
+        DeckType::Checkered,
+    ];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            DeckType::Standard => "Standard Deck",
+            DeckType::Red => "Red Deck",
+            DeckType::Blue => "Blue Deck",
+            DeckType::Abandoned => "Abandoned Deck",
+            DeckType::Checkered => "Checkered Deck",
+        }
+    }
+
+    pub fn description(&self) -> &'static str {
+        match self {
+            DeckType::Standard => "52 cards, no modifiers",
+            DeckType::Red => "+1 discard every round",
+            DeckType::Blue => "+1 hand every round",
+            DeckType::Abandoned => "No face cards",
+            DeckType::Checkered => "26 Spades and 26 Hearts",
+        }
+    }
+
+    /// Extra hands every round
+    pub fn bonus_hands(&self) -> u8 {
+        u8::from(*self == DeckType::Blue)
+    }
+
+    /// Extra discards every round
+    pub fn bonus_discards(&self) -> u8 {
+        u8::from(*self == DeckType::Red)
+    }
+
+    /// Next deck in `ALL`, wrapping around (`forward = false` goes back)
+    pub fn cycle(&self, forward: bool) -> Self {
+        let len = Self::ALL.len();
+        let idx = Self::ALL.iter().position(|d| d == self).unwrap_or(0);
+        let next = if forward {
+            (idx + 1) % len
+        } else {
+            (idx + len - 1) % len
+        };
+        Self::ALL[next]
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Deck {
     cards: Vec<PlayingCard>,
     discard: Vec<PlayingCard>,
+    /// Cards played this round: out of the draw until the round resets
+    #[serde(default)]
+    played: Vec<PlayingCard>,
 }
 
 impl Deck {
@@ -21,6 +88,45 @@ impl Deck {
         Self {
             cards,
             discard: Vec::new(),
+            played: Vec::new(),
+        }
+    }
+
+    /// Create a deck with no cards, to be filled with `add_card`
+    pub fn empty() -> Self {
+        Self {
+            cards: Vec::new(),
+            discard: Vec::new(),
+            played: Vec::new(),
+        }
+    }
+
+    /// Create the starting deck for `deck_type`
+    pub fn from_type(deck_type: DeckType) -> Self {
+        let cards = match deck_type {
+            DeckType::Standard | DeckType::Red | DeckType::Blue => return Self::standard(),
+            DeckType::Abandoned => Suit::ALL
+                .iter()
+                .flat_map(|&suit| {
+                    Rank::ALL
+                        .iter()
+                        .filter(|rank| !rank.is_face())
+                        .map(move |&rank| PlayingCard::new(rank, suit))
+                })
+                .collect(),
+            DeckType::Checkered => [Suit::Spades, Suit::Spades, Suit::Hearts, Suit::Hearts]
+                .iter()
+                .flat_map(|&suit| {
+                    Rank::ALL
+                        .iter()
+                        .map(move |&rank| PlayingCard::new(rank, suit))
+                })
+                .collect(),
+        };
+        Self {
+            cards,
+            discard: Vec::new(),
+            played: Vec::new(),
         }
     }
 
@@ -42,9 +148,39 @@ impl Deck {
         drawn
     }
 
+    /// The next n cards `draw(n)` would return, in the same order, without
+    /// drawing them
+    pub fn peek(&self, n: usize) -> Vec<PlayingCard> {
+        self.cards.iter().rev().take(n).copied().collect()
+    }
+
+    /// Draw n cards, shuffling the discard pile back in when the draw pile
+    /// runs out. Returns fewer only when both piles are empty.
+    pub fn draw_reshuffling<R: Rng>(&mut self, n: usize, rng: &mut R) -> Vec<PlayingCard> {
+        let mut drawn = self.draw(n);
+        if drawn.len() < n && !self.discard.is_empty() {
+            self.reshuffle_discard(rng);
+            drawn.extend(self.draw(n - drawn.len()));
+        }
+        drawn
+    }
+
     /// Put cards into the discard pile
     pub fn discard_cards(&mut self, cards: &[PlayingCard]) {
-        self.discard.extend_from_slice(cards);
+        // Cards always land in the discard pile face up
+        self.discard.extend(cards.iter().map(|card| PlayingCard {
+            face_down: false,
+            ..*card
+        }));
+    }
+
+    /// Set played cards aside until the round resets; a mid-round reshuffle
+    /// leaves them out
+    pub fn play_cards(&mut self, cards: &[PlayingCard]) {
+        self.played.extend(cards.iter().map(|card| PlayingCard {
+            face_down: false,
+            ..*card
+        }));
     }
 
     /// Shuffle the discard pile back into the draw pile
@@ -63,18 +199,35 @@ impl Deck {
         self.discard.len()
     }
 
-    /// Total cards in the deck (draw + discard)
+    /// Cards played this round
+    pub fn played_count(&self) -> usize {
+        self.played.len()
+    }
+
+    /// Total cards in the deck (draw + discard + played)
     pub fn total(&self) -> usize {
-        self.cards.len() + self.discard.len()
+        self.cards.len() + self.discard.len() + self.played.len()
     }
 
-    /// Reset: gather all cards, re-create a full deck with any modifications preserved
+    /// Same as `total`: cards owned by the deck, not counting the hand
+    pub fn len(&self) -> usize {
+        self.total()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Reset: gather every owned card (draw, discard and played piles) back
+    /// into the draw pile, modifications preserved, and shuffle all of it.
+    /// The resulting order depends only on the cards and `rng`.
     pub fn reset_and_shuffle<R: Rng>(&mut self, rng: &mut R) {
         self.cards.append(&mut self.discard);
+        self.cards.append(&mut self.played);
         self.shuffle(rng);
     }
 
-    /// Add a card to the deck
+    /// Add a card to the draw pile
     pub fn add_card(&mut self, card: PlayingCard) {
         self.cards.push(card);
     }
@@ -89,10 +242,11 @@ impl Deck {
         &self.discard
     }
 
-    /// All cards in the deck (draw + discard), not including cards currently in hand
+    /// All cards in the deck (draw + discard + played), not including cards currently in hand
     pub fn all_cards(&self) -> Vec<PlayingCard> {
         let mut all = self.cards.clone();
         all.extend_from_slice(&self.discard);
+        all.extend_from_slice(&self.played);
         all
     }
 
@@ -104,15 +258,32 @@ impl Deck {
         } else if let Some(pos) = self.discard.iter().position(|c| c == card) {
             self.discard.remove(pos);
             true
+        } else if let Some(pos) = self.played.iter().position(|c| c == card) {
+            self.played.remove(pos);
+            true
         } else {
             false
         }
     }
+
+    /// Remove the card at `index` in `all_cards()` order (draw pile, discard
+    /// pile, then played cards). Returns `None` if out of range.
+    pub fn remove_at(&mut self, index: usize) -> Option<PlayingCard> {
+        let mut index = index;
+        for pile in [&mut self.cards, &mut self.discard, &mut self.played] {
+            if index < pile.len() {
+                return Some(pile.remove(index));
+            }
+            index -= pile.len();
+        }
+        None
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use rand::SeedableRng;
 
     #[test]
     fn test_standard_deck_has_52_cards() {
@@ -120,6 +291,22 @@ mod tests {
         assert_eq!(deck.total(), 52);
     }
 
+    #[test]
+    fn test_abandoned_deck_has_no_face_cards() {
+        let deck = Deck::from_type(DeckType::Abandoned);
+        assert_eq!(deck.total(), 40);
+        assert!(deck.all_cards().iter().all(|c| !c.rank.is_face()));
+    }
+
+    #[test]
+    fn test_checkered_deck_is_spades_and_hearts() {
+        let cards = Deck::from_type(DeckType::Checkered).all_cards();
+        assert_eq!(cards.len(), 52);
+        let count = |suit| cards.iter().filter(|c| c.suit == suit).count();
+        assert_eq!(count(Suit::Spades), 26);
+        assert_eq!(count(Suit::Hearts), 26);
+    }
+
     #[test]
     fn test_draw_reduces_remaining() {
         let mut deck = Deck::standard();
@@ -128,6 +315,22 @@ mod tests {
         assert_eq!(deck.remaining(), 47);
     }
 
+    #[test]
+    fn test_peek_matches_the_next_draw() {
+        let mut rng = rand_chacha::ChaCha12Rng::seed_from_u64(3);
+        let mut deck = Deck::standard();
+        deck.shuffle(&mut rng);
+
+        let peeked = deck.peek(3);
+        assert_eq!(deck.remaining(), 52);
+        assert_eq!(deck.draw(3), peeked);
+
+        // Peeking past the end returns only what's left
+        deck.draw(47);
+        assert_eq!(deck.peek(5), deck.draw(5));
+        assert!(deck.peek(1).is_empty());
+    }
+
     #[test]
     fn test_discard_and_reshuffle() {
         let mut rng = rand::thread_rng();
@@ -140,4 +343,73 @@ mod tests {
         assert_eq!(deck.remaining(), 52);
         assert_eq!(deck.discard_count(), 0);
     }
+
+    #[test]
+    fn test_add_then_remove_card_restores_count() {
+        let mut deck = Deck::standard();
+        let stone = PlayingCard {
+            enhancement: Some(crate::card::Enhancement::Stone),
+            ..PlayingCard::new(Rank::Two, Suit::Clubs)
+        };
+        deck.add_card(stone);
+        assert_eq!(deck.all_cards().len(), 53);
+        assert_eq!(deck.len(), 53);
+        assert!(deck.draw_pile().contains(&stone));
+
+        assert!(deck.remove_card(&stone));
+        assert_eq!(deck.all_cards().len(), 52);
+        assert!(!deck.remove_card(&stone));
+    }
+
+    #[test]
+    fn test_remove_at_indexes_draw_then_discard_pile() {
+        let mut deck = Deck::standard();
+        let drawn = deck.draw(2);
+        deck.discard_cards(&drawn);
+        let all = deck.all_cards();
+
+        assert_eq!(deck.remove_at(0), Some(all[0]));
+        // Last index now points at the end of the discard pile
+        assert_eq!(deck.remove_at(50), Some(all[51]));
+        assert_eq!(deck.remove_at(50), None);
+        assert_eq!(deck.len(), 50);
+        assert!(!deck.is_empty());
+    }
+
+    #[test]
+    fn test_draw_reshuffles_discards_when_draw_pile_runs_out() {
+        let mut rng = rand::thread_rng();
+        let mut deck = Deck::standard();
+        let discarded = deck.draw(10);
+        deck.discard_cards(&discarded);
+        deck.draw(40);
+        assert_eq!(deck.remaining(), 2);
+
+        let drawn = deck.draw_reshuffling(5, &mut rng);
+        assert_eq!(drawn.len(), 5);
+        assert_eq!(deck.discard_count(), 0);
+        assert_eq!(deck.remaining(), 7);
+        // The two left in the draw pile come first
+        assert!(drawn[2..].iter().all(|c| discarded.contains(c)));
+    }
+
+    #[test]
+    fn test_played_cards_stay_out_of_a_mid_round_reshuffle() {
+        let mut rng = rand::thread_rng();
+        let mut deck = Deck::standard();
+        let played = deck.draw(5);
+        deck.play_cards(&played);
+        let discarded = deck.draw(3);
+        deck.discard_cards(&discarded);
+        deck.draw(44);
+
+        let drawn = deck.draw_reshuffling(8, &mut rng);
+        assert_eq!(drawn.len(), 3);
+        assert!(drawn.iter().all(|c| discarded.contains(c)));
+        assert_eq!(deck.played_count(), 5);
+        assert_eq!(deck.total(), 5);
+
+        deck.reset_and_shuffle(&mut rng);
+        assert_eq!(deck.remaining(), 5);
+    }
 }
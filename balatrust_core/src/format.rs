@@ -0,0 +1,100 @@
+/// Values at or above this are shown in scientific notation ("1.5e15")
+pub const SCIENTIFIC_THRESHOLD: u64 = 1_000_000_000_000_000;
+
+const SUFFIXES: [(u64, &str); 4] = [
+    (1_000, "K"),
+    (1_000_000, "M"),
+    (1_000_000_000, "B"),
+    (1_000_000_000_000, "T"),
+];
+
+/// How large numbers are shortened for display
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NumberFormat {
+    /// Exact with comma groups: "12,345,678"
+    Grouped,
+    /// One decimal and a K/M/B/T suffix: "12.3K", "4.5B"
+    Suffix,
+}
+
+/// Default display for scores and targets: exact below a million, suffixed above
+pub fn format_number(n: u64) -> String {
+    if n < 1_000_000 {
+        format_number_with(n, NumberFormat::Grouped)
+    } else {
+        format_number_with(n, NumberFormat::Suffix)
+    }
+}
+
+/// Format `n` in the given style, switching to scientific notation past `SCIENTIFIC_THRESHOLD`
+pub fn format_number_with(n: u64, format: NumberFormat) -> String {
+    if n >= SCIENTIFIC_THRESHOLD {
+        return format!("{:.1e}", n as f64);
+    }
+    match format {
+        NumberFormat::Grouped => group_thousands(n),
+        NumberFormat::Suffix => {
+            if n < 1_000 {
+                return n.to_string();
+            }
+            // Smallest suffix that keeps the rounded value under 1000 ("999,999" is "1.0M")
+            let (size, suffix) = SUFFIXES
+                .iter()
+                .find(|(size, _)| n as f64 / (*size as f64) < 999.95)
+                .unwrap_or(&SUFFIXES[SUFFIXES.len() - 1]);
+            format!("{:.1}{}", n as f64 / *size as f64, suffix)
+        }
+    }
+}
+
+fn group_thousands(n: u64) -> String {
+    let s = n.to_string();
+    let mut result = String::new();
+    for (i, c) in s.chars().rev().enumerate() {
+        if i > 0 && i % 3 == 0 {
+            result.push(',');
+        }
+        result.push(c);
+    }
+    result.chars().rev().collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_grouped_boundaries() {
+        let f = |n| format_number_with(n, NumberFormat::Grouped);
+        assert_eq!(f(999), "999");
+        assert_eq!(f(1_000), "1,000");
+        assert_eq!(f(1_000_000), "1,000,000");
+        assert_eq!(f(10_000_000_000_000), "10,000,000,000,000");
+        assert_eq!(f(SCIENTIFIC_THRESHOLD), "1.0e15");
+    }
+
+    #[test]
+    fn test_suffix_boundaries() {
+        let f = |n| format_number_with(n, NumberFormat::Suffix);
+        assert_eq!(f(999), "999");
+        assert_eq!(f(1_000), "1.0K");
+        assert_eq!(f(999_999), "1.0M");
+        assert_eq!(f(1_000_000), "1.0M");
+        assert_eq!(f(2_500_000_000), "2.5B");
+        assert_eq!(f(10_000_000_000_000), "10.0T");
+        assert_eq!(f(1_500_000_000_000_000), "1.5e15");
+        assert_eq!(f(u64::MAX), "1.8e19");
+    }
+
+    #[test]
+    fn test_default_is_exact_below_a_million() {
+        assert_eq!(format_number(999), "999");
+        assert_eq!(format_number(1_000), "1,000");
+        assert_eq!(format_number(12_345), "12,345");
+        assert_eq!(format_number(999_999), "999,999");
+        assert_eq!(format_number(1_000_000), "1.0M");
+        assert_eq!(format_number(2_500_000), "2.5M");
+        assert_eq!(format_number(10_000_000_000_000), "10.0T");
+        assert_eq!(format_number(u64::MAX), "1.8e19");
+    }
+}
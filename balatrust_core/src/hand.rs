@@ -1,10 +1,13 @@
 use std::collections::HashMap;
 use std::fmt;
 
+use serde::{Deserialize, Serialize};
+
 use crate::card::{PlayingCard, Rank, Suit};
+use crate::joker::{Joker, JokerType};
 
 /// All recognized poker hands, ordered from worst to best
-#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
 pub enum PokerHand {
     HighCard,
     Pair,
@@ -143,9 +146,60 @@ pub struct HandResult {
     pub scoring_indices: Vec<usize>,
 }
 
-/// Detect the best poker hand from a set of played cards (up to 5).
+/// Joker-granted tweaks to what counts as a poker hand
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct HandRules {
+    /// Shortcut: straights may skip one rank between cards (3-5-7-9-J)
+    pub shortcut: bool,
+    /// Four Fingers: flushes and straights only need 4 cards
+    pub four_fingers: bool,
+}
+
+impl HandRules {
+    /// Rules granted by the jokers currently owned
+    pub fn from_jokers(jokers: &[Joker]) -> Self {
+        let owns = |joker_type| jokers.iter().any(|j| j.joker_type == joker_type);
+        Self {
+            shortcut: owns(JokerType::Shortcut),
+            four_fingers: owns(JokerType::FourFingers),
+        }
+    }
+
+    /// Cards needed for a flush or a straight
+    fn min_run(&self) -> usize {
+        if self.four_fingers {
+            4
+        } else {
+            5
+        }
+    }
+}
+
+/// Detect the best poker hand from a set of played cards.
 /// Returns the hand type and which card indices contribute to scoring.
+/// When more than 5 ranked cards are played, only the best 5-card hand among
+/// them is considered (see `detect_best_five`).
 pub fn detect_hand(cards: &[PlayingCard]) -> HandResult {
+    detect_hand_with(cards, HandRules::default())
+}
+
+/// `detect_hand` under the given joker rules
+pub fn detect_hand_with(cards: &[PlayingCard], rules: HandRules) -> HandResult {
+    // Stone cards have no rank or suit: detect the hand from the other cards,
+    // then every Stone card scores on top of it
+    let ranked: Vec<usize> = (0..cards.len()).filter(|&i| !cards[i].is_stone()).collect();
+    let ranked_cards: Vec<PlayingCard> = ranked.iter().map(|&i| cards[i]).collect();
+
+    let mut result = detect_ranked_hand(&ranked_cards, rules);
+    result.scoring_indices = result.scoring_indices.iter().map(|&i| ranked[i]).collect();
+    result
+        .scoring_indices
+        .extend((0..cards.len()).filter(|&i| cards[i].always_scores()));
+    result
+}
+
+/// Hand detection over cards that all have a rank and suit
+fn detect_ranked_hand(cards: &[PlayingCard], rules: HandRules) -> HandResult {
     if cards.is_empty() {
         return HandResult {
             hand_type: PokerHand::HighCard,
@@ -154,6 +208,9 @@ pub fn detect_hand(cards: &[PlayingCard]) -> HandResult {
     }
 
     let n = cards.len();
+    if n > 5 {
+        return detect_best_five(cards, rules);
+    }
 
     // Build rank frequency map
     let mut rank_freq: HashMap<Rank, Vec<usize>> = HashMap::new();
@@ -161,16 +218,10 @@ pub fn detect_hand(cards: &[PlayingCard]) -> HandResult {
         rank_freq.entry(card.rank).or_default().push(i);
     }
 
-    // Check flush (all same suit, considering wilds)
-    let is_flush = n >= 5 && {
-        // Try each suit - a card matches if it's that suit or is wild
-        Suit::ALL
-            .iter()
-            .any(|&target_suit| cards.iter().all(|c| c.suit == target_suit || c.is_wild()))
-    };
-
-    // Check straight
-    let is_straight = check_straight(cards);
+    // Cards making up the flush / straight (all 5 unless Four Fingers allows 4)
+    let flush = flush_indices(cards, rules.min_run());
+    let straight = straight_indices(cards, rules);
+    let is_flush = flush.is_some();
 
     // Get groups sorted by size (descending), then rank (descending)
     let mut groups: Vec<(Rank, Vec<usize>)> = rank_freq.into_iter().collect();
@@ -206,21 +257,20 @@ pub fn detect_hand(cards: &[PlayingCard]) -> HandResult {
         };
     }
 
-    // Royal Flush: A-K-Q-J-10 straight + flush
-    if n == 5 && is_straight && is_flush && is_royal(cards) {
-        let all_indices: Vec<usize> = (0..n).collect();
-        return HandResult {
-            hand_type: PokerHand::RoyalFlush,
-            scoring_indices: all_indices,
+    // Straight Flush: straight + flush, scoring every card in either.
+    // Royal Flush when the straight runs up to the Ace (J-Q-K-A with Four Fingers)
+    if let (Some(straight), Some(flush)) = (&straight, &flush) {
+        let mut scoring = straight.clone();
+        scoring.extend(flush.iter().filter(|i| !straight.contains(i)));
+        scoring.sort_unstable();
+        let hand_type = if is_royal(cards, straight) {
+            PokerHand::RoyalFlush
+        } else {
+            PokerHand::StraightFlush
         };
-    }
-
-    // Straight Flush: straight + flush
-    if n == 5 && is_straight && is_flush {
-        let all_indices: Vec<usize> = (0..n).collect();
         return HandResult {
-            hand_type: PokerHand::StraightFlush,
-            scoring_indices: all_indices,
+            hand_type,
+            scoring_indices: scoring,
         };
     }
 
@@ -244,20 +294,18 @@ pub fn detect_hand(cards: &[PlayingCard]) -> HandResult {
     }
 
     // Flush
-    if is_flush {
-        let all_indices: Vec<usize> = (0..n).collect();
+    if let Some(scoring) = flush {
         return HandResult {
             hand_type: PokerHand::Flush,
-            scoring_indices: all_indices,
+            scoring_indices: scoring,
         };
     }
 
     // Straight
-    if is_straight {
-        let all_indices: Vec<usize> = (0..n).collect();
+    if let Some(scoring) = straight {
         return HandResult {
             hand_type: PokerHand::Straight,
-            scoring_indices: all_indices,
+            scoring_indices: scoring,
         };
     }
 
@@ -303,39 +351,135 @@ pub fn detect_hand(cards: &[PlayingCard]) -> HandResult {
     }
 }
 
-/// Check if cards form a straight (5 consecutive ranks).
-/// Handles Ace-low (A-2-3-4-5) and Ace-high (10-J-Q-K-A).
-fn check_straight(cards: &[PlayingCard]) -> bool {
-    if cards.len() != 5 {
-        return false;
+/// Indices of at least `min_cards` cards sharing one suit, where Wild cards
+/// count as every suit. Stone cards have no suit, so they never join a flush
+/// (`detect_hand` already leaves them out before getting here).
+fn flush_indices(cards: &[PlayingCard], min_cards: usize) -> Option<Vec<usize>> {
+    Suit::ALL
+        .iter()
+        .map(|&suit| {
+            (0..cards.len())
+                .filter(|&i| cards[i].is_suit(suit))
+                .collect::<Vec<usize>>()
+        })
+        .filter(|indices| indices.len() >= min_cards)
+        .max_by_key(|indices| indices.len())
+}
+
+/// Indices of the cards forming a straight: all 5, or with Four Fingers the
+/// best 4 (highest ranks, then earliest cards) when no 5-card straight exists
+fn straight_indices(cards: &[PlayingCard], rules: HandRules) -> Option<Vec<usize>> {
+    let n = cards.len();
+    for size in (rules.min_run()..=n).rev() {
+        let mut best: Option<(Vec<usize>, u32)> = None;
+        for subset in subsets(n, size) {
+            let subset_cards: Vec<PlayingCard> = subset.iter().map(|&i| cards[i]).collect();
+            if !check_straight(&subset_cards, rules.shortcut) {
+                continue;
+            }
+            let total: u32 = subset_cards.iter().map(|c| c.rank as u32).sum();
+            if best
+                .as_ref()
+                .is_none_or(|(_, best_total)| total > *best_total)
+            {
+                best = Some((subset, total));
+            }
+        }
+        if let Some((indices, _)) = best {
+            return Some(indices);
+        }
+    }
+    None
+}
+
+/// More than 5 cards: every 5-card subset is tried and the best one scores.
+/// A higher hand type wins; ties go to the subset whose scoring cards have the
+/// higher total rank (then the earliest cards). Cards outside it don't score.
+fn detect_best_five(cards: &[PlayingCard], rules: HandRules) -> HandResult {
+    let mut best: Option<(HandResult, u32)> = None;
+
+    for subset in subsets(cards.len(), 5) {
+        let subset_cards: Vec<PlayingCard> = subset.iter().map(|&i| cards[i]).collect();
+
+        let mut result = detect_ranked_hand(&subset_cards, rules);
+        result.scoring_indices = result.scoring_indices.iter().map(|&i| subset[i]).collect();
+        let rank_total: u32 = result
+            .scoring_indices
+            .iter()
+            .map(|&i| cards[i].rank as u32)
+            .sum();
+
+        let better = match &best {
+            None => true,
+            Some((current, current_total)) => {
+                (result.hand_type, rank_total) > (current.hand_type, *current_total)
+            }
+        };
+        if better {
+            best = Some((result, rank_total));
+        }
     }
 
+    best.map(|(result, _)| result)
+        .expect("more than 5 cards always have a 5-card subset")
+}
+
+/// Every `size`-card subset of `n` cards as sorted indices, in the order a
+/// counting bitmask would visit them (so ties still go to the earliest cards)
+pub(crate) fn subsets(n: usize, size: usize) -> impl Iterator<Item = Vec<usize>> {
+    let mut next = (size <= n).then(|| (0..size).collect::<Vec<usize>>());
+    std::iter::from_fn(move || {
+        let current = next.take()?;
+        // Move up the lowest index that has room, and pack the ones below it back down
+        let bound = |i: usize| current.get(i + 1).copied().unwrap_or(n);
+        if let Some(i) = (0..size).find(|&i| current[i] + 1 < bound(i)) {
+            let mut following = current.clone();
+            following[i] += 1;
+            for (j, index) in following.iter_mut().enumerate().take(i) {
+                *index = j;
+            }
+            next = Some(following);
+        }
+        Some(current)
+    })
+}
+
+/// Check if cards form a straight (consecutive ranks, 5 of them unless Four
+/// Fingers lets the caller pass 4). Handles Ace-low (A-2-3-4-5) and Ace-high
+/// (10-J-Q-K-A). With `shortcut`, neighbouring ranks may be one apart
+/// (3-5-7-9-J, A-3-5-7-9).
+fn check_straight(cards: &[PlayingCard], shortcut: bool) -> bool {
     let mut ranks: Vec<u8> = cards.iter().map(|c| c.rank as u8).collect();
     ranks.sort();
     ranks.dedup();
 
-    if ranks.len() != 5 {
+    if ranks.len() != cards.len() {
         return false;
     }
 
-    // Normal straight: max - min == 4
-    if ranks[4] - ranks[0] == 4 {
+    let max_step = if shortcut { 2 } else { 1 };
+    let connected = |ranks: &[u8]| ranks.windows(2).all(|w| w[1] - w[0] <= max_step);
+
+    if connected(&ranks) {
         return true;
     }
 
-    // Ace-low straight: A,2,3,4,5 => values 14,2,3,4,5
-    if ranks == [2, 3, 4, 5, 14] {
-        return true;
+    // Ace-low: the Ace (14) can also sit below the Two as a 1
+    if ranks.last() == Some(&(Rank::Ace as u8)) {
+        let mut low = vec![1];
+        low.extend_from_slice(&ranks[..ranks.len() - 1]);
+        return connected(&low);
     }
 
     false
 }
 
-/// Check if cards form a royal (10-J-Q-K-A)
-fn is_royal(cards: &[PlayingCard]) -> bool {
-    let mut ranks: Vec<Rank> = cards.iter().map(|c| c.rank).collect();
+/// Check if the straight's cards form a royal (10-J-Q-K-A, or J-Q-K-A with Four Fingers)
+fn is_royal(cards: &[PlayingCard], straight: &[usize]) -> bool {
+    const ROYAL: [Rank; 5] = [Rank::Ten, Rank::Jack, Rank::Queen, Rank::King, Rank::Ace];
+    let mut ranks: Vec<Rank> = straight.iter().map(|&i| cards[i].rank).collect();
     ranks.sort();
-    ranks == [Rank::Ten, Rank::Jack, Rank::Queen, Rank::King, Rank::Ace]
+    ranks.len() <= ROYAL.len() && ranks == ROYAL[ROYAL.len() - ranks.len()..]
 }
 
 #[cfg(test)]
@@ -356,6 +500,63 @@ mod tests {
         assert_eq!(result.scoring_indices.len(), 1);
     }
 
+    #[test]
+    fn test_seven_cards_find_best_five() {
+        // A flush hides among a pair and two off-suit cards
+        let cards = vec![
+            c(Two, Hearts),
+            c(King, Spades),
+            c(Seven, Hearts),
+            c(King, Clubs),
+            c(Nine, Hearts),
+            c(Jack, Hearts),
+            c(Four, Hearts),
+        ];
+        let result = detect_hand(&cards);
+        assert_eq!(result.hand_type, PokerHand::Flush);
+        let mut scoring = result.scoring_indices.clone();
+        scoring.sort();
+        assert_eq!(scoring, vec![0, 2, 4, 5, 6]);
+    }
+
+    #[test]
+    fn test_subsets_follow_bitmask_order() {
+        let masks: Vec<Vec<usize>> = (0u32..(1 << 5))
+            .filter(|mask| mask.count_ones() == 3)
+            .map(|mask| (0..5).filter(|&i| mask & (1 << i) != 0).collect())
+            .collect();
+        assert_eq!(subsets(5, 3).collect::<Vec<_>>(), masks);
+        assert_eq!(subsets(3, 4).count(), 0);
+    }
+
+    #[test]
+    fn test_more_than_32_cards_find_best_five() {
+        // Every Spade and Heart, then the first 7 Diamonds
+        let cards: Vec<PlayingCard> = Suit::ALL
+            .iter()
+            .flat_map(|&suit| Rank::ALL.iter().map(move |&rank| c(rank, suit)))
+            .take(33)
+            .collect();
+        let result = detect_hand(&cards);
+        assert_eq!(result.hand_type, PokerHand::RoyalFlush);
+        assert_eq!(result.scoring_indices.len(), 5);
+    }
+
+    #[test]
+    fn test_six_cards_straight_skips_extra_card() {
+        let cards = vec![
+            c(Five, Spades),
+            c(Six, Hearts),
+            c(Seven, Clubs),
+            c(Eight, Diamonds),
+            c(Nine, Spades),
+            c(Two, Clubs),
+        ];
+        let result = detect_hand(&cards);
+        assert_eq!(result.hand_type, PokerHand::Straight);
+        assert!(!result.scoring_indices.contains(&5));
+    }
+
     #[test]
     fn test_pair() {
         let cards = vec![c(King, Spades), c(King, Hearts), c(Five, Clubs)];
@@ -413,6 +614,136 @@ mod tests {
         assert_eq!(result.hand_type, PokerHand::Straight);
     }
 
+    fn gapped_straight() -> Vec<PlayingCard> {
+        vec![
+            c(Three, Spades),
+            c(Five, Hearts),
+            c(Seven, Clubs),
+            c(Nine, Diamonds),
+            c(Jack, Spades),
+        ]
+    }
+
+    #[test]
+    fn test_gapped_straight_needs_shortcut() {
+        let cards = gapped_straight();
+        assert_eq!(detect_hand(&cards).hand_type, PokerHand::HighCard);
+
+        let rules = HandRules {
+            shortcut: true,
+            ..HandRules::default()
+        };
+        let result = detect_hand_with(&cards, rules);
+        assert_eq!(result.hand_type, PokerHand::Straight);
+        assert_eq!(result.scoring_indices, vec![0, 1, 2, 3, 4]);
+    }
+
+    #[test]
+    fn test_shortcut_straight_limits() {
+        let rules = HandRules {
+            shortcut: true,
+            ..HandRules::default()
+        };
+        // A two-rank gap is still too wide
+        let mut cards = gapped_straight();
+        cards[4] = c(Queen, Spades);
+        assert_eq!(
+            detect_hand_with(&cards, rules).hand_type,
+            PokerHand::HighCard
+        );
+
+        // Ace can still play low: A-3-5-7-9
+        cards = vec![
+            c(Ace, Spades),
+            c(Three, Hearts),
+            c(Five, Clubs),
+            c(Seven, Diamonds),
+            c(Nine, Spades),
+        ];
+        assert_eq!(
+            detect_hand_with(&cards, rules).hand_type,
+            PokerHand::Straight
+        );
+
+        // 2-3-5-7-9 also connects, but the higher straight is the one that scores
+        let mut cards = gapped_straight();
+        cards.push(c(Two, Hearts));
+        let result = detect_hand_with(&cards, rules);
+        assert_eq!(result.hand_type, PokerHand::Straight);
+        assert_eq!(result.scoring_indices, vec![0, 1, 2, 3, 4]);
+    }
+
+    const FOUR_FINGERS: HandRules = HandRules {
+        shortcut: false,
+        four_fingers: true,
+    };
+
+    #[test]
+    fn test_four_fingers_four_card_flush() {
+        let cards = vec![
+            c(Two, Hearts),
+            c(Six, Hearts),
+            c(Nine, Hearts),
+            c(King, Hearts),
+        ];
+        assert_eq!(detect_hand(&cards).hand_type, PokerHand::HighCard);
+        let result = detect_hand_with(&cards, FOUR_FINGERS);
+        assert_eq!(result.hand_type, PokerHand::Flush);
+        assert_eq!(result.scoring_indices, vec![0, 1, 2, 3]);
+
+        // The off-suit fifth card doesn't score
+        let mut cards = cards;
+        cards.insert(1, c(Four, Spades));
+        let result = detect_hand_with(&cards, FOUR_FINGERS);
+        assert_eq!(result.hand_type, PokerHand::Flush);
+        assert_eq!(result.scoring_indices, vec![0, 2, 3, 4]);
+    }
+
+    #[test]
+    fn test_four_fingers_four_card_straight() {
+        let cards = vec![
+            c(Six, Spades),
+            c(Seven, Hearts),
+            c(Eight, Clubs),
+            c(Nine, Diamonds),
+        ];
+        assert_eq!(detect_hand(&cards).hand_type, PokerHand::HighCard);
+        let result = detect_hand_with(&cards, FOUR_FINGERS);
+        assert_eq!(result.hand_type, PokerHand::Straight);
+        assert_eq!(result.scoring_indices, vec![0, 1, 2, 3]);
+
+        // A pair on top still reads as the straight, without the spare card
+        let mut cards = cards;
+        cards.push(c(Six, Hearts));
+        let result = detect_hand_with(&cards, FOUR_FINGERS);
+        assert_eq!(result.hand_type, PokerHand::Straight);
+        assert_eq!(result.scoring_indices, vec![0, 1, 2, 3]);
+    }
+
+    #[test]
+    fn test_four_fingers_straight_and_royal_flush() {
+        let cards = vec![
+            c(Jack, Hearts),
+            c(Queen, Hearts),
+            c(King, Hearts),
+            c(Ace, Hearts),
+            c(Three, Clubs),
+        ];
+        assert_eq!(detect_hand(&cards).hand_type, PokerHand::HighCard);
+        let result = detect_hand_with(&cards, FOUR_FINGERS);
+        assert_eq!(result.hand_type, PokerHand::RoyalFlush);
+        assert_eq!(result.scoring_indices, vec![0, 1, 2, 3]);
+
+        let cards = vec![
+            c(Five, Hearts),
+            c(Six, Hearts),
+            c(Seven, Hearts),
+            c(Eight, Hearts),
+        ];
+        let result = detect_hand_with(&cards, FOUR_FINGERS);
+        assert_eq!(result.hand_type, PokerHand::StraightFlush);
+    }
+
     #[test]
     fn test_flush() {
         let cards = vec![
@@ -495,4 +826,88 @@ mod tests {
         let result = detect_hand(&cards);
         assert_eq!(result.hand_type, PokerHand::FiveOfAKind);
     }
+
+    fn stone(rank: Rank, suit: Suit) -> PlayingCard {
+        c(rank, suit).with_enhancement(crate::card::Enhancement::Stone)
+    }
+
+    #[test]
+    fn test_stone_card_always_scores() {
+        let cards = vec![c(Nine, Clubs), stone(Two, Hearts), c(Four, Spades)];
+        let result = detect_hand(&cards);
+        assert_eq!(result.hand_type, PokerHand::HighCard);
+        assert!(result.scoring_indices.contains(&0));
+        assert!(result.scoring_indices.contains(&1));
+        assert!(!result.scoring_indices.contains(&2));
+    }
+
+    #[test]
+    fn test_stone_card_has_no_rank() {
+        // The Stone's hidden King must not pair with the real King
+        let cards = vec![c(King, Clubs), stone(King, Hearts)];
+        let result = detect_hand(&cards);
+        assert_eq!(result.hand_type, PokerHand::HighCard);
+        assert_eq!(result.scoring_indices.len(), 2);
+    }
+
+    #[test]
+    fn test_stone_card_neither_makes_nor_counts_toward_flush() {
+        let cards = vec![
+            c(Two, Hearts),
+            c(Five, Hearts),
+            c(Eight, Hearts),
+            c(Jack, Hearts),
+            stone(Ace, Hearts),
+        ];
+        let result = detect_hand(&cards);
+        assert_ne!(result.hand_type, PokerHand::Flush);
+        assert!(result.scoring_indices.contains(&4));
+    }
+
+    fn wild(rank: Rank, suit: Suit) -> PlayingCard {
+        c(rank, suit).with_enhancement(crate::card::Enhancement::Wild)
+    }
+
+    #[test]
+    fn test_wild_card_completes_flush_but_stone_does_not() {
+        let hearts = [
+            c(Two, Hearts),
+            c(Five, Hearts),
+            c(Eight, Hearts),
+            c(Jack, Hearts),
+        ];
+        let mut cards = hearts.to_vec();
+        cards.push(wild(King, Spades));
+        assert_eq!(detect_hand(&cards).hand_type, PokerHand::Flush);
+
+        let mut cards = hearts.to_vec();
+        cards.push(stone(King, Hearts));
+        assert_eq!(detect_hand(&cards).hand_type, PokerHand::HighCard);
+        assert!(flush_indices(&cards, 5).is_none());
+    }
+
+    #[test]
+    fn test_flush_five_and_flush_house_need_a_real_flush() {
+        let mut cards = vec![
+            c(Queen, Clubs),
+            c(Queen, Clubs),
+            c(Queen, Clubs),
+            c(Queen, Clubs),
+            wild(Queen, Diamonds),
+        ];
+        assert_eq!(detect_hand(&cards).hand_type, PokerHand::FlushFive);
+        cards[4] = c(Queen, Diamonds);
+        assert_eq!(detect_hand(&cards).hand_type, PokerHand::FiveOfAKind);
+
+        let mut cards = vec![
+            c(Six, Spades),
+            c(Six, Spades),
+            c(Six, Spades),
+            c(Ten, Spades),
+            wild(Ten, Hearts),
+        ];
+        assert_eq!(detect_hand(&cards).hand_type, PokerHand::FlushHouse);
+        cards[4] = stone(Ten, Spades);
+        assert_eq!(detect_hand(&cards).hand_type, PokerHand::ThreeOfAKind);
+    }
 }
@@ -1,6 +1,9 @@
 use std::fmt;
 
-use crate::card::{PlayingCard, Rank, Suit};
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+
+use crate::card::{Edition, Enhancement, PlayingCard, Rank, Suit};
 use crate::hand::PokerHand;
 
 /// Rarity tier for jokers
@@ -23,8 +26,37 @@ impl JokerRarity {
     }
 }
 
+/// Relative odds of each joker rarity in `JokerType::random_weighted`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RarityWeights {
+    pub common: u32,
+    pub uncommon: u32,
+    pub rare: u32,
+    pub legendary: u32,
+    /// Added to the Uncommon weight for every ante past the first
+    pub uncommon_per_ante: u32,
+    /// Added to the Rare weight for every ante past the first
+    pub rare_per_ante: u32,
+}
+
+impl RarityWeights {
+    /// The weights at `ante`, with the per-ante growth applied
+    pub fn at_ante(&self, ante: u8) -> RarityWeights {
+        let past_first = ante.saturating_sub(1) as u32;
+        RarityWeights {
+            uncommon: self.uncommon + self.uncommon_per_ante * past_first,
+            rare: self.rare + self.rare_per_ante * past_first,
+            ..*self
+        }
+    }
+
+    pub fn total(&self) -> u32 {
+        self.common + self.uncommon + self.rare + self.legendary
+    }
+}
+
 /// All joker types in the MVP
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum JokerType {
     // Flat mult
     Joker, // +4 Mult
@@ -40,9 +72,11 @@ pub enum JokerType {
     // Size conditional
     HalfJoker, // +20 Mult if played hand <=3 cards
     // Chips
-    Banner,  // +30 Chips per discard remaining
-    OddTodd, // +31 Chips per odd-ranked card scored
-    Scholar, // +20 Chips and +4 Mult per Ace scored
+    Banner,     // +30 Chips per discard remaining
+    OddTodd,    // +31 Chips per odd-ranked card scored (A, 9, 7, 5, 3)
+    EvenSteven, // +4 Mult per even-ranked card scored (10, 8, 6, 4, 2)
+    Scholar,    // +20 Chips and +4 Mult per Ace scored
+    Fibonacci,  // +8 Mult per Ace, 2, 3, 5 or 8 scored
     // xMult
     SteelJoker, // xMult based on steel cards in hand
     Blackboard, // x3 Mult if all held cards are Spades or Clubs
@@ -50,16 +84,33 @@ pub enum JokerType {
     // Economy
     Egg,         // +$3 sell value per round
     GoldenJoker, // +$4 at end of round
+    Rocket,      // +$1 at end of round, +$2 more per boss beaten
     // Retrigger
     Hack, // Retrigger 2,3,4,5 cards
+    Mime, // Retrigger held-in-hand card abilities
+    // Legendary
+    Perkeo, // Negative copy of a random consumable when leaving the shop
     // Meta
-    Blueprint, // Copy joker to the right
+    Blueprint,  // Copy joker to the right
+    Brainstorm, // Copy the leftmost joker
     // Hand conditional xMult
     TheTrio, // x3 Mult if hand contains Three of a Kind
+    // Face cards
+    Photograph, // x2 Mult for the first scored face card
+    Pareidolia, // All cards count as face cards
+    MidasMask,  // Scored face cards become Gold cards
+    // Economy scaling
+    Bootstraps, // +2 Mult per $5 held
+    // Hand scaling
+    RideTheBus, // +1 Mult per consecutive hand without a scoring face card
+    Obelisk,    // X0.2 Mult per consecutive hand that isn't the most played
+    // Hand rules
+    Shortcut,    // Straights can skip one rank between cards
+    FourFingers, // Flushes and Straights need only 4 cards
 }
 
 impl JokerType {
-    pub const ALL: [JokerType; 20] = [
+    pub const ALL: [JokerType; 34] = [
         JokerType::Joker,
         JokerType::GreedyJoker,
         JokerType::LustyJoker,
@@ -80,6 +131,20 @@ impl JokerType {
         JokerType::Hack,
         JokerType::Blueprint,
         JokerType::TheTrio,
+        JokerType::Photograph,
+        JokerType::Pareidolia,
+        JokerType::Bootstraps,
+        JokerType::Brainstorm,
+        JokerType::RideTheBus,
+        JokerType::Mime,
+        JokerType::Perkeo,
+        JokerType::EvenSteven,
+        JokerType::Obelisk,
+        JokerType::Fibonacci,
+        JokerType::Rocket,
+        JokerType::Shortcut,
+        JokerType::FourFingers,
+        JokerType::MidasMask,
     ];
 
     pub fn name(&self) -> &'static str {
@@ -95,15 +160,29 @@ impl JokerType {
             JokerType::HalfJoker => "Half Joker",
             JokerType::Banner => "Banner",
             JokerType::OddTodd => "Odd Todd",
+            JokerType::EvenSteven => "Even Steven",
             JokerType::Scholar => "Scholar",
+            JokerType::Fibonacci => "Fibonacci",
             JokerType::SteelJoker => "Steel Joker",
             JokerType::Blackboard => "Blackboard",
             JokerType::TheDuo => "The Duo",
             JokerType::Egg => "Egg",
             JokerType::GoldenJoker => "Golden Joker",
+            JokerType::Rocket => "Rocket",
             JokerType::Hack => "Hack",
+            JokerType::Mime => "Mime",
+            JokerType::Perkeo => "Perkeo",
             JokerType::Blueprint => "Blueprint",
+            JokerType::Brainstorm => "Brainstorm",
             JokerType::TheTrio => "The Trio",
+            JokerType::Photograph => "Photograph",
+            JokerType::Pareidolia => "Pareidolia",
+            JokerType::MidasMask => "Midas Mask",
+            JokerType::Bootstraps => "Bootstraps",
+            JokerType::RideTheBus => "Ride the Bus",
+            JokerType::Obelisk => "Obelisk",
+            JokerType::Shortcut => "Shortcut",
+            JokerType::FourFingers => "Four Fingers",
         }
     }
 
@@ -119,16 +198,30 @@ impl JokerType {
             JokerType::CrazyJoker => "+12 Mult if Straight",
             JokerType::HalfJoker => "+20 Mult if <=3 cards",
             JokerType::Banner => "+30 Chips per discard left",
-            JokerType::OddTodd => "+31 Chips per odd card",
+            JokerType::OddTodd => "+31 Chips per A,9,7,5,3",
+            JokerType::EvenSteven => "+4 Mult per 10,8,6,4,2",
             JokerType::Scholar => "+20 Chips, +4 Mult per Ace",
+            JokerType::Fibonacci => "+8 Mult per A,2,3,5,8",
             JokerType::SteelJoker => "x0.2 Mult per Steel card",
             JokerType::Blackboard => "x3 if held cards all dark",
             JokerType::TheDuo => "x2 Mult if Pair in hand",
             JokerType::Egg => "+$3 sell value per round",
             JokerType::GoldenJoker => "+$4 at end of round",
+            JokerType::Rocket => "+$1 per round, +$2 per boss",
             JokerType::Hack => "Retrigger 2,3,4,5 cards",
+            JokerType::Mime => "Retrigger held card abilities",
+            JokerType::Perkeo => "Negative copy of a consumable after shop",
             JokerType::Blueprint => "Copy joker to the right",
+            JokerType::Brainstorm => "Copy the leftmost joker",
             JokerType::TheTrio => "x3 if Three of a Kind",
+            JokerType::Photograph => "x2 Mult on first face card",
+            JokerType::Pareidolia => "All cards are face cards",
+            JokerType::MidasMask => "Scored face cards turn Gold",
+            JokerType::Bootstraps => "+2 Mult per $5 held",
+            JokerType::RideTheBus => "+1 Mult per hand, no faces",
+            JokerType::Obelisk => "X0.2 Mult per hand, not most played",
+            JokerType::Shortcut => "Straights can skip one rank",
+            JokerType::FourFingers => "Flushes, Straights need 4 cards",
         }
     }
 
@@ -145,19 +238,98 @@ impl JokerType {
             | JokerType::HalfJoker
             | JokerType::Banner
             | JokerType::OddTodd
+            | JokerType::EvenSteven
             | JokerType::Egg
             | JokerType::GoldenJoker
-            | JokerType::Hack => JokerRarity::Common,
-            JokerType::Scholar | JokerType::SteelJoker | JokerType::TheDuo | JokerType::TheTrio => {
-                JokerRarity::Uncommon
-            }
-            JokerType::Blackboard | JokerType::Blueprint => JokerRarity::Rare,
+            | JokerType::Hack
+            | JokerType::Photograph
+            | JokerType::RideTheBus => JokerRarity::Common,
+            JokerType::Scholar
+            | JokerType::Fibonacci
+            | JokerType::Rocket
+            | JokerType::SteelJoker
+            | JokerType::TheDuo
+            | JokerType::TheTrio
+            | JokerType::Pareidolia
+            | JokerType::Bootstraps
+            | JokerType::Mime
+            | JokerType::Shortcut
+            | JokerType::FourFingers
+            | JokerType::MidasMask => JokerRarity::Uncommon,
+            JokerType::Blackboard
+            | JokerType::Blueprint
+            | JokerType::Brainstorm
+            | JokerType::Obelisk => JokerRarity::Rare,
+            JokerType::Perkeo => JokerRarity::Legendary,
         }
     }
 
     pub fn price(&self) -> u32 {
         self.rarity().base_price()
     }
+
+    /// Roll a joker for the shop or a Buffoon pack: Legendaries show up 0.3% of
+    /// the time, everything else is equally likely
+    pub fn random<R: Rng>(rng: &mut R) -> JokerType {
+        let legendary = rng.gen_bool(0.003);
+        let pool: Vec<JokerType> = Self::ALL
+            .into_iter()
+            .filter(|t| (t.rarity() == JokerRarity::Legendary) == legendary)
+            .collect();
+        pool[rng.gen_range(0..pool.len())]
+    }
+
+    /// Roll a rarity by `weights` at `ante`, then a joker of that rarity
+    pub fn random_weighted<R: Rng>(rng: &mut R, weights: &RarityWeights, ante: u8) -> JokerType {
+        let weights = weights.at_ante(ante);
+        let mut roll = rng.gen_range(0..weights.total().max(1));
+        let mut rarity = JokerRarity::Common;
+        for (candidate, weight) in [
+            (JokerRarity::Common, weights.common),
+            (JokerRarity::Uncommon, weights.uncommon),
+            (JokerRarity::Rare, weights.rare),
+            (JokerRarity::Legendary, weights.legendary),
+        ] {
+            if roll < weight {
+                rarity = candidate;
+                break;
+            }
+            roll -= weight;
+        }
+        Self::random_with_rarity(rng, rarity)
+    }
+
+    /// Roll a joker of exactly `rarity` (Uncommon Tag)
+    pub fn random_with_rarity<R: Rng>(rng: &mut R, rarity: JokerRarity) -> JokerType {
+        let pool: Vec<JokerType> = Self::ALL
+            .into_iter()
+            .filter(|t| t.rarity() == rarity)
+            .collect();
+        pool[rng.gen_range(0..pool.len())]
+    }
+
+    /// Blueprint and Brainstorm take on another joker's effect
+    pub fn is_copier(&self) -> bool {
+        matches!(self, JokerType::Blueprint | JokerType::Brainstorm)
+    }
+
+    /// Current contribution for jokers that scale with run state, for inspect popups
+    pub fn live_preview(&self, money: u32) -> Option<String> {
+        match self {
+            JokerType::Bootstraps => Some(format!("Currently +{} Mult", bootstraps_mult(money))),
+            _ => None,
+        }
+    }
+}
+
+/// Obelisk: X1 plus X0.2 per hand in a row that wasn't the most played
+pub fn obelisk_x_mult(counter: u32) -> f64 {
+    1.0 + 0.2 * counter as f64
+}
+
+/// Bootstraps: +2 Mult for every full $5 held
+pub fn bootstraps_mult(money: u32) -> u64 {
+    (money / 5) as u64 * 2
 }
 
 impl fmt::Display for JokerType {
@@ -167,12 +339,17 @@ impl fmt::Display for JokerType {
 }
 
 /// A joker instance owned by the player
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Joker {
     pub joker_type: JokerType,
     pub sell_value: u32,
     /// For Egg: accumulates bonus sell value
     pub bonus_sell: u32,
+    #[serde(default)]
+    pub edition: Edition,
+    /// Run-long state for scaling jokers (Ride the Bus: hands in a row without a face card)
+    #[serde(default)]
+    pub counter: u32,
 }
 
 impl Joker {
@@ -181,9 +358,92 @@ impl Joker {
             sell_value: joker_type.price() / 2,
             bonus_sell: 0,
             joker_type,
+            edition: Edition::Base,
+            counter: 0,
         }
     }
 
+    /// Current contribution for jokers that scale with run state, for inspect popups
+    pub fn live_preview(&self, money: u32) -> Option<String> {
+        match self.joker_type {
+            JokerType::RideTheBus => Some(format!("Currently +{} Mult", self.counter)),
+            JokerType::Obelisk => Some(format!(
+                "Currently X{:.1} Mult",
+                obelisk_x_mult(self.counter)
+            )),
+            JokerType::Rocket => Some(format!("Currently ${} per round", self.round_money())),
+            other => other.live_preview(money),
+        }
+    }
+
+    /// Money paid at the end of each round (Golden Joker, Rocket)
+    pub fn round_money(&self) -> u32 {
+        match self.joker_type {
+            JokerType::GoldenJoker => 4,
+            JokerType::Rocket => 1 + 2 * self.counter,
+            _ => 0,
+        }
+    }
+
+    /// Sell value gained at the end of each round (Egg)
+    pub fn round_sell_value(&self) -> u32 {
+        match self.joker_type {
+            JokerType::Egg => 3,
+            _ => 0,
+        }
+    }
+
+    /// Apply end-of-round economy: grow sell value, and count a beaten boss for Rocket
+    pub fn after_round(&mut self, boss_beaten: bool) {
+        self.bonus_sell += self.round_sell_value();
+        if self.joker_type == JokerType::Rocket && boss_beaten {
+            self.counter += 1;
+        }
+    }
+
+    /// Update scaling state as a hand is played, before it scores. `most_played`:
+    /// counting this hand, its type has been played more than any other.
+    pub fn before_hand_played(&mut self, most_played: bool) {
+        if self.joker_type == JokerType::Obelisk {
+            self.counter = if most_played { 0 } else { self.counter + 1 };
+        }
+    }
+
+    /// Update scaling state after a hand has been scored
+    pub fn after_hand_played(&mut self, scored_face_card: bool) {
+        if self.joker_type == JokerType::RideTheBus {
+            self.counter = if scored_face_card {
+                0
+            } else {
+                self.counter + 1
+            };
+        }
+    }
+
+    pub fn with_edition(mut self, edition: Edition) -> Self {
+        self.edition = edition;
+        self.sell_value = self.price() / 2;
+        self
+    }
+
+    /// Shop price, including the edition surcharge
+    pub fn price(&self) -> u32 {
+        self.joker_type.price() + self.edition.joker_extra_cost()
+    }
+
+    /// Display name, prefixed by the edition if any ("Negative Blueprint")
+    pub fn name(&self) -> String {
+        match self.edition {
+            Edition::Base => self.joker_type.name().to_string(),
+            edition => format!("{} {}", edition.name(), self.joker_type.name()),
+        }
+    }
+
+    /// Negative jokers don't use up a joker slot
+    pub fn takes_slot(&self) -> bool {
+        self.edition != Edition::Negative
+    }
+
     pub fn total_sell_value(&self) -> u32 {
         self.sell_value + self.bonus_sell
     }
@@ -197,6 +457,24 @@ pub struct JokerContext<'a> {
     pub held_cards: &'a [PlayingCard],
     pub discards_remaining: u8,
     pub num_played: usize,
+    /// Leftmost played card that scores (for "first scored card" jokers)
+    pub first_scored_index: Option<usize>,
+    /// Pareidolia is owned: every card counts as a face card
+    pub pareidolia: bool,
+    /// Money held when the hand was played (snapshot, so money earned
+    /// while scoring doesn't feed back into money-scaling jokers)
+    pub money: u32,
+}
+
+impl JokerContext<'_> {
+    /// Scoring cards that can set off per-card joker effects: debuffed cards
+    /// still count toward the hand but trigger nothing
+    pub fn triggering_cards(&self) -> impl Iterator<Item = (usize, &PlayingCard)> + '_ {
+        self.scoring_indices
+            .iter()
+            .map(|&i| (i, &self.played_cards[i]))
+            .filter(|(_, card)| !card.debuffed)
+    }
 }
 
 /// The effect a joker applies to scoring
@@ -208,6 +486,8 @@ pub enum JokerEffect {
     AddMult(u64),
     /// Multiply mult
     XMult(f64),
+    /// Multiply mult, triggered by a specific scoring card
+    XMultOnCard { card_index: usize, x_mult: f64 },
     /// Add chips for specific scoring card indices
     AddChipsPerCard {
         card_indices: Vec<usize>,
@@ -230,26 +510,83 @@ pub enum JokerEffect {
     None,
 }
 
+/// The joker type the copier (Blueprint/Brainstorm) at `index` ends up copying,
+/// following chains of copiers: a row of Blueprints all copy the same joker, and
+/// Brainstorm on a leftmost Blueprint copies what that Blueprint copies.
+/// Chains that run off the end or loop back on themselves copy nothing.
+pub fn copy_target(jokers: &[Joker], index: usize) -> Option<JokerType> {
+    copy_target_index(jokers, index).map(|i| jokers[i].joker_type)
+}
+
+/// Index of the joker that the copier at `index` ends up copying (see `copy_target`)
+pub fn copy_target_index(jokers: &[Joker], index: usize) -> Option<usize> {
+    let mut current = index;
+    // Any chain longer than the joker row must be a loop
+    for _ in 0..jokers.len() {
+        current = match jokers.get(current)?.joker_type {
+            JokerType::Blueprint => current + 1,
+            JokerType::Brainstorm => 0,
+            _ => return (current != index).then_some(current),
+        };
+        if current == index {
+            return None;
+        }
+    }
+    None
+}
+
+/// Extra triggers for held-in-hand card abilities (Steel, Gold):
+/// one per Mime, including Blueprint/Brainstorm copying a Mime
+pub fn held_retriggers(jokers: &[Joker]) -> usize {
+    (0..jokers.len())
+        .filter(|&i| {
+            let source = if jokers[i].joker_type.is_copier() {
+                copy_target_index(jokers, i)
+            } else {
+                Some(i)
+            };
+            source.is_some_and(|s| jokers[s].joker_type == JokerType::Mime)
+        })
+        .count()
+}
+
 /// Evaluate a joker's effect given the current context.
-/// For Blueprint, pass the next joker's type to copy.
-pub fn evaluate_joker(
-    joker: &Joker,
-    ctx: &JokerContext,
-    next_joker_type: Option<JokerType>,
-) -> JokerEffect {
-    let jtype = if joker.joker_type == JokerType::Blueprint {
-        match next_joker_type {
-            Some(t) => t,
+/// For Blueprint/Brainstorm, pass the joker it copies (see `copy_target_index`).
+pub fn evaluate_joker(joker: &Joker, ctx: &JokerContext, copied: Option<&Joker>) -> JokerEffect {
+    let source = if joker.joker_type.is_copier() {
+        match copied {
+            Some(j) => j,
             None => return JokerEffect::None,
         }
     } else {
-        joker.joker_type
+        joker
     };
 
-    evaluate_type(jtype, ctx)
+    evaluate_type(source.joker_type, source.counter, ctx)
+}
+
+/// Enhancement the jokers give a scored card before it goes back to the deck
+pub fn scored_card_enhancement(
+    jokers: &[Joker],
+    card: &PlayingCard,
+    pareidolia: bool,
+) -> Option<Enhancement> {
+    let midas = jokers.iter().any(|j| j.joker_type == JokerType::MidasMask);
+    (midas && !card.debuffed && card.is_face_with(pareidolia)).then_some(Enhancement::Gold)
 }
 
-fn evaluate_type(jtype: JokerType, ctx: &JokerContext) -> JokerEffect {
+/// Whether any scoring card counts as a face card (resets Ride the Bus)
+pub fn scored_face_card(
+    played_cards: &[PlayingCard],
+    scoring_indices: &[usize],
+    pareidolia: bool,
+) -> bool {
+    scoring_indices
+        .iter()
+        .any(|&i| played_cards[i].is_face_with(pareidolia))
+}
+
+fn evaluate_type(jtype: JokerType, counter: u32, ctx: &JokerContext) -> JokerEffect {
     match jtype {
         JokerType::Joker => JokerEffect::AddMult(4),
 
@@ -295,13 +632,9 @@ fn evaluate_type(jtype: JokerType, ctx: &JokerContext) -> JokerEffect {
 
         JokerType::OddTodd => {
             let odd_indices: Vec<usize> = ctx
-                .scoring_indices
-                .iter()
-                .filter(|&&i| {
-                    let rank_val = ctx.played_cards[i].rank as u8;
-                    rank_val % 2 == 1 // Odd ranks: 3,5,7,9,J(11),K(13)
-                })
-                .copied()
+                .triggering_cards()
+                .filter(|(_, card)| !card.is_stone() && card.rank.is_odd())
+                .map(|(i, _)| i)
                 .collect();
             if odd_indices.is_empty() {
                 JokerEffect::None
@@ -313,12 +646,27 @@ fn evaluate_type(jtype: JokerType, ctx: &JokerContext) -> JokerEffect {
             }
         }
 
+        JokerType::EvenSteven => {
+            let even_indices: Vec<usize> = ctx
+                .triggering_cards()
+                .filter(|(_, card)| !card.is_stone() && card.rank.is_even())
+                .map(|(i, _)| i)
+                .collect();
+            if even_indices.is_empty() {
+                JokerEffect::None
+            } else {
+                JokerEffect::AddMultPerCard {
+                    card_indices: even_indices,
+                    mult_each: 4,
+                }
+            }
+        }
+
         JokerType::Scholar => {
             let ace_indices: Vec<usize> = ctx
-                .scoring_indices
-                .iter()
-                .filter(|&&i| ctx.played_cards[i].rank == Rank::Ace)
-                .copied()
+                .triggering_cards()
+                .filter(|(_, card)| !card.is_stone() && card.rank == Rank::Ace)
+                .map(|(i, _)| i)
                 .collect();
             if ace_indices.is_empty() {
                 JokerEffect::None
@@ -331,6 +679,22 @@ fn evaluate_type(jtype: JokerType, ctx: &JokerContext) -> JokerEffect {
             }
         }
 
+        JokerType::Fibonacci => {
+            let fib_indices: Vec<usize> = ctx
+                .triggering_cards()
+                .filter(|(_, card)| !card.is_stone() && card.rank.is_fibonacci())
+                .map(|(i, _)| i)
+                .collect();
+            if fib_indices.is_empty() {
+                JokerEffect::None
+            } else {
+                JokerEffect::AddMultPerCard {
+                    card_indices: fib_indices,
+                    mult_each: 8,
+                }
+            }
+        }
+
         JokerType::SteelJoker => {
             let steel_count = ctx
                 .held_cards
@@ -372,17 +736,16 @@ fn evaluate_type(jtype: JokerType, ctx: &JokerContext) -> JokerEffect {
             }
         }
 
-        JokerType::Egg | JokerType::GoldenJoker => JokerEffect::None,
+        JokerType::Egg | JokerType::GoldenJoker | JokerType::Rocket => JokerEffect::None,
 
         JokerType::Hack => {
             let retrigger_indices: Vec<usize> = ctx
-                .scoring_indices
-                .iter()
-                .filter(|&&i| {
-                    let rank = ctx.played_cards[i].rank;
-                    matches!(rank, Rank::Two | Rank::Three | Rank::Four | Rank::Five)
+                .triggering_cards()
+                .filter(|(_, card)| {
+                    !card.is_stone()
+                        && matches!(card.rank, Rank::Two | Rank::Three | Rank::Four | Rank::Five)
                 })
-                .copied()
+                .map(|(i, _)| i)
                 .collect();
             if retrigger_indices.is_empty() {
                 JokerEffect::None
@@ -393,7 +756,53 @@ fn evaluate_type(jtype: JokerType, ctx: &JokerContext) -> JokerEffect {
             }
         }
 
-        JokerType::Blueprint => {
+        JokerType::Photograph => match ctx.first_scored_index {
+            Some(i)
+                if !ctx.played_cards[i].debuffed
+                    && ctx.played_cards[i].is_face_with(ctx.pareidolia) =>
+            {
+                JokerEffect::XMultOnCard {
+                    card_index: i,
+                    x_mult: 2.0,
+                }
+            }
+            _ => JokerEffect::None,
+        },
+
+        JokerType::Bootstraps => match bootstraps_mult(ctx.money) {
+            0 => JokerEffect::None,
+            mult => JokerEffect::AddMult(mult),
+        },
+
+        // Counts this hand too; the stored counter catches up after scoring
+        JokerType::RideTheBus => {
+            if scored_face_card(ctx.played_cards, ctx.scoring_indices, ctx.pareidolia) {
+                JokerEffect::None
+            } else {
+                JokerEffect::AddMult(counter as u64 + 1)
+            }
+        }
+
+        // Counter already updated for this hand (see `Joker::before_hand_played`)
+        JokerType::Obelisk if counter > 0 => JokerEffect::XMult(obelisk_x_mult(counter)),
+        JokerType::Obelisk => JokerEffect::None,
+
+        // Passive: read through `JokerContext::pareidolia`
+        JokerType::Pareidolia => JokerEffect::None,
+
+        // Acts after scoring (see `scored_card_enhancement`)
+        JokerType::MidasMask => JokerEffect::None,
+
+        // Passive: counted by `held_retriggers`
+        JokerType::Mime => JokerEffect::None,
+
+        // Passive: read through `HandRules::from_jokers`
+        JokerType::Shortcut | JokerType::FourFingers => JokerEffect::None,
+
+        // Acts when leaving the shop (see `RunState::leave_shop`)
+        JokerType::Perkeo => JokerEffect::None,
+
+        JokerType::Blueprint | JokerType::Brainstorm => {
             // Handled in evaluate_joker
             JokerEffect::None
         }
@@ -402,13 +811,9 @@ fn evaluate_type(jtype: JokerType, ctx: &JokerContext) -> JokerEffect {
 
 fn suit_mult_joker(ctx: &JokerContext, suit: Suit, mult_per: u64) -> JokerEffect {
     let matching: Vec<usize> = ctx
-        .scoring_indices
-        .iter()
-        .filter(|&&i| {
-            let card = &ctx.played_cards[i];
-            card.suit == suit || card.is_wild()
-        })
-        .copied()
+        .triggering_cards()
+        .filter(|(_, card)| card.is_suit(suit))
+        .map(|(i, _)| i)
         .collect();
 
     if matching.is_empty() {
@@ -447,6 +852,9 @@ mod tests {
             held_cards: &[],
             discards_remaining: 3,
             num_played: 2,
+            first_scored_index: Some(0),
+            pareidolia: false,
+            money: 0,
         };
         let effect = evaluate_joker(&joker, &ctx, None);
         match effect {
@@ -466,6 +874,9 @@ mod tests {
             held_cards: &[],
             discards_remaining: 3,
             num_played: 3,
+            first_scored_index: Some(0),
+            pareidolia: false,
+            money: 0,
         };
         let effect = evaluate_joker(&joker, &ctx, None);
         match effect {
@@ -491,6 +902,9 @@ mod tests {
             held_cards: &[],
             discards_remaining: 3,
             num_played: 2,
+            first_scored_index: Some(0),
+            pareidolia: false,
+            money: 0,
         };
         let effect = evaluate_joker(&joker, &ctx, None);
         match effect {
@@ -510,6 +924,9 @@ mod tests {
             held_cards: &[],
             discards_remaining: 3,
             num_played: 1,
+            first_scored_index: Some(0),
+            pareidolia: false,
+            money: 0,
         };
         let effect = evaluate_joker(&joker, &ctx, None);
         match effect {
@@ -529,6 +946,9 @@ mod tests {
             held_cards: &[],
             discards_remaining: 3,
             num_played: 2,
+            first_scored_index: Some(0),
+            pareidolia: false,
+            money: 0,
         };
         let effect = evaluate_joker(&joker, &ctx, None);
         match effect {
@@ -536,4 +956,89 @@ mod tests {
             _ => panic!("Expected AddMult"),
         }
     }
+
+    fn photograph_ctx<'a>(cards: &'a [PlayingCard], pareidolia: bool) -> JokerContext<'a> {
+        JokerContext {
+            played_cards: cards,
+            scoring_indices: &[0],
+            hand_type: PokerHand::HighCard,
+            held_cards: &[],
+            discards_remaining: 3,
+            num_played: cards.len(),
+            first_scored_index: Some(0),
+            pareidolia,
+            money: 0,
+        }
+    }
+
+    #[test]
+    fn test_odd_todd_and_even_steven_rank_parity() {
+        let todd = Joker::new(JokerType::OddTodd);
+        let steven = Joker::new(JokerType::EvenSteven);
+        let mut odd = Vec::new();
+        let mut even = Vec::new();
+        for rank in Rank::ALL {
+            let cards = vec![c(rank, Hearts)];
+            let ctx = photograph_ctx(&cards, false);
+            if !matches!(evaluate_joker(&todd, &ctx, None), JokerEffect::None) {
+                odd.push(rank);
+            }
+            if !matches!(evaluate_joker(&steven, &ctx, None), JokerEffect::None) {
+                even.push(rank);
+            }
+        }
+        odd.sort();
+        even.sort();
+        assert_eq!(odd, vec![Three, Five, Seven, Nine, Ace]);
+        assert_eq!(even, vec![Two, Four, Six, Eight, Ten]);
+    }
+
+    #[test]
+    fn test_photograph_first_face_card() {
+        let joker = Joker::new(JokerType::Photograph);
+
+        let cards = vec![c(King, Spades)];
+        match evaluate_joker(&joker, &photograph_ctx(&cards, false), None) {
+            JokerEffect::XMultOnCard { card_index, x_mult } => {
+                assert_eq!(card_index, 0);
+                assert!((x_mult - 2.0).abs() < f64::EPSILON);
+            }
+            _ => panic!("Expected XMultOnCard"),
+        }
+
+        let cards = vec![c(Seven, Spades)];
+        assert!(matches!(
+            evaluate_joker(&joker, &photograph_ctx(&cards, false), None),
+            JokerEffect::None
+        ));
+    }
+
+    #[test]
+    fn test_photograph_with_pareidolia() {
+        let joker = Joker::new(JokerType::Photograph);
+        let cards = vec![c(Seven, Spades)];
+        assert!(matches!(
+            evaluate_joker(&joker, &photograph_ctx(&cards, true), None),
+            JokerEffect::XMultOnCard { card_index: 0, .. }
+        ));
+    }
+
+    #[test]
+    fn test_bootstraps_scales_with_money() {
+        let joker = Joker::new(JokerType::Bootstraps);
+        let cards = vec![c(Ace, Spades)];
+        let mut ctx = photograph_ctx(&cards, false);
+
+        ctx.money = 9;
+        match evaluate_joker(&joker, &ctx, None) {
+            JokerEffect::AddMult(m) => assert_eq!(m, 2),
+            _ => panic!("Expected AddMult"),
+        }
+
+        ctx.money = 10;
+        match evaluate_joker(&joker, &ctx, None) {
+            JokerEffect::AddMult(m) => assert_eq!(m, 4),
+            _ => panic!("Expected AddMult"),
+        }
+    }
 }
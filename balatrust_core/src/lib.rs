@@ -2,17 +2,27 @@ pub mod blind;
 pub mod card;
 pub mod consumable;
 pub mod deck;
+pub mod format;
 pub mod hand;
 pub mod joker;
+pub mod pack;
 pub mod run;
 pub mod scoring;
 pub mod shop;
+pub mod stake;
+pub mod tag;
+pub mod voucher;
 
 pub use blind::{BlindType, BossBlind};
 pub use card::*;
-pub use consumable::{Consumable, ConsumableType};
-pub use deck::Deck;
+pub use consumable::{Consumable, ConsumableType, ConsumableWeights};
+pub use deck::{Deck, DeckType};
 pub use hand::PokerHand;
 pub use joker::{Joker, JokerRarity, JokerType};
-pub use run::{BlindOutcome, RewardBreakdown, RunState};
-pub use scoring::{ScoreResult, ScoreStep};
+pub use run::{
+    BlindOutcome, JokerPayout, PracticeSetup, Purchase, RewardBreakdown, RunState, RunStats,
+    SandboxSetup,
+};
+pub use scoring::{ScoreResult, ScoreStep, ScoredHand};
+pub use stake::Stake;
+pub use tag::Tag;
@@ -0,0 +1,112 @@
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+
+use crate::card::{Enhancement, PlayingCard, Rank, Suit};
+use crate::consumable::{Consumable, ConsumableType, ConsumableWeights};
+use crate::joker::{Joker, JokerType};
+
+/// Booster packs reveal a few options and let the player keep one
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum BoosterPack {
+    Arcana,    // Tarot cards
+    Celestial, // Planet cards, used on pick
+    Standard,  // Playing cards added to the deck
+    Buffoon,   // Jokers
+}
+
+impl BoosterPack {
+    pub const ALL: [BoosterPack; 4] = [
+        BoosterPack::Arcana,
+        BoosterPack::Celestial,
+        BoosterPack::Standard,
+        BoosterPack::Buffoon,
+    ];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            BoosterPack::Arcana => "Arcana Pack",
+            BoosterPack::Celestial => "Celestial Pack",
+            BoosterPack::Standard => "Standard Pack",
+            BoosterPack::Buffoon => "Buffoon Pack",
+        }
+    }
+
+    pub fn description(&self) -> &'static str {
+        match self {
+            BoosterPack::Arcana => "Choose 1 of 3 Tarot cards",
+            BoosterPack::Celestial => "Choose 1 of 3 Planet cards",
+            BoosterPack::Standard => "Add 1 of 3 playing cards to deck",
+            BoosterPack::Buffoon => "Choose 1 of 2 Jokers",
+        }
+    }
+
+    pub fn price(&self) -> u32 {
+        match self {
+            BoosterPack::Buffoon => 5,
+            _ => 4,
+        }
+    }
+
+    /// How many options are revealed when opened
+    pub fn option_count(&self) -> usize {
+        match self {
+            BoosterPack::Buffoon => 2,
+            _ => 3,
+        }
+    }
+
+    /// Roll the options revealed when this pack is opened
+    pub fn roll_options<R: Rng>(&self, rng: &mut R) -> Vec<PackOption> {
+        (0..self.option_count())
+            .map(|_| match self {
+                BoosterPack::Arcana => PackOption::Consumable(Consumable::new(
+                    ConsumableType::random(rng, &ConsumableWeights::TAROT_ONLY),
+                )),
+                BoosterPack::Celestial => PackOption::Consumable(Consumable::new(
+                    ConsumableType::random(rng, &ConsumableWeights::PLANET_ONLY),
+                )),
+                BoosterPack::Standard => {
+                    let rank = Rank::ALL[rng.gen_range(0..Rank::ALL.len())];
+                    let suit = Suit::ALL[rng.gen_range(0..Suit::ALL.len())];
+                    let mut card = PlayingCard::new(rank, suit);
+                    // Roughly a third of Standard pack cards come enhanced
+                    if rng.gen_ratio(1, 3) {
+                        card.enhancement =
+                            Some(Enhancement::ALL[rng.gen_range(0..Enhancement::ALL.len())]);
+                    }
+                    PackOption::Card(card)
+                }
+                BoosterPack::Buffoon => PackOption::Joker(Joker::new(JokerType::random(rng))),
+            })
+            .collect()
+    }
+}
+
+/// One revealed choice inside an opened booster pack
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum PackOption {
+    Joker(Joker),
+    Consumable(Consumable),
+    Card(PlayingCard),
+}
+
+impl PackOption {
+    pub fn name(&self) -> String {
+        match self {
+            PackOption::Joker(j) => j.joker_type.name().to_string(),
+            PackOption::Consumable(c) => c.consumable_type.name().to_string(),
+            PackOption::Card(card) => format!("{} of {}", card.rank.name(), card.suit.name()),
+        }
+    }
+
+    pub fn description(&self) -> String {
+        match self {
+            PackOption::Joker(j) => j.joker_type.description().to_string(),
+            PackOption::Consumable(c) => c.consumable_type.description(),
+            PackOption::Card(card) => match card.enhancement {
+                Some(enhancement) => format!("{:?} card", enhancement),
+                None => "Playing card".to_string(),
+            },
+        }
+    }
+}
@@ -1,16 +1,29 @@
-use rand::rngs::StdRng;
+use std::collections::HashMap;
+use std::io;
+use std::path::Path;
+
 use rand::{Rng, SeedableRng};
+use rand_chacha::ChaCha12Rng;
+use serde::{Deserialize, Serialize};
 
 use crate::blind::{self, BlindType, BossBlind};
-use crate::card::PlayingCard;
-use crate::consumable::{Consumable, ConsumableType, TarotCard};
-use crate::deck::Deck;
-use crate::joker::{Joker, JokerType};
-use crate::scoring::HandLevels;
-use crate::shop::{Shop, ShopItem};
+use crate::card::{Edition, Enhancement, PlayingCard, Rank, Seal, Suit};
+use crate::consumable::{
+    Consumable, ConsumableType, ConsumableWeights, PlanetCard, SpectralCard, TarotCard,
+};
+use crate::deck::{Deck, DeckType};
+use crate::format::format_number;
+use crate::hand::{detect_hand_with, subsets, HandResult, HandRules, PokerHand};
+use crate::joker::{held_retriggers, scored_card_enhancement, scored_face_card, Joker, JokerType};
+use crate::pack::{BoosterPack, PackOption};
+use crate::scoring::{HandLevels, ScoreResult, ScoredHand, ScoringContext};
+use crate::shop::{Shop, ShopConfig, ShopItem};
+use crate::stake::Stake;
+use crate::tag::Tag;
+use crate::voucher::Voucher;
 
 /// The phase within an ante
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum AntePhase {
     /// Choosing which blind to play/skip
     BlindSelect,
@@ -21,7 +34,7 @@ pub enum AntePhase {
 }
 
 /// Outcome of a blind within an ante (for display on blind select screen)
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum BlindOutcome {
     /// Not yet reached
     Upcoming,
@@ -33,6 +46,75 @@ pub enum BlindOutcome {
     Beaten,
 }
 
+/// Starting loadout for a boss practice run
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PracticeSetup {
+    pub money: u32,
+    pub jokers: Vec<JokerType>,
+}
+
+impl PracticeSetup {
+    /// Roughly what a run has banked by the time it reaches `ante`'s boss
+    pub fn for_ante(ante: u8) -> Self {
+        Self {
+            money: 4 + 10 * ante.saturating_sub(1) as u32,
+            jokers: Vec::new(),
+        }
+    }
+}
+
+/// Hand-built starting deck and jokers for a sandbox run
+#[derive(Debug, Clone)]
+pub struct SandboxSetup {
+    pub deck: Deck,
+    pub jokers: Vec<Joker>,
+}
+
+impl SandboxSetup {
+    /// An empty deck and no jokers
+    pub fn new() -> Self {
+        Self {
+            deck: Deck::empty(),
+            jokers: Vec::new(),
+        }
+    }
+
+    /// Start from the 52 standard cards instead of an empty deck
+    pub fn standard() -> Self {
+        Self {
+            deck: Deck::standard(),
+            jokers: Vec::new(),
+        }
+    }
+}
+
+impl Default for SandboxSetup {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// What one economy joker gives at the end of a round
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct JokerPayout {
+    pub joker_index: usize,
+    pub name: &'static str,
+    /// Paid out with the cash out
+    pub money: u32,
+    /// Added to the joker's own sell value (Egg)
+    pub sell_value: u32,
+}
+
+/// One item bought in the shop, for the purchase log
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Purchase {
+    pub name: String,
+    /// Price paid, after discounts
+    pub price: u32,
+    /// Ante the item was bought in
+    pub ante: u8,
+}
+
 /// Itemized breakdown of the reward for beating a blind
 #[derive(Debug, Clone)]
 pub struct RewardBreakdown {
@@ -42,18 +124,45 @@ pub struct RewardBreakdown {
     pub hands_bonus: u32,
     /// Number of hands remaining (for display)
     pub hands_remaining: u8,
-    /// Interest earned ($1 per $5 held, max $5)
+    /// Interest earned ($1 per $5 held, up to `interest_cap`)
     pub interest: u32,
+    /// Most interest that can be earned in one cash out
+    pub interest_cap: u32,
     /// Money held when interest was calculated (for display)
     pub money_held: u32,
-    /// Bonus from Golden Jokers ($4 each)
-    pub golden_joker_bonus: u32,
+    /// One entry per economy joker, in joker order
+    pub joker_payouts: Vec<JokerPayout>,
+    /// Money from all `joker_payouts`
+    pub economy_joker_bonus: u32,
+    /// Bonus from Gold cards held in hand ($3 each)
+    pub gold_cards_bonus: u32,
     /// Total payout
     pub total: u32,
 }
 
+/// Running totals for the whole run, shown on the game over screen
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct RunStats {
+    pub hands_played: u32,
+    pub discards_used: u32,
+    /// How many times each poker hand was played
+    pub hand_counts: HashMap<PokerHand, u32>,
+    /// Money from blind rewards and scored cards
+    pub money_earned: u32,
+}
+
+impl RunStats {
+    /// Most-played poker hand and its count. Ties go to the higher-ranked hand.
+    pub fn most_played_hand(&self) -> Option<(PokerHand, u32)> {
+        self.hand_counts
+            .iter()
+            .max_by_key(|&(&hand, &count)| (count, hand))
+            .map(|(&hand, &count)| (hand, count))
+    }
+}
+
 /// Complete run state
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct RunState {
     pub ante: u8,
     pub blind_type: BlindType,
@@ -79,32 +188,198 @@ pub struct RunState {
     pub score_target: u64,
 
     pub boss_blind: BossBlind,
-    pub rng: StdRng,
+    /// Run RNG. ChaCha (the algorithm behind `StdRng`) so its exact stream
+    /// position survives a save/load round trip.
+    pub rng: ChaCha12Rng,
 
     /// Blinds beaten this ante (to track progression)
     pub blinds_beaten: u8,
 
     /// Per-blind outcomes for the current ante: [Small, Big, Boss]
     pub blind_outcomes: [BlindOutcome; 3],
+    /// Tag won by skipping each blind this ante: [Small, Big, Boss]
+    #[serde(default)]
+    pub skip_tags: [Option<Tag>; 3],
+    /// Tags waiting for the next shop
+    #[serde(default)]
+    pub pending_tags: Vec<Tag>,
 
     /// Shop state
     pub shop: Option<Shop>,
+
+    /// Seed the run was created from (shown on the recap card)
+    #[serde(default)]
+    pub seed: u64,
+    /// Player-entered seed text the numeric seed was hashed from, if any
+    #[serde(default)]
+    pub seed_string: Option<String>,
+    /// Sum of every hand scored this run
+    #[serde(default)]
+    pub total_score: u64,
+    /// Highest-scoring single hand this run
+    #[serde(default)]
+    pub best_hand: Option<(PokerHand, u64)>,
+    /// Last poker hand played this round (Blue seals create its Planet)
+    #[serde(default)]
+    pub last_played_hand: Option<PokerHand>,
+    /// Poker hands played this round, in order (The Eye and The Mouth check them)
+    #[serde(default)]
+    pub played_hand_types: Vec<PokerHand>,
+    /// Most recent scored hands, oldest first (at most `SCORE_LOG_LEN`; not saved)
+    #[serde(skip)]
+    pub score_log: Vec<ScoredHand>,
+    /// Last Tarot or Planet used (The Fool copies it)
+    #[serde(default)]
+    pub last_consumable: Option<ConsumableType>,
+
+    /// Vouchers bought this run (never offered again)
+    #[serde(default)]
+    pub vouchers: Vec<Voucher>,
+    /// Extra hands every round (Grabber)
+    #[serde(default)]
+    pub bonus_hands: u8,
+    /// Extra discards every round (Wasteful)
+    #[serde(default)]
+    pub bonus_discards: u8,
+    /// Card items offered per shop (Overstock adds one)
+    #[serde(default = "default_shop_slots")]
+    pub shop_slots: u8,
+    /// Percentage off every shop price (Clearance Sale)
+    #[serde(default)]
+    pub shop_discount_percent: u32,
+    /// Options revealed by the booster pack being opened (empty when none is open)
+    #[serde(default)]
+    pub pack_options: Vec<PackOption>,
+    /// Whether this shop's first Celestial pack has been opened (Telescope)
+    #[serde(default)]
+    pub telescope_used: bool,
+    /// Boss rerolls bought this ante (Director's Cut allows one)
+    #[serde(default)]
+    pub boss_rerolls_used: u8,
+    /// Whole-run statistics
+    #[serde(default)]
+    pub stats: RunStats,
+    /// Most cards that can be selected and played at once
+    #[serde(default = "default_max_selected")]
+    pub max_selected: u8,
+    /// The next draw comes in face down (The Fish)
+    #[serde(default)]
+    pub draw_face_down: bool,
+    /// Hand size taken away by the current boss, given back when the blind ends
+    #[serde(default)]
+    pub hand_size_penalty: u8,
+    /// Most interest earned per cash out (Seed Money / Money Tree raise it)
+    #[serde(default = "default_interest_cap")]
+    pub interest_cap: u32,
+    /// Reroll cost each new shop starts at (Reroll Surplus / Glut lower it)
+    #[serde(default = "default_reroll_base")]
+    pub reroll_base: u32,
+    /// Starting deck the run was created with
+    #[serde(default)]
+    pub deck_type: DeckType,
+    /// State before the last play or discard, restorable with `undo`
+    #[serde(skip)]
+    undo_snapshot: Option<Box<RunState>>,
+    /// Whether this round's single undo has been spent
+    #[serde(default)]
+    pub undo_used: bool,
+    /// Play on past ante 8 instead of ending the run on a win
+    #[serde(default)]
+    pub endless: bool,
+    /// Difficulty the run was started at
+    #[serde(default)]
+    pub stake: Stake,
+    /// Every shop purchase this run, oldest first
+    #[serde(default)]
+    pub purchases: Vec<Purchase>,
+}
+
+fn default_shop_slots() -> u8 {
+    2
+}
+
+fn default_interest_cap() -> u32 {
+    5
+}
+
+fn default_reroll_base() -> u32 {
+    5
+}
+
+fn default_max_selected() -> u8 {
+    5
+}
+
+impl Default for RunState {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 impl RunState {
     pub fn new() -> Self {
-        Self::with_seed(rand::thread_rng().gen())
+        Self::new_with_deck(DeckType::default())
+    }
+
+    /// Random-seed run starting with `deck_type`
+    pub fn new_with_deck(deck_type: DeckType) -> Self {
+        Self::with_seed_and_deck(rand::thread_rng().gen(), deck_type)
+    }
+
+    /// Start a run from a player-typed seed like "BALA1234". The text is normalized
+    /// with `normalize_seed_string`; if nothing is left, a random seed is used.
+    pub fn with_seed_string(text: &str) -> Self {
+        Self::with_seed_string_and_deck(text, DeckType::default())
+    }
+
+    /// Like `with_seed_string`, starting with `deck_type`
+    pub fn with_seed_string_and_deck(text: &str, deck_type: DeckType) -> Self {
+        let normalized = normalize_seed_string(text);
+        if normalized.is_empty() {
+            return Self::new_with_deck(deck_type);
+        }
+        let mut run = Self::with_seed_and_deck(hash_seed_string(&normalized), deck_type);
+        run.seed_string = Some(normalized);
+        run
+    }
+
+    /// Seed as shown to players: the typed text when there is one, else the number
+    pub fn seed_label(&self) -> String {
+        match &self.seed_string {
+            Some(text) => text.clone(),
+            None => self.seed.to_string(),
+        }
+    }
+
+    /// A fresh run with this run's seed and starting deck
+    pub fn restarted(&self) -> Self {
+        let mut run = Self::with_seed_and_deck(self.seed, self.deck_type).with_stake(self.stake);
+        run.seed_string = self.seed_string.clone();
+        run
+    }
+
+    /// Play this freshly created run at `stake`
+    pub fn with_stake(mut self, stake: Stake) -> Self {
+        self.stake = stake;
+        self.hand_size = self.hand_size.saturating_sub(stake.hand_size_penalty());
+        self.reroll_base += stake.reroll_penalty();
+        self.score_target = blind::score_target(self.ante, &self.blind_type, stake);
+        self
     }
 
     pub fn with_seed(seed: u64) -> Self {
-        let mut rng = StdRng::seed_from_u64(seed);
-        let mut deck = Deck::standard();
+        Self::with_seed_and_deck(seed, DeckType::default())
+    }
+
+    pub fn with_seed_and_deck(seed: u64, deck_type: DeckType) -> Self {
+        let mut rng = ChaCha12Rng::seed_from_u64(seed);
+        let mut deck = Deck::from_type(deck_type);
         deck.shuffle(&mut rng);
 
         let boss = Self::random_boss(&mut rng);
         let blind_type = BlindType::Small;
         let ante = 1;
-        let score_target = blind::score_target(ante, &blind_type);
+        let score_target = blind::score_target(ante, &blind_type, Stake::default());
 
         Self {
             ante,
@@ -132,30 +407,124 @@ impl RunState {
                 BlindOutcome::Upcoming,
                 BlindOutcome::Upcoming,
             ],
+            skip_tags: [None; 3],
+            pending_tags: Vec::new(),
             shop: None,
+            seed,
+            seed_string: None,
+            total_score: 0,
+            best_hand: None,
+            last_played_hand: None,
+            played_hand_types: Vec::new(),
+            score_log: Vec::new(),
+            last_consumable: None,
+            vouchers: Vec::new(),
+            bonus_hands: deck_type.bonus_hands(),
+            bonus_discards: deck_type.bonus_discards(),
+            shop_slots: default_shop_slots(),
+            shop_discount_percent: 0,
+            pack_options: Vec::new(),
+            telescope_used: false,
+            boss_rerolls_used: 0,
+            stats: RunStats::default(),
+            max_selected: default_max_selected(),
+            draw_face_down: false,
+            hand_size_penalty: 0,
+            interest_cap: default_interest_cap(),
+            reroll_base: default_reroll_base(),
+            deck_type,
+            undo_snapshot: None,
+            undo_used: false,
+            endless: false,
+            stake: Stake::default(),
+            purchases: Vec::new(),
+        }
+    }
+
+    /// Start a run straight at `ante`'s boss blind, skipping small/big blinds
+    pub fn practice_boss(seed: u64, ante: u8, boss: BossBlind) -> Self {
+        Self::practice_boss_with(seed, ante, boss, PracticeSetup::for_ante(ante))
+    }
+
+    /// Like `practice_boss`, with an explicit money/joker loadout
+    pub fn practice_boss_with(seed: u64, ante: u8, boss: BossBlind, setup: PracticeSetup) -> Self {
+        let mut run = Self::with_seed(seed);
+        run.ante = ante.max(1);
+        run.boss_blind = boss;
+        run.blind_type = BlindType::Boss(boss);
+        run.blind_outcomes = [
+            BlindOutcome::Skipped,
+            BlindOutcome::Skipped,
+            BlindOutcome::Active,
+        ];
+        run.money = setup.money;
+        run.jokers = setup
+            .jokers
+            .into_iter()
+            .take(run.max_jokers as usize)
+            .map(Joker::new)
+            .collect();
+        run.start_blind();
+        run
+    }
+
+    /// Start a run at the first blind with a hand-built deck and jokers.
+    /// Jokers that don't fit in the joker slots are dropped.
+    pub fn sandbox(seed: u64, setup: SandboxSetup) -> Self {
+        let mut run = Self::with_seed(seed);
+        run.deck = setup.deck;
+        run.deck.reset_and_shuffle(&mut run.rng);
+        for joker in setup.jokers {
+            if run.has_room_for(&joker) {
+                run.jokers.push(joker);
+            }
         }
+        run
     }
 
-    fn random_boss(rng: &mut StdRng) -> BossBlind {
+    fn random_boss(rng: &mut ChaCha12Rng) -> BossBlind {
         let idx = rng.gen_range(0..BossBlind::ALL.len());
         BossBlind::ALL[idx]
     }
 
+    /// Write the full run state (including the in-progress round) to `path` as JSON
+    pub fn save_to_path(&self, path: &Path) -> io::Result<()> {
+        let json = serde_json::to_string(self).map_err(io::Error::other)?;
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        std::fs::write(path, json)
+    }
+
+    /// Load a run previously written by `save_to_path`
+    pub fn load_from_path(path: &Path) -> io::Result<Self> {
+        let json = std::fs::read_to_string(path)?;
+        serde_json::from_str(&json).map_err(io::Error::other)
+    }
+
     /// Start playing a blind: reset round state and draw hand
     pub fn start_blind(&mut self) {
         self.ante_phase = AntePhase::Playing;
         self.round_score = 0;
-        self.hands_remaining = 4;
-        self.discards_remaining = 3;
+        self.last_played_hand = None;
+        self.played_hand_types.clear();
+        self.draw_face_down = false;
+        self.undo_snapshot = None;
+        self.undo_used = false;
+        self.hands_remaining = 4 + self.bonus_hands;
+        self.discards_remaining =
+            (3 + self.bonus_discards).saturating_sub(self.stake.discard_penalty());
         self.selected_indices.clear();
-        self.score_target = blind::score_target(self.ante, &self.blind_type);
+        self.score_target = blind::score_target(self.ante, &self.blind_type, self.stake);
 
         // Apply boss blind effects at start
-        if let BlindType::Boss(boss) = &self.blind_type {
-            match boss {
-                BossBlind::TheNeedle => self.hands_remaining = 1,
-                _ => {}
-            }
+        if let BlindType::Boss(BossBlind::TheNeedle) = &self.blind_type {
+            self.hands_remaining = 1;
+        }
+        self.restore_hand_size();
+        if let BlindType::Boss(BossBlind::TheManacle) = &self.blind_type {
+            self.hand_size_penalty = 1.min(self.hand_size.saturating_sub(1));
+            self.hand_size -= self.hand_size_penalty;
         }
 
         // Reset deck and draw hand
@@ -166,43 +535,182 @@ impl RunState {
         self.apply_boss_debuffs();
     }
 
-    /// Apply boss blind suit debuffs to hand cards
+    /// Give back hand size taken by a boss blind
+    fn restore_hand_size(&mut self) {
+        self.hand_size += self.hand_size_penalty;
+        self.hand_size_penalty = 0;
+    }
+
+    /// Apply boss blind debuffs (see `BossBlind::debuffs`) to hand cards
     fn apply_boss_debuffs(&mut self) {
         if let BlindType::Boss(boss) = &self.blind_type {
-            let debuff_suit = match boss {
-                BossBlind::TheClub => Some(crate::card::Suit::Clubs),
-                BossBlind::TheGoad => Some(crate::card::Suit::Spades),
-                BossBlind::TheWindow => Some(crate::card::Suit::Diamonds),
-                BossBlind::TheHead => Some(crate::card::Suit::Hearts),
-                _ => None,
-            };
-            if let Some(suit) = debuff_suit {
-                for card in &mut self.hand {
-                    if card.suit == suit {
-                        card.debuffed = true;
-                    }
+            for card in &mut self.hand {
+                if boss.debuffs(card) {
+                    card.debuffed = true;
                 }
             }
         }
     }
 
+    /// Whether an owned voucher still allows a boss reroll this ante
+    pub fn has_boss_reroll(&self) -> bool {
+        self.vouchers.contains(&Voucher::Retcon)
+            || (self.vouchers.contains(&Voucher::DirectorsCut) && self.boss_rerolls_used == 0)
+    }
+
+    /// Why the boss can't be rerolled right now, or `None` if it can
+    pub fn boss_reroll_problem(&self) -> Option<String> {
+        if !self.vouchers.contains(&Voucher::DirectorsCut) {
+            return Some("Rerolling the boss needs the Director's Cut voucher".to_string());
+        }
+        if !self.has_boss_reroll() {
+            return Some("Boss already rerolled this ante (Retcon allows more)".to_string());
+        }
+        if self.ante_phase != AntePhase::BlindSelect
+            || self.blind_outcomes[2] != BlindOutcome::Upcoming
+        {
+            return Some("The boss can only be rerolled before it comes up".to_string());
+        }
+        if self.money < BOSS_REROLL_COST {
+            return Some(format!("Rerolling the boss costs ${}", BOSS_REROLL_COST));
+        }
+        None
+    }
+
+    /// Pay `BOSS_REROLL_COST` to replace the upcoming boss with a different one.
+    /// Returns false (and changes nothing) if `boss_reroll_problem` has a reason.
+    pub fn reroll_boss(&mut self) -> bool {
+        if self.boss_reroll_problem().is_some() {
+            return false;
+        }
+        self.money -= BOSS_REROLL_COST;
+        self.boss_rerolls_used += 1;
+        let previous = self.boss_blind;
+        while self.boss_blind == previous {
+            self.boss_blind = Self::random_boss(&mut self.rng);
+        }
+        true
+    }
+
     /// Skip the current blind
     pub fn skip_blind(&mut self) {
         // Mark current blind as skipped
         let blind_index = self.current_blind_index();
         self.blind_outcomes[blind_index] = BlindOutcome::Skipped;
+
+        let tag = Tag::ALL[self.rng.gen_range(0..Tag::ALL.len())];
+        self.skip_tags[blind_index] = Some(tag);
+        self.apply_tag(tag);
+
         self.advance_blind();
     }
 
+    /// Pay out `tag` now, or hold it for the next shop
+    fn apply_tag(&mut self, tag: Tag) {
+        if tag.applies_in_shop() {
+            self.pending_tags.push(tag);
+            return;
+        }
+        if tag == Tag::Economy {
+            let gain = self.money.min(Tag::ECONOMY_CAP);
+            self.money += gain;
+            self.stats.money_earned += gain;
+        }
+    }
+
+    /// Apply every tag held for the shop that was just generated
+    fn apply_shop_tags(&mut self) {
+        let Some(shop) = &mut self.shop else {
+            return;
+        };
+        for tag in self.pending_tags.drain(..) {
+            match tag {
+                Tag::Voucher => {
+                    shop.add_voucher(&mut self.rng, &self.vouchers);
+                }
+                Tag::Uncommon => shop.add_free_uncommon_joker(&mut self.rng),
+                Tag::Economy => {}
+            }
+        }
+    }
+
     /// Add score from a hand
     pub fn add_score(&mut self, score: u64) {
-        self.round_score += score;
+        // Saturate rather than wrap: late-game builds can push past u64
+        self.round_score = self.round_score.saturating_add(score);
+        self.total_score = self.total_score.saturating_add(score);
+    }
+
+    /// Apply a scored hand once its animation finishes: add the score and any
+    /// money earned while scoring, then send the played cards to the discard pile.
+    /// Scored Glass cards roll once (1 in 4) to shatter and leave the deck for good;
+    /// the shattered cards are returned.
+    pub fn resolve_played_hand(
+        &mut self,
+        played: &[PlayingCard],
+        result: &ScoreResult,
+    ) -> Vec<PlayingCard> {
+        // Once a score is applied the play is final
+        self.undo_snapshot = None;
+        self.add_score(result.final_score);
+        self.record_hand(result.hand_type, result.final_score);
+        self.money += result.money_earned;
+        self.stats.money_earned += result.money_earned;
+        self.last_played_hand = Some(result.hand_type);
+        self.played_hand_types.push(result.hand_type);
+        self.log_scored_hand(played, result);
+
+        let pareidolia = self
+            .jokers
+            .iter()
+            .any(|j| j.joker_type == JokerType::Pareidolia);
+        let scored_face = scored_face_card(played, &result.scoring_indices, pareidolia);
+        for joker in &mut self.jokers {
+            joker.after_hand_played(scored_face);
+        }
+
+        let mut shattered = Vec::new();
+        for (idx, card) in played.iter().enumerate() {
+            let scored = result.scoring_indices.contains(&idx);
+            if scored && card.is_glass() && !card.debuffed && self.rng.gen_ratio(1, 4) {
+                shattered.push(*card);
+                continue;
+            }
+            let mut card = *card;
+            if scored {
+                card.enhancement =
+                    scored_card_enhancement(&self.jokers, &card, pareidolia).or(card.enhancement);
+            }
+            self.deck.play_cards(std::slice::from_ref(&card));
+        }
+        shattered
+    }
+
+    /// Keep `result` in the score log, dropping the oldest past `SCORE_LOG_LEN`
+    fn log_scored_hand(&mut self, played: &[PlayingCard], result: &ScoreResult) {
+        if self.score_log.len() >= SCORE_LOG_LEN {
+            self.score_log.remove(0);
+        }
+        self.score_log.push(ScoredHand {
+            played: played.to_vec(),
+            jokers: self.jokers.iter().map(|j| j.joker_type).collect(),
+            result: result.clone(),
+        });
+    }
+
+    /// Count `hand_type` as played and remember it if it's the best single hand of the run
+    pub fn record_hand(&mut self, hand_type: PokerHand, score: u64) {
+        *self.stats.hand_counts.entry(hand_type).or_insert(0) += 1;
+        if self.best_hand.is_none_or(|(_, best)| score > best) {
+            self.best_hand = Some((hand_type, score));
+        }
     }
 
     /// Use a hand (after playing)
     pub fn use_hand(&mut self) {
         if self.hands_remaining > 0 {
             self.hands_remaining -= 1;
+            self.stats.hands_played += 1;
         }
     }
 
@@ -210,6 +718,7 @@ impl RunState {
     pub fn use_discard(&mut self) -> bool {
         if self.discards_remaining > 0 {
             self.discards_remaining -= 1;
+            self.stats.discards_used += 1;
             true
         } else {
             false
@@ -221,14 +730,60 @@ impl RunState {
         self.round_score >= self.score_target
     }
 
-    /// Check if the round is lost (no hands left and target not met)
+    /// Check if the round is lost: target not met and no hands left, or no
+    /// cards left to play (small sandbox decks run dry; played cards don't come back mid-round)
     pub fn round_lost(&self) -> bool {
-        self.hands_remaining == 0 && !self.blind_beaten()
+        let out_of_cards = self.hand.is_empty() && self.deck.remaining() == 0;
+        (self.hands_remaining == 0 || out_of_cards) && !self.blind_beaten()
     }
 
-    /// Check if the entire run is won (beat ante 8)
+    /// Check if the entire run is won (beat ante 8, outside endless mode)
     pub fn run_won(&self) -> bool {
-        self.ante > 8
+        let final_boss_beaten =
+            self.ante == WINNING_ANTE && self.blind_outcomes[2] == BlindOutcome::Beaten;
+        !self.endless && (self.ante > WINNING_ANTE || final_boss_beaten)
+    }
+
+    /// Keep playing a won run past ante 8
+    pub fn continue_endless(&mut self) {
+        self.endless = true;
+    }
+
+    /// Highest ante played: the current one, or ante 8 once it has been won
+    pub fn ante_reached(&self) -> u8 {
+        if self.endless {
+            self.ante
+        } else {
+            self.ante.min(WINNING_ANTE)
+        }
+    }
+
+    /// One-line summary of how the run ended, for the game over screen
+    pub fn outcome_line(&self) -> String {
+        if self.run_won() {
+            format!(
+                "Beat Ante 8 with a total score of {}",
+                format_number(self.total_score)
+            )
+        } else {
+            format!(
+                "Ran out of hands on {} ({} / {} chips)",
+                self.blind_type,
+                format_number(self.round_score),
+                format_number(self.score_target)
+            )
+        }
+    }
+
+    /// Money for beating the current blind at this run's stake
+    pub fn blind_reward(&self) -> u32 {
+        self.blind_type.reward_at(self.stake)
+    }
+
+    /// Most interest earned per cash out, after the stake's penalty
+    pub fn interest_cap(&self) -> u32 {
+        self.interest_cap
+            .saturating_sub(self.stake.interest_cap_penalty())
     }
 
     /// Calculate money earned after beating a blind
@@ -238,62 +793,134 @@ impl RunState {
 
     /// Calculate an itemized breakdown of the reward for beating the current blind
     pub fn calculate_reward_breakdown(&self) -> RewardBreakdown {
-        let blind_reward = self.blind_type.reward();
+        let blind_reward = self.blind_reward();
         let hands_bonus = self.hands_remaining as u32;
-        let interest = (self.money / 5).min(5);
-        let golden_joker_bonus: u32 = self
-            .jokers
+        let interest_cap = self.interest_cap();
+        let interest = (self.money / 5).min(interest_cap);
+        let joker_payouts = self.joker_payouts();
+        let economy_joker_bonus: u32 = joker_payouts.iter().map(|p| p.money).sum();
+        // Counts whatever is held when the blind is cashed out, including cards
+        // drawn after the final hand. Mime pays them out again.
+        let gold_cards_bonus: u32 = self
+            .hand
             .iter()
-            .filter(|j| j.joker_type == JokerType::GoldenJoker)
+            .filter(|c| c.enhancement == Some(Enhancement::Gold) && !c.debuffed)
             .count() as u32
-            * 4;
-        let total = blind_reward + hands_bonus + interest + golden_joker_bonus;
+            * 3
+            * (1 + held_retriggers(&self.jokers) as u32);
+        let total = blind_reward + hands_bonus + interest + economy_joker_bonus + gold_cards_bonus;
 
         RewardBreakdown {
             blind_reward,
             hands_bonus,
             hands_remaining: self.hands_remaining,
             interest,
+            interest_cap,
             money_held: self.money,
-            golden_joker_bonus,
+            joker_payouts,
+            economy_joker_bonus,
+            gold_cards_bonus,
             total,
         }
     }
 
+    /// End-of-round money and sell value from each economy joker
+    fn joker_payouts(&self) -> Vec<JokerPayout> {
+        self.jokers
+            .iter()
+            .enumerate()
+            .map(|(joker_index, joker)| JokerPayout {
+                joker_index,
+                name: joker.joker_type.name(),
+                money: joker.round_money(),
+                sell_value: joker.round_sell_value(),
+            })
+            .filter(|p| p.money > 0 || p.sell_value > 0)
+            .collect()
+    }
+
     /// Beat the current blind and collect rewards, then go to shop
     pub fn beat_blind(&mut self) {
+        self.restore_hand_size();
         let reward = self.calculate_reward();
         self.money += reward;
+        self.stats.money_earned += reward;
         self.blinds_beaten += 1;
 
         // Mark current blind as beaten
         let blind_index = self.current_blind_index();
         self.blind_outcomes[blind_index] = BlindOutcome::Beaten;
 
-        // Egg joker: +$3 sell value per round
+        // Economy jokers after paying out: Egg grows, Rocket counts bosses
+        let boss_beaten = matches!(self.blind_type, BlindType::Boss(_));
         for joker in &mut self.jokers {
-            if joker.joker_type == JokerType::Egg {
-                joker.bonus_sell += 3;
-            }
+            joker.after_round(boss_beaten);
         }
 
         self.ante_phase = AntePhase::Shop;
 
+        // Blue seals held at end of round create the Planet for the last hand played
+        if let Some(hand) = self.last_played_hand {
+            let blue_count = self
+                .hand
+                .iter()
+                .filter(|c| c.seal == Some(Seal::Blue) && !c.debuffed)
+                .count();
+            for _ in 0..blue_count {
+                if !self.has_consumable_room() {
+                    break;
+                }
+                self.consumables
+                    .push(Consumable::planet(PlanetCard::for_hand(hand)));
+            }
+        }
+
         // Return hand cards to deck and clear debuffs
         for card in &mut self.hand {
             card.debuffed = false;
+            card.face_down = false;
         }
         let hand_cards: Vec<PlayingCard> = self.hand.drain(..).collect();
         self.deck.discard_cards(&hand_cards);
         self.selected_indices.clear();
 
         // Generate shop
-        self.shop = Some(Shop::generate(&mut self.rng, self.ante));
+        self.telescope_used = false;
+        let config = self.shop_config();
+        self.shop = Some(
+            Shop::generate(&mut self.rng, self.ante, &config, &self.vouchers)
+                .with_reroll_cost(self.reroll_base),
+        );
+        self.apply_shop_tags();
+    }
+
+    /// How this run's shops are generated
+    pub fn shop_config(&self) -> ShopConfig {
+        ShopConfig {
+            item_slots: self.shop_slots as usize,
+            ..ShopConfig::default()
+        }
     }
 
     /// Leave the shop and advance to next blind
     pub fn leave_shop(&mut self) {
+        // Perkeo: a Negative copy of a random consumable
+        let perkeos = self
+            .jokers
+            .iter()
+            .filter(|j| j.joker_type == JokerType::Perkeo)
+            .count();
+        for _ in 0..perkeos {
+            if self.consumables.is_empty() {
+                break;
+            }
+            let idx = self.rng.gen_range(0..self.consumables.len());
+            let copy = self.consumables[idx].clone().with_negative();
+            self.consumables.push(copy);
+        }
+
         self.shop = None;
+        self.pack_options.clear();
         self.advance_blind();
     }
 
@@ -310,9 +937,10 @@ impl RunState {
             }
             BlindType::Boss(_) => {
                 // Completed the ante, advance
-                self.ante += 1;
+                self.ante = self.ante.saturating_add(1);
                 self.blinds_beaten = 0;
                 self.boss_blind = Self::random_boss(&mut self.rng);
+                self.boss_rerolls_used = 0;
                 self.blind_type = BlindType::Small;
                 // Reset outcomes for the new ante
                 self.blind_outcomes = [
@@ -320,14 +948,16 @@ impl RunState {
                     BlindOutcome::Upcoming,
                     BlindOutcome::Upcoming,
                 ];
+                self.skip_tags = [None; 3];
             }
         }
         self.ante_phase = AntePhase::BlindSelect;
-        self.score_target = blind::score_target(self.ante, &self.blind_type);
+        self.score_target = blind::score_target(self.ante, &self.blind_type, self.stake);
     }
 
     /// Remove selected cards from hand and draw replacements
     pub fn discard_selected(&mut self) -> Vec<PlayingCard> {
+        self.save_undo_snapshot();
         let mut discarded = Vec::new();
         let mut indices: Vec<usize> = self.selected_indices.clone();
         indices.sort_unstable_by(|a, b| b.cmp(a));
@@ -341,9 +971,17 @@ impl RunState {
         self.deck.discard_cards(&discarded);
         self.selected_indices.clear();
 
+        // Purple seals create a random Tarot when discarded
+        for card in &discarded {
+            if card.seal == Some(Seal::Purple) && !card.debuffed && self.has_consumable_room() {
+                let tarot = ConsumableType::random(&mut self.rng, &ConsumableWeights::TAROT_ONLY);
+                self.consumables.push(Consumable::new(tarot));
+            }
+        }
+
         // Draw replacements
         let need = (self.hand_size as usize).saturating_sub(self.hand.len());
-        let mut drawn = self.deck.draw(need);
+        let mut drawn = self.deck.draw_reshuffling(need, &mut self.rng);
         self.hand.append(&mut drawn);
 
         // Apply debuffs to new cards
@@ -354,6 +992,7 @@ impl RunState {
 
     /// Play selected cards: remove them from hand, return the played cards
     pub fn play_selected(&mut self) -> Vec<PlayingCard> {
+        self.save_undo_snapshot();
         let mut played = Vec::new();
         let mut indices: Vec<usize> = self.selected_indices.clone();
         indices.sort_unstable_by(|a, b| b.cmp(a));
@@ -368,17 +1007,47 @@ impl RunState {
         played.reverse();
         self.selected_indices.clear();
 
+        // Playing a hand reveals everything; The Fish hides the replacements
+        for card in played.iter_mut().chain(self.hand.iter_mut()) {
+            card.face_down = false;
+        }
+        self.draw_face_down = matches!(self.blind_type, BlindType::Boss(BossBlind::TheFish));
+
+        // The Tooth: $1 per card played, down to $0
+        self.money = self.money_after_toll(played.len());
+
         played
     }
 
+    /// Money left once `played` cards have paid The Tooth's $1 each
+    fn money_after_toll(&self, played: usize) -> u32 {
+        match self.blind_type {
+            BlindType::Boss(BossBlind::TheTooth) => self.money.saturating_sub(played as u32),
+            _ => self.money,
+        }
+    }
+
     /// Draw cards to fill hand back to hand_size
     pub fn draw_to_hand_size(&mut self) {
         let need = (self.hand_size as usize).saturating_sub(self.hand.len());
         if need > 0 {
-            let mut drawn = self.deck.draw(need);
+            let mut drawn = self.deck.draw_reshuffling(need, &mut self.rng);
+            if self.draw_face_down {
+                for card in &mut drawn {
+                    card.face_down = true;
+                }
+            }
             self.hand.append(&mut drawn);
             self.apply_boss_debuffs();
         }
+        self.draw_face_down = false;
+    }
+
+    /// Does the selection include a face-down card?
+    pub fn selection_hidden(&self) -> bool {
+        self.selected_indices
+            .iter()
+            .any(|&i| self.hand.get(i).is_some_and(|c| c.face_down))
     }
 
     /// Toggle selection of a card at index
@@ -388,7 +1057,7 @@ impl RunState {
         }
         if let Some(pos) = self.selected_indices.iter().position(|&i| i == idx) {
             self.selected_indices.remove(pos);
-        } else if self.selected_indices.len() < 5 {
+        } else if self.selected_indices.len() < self.max_selected as usize {
             self.selected_indices.push(idx);
         }
     }
@@ -406,21 +1075,184 @@ impl RunState {
             .collect()
     }
 
+    /// Detect the poker hand in `cards` under the owned jokers' rules (Shortcut)
+    pub fn detect_hand(&self, cards: &[PlayingCard]) -> HandResult {
+        detect_hand_with(cards, HandRules::from_jokers(&self.jokers))
+    }
+
+    /// Projected score of the current selection with jokers and held cards,
+    /// without rolling Lucky cards. `None` when nothing is selected.
+    pub fn preview_selected(&self) -> Option<ScoreResult> {
+        if self.selected_indices.is_empty() {
+            return None;
+        }
+        Some(self.preview_indices(&self.selected_indices))
+    }
+
+    /// Projected score of playing the hand cards at `indices` (see `preview_selected`)
+    fn preview_indices(&self, indices: &[usize]) -> ScoreResult {
+        let held: Vec<PlayingCard> = (0..self.hand.len())
+            .filter(|i| !indices.contains(i))
+            .map(|i| self.hand[i])
+            .collect();
+        let selected: Vec<PlayingCard> = indices.iter().map(|&i| self.hand[i]).collect();
+        let hand = self.detect_hand(&selected).hand_type;
+        let held_planets = self.observatory_planets(hand);
+        crate::scoring::preview(
+            &selected,
+            &self.scoring_levels(hand),
+            &self.jokers_before_hand(hand),
+            &ScoringContext {
+                held_cards: &held,
+                discards_remaining: self.discards_remaining,
+                money: self.money_after_toll(indices.len()),
+                held_planets: &held_planets,
+            },
+        )
+    }
+
+    /// The hand cards that would score the most if played right now, trying
+    /// every selection `can_play` would accept. Face-down cards are left out, and
+    /// on a tie the smaller selection wins. `None` when no card can be suggested.
+    pub fn best_play(&self) -> Option<Vec<usize>> {
+        let candidates: Vec<usize> = (0..self.hand.len())
+            .filter(|&i| !self.hand[i].face_down)
+            .collect();
+        let n = candidates.len();
+
+        // Smaller selections are tried first, so a tie keeps the one found earlier
+        let mut best: Option<(u64, Vec<usize>)> = None;
+        let selections = (1..=self.max_selected as usize).flat_map(|size| subsets(n, size));
+        for subset in selections {
+            let play: Vec<usize> = subset.into_iter().map(|i| candidates[i]).collect();
+            if !self.boss_allows_selection(&play) {
+                continue;
+            }
+            let score = self.preview_indices(&play).final_score;
+            if best.as_ref().is_none_or(|(top, _)| score > *top) {
+                best = Some((score, play));
+            }
+        }
+        best.map(|(_, play)| play)
+    }
+
+    /// On the last hand, how much more the selection would need to beat the
+    /// blind. `None` with hands to spare, nothing selected, or enough score.
+    pub fn last_hand_shortfall(&self) -> Option<u64> {
+        if self.hands_remaining != 1 {
+            return None;
+        }
+        let projected = self.preview_selected()?.final_score;
+        let gap = self.score_target.saturating_sub(self.round_score);
+        (projected < gap).then(|| gap - projected)
+    }
+
     /// Can the player play a hand right now?
     pub fn can_play(&self) -> bool {
         if self.hands_remaining == 0 || self.selected_indices.is_empty() {
             return false;
         }
-        if self.selected_indices.len() > 5 {
+        if self.selected_indices.len() > self.max_selected as usize {
             return false;
         }
+        self.boss_allows_selection(&self.selected_indices)
+    }
+
+    /// Whether the boss lets the hand cards at `indices` be played
+    fn boss_allows_selection(&self, indices: &[usize]) -> bool {
         // The Psychic: must play exactly 5 cards
         if let BlindType::Boss(BossBlind::ThePsychic) = &self.blind_type {
-            if self.selected_indices.len() != 5 {
+            if indices.len() != 5 {
                 return false;
             }
         }
-        true
+        let cards: Vec<PlayingCard> = indices.iter().map(|&i| self.hand[i]).collect();
+        self.boss_allows_hand(self.detect_hand(&cards).hand_type)
+    }
+
+    /// Hand levels to score `hand` with. The Arm lowers it one level for this
+    /// hand only; the run's own levels are left alone.
+    pub fn scoring_levels(&self, hand: PokerHand) -> HandLevels {
+        let mut levels = self.hand_levels.clone();
+        if let BlindType::Boss(BossBlind::TheArm) = &self.blind_type {
+            levels.level_down(hand);
+        }
+        levels
+    }
+
+    /// How many times `hand` has been played this run
+    pub fn times_played(&self, hand: PokerHand) -> u32 {
+        self.stats.hand_counts.get(&hand).copied().unwrap_or(0)
+    }
+
+    /// Whether playing `hand` now would make it played more often than any other hand
+    pub fn is_most_played(&self, hand: PokerHand) -> bool {
+        let count = self.times_played(hand) + 1;
+        self.stats
+            .hand_counts
+            .iter()
+            .all(|(&other, &n)| other == hand || n < count)
+    }
+
+    /// Jokers as they stand once `hand` is played, before it scores (Obelisk)
+    fn jokers_before_hand(&self, hand: PokerHand) -> Vec<Joker> {
+        let most_played = self.is_most_played(hand);
+        let mut jokers = self.jokers.clone();
+        for joker in &mut jokers {
+            joker.before_hand_played(most_played);
+        }
+        jokers
+    }
+
+    /// Score `played` (already removed from the hand) with jokers, the cards
+    /// still held and the current boss's level adjustment
+    pub fn score_played(&mut self, played: &[PlayingCard]) -> ScoreResult {
+        let hand = self.detect_hand(played).hand_type;
+        self.jokers = self.jokers_before_hand(hand);
+        let levels = self.scoring_levels(hand);
+        let held_planets = self.observatory_planets(hand);
+        crate::scoring::calculate_score_with_jokers(
+            played,
+            &levels,
+            &self.jokers,
+            &ScoringContext {
+                held_cards: &self.hand, // remaining hand = held cards
+                discards_remaining: self.discards_remaining,
+                // Already past The Tooth's toll; money earned while scoring comes later
+                money: self.money,
+                held_planets: &held_planets,
+            },
+            &mut self.rng,
+        )
+    }
+
+    /// Consumable slots holding a Planet for `hand`, if Observatory is owned
+    pub fn observatory_planets(&self, hand: PokerHand) -> Vec<usize> {
+        if !self.vouchers.contains(&Voucher::Observatory) {
+            return Vec::new();
+        }
+        self.consumables
+            .iter()
+            .enumerate()
+            .filter(|(_, c)| {
+                matches!(c.consumable_type, ConsumableType::Planet(p) if p.hand_type() == hand)
+            })
+            .map(|(i, _)| i)
+            .collect()
+    }
+
+    /// Whether the current boss lets `hand` be played, given the hands already played this round
+    pub fn boss_allows_hand(&self, hand: PokerHand) -> bool {
+        match &self.blind_type {
+            // The Eye: no repeat hand types
+            BlindType::Boss(BossBlind::TheEye) => !self.played_hand_types.contains(&hand),
+            // The Mouth: only the first hand type played
+            BlindType::Boss(BossBlind::TheMouth) => self
+                .played_hand_types
+                .first()
+                .is_none_or(|&first| first == hand),
+            _ => true,
+        }
     }
 
     /// Apply The Hook effect: discard 2 random cards from hand
@@ -468,40 +1300,25 @@ impl RunState {
 
     /// Buy a shop item
     pub fn buy_shop_item(&mut self, index: usize) -> bool {
-        let price = if let Some(shop) = &self.shop {
-            if let Some(item) = shop.items.get(index) {
-                item.price()
-            } else {
-                return false;
-            }
-        } else {
-            return false;
-        };
-
-        if self.money < price {
+        if !self.can_buy_shop_item(index) {
             return false;
         }
-
-        // Check capacity
-        if let Some(shop) = &self.shop {
-            match &shop.items.get(index) {
-                Some(ShopItem::JokerItem(_)) => {
-                    if self.jokers.len() >= self.max_jokers as usize {
-                        return false;
-                    }
-                }
-                Some(ShopItem::ConsumableItem(_)) => {
-                    if self.consumables.len() >= self.max_consumables as usize {
-                        return false;
-                    }
-                }
-                None => return false,
-            }
-        }
+        let Some(price) = self
+            .shop
+            .as_ref()
+            .and_then(|shop| shop.price(index, self.shop_discount_percent))
+        else {
+            return false;
+        };
 
         if let Some(shop) = &mut self.shop {
             if let Some(item) = shop.buy(index) {
                 self.money -= price;
+                self.purchases.push(Purchase {
+                    name: item.name(),
+                    price,
+                    ante: self.ante,
+                });
                 match item {
                     ShopItem::JokerItem(joker) => {
                         self.jokers.push(joker);
@@ -509,6 +1326,12 @@ impl RunState {
                     ShopItem::ConsumableItem(consumable) => {
                         self.consumables.push(consumable);
                     }
+                    ShopItem::VoucherItem(voucher) => {
+                        self.redeem_voucher(voucher);
+                    }
+                    ShopItem::PackItem(pack) => {
+                        self.open_pack(pack);
+                    }
                 }
                 return true;
             }
@@ -516,13 +1339,177 @@ impl RunState {
         false
     }
 
-    /// Sell a joker
-    pub fn sell_joker(&mut self, index: usize) -> bool {
-        if index >= self.jokers.len() {
-            return false;
+    /// Apply a voucher's permanent effect and remember it was bought
+    pub fn redeem_voucher(&mut self, voucher: Voucher) {
+        match voucher {
+            Voucher::Overstock => self.shop_slots += 1,
+            Voucher::ClearanceSale => self.shop_discount_percent = 25,
+            Voucher::Grabber => self.bonus_hands += 1,
+            Voucher::Wasteful => self.bonus_discards += 1,
+            Voucher::CrystalBall => self.max_consumables += 1,
+            Voucher::SeedMoney => self.interest_cap = self.interest_cap.max(10),
+            Voucher::MoneyTree => self.interest_cap = self.interest_cap.max(20),
+            Voucher::RerollSurplus | Voucher::RerollGlut => {
+                self.reroll_base = self.reroll_base.saturating_sub(2);
+                if let Some(shop) = &mut self.shop {
+                    shop.reroll_cost = shop.reroll_cost.saturating_sub(2);
+                }
+            }
+            // Checked when a Celestial pack opens, a hand scores or the boss is rerolled
+            Voucher::Telescope | Voucher::Observatory | Voucher::DirectorsCut | Voucher::Retcon => {
+            }
         }
-        let joker = self.jokers.remove(index);
-        self.money += joker.total_sell_value();
+        self.vouchers.push(voucher);
+    }
+
+    /// Joker slots in use; Negative jokers don't count
+    pub fn joker_slots_used(&self) -> usize {
+        self.jokers.iter().filter(|j| j.takes_slot()).count()
+    }
+
+    /// Whether `joker` can be added without going over `max_jokers`
+    pub fn has_room_for(&self, joker: &Joker) -> bool {
+        !joker.takes_slot() || self.joker_slots_used() < self.max_jokers as usize
+    }
+
+    /// Reveal a booster pack's options; they stay in `pack_options` until one is picked or skipped
+    pub fn open_pack(&mut self, pack: BoosterPack) -> Vec<PackOption> {
+        self.pack_options = pack.roll_options(&mut self.rng);
+
+        // Telescope: the shop's first Celestial pack offers the most played hand's Planet
+        if pack == BoosterPack::Celestial
+            && self.vouchers.contains(&Voucher::Telescope)
+            && !self.telescope_used
+        {
+            self.telescope_used = true;
+            if let Some((hand, _)) = self.stats.most_played_hand() {
+                let planet = Consumable::planet(PlanetCard::for_hand(hand));
+                self.pack_options[0] = PackOption::Consumable(planet);
+            }
+        }
+        self.pack_options.clone()
+    }
+
+    /// Whether the shop item at `index` is affordable and there's room to take it
+    pub fn can_buy_shop_item(&self, index: usize) -> bool {
+        let Some(shop) = &self.shop else {
+            return false;
+        };
+        let (Some(item), Some(price)) = (
+            shop.items.get(index),
+            shop.price(index, self.shop_discount_percent),
+        ) else {
+            return false;
+        };
+        if self.money < price {
+            return false;
+        }
+
+        // Check capacity
+        match item {
+            ShopItem::JokerItem(joker) => self.has_room_for(joker),
+            ShopItem::ConsumableItem(consumable) => self.has_room_for_consumable(consumable),
+            ShopItem::VoucherItem(_) => true,
+            // Finish picking from the current pack first
+            ShopItem::PackItem(_) => self.pack_options.is_empty(),
+        }
+    }
+
+    /// Number of shop items that could be bought right now
+    pub fn affordable_shop_items(&self) -> usize {
+        let count = self.shop.as_ref().map_or(0, |shop| shop.items.len());
+        (0..count).filter(|&i| self.can_buy_shop_item(i)).count()
+    }
+
+    /// Consumable slots in use (Negative copies are free)
+    pub fn consumable_slots_used(&self) -> usize {
+        self.consumables.iter().filter(|c| c.takes_slot()).count()
+    }
+
+    /// Whether a new consumable fits within `max_consumables`
+    pub fn has_consumable_room(&self) -> bool {
+        self.consumable_slots_used() < self.max_consumables as usize
+    }
+
+    /// Whether `consumable` can be added; Negative ones always fit
+    pub fn has_room_for_consumable(&self, consumable: &Consumable) -> bool {
+        !consumable.takes_slot() || self.has_consumable_room()
+    }
+
+    /// Whether the pack option at `index` can be taken with the current slots
+    pub fn can_pick_from_pack(&self, index: usize) -> bool {
+        match self.pack_options.get(index) {
+            Some(PackOption::Joker(joker)) => self.has_room_for(joker),
+            // Planets are used on the spot, so they never need a slot
+            Some(PackOption::Consumable(c)) => {
+                matches!(c.consumable_type, ConsumableType::Planet(_))
+                    || self.has_room_for_consumable(c)
+            }
+            Some(PackOption::Card(_)) => true,
+            None => false,
+        }
+    }
+
+    /// Take one option from the open pack and close it
+    pub fn pick_from_pack(&mut self, index: usize) -> bool {
+        if !self.can_pick_from_pack(index) {
+            return false;
+        }
+        match self.pack_options.remove(index) {
+            PackOption::Joker(joker) => self.jokers.push(joker),
+            PackOption::Consumable(consumable) => match consumable.consumable_type {
+                ConsumableType::Planet(planet) => {
+                    self.hand_levels.level_up(planet.hand_type());
+                    self.last_consumable = Some(consumable.consumable_type);
+                }
+                _ => self.consumables.push(consumable),
+            },
+            PackOption::Card(card) => self.deck.add_card(card),
+        }
+        self.pack_options.clear();
+        true
+    }
+
+    /// Close the open pack without taking anything
+    pub fn skip_pack(&mut self) {
+        self.pack_options.clear();
+    }
+
+    /// Sell a joker
+    pub fn sell_joker(&mut self, index: usize) -> bool {
+        if index >= self.jokers.len() {
+            return false;
+        }
+        let joker = self.jokers.remove(index);
+        self.money += joker.total_sell_value();
+        true
+    }
+
+    /// Sell every owned joker at once. Returns the money gained.
+    pub fn sell_all_jokers(&mut self) -> u32 {
+        let gained: u32 = self.jokers.drain(..).map(|j| j.total_sell_value()).sum();
+        self.money += gained;
+        gained
+    }
+
+    /// Move the joker at `from` to position `to`, shifting the ones in between.
+    /// Order matters: jokers score left to right and Blueprint copies its right neighbour.
+    pub fn move_joker(&mut self, from: usize, to: usize) -> bool {
+        if from >= self.jokers.len() || to >= self.jokers.len() {
+            return false;
+        }
+        let joker = self.jokers.remove(from);
+        self.jokers.insert(to, joker);
+        true
+    }
+
+    /// Sell a consumable for its sell value
+    pub fn sell_consumable(&mut self, index: usize) -> bool {
+        if index >= self.consumables.len() {
+            return false;
+        }
+        let consumable = self.consumables.remove(index);
+        self.money += consumable.consumable_type.sell_value();
         true
     }
 
@@ -539,15 +1526,82 @@ impl RunState {
         }
 
         self.money -= cost;
-        let ante = self.ante;
+        let config = self.shop_config();
         if let Some(shop) = &mut self.shop {
-            let old_cost = shop.reroll_cost;
-            *shop = Shop::generate(&mut self.rng, ante);
-            shop.reroll_cost = old_cost + 1;
+            shop.reroll(&mut self.rng, self.ante, &config);
         }
         true
     }
 
+    /// Use any consumable: planets level a hand, tarots and spectrals act on
+    /// the selected cards. Returns false if it can't be used right now.
+    pub fn use_consumable(&mut self, consumable_index: usize) -> bool {
+        let used = self.use_planet(consumable_index)
+            || self.use_tarot(consumable_index)
+            || self.use_spectral(consumable_index);
+        if used {
+            // Undoing past a used consumable would hand it back
+            self.undo_snapshot = None;
+        }
+        used
+    }
+
+    // ─── Undo ────────────────────────────────────────────────────────
+
+    /// Remember the current state (hand, draw order, counts, RNG) so the next
+    /// play or discard can be taken back
+    fn save_undo_snapshot(&mut self) {
+        if self.undo_used {
+            return;
+        }
+        self.undo_snapshot = None;
+        self.undo_snapshot = Some(Box::new(self.clone()));
+    }
+
+    /// Whether `undo` would do anything right now
+    pub fn can_undo(&self) -> bool {
+        self.undo_snapshot.is_some()
+    }
+
+    /// Restore the state from before the last play or discard. Allowed once per round.
+    pub fn undo(&mut self) -> bool {
+        let Some(snapshot) = self.undo_snapshot.take() else {
+            return false;
+        };
+        *self = *snapshot;
+        self.undo_used = true;
+        true
+    }
+
+    /// Why the consumable at `consumable_index` can't be used with the current
+    /// selection, as a message for the player (None when it can be tried)
+    pub fn consumable_use_problem(&self, consumable_index: usize) -> Option<String> {
+        let consumable_type = self.consumables.get(consumable_index)?.consumable_type;
+        let (min, max) = match consumable_type {
+            ConsumableType::Planet(_) => return None,
+            ConsumableType::Tarot(TarotCard::TheFool) if self.last_consumable.is_none() => {
+                return Some("The Fool has nothing to copy yet".to_string());
+            }
+            ConsumableType::Tarot(tarot) => tarot.cards_needed(),
+            ConsumableType::Spectral(spectral) => spectral.cards_needed(),
+        };
+        let selected = self.selected_indices.len();
+        if min == 0 || (min..=max).contains(&selected) {
+            return None;
+        }
+        let cards = if max == 1 { "card" } else { "cards" };
+        let needed = if min == max {
+            format!("{} {}", min, cards)
+        } else {
+            format!("{}-{} {}", min, max, cards)
+        };
+        Some(format!(
+            "{} needs {} selected",
+            consumable_type.name(),
+            needed
+        ))
+    }
+
     /// Use a consumable (planet card - level up)
     pub fn use_planet(&mut self, consumable_index: usize) -> bool {
         if consumable_index >= self.consumables.len() {
@@ -556,6 +1610,7 @@ impl RunState {
         if let ConsumableType::Planet(planet) = self.consumables[consumable_index].consumable_type {
             self.hand_levels.level_up(planet.hand_type());
             self.consumables.remove(consumable_index);
+            self.last_consumable = Some(ConsumableType::Planet(planet));
             true
         } else {
             false
@@ -581,10 +1636,13 @@ impl RunState {
     }
 
     /// Get all cards in the full deck (draw pile + discard pile + cards currently in hand).
-    /// This represents the complete deck the player owns.
+    /// This represents the complete deck the player owns. Cards come back in a
+    /// deterministic order (`PlayingCard::display_order_key`) no matter where
+    /// they currently sit, so indices into it are stable between calls.
     pub fn full_deck(&self) -> Vec<PlayingCard> {
         let mut all = self.deck.all_cards();
         all.extend_from_slice(&self.hand);
+        all.sort_by_key(PlayingCard::display_order_key);
         all
     }
 
@@ -595,7 +1653,131 @@ impl RunState {
             1 => BlindType::Big,
             _ => BlindType::Boss(self.boss_blind),
         };
-        blind::score_target(self.ante, &blind_type)
+        blind::score_target(self.ante, &blind_type, self.stake)
+    }
+
+    /// Plain-text description of the current game state, for screen readers
+    /// and other external tools
+    pub fn describe_text(&self) -> String {
+        let mut out = String::new();
+
+        out.push_str(&format!(
+            "Ante {}, round {}: {}\n",
+            self.ante,
+            self.round_number(),
+            self.blind_type.name()
+        ));
+        if let BlindType::Boss(boss) = &self.blind_type {
+            out.push_str(&format!("Boss effect: {}\n", boss.description()));
+        }
+        out.push_str(&format!("Target: {} chips\n", self.score_target));
+        out.push_str(&format!("Round score: {}\n", self.round_score));
+        out.push_str(&format!(
+            "Hands left: {}, discards left: {}\n",
+            self.hands_remaining, self.discards_remaining
+        ));
+        out.push_str(&format!("Money: ${}\n", self.money));
+
+        out.push_str(&format!("Hand ({} cards):\n", self.hand.len()));
+        for (i, card) in self.hand.iter().enumerate() {
+            let mut notes = Vec::new();
+            if self.is_selected(i) {
+                notes.push("selected");
+            }
+            if card.debuffed {
+                notes.push("debuffed");
+            }
+            let notes = if notes.is_empty() {
+                String::new()
+            } else {
+                format!(" ({})", notes.join(", "))
+            };
+            out.push_str(&format!(
+                "  {}. {} of {}{}\n",
+                i + 1,
+                card.rank.name(),
+                card.suit.name(),
+                notes
+            ));
+        }
+
+        out.push_str(&format!(
+            "Jokers ({}/{}):\n",
+            self.joker_slots_used(),
+            self.max_jokers
+        ));
+        for joker in &self.jokers {
+            out.push_str(&format!(
+                "  {}: {}\n",
+                joker.joker_type.name(),
+                joker.joker_type.description()
+            ));
+        }
+
+        out.push_str(&format!(
+            "Consumables ({}/{}):\n",
+            self.consumable_slots_used(),
+            self.max_consumables
+        ));
+        for consumable in &self.consumables {
+            out.push_str(&format!(
+                "  {}: {}\n",
+                consumable.consumable_type.name(),
+                consumable.consumable_type.description()
+            ));
+        }
+
+        out
+    }
+
+    /// Compact end-of-run summary, one entry per line (rendered as the
+    /// game-over recap card and written out as plain text)
+    pub fn recap_card_lines(&self) -> Vec<String> {
+        let result = if self.run_won() { "Victory" } else { "Defeat" };
+        let mut lines = vec![
+            format!("BALATRUST RUN RECAP - {}", result),
+            format!("Seed: {}", self.seed_label()),
+            format!("Deck: {}", self.deck_type.name()),
+            format!("Stake: {}", self.stake.name()),
+            format!("Ante reached: {}", self.ante_reached()),
+        ];
+        match self.best_hand {
+            Some((hand, score)) => lines.push(format!(
+                "Best hand: {} ({} chips)",
+                hand,
+                format_number(score)
+            )),
+            None => lines.push("Best hand: -".to_string()),
+        }
+        lines.push(format!("Total score: {}", format_number(self.total_score)));
+        lines.push(format!("Money: ${}", self.money));
+        if self.jokers.is_empty() {
+            lines.push("Jokers: none".to_string());
+        } else {
+            lines.push(format!("Jokers ({}):", self.jokers.len()));
+            for joker in &self.jokers {
+                lines.push(format!("  {}", joker.name()));
+            }
+        }
+        lines
+    }
+
+    /// Run stats card for the game over screen, one entry per line
+    pub fn stats_card_lines(&self) -> Vec<String> {
+        let most_played = match self.stats.most_played_hand() {
+            Some((hand, count)) => format!("{} (x{})", hand, count),
+            None => "-".to_string(),
+        };
+        let best_score = self.best_hand.map_or(0, |(_, score)| score);
+        vec![
+            "RUN STATS".to_string(),
+            format!("Hands played: {}", self.stats.hands_played),
+            format!("Discards used: {}", self.stats.discards_used),
+            format!("Best hand score: {}", format_number(best_score)),
+            format!("Most played: {}", most_played),
+            format!("Money earned: ${}", self.stats.money_earned),
+            format!("Highest ante: {}", self.ante_reached()),
+        ]
     }
 
     /// Use a tarot card (apply enhancement to selected cards)
@@ -686,17 +1868,187 @@ impl RunState {
                     let total_sell: u32 = self.jokers.iter().map(|j| j.total_sell_value()).sum();
                     self.money += total_sell.min(50);
                 }
-                _ => {
-                    // Other tarots not fully implemented yet
+                TarotCard::Justice => {
+                    // Enhance to Glass
+                    if let Some(card) = self.hand.get_mut(self.selected_indices[0]) {
+                        card.enhancement = Some(Enhancement::Glass);
+                    }
+                }
+                TarotCard::TheTower => {
+                    // Enhance to Stone
+                    if let Some(card) = self.hand.get_mut(self.selected_indices[0]) {
+                        card.enhancement = Some(Enhancement::Stone);
+                    }
+                }
+                TarotCard::TheStar
+                | TarotCard::TheMoon
+                | TarotCard::TheSun
+                | TarotCard::TheWorld => {
+                    if let Some(suit) = tarot.converts_to_suit() {
+                        for &idx in &self.selected_indices {
+                            if let Some(card) = self.hand.get_mut(idx) {
+                                card.suit = suit;
+                            }
+                        }
+                    }
+                }
+                TarotCard::Death => {
+                    // Left card becomes a copy of the right card
+                    let left = self.selected_indices[0].min(self.selected_indices[1]);
+                    let right = self.selected_indices[0].max(self.selected_indices[1]);
+                    if right >= self.hand.len() {
+                        return false;
+                    }
+                    self.hand[left] = self.hand[right];
+                }
+                TarotCard::TheHighPriestess => {
+                    // Up to 2 random planets, room permitting once this card is gone
+                    for _ in 0..self.consumable_room_after_use(consumable_index).min(2) {
+                        let planet =
+                            ConsumableType::random(&mut self.rng, &ConsumableWeights::PLANET_ONLY);
+                        self.consumables.push(Consumable::new(planet));
+                    }
+                }
+                TarotCard::TheEmperor => {
+                    // Up to 2 random tarots, never another Emperor
+                    for _ in 0..self.consumable_room_after_use(consumable_index).min(2) {
+                        let choices: Vec<TarotCard> = TarotCard::ALL
+                            .into_iter()
+                            .filter(|t| *t != TarotCard::TheEmperor)
+                            .collect();
+                        let idx = self.rng.gen_range(0..choices.len());
+                        self.consumables.push(Consumable::tarot(choices[idx]));
+                    }
+                }
+                TarotCard::TheFool => {
+                    // Copy of the last Tarot/Planet used (never The Fool itself)
+                    let Some(last) = self.last_consumable else {
+                        return false;
+                    };
+                    self.consumables.push(Consumable::new(last));
                 }
             }
 
+            if tarot != TarotCard::TheFool {
+                self.last_consumable = Some(ConsumableType::Tarot(tarot));
+            }
+            self.selected_indices.clear();
             self.consumables.remove(consumable_index);
             true
         } else {
             false
         }
     }
+
+    /// Free consumable slots once the card being used has been removed
+    fn consumable_room_after_use(&self, consumable_index: usize) -> usize {
+        let freed = self.consumables[consumable_index].takes_slot() as usize;
+        (self.max_consumables as usize + freed).saturating_sub(self.consumable_slots_used())
+    }
+
+    /// Use a spectral card from consumables
+    pub fn use_spectral(&mut self, consumable_index: usize) -> bool {
+        if consumable_index >= self.consumables.len() {
+            return false;
+        }
+        let ConsumableType::Spectral(spectral) = self.consumables[consumable_index].consumable_type
+        else {
+            return false;
+        };
+        let (min, max) = spectral.cards_needed();
+        let selected_count = self.selected_indices.len();
+        if min > 0 && (selected_count < min || selected_count > max) {
+            return false;
+        }
+
+        match spectral {
+            SpectralCard::Familiar => {
+                self.destroy_random_hand_card();
+                self.add_enhanced_cards(3, &[Rank::Jack, Rank::Queen, Rank::King]);
+            }
+            SpectralCard::Grim => {
+                self.destroy_random_hand_card();
+                self.add_enhanced_cards(2, &[Rank::Ace]);
+            }
+            SpectralCard::Incantation => {
+                self.destroy_random_hand_card();
+                self.add_enhanced_cards(4, &Rank::ALL[..9]);
+            }
+            SpectralCard::Talisman => {
+                if let Some(card) = self.hand.get_mut(self.selected_indices[0]) {
+                    card.seal = Some(Seal::Gold);
+                }
+            }
+            SpectralCard::AuraFoil => {
+                if let Some(card) = self.hand.get_mut(self.selected_indices[0]) {
+                    card.edition = Edition::Foil;
+                }
+            }
+            SpectralCard::Ectoplasm => {
+                if self.hand_size <= 1 {
+                    return false;
+                }
+                self.max_jokers += 1;
+                self.hand_size -= 1;
+            }
+        }
+
+        self.selected_indices.clear();
+        self.consumables.remove(consumable_index);
+        true
+    }
+
+    /// Permanently remove a random card from the hand
+    fn destroy_random_hand_card(&mut self) {
+        if !self.hand.is_empty() {
+            let idx = self.rng.gen_range(0..self.hand.len());
+            self.hand.remove(idx);
+        }
+    }
+
+    /// Add `count` randomly enhanced cards with ranks drawn from `ranks` to the
+    /// hand, or straight to the deck outside a round (the next draw replaces the hand)
+    fn add_enhanced_cards(&mut self, count: usize, ranks: &[Rank]) {
+        for _ in 0..count {
+            let rank = ranks[self.rng.gen_range(0..ranks.len())];
+            let suit = Suit::ALL[self.rng.gen_range(0..Suit::ALL.len())];
+            let enhancement = Enhancement::ALL[self.rng.gen_range(0..Enhancement::ALL.len())];
+            let card = PlayingCard::new(rank, suit).with_enhancement(enhancement);
+            if self.ante_phase == AntePhase::Playing {
+                self.hand.push(card);
+            } else {
+                self.deck.add_card(card);
+            }
+        }
+    }
+}
+
+/// Money charged per boss reroll (Director's Cut / Retcon)
+pub const BOSS_REROLL_COST: u32 = 10;
+
+/// Scored hands kept in `RunState::score_log`
+pub const SCORE_LOG_LEN: usize = 10;
+
+/// Ante whose boss ends the run with a win (unless playing endless)
+pub const WINNING_ANTE: u8 = 8;
+
+/// Longest seed text accepted, matching Balatro's 8-character seeds
+pub const MAX_SEED_LEN: usize = 8;
+
+/// Keep only ASCII letters and digits, uppercased, up to `MAX_SEED_LEN` characters
+pub fn normalize_seed_string(text: &str) -> String {
+    text.chars()
+        .filter(|c| c.is_ascii_alphanumeric())
+        .map(|c| c.to_ascii_uppercase())
+        .take(MAX_SEED_LEN)
+        .collect()
+}
+
+/// FNV-1a hash, stable across platforms and Rust versions
+fn hash_seed_string(text: &str) -> u64 {
+    text.bytes().fold(0xcbf2_9ce4_8422_2325, |hash, byte| {
+        (hash ^ byte as u64).wrapping_mul(0x0100_0000_01b3)
+    })
 }
 
 /// Consistent suit ordering for sort: Spades(0), Hearts(1), Diamonds(2), Clubs(3)
@@ -708,3 +2060,1822 @@ fn suit_order(suit: crate::card::Suit) -> u8 {
         crate::card::Suit::Clubs => 3,
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::scoring::ScoreStep;
+
+    fn temp_save_path(name: &str) -> std::path::PathBuf {
+        std::env::temp_dir().join(format!(
+            "balatrust-test-{}-{}.json",
+            name,
+            std::process::id()
+        ))
+    }
+
+    fn play_first_card(run: &mut RunState) -> ScoreResult {
+        run.selected_indices = vec![0];
+        let played = run.play_selected();
+        let result = run.score_played(&played);
+        run.use_hand();
+        run.resolve_played_hand(&played, &result);
+        result
+    }
+
+    fn play_selection(run: &mut RunState, indices: &[usize]) {
+        run.selected_indices = indices.to_vec();
+        let played = run.play_selected();
+        let result = crate::scoring::calculate_score(&played, &run.hand_levels);
+        run.use_hand();
+        run.resolve_played_hand(&played, &result);
+        run.draw_to_hand_size();
+    }
+
+    fn boss_run(boss: BossBlind) -> RunState {
+        let setup = PracticeSetup {
+            money: 0,
+            jokers: Vec::new(),
+        };
+        let mut run = RunState::practice_boss_with(4, 1, boss, setup);
+        for card in &mut run.hand {
+            card.debuffed = false;
+        }
+        run
+    }
+
+    #[test]
+    fn test_best_play_picks_the_pair_of_kings() {
+        let mut run = RunState::with_seed(4);
+        run.start_blind();
+        run.hand = vec![
+            PlayingCard::new(Rank::Two, Suit::Clubs),
+            PlayingCard::new(Rank::King, Suit::Spades),
+            PlayingCard::new(Rank::Seven, Suit::Hearts),
+            PlayingCard::new(Rank::Four, Suit::Diamonds),
+            PlayingCard::new(Rank::King, Suit::Hearts),
+            PlayingCard::new(Rank::Nine, Suit::Clubs),
+            PlayingCard::new(Rank::Three, Suit::Spades),
+            PlayingCard::new(Rank::Eight, Suit::Diamonds),
+        ];
+        assert_eq!(run.best_play(), Some(vec![1, 4]));
+
+        // Face-down cards are never suggested
+        run.hand[4].face_down = true;
+        let play = run.best_play().unwrap();
+        assert!(!play.contains(&4));
+
+        run.hand.clear();
+        assert_eq!(run.best_play(), None);
+    }
+
+    #[test]
+    fn test_best_play_follows_the_boss_rules() {
+        let hand = vec![
+            PlayingCard::new(Rank::Two, Suit::Clubs),
+            PlayingCard::new(Rank::King, Suit::Spades),
+            PlayingCard::new(Rank::Seven, Suit::Hearts),
+            PlayingCard::new(Rank::Four, Suit::Diamonds),
+            PlayingCard::new(Rank::King, Suit::Hearts),
+            PlayingCard::new(Rank::Nine, Suit::Clubs),
+            PlayingCard::new(Rank::Three, Suit::Spades),
+            PlayingCard::new(Rank::Eight, Suit::Diamonds),
+        ];
+
+        // The Psychic: only five-card plays
+        let mut run = RunState::practice_boss(4, 1, BossBlind::ThePsychic);
+        run.hand = hand.clone();
+        let play = run.best_play().unwrap();
+        assert_eq!(play.len(), 5);
+        assert!(play.contains(&1) && play.contains(&4));
+
+        // The Eye: the Pair was already played this round
+        let mut run = RunState::practice_boss(4, 1, BossBlind::TheEye);
+        run.hand = hand;
+        run.played_hand_types.push(PokerHand::Pair);
+        let play = run.best_play().unwrap();
+        run.selected_indices = play;
+        assert!(run.can_play());
+    }
+
+    #[test]
+    fn test_best_play_handles_more_than_32_cards() {
+        let mut run = RunState::with_seed(4);
+        run.start_blind();
+        run.hand = run.full_deck().into_iter().take(40).collect();
+        run.hand[39] =
+            PlayingCard::new(Rank::Ace, Suit::Spades).with_enhancement(Enhancement::Bonus);
+        run.max_selected = 1;
+
+        assert_eq!(run.best_play(), Some(vec![39]));
+    }
+
+    #[test]
+    fn test_pile_counts_add_up_after_drawing_and_discarding() {
+        let mut run = RunState::with_seed(11);
+        run.start_blind();
+        let owned = run.full_deck().len();
+        let piles =
+            |run: &RunState| run.deck.remaining() + run.deck.discard_count() + run.hand.len();
+        assert_eq!(piles(&run), owned);
+
+        run.selected_indices = vec![0, 1, 2];
+        run.discard_selected();
+        assert_eq!(run.deck.discard_count(), 3);
+        assert_eq!(run.hand.len(), run.hand_size as usize);
+        assert_eq!(piles(&run), owned);
+    }
+
+    #[test]
+    fn test_midas_mask_gilds_scored_face_cards() {
+        let mut run = RunState::with_seed(5);
+        run.start_blind();
+        run.jokers.push(Joker::new(JokerType::MidasMask));
+        run.hand[0] = PlayingCard::new(Rank::King, Suit::Spades);
+        run.hand[1] = PlayingCard::new(Rank::Two, Suit::Hearts);
+
+        run.selected_indices = vec![0, 1];
+        let played = run.play_selected();
+        let result = run.score_played(&played);
+        run.resolve_played_hand(&played, &result);
+
+        // High Card: only the King scored, so only the King comes back Gold
+        let gold: Vec<PlayingCard> = run
+            .full_deck()
+            .into_iter()
+            .filter(|c| c.enhancement == Some(Enhancement::Gold))
+            .collect();
+        assert_eq!(gold.len(), 1);
+        assert_eq!((gold[0].rank, gold[0].suit), (Rank::King, Suit::Spades));
+    }
+
+    #[test]
+    fn test_gold_seal_money_applied_on_resolve() {
+        let mut run = RunState::with_seed(5);
+        run.start_blind();
+        run.hand[0].seal = Some(Seal::Gold);
+        run.hand[0].debuffed = false;
+        let money_before = run.money;
+
+        play_first_card(&mut run);
+        assert_eq!(run.money, money_before + 3);
+        // Played card goes back to the deck instead of vanishing
+        assert_eq!(run.full_deck().len(), 52);
+    }
+
+    #[test]
+    fn test_glass_shatter_is_seeded_and_permanent() {
+        let outcome = |seed: u64| {
+            let mut run = RunState::with_seed(seed);
+            run.start_blind();
+            run.hand[0].enhancement = Some(Enhancement::Glass);
+            run.hand[0].seal = Some(Seal::Red);
+            run.hand[0].debuffed = false;
+            let glass = run.hand[0];
+
+            let shattered = {
+                run.selected_indices = vec![0];
+                let played = run.play_selected();
+                let result = crate::scoring::calculate_score(&played, &run.hand_levels);
+                run.resolve_played_hand(&played, &result)
+            };
+            let in_deck = run.full_deck().contains(&glass);
+            assert_ne!(shattered.is_empty(), !in_deck);
+            // A shattered card never comes back, even after the next blind resets the deck
+            run.beat_blind();
+            run.leave_shop();
+            run.start_blind();
+            assert_eq!(run.full_deck().len(), 52 - shattered.len());
+            shattered.len()
+        };
+
+        let results: Vec<usize> = (0..40).map(outcome).collect();
+        assert_eq!(results, (0..40).map(outcome).collect::<Vec<_>>());
+        // Red seal doubles the x1.5 but the card still rolls only once
+        assert!(results.iter().all(|&n| n <= 1));
+        assert!(results.contains(&0) && results.contains(&1));
+    }
+
+    #[test]
+    fn test_economy_jokers_are_itemized_in_the_cash_out() {
+        let mut run = RunState::with_seed(9);
+        let mut rocket = Joker::new(JokerType::Rocket);
+        rocket.counter = 1;
+        run.jokers = vec![
+            Joker::new(JokerType::GoldenJoker),
+            Joker::new(JokerType::Egg),
+            Joker::new(JokerType::Joker),
+            Joker::new(JokerType::GoldenJoker),
+            rocket,
+        ];
+        run.money = 0;
+
+        let breakdown = run.calculate_reward_breakdown();
+        let items: Vec<(usize, &str, u32, u32)> = breakdown
+            .joker_payouts
+            .iter()
+            .map(|p| (p.joker_index, p.name, p.money, p.sell_value))
+            .collect();
+        assert_eq!(
+            items,
+            vec![
+                (0, "Golden Joker", 4, 0),
+                (1, "Egg", 0, 3),
+                (3, "Golden Joker", 4, 0),
+                (4, "Rocket", 3, 0),
+            ]
+        );
+        assert_eq!(breakdown.economy_joker_bonus, 11);
+        // $3 Small Blind + 4 hands + 11 from jokers
+        assert_eq!(breakdown.total, 3 + 4 + 11);
+
+        run.beat_blind();
+        assert_eq!(run.money, breakdown.total);
+        assert_eq!(run.jokers[1].bonus_sell, 3);
+        // Only a boss raises Rocket's payout
+        assert_eq!(run.jokers[4].counter, 1);
+    }
+
+    #[test]
+    fn test_rocket_payout_grows_with_each_boss() {
+        let mut run = boss_run(BossBlind::TheWall);
+        run.jokers.push(Joker::new(JokerType::Rocket));
+        assert_eq!(run.calculate_reward_breakdown().economy_joker_bonus, 1);
+
+        run.beat_blind();
+        assert_eq!(run.jokers[0].counter, 1);
+        assert_eq!(run.jokers[0].round_money(), 3);
+    }
+
+    #[test]
+    fn test_gold_cards_held_pay_at_cashout() {
+        let mut run = RunState::with_seed(9);
+        run.start_blind();
+        for card in run.hand.iter_mut().take(2) {
+            card.enhancement = Some(Enhancement::Gold);
+            card.debuffed = false;
+        }
+
+        let breakdown = run.calculate_reward_breakdown();
+        assert_eq!(breakdown.gold_cards_bonus, 6);
+
+        let money_before = run.money;
+        run.beat_blind();
+        assert_eq!(run.money, money_before + breakdown.total);
+    }
+
+    #[test]
+    fn test_blue_seal_held_creates_planet_for_last_hand() {
+        let mut run = RunState::with_seed(5);
+        run.start_blind();
+        run.hand[1].seal = Some(Seal::Blue);
+        run.hand[1].debuffed = false;
+
+        let result = play_first_card(&mut run);
+        run.beat_blind();
+        assert_eq!(run.consumables.len(), 1);
+        assert_eq!(
+            run.consumables[0].consumable_type,
+            ConsumableType::Planet(PlanetCard::for_hand(result.hand_type))
+        );
+    }
+
+    #[test]
+    fn test_celestial_pack_offers_planets_and_pick_levels_hand() {
+        let mut run = RunState::with_seed(21);
+        let options = run.open_pack(BoosterPack::Celestial);
+        assert_eq!(options.len(), 3);
+        assert!(options.iter().all(|o| matches!(
+            o,
+            PackOption::Consumable(c) if matches!(c.consumable_type, ConsumableType::Planet(_))
+        )));
+
+        let PackOption::Consumable(c) = &options[1] else {
+            unreachable!()
+        };
+        let ConsumableType::Planet(planet) = c.consumable_type else {
+            unreachable!()
+        };
+        let hand = planet.hand_type();
+        let level_before = run.hand_levels.get_level(&hand);
+
+        assert!(run.pick_from_pack(1));
+        assert_eq!(run.hand_levels.get_level(&hand), level_before + 1);
+        assert!(run.pack_options.is_empty());
+        assert!(run.consumables.is_empty());
+    }
+
+    #[test]
+    fn test_pack_pick_rejected_when_slots_full() {
+        let mut run = RunState::with_seed(21);
+        for _ in 0..run.max_jokers {
+            run.jokers.push(Joker::new(JokerType::Joker));
+        }
+        run.open_pack(BoosterPack::Buffoon);
+
+        assert!(!run.can_pick_from_pack(0));
+        assert!(!run.pick_from_pack(0));
+        assert_eq!(run.pack_options.len(), 2);
+
+        run.skip_pack();
+        assert!(run.pack_options.is_empty());
+    }
+
+    #[test]
+    fn test_negative_joker_bought_at_full_slots() {
+        let mut run = RunState::with_seed(21);
+        for _ in 0..run.max_jokers {
+            run.jokers.push(Joker::new(JokerType::Joker));
+        }
+        run.money = 20;
+        run.shop = Some(Shop {
+            items: vec![
+                ShopItem::JokerItem(Joker::new(JokerType::Scholar)),
+                ShopItem::JokerItem(Joker::new(JokerType::Scholar).with_edition(Edition::Negative)),
+            ],
+            reroll_cost: 5,
+            free_items: Vec::new(),
+        });
+
+        assert!(!run.buy_shop_item(0));
+        assert!(run.buy_shop_item(1));
+        assert_eq!(run.jokers.len(), 6);
+        assert_eq!(run.joker_slots_used(), 5);
+        // $5 Negative surcharge on top of the base price
+        assert_eq!(run.money, 20 - JokerType::Scholar.price() - 5);
+    }
+
+    #[test]
+    fn test_crystal_ball_adds_a_slot_and_negatives_skip_the_cap() {
+        let mut run = RunState::with_seed(21);
+        run.money = 50;
+        let tarot = || ShopItem::ConsumableItem(Consumable::tarot(TarotCard::TheFool));
+        run.shop = Some(Shop {
+            items: vec![tarot(), tarot(), tarot(), tarot()],
+            reroll_cost: 5,
+            free_items: Vec::new(),
+        });
+
+        assert!(run.buy_shop_item(0));
+        assert!(run.buy_shop_item(0));
+        assert!(!run.buy_shop_item(0));
+
+        run.redeem_voucher(Voucher::CrystalBall);
+        assert_eq!(run.max_consumables, 3);
+        assert!(run.buy_shop_item(0));
+        assert_eq!(run.consumables.len(), 3);
+        assert!(!run.has_consumable_room());
+
+        let negative = Consumable::tarot(TarotCard::TheFool).with_negative();
+        run.shop.as_mut().unwrap().items = vec![ShopItem::ConsumableItem(negative)];
+        assert!(run.buy_shop_item(0));
+        assert_eq!(run.consumables.len(), 4);
+        assert_eq!(run.consumable_slots_used(), 3);
+    }
+
+    #[test]
+    fn test_purchases_are_logged_with_name_and_price() {
+        let mut run = RunState::with_seed(42);
+        run.money = 50;
+        run.shop = Some(Shop {
+            items: vec![
+                ShopItem::JokerItem(Joker::new(JokerType::Joker)),
+                ShopItem::ConsumableItem(Consumable::planet(PlanetCard::Jupiter)),
+            ],
+            reroll_cost: 5,
+            free_items: Vec::new(),
+        });
+        let joker_price = run.shop.as_ref().unwrap().items[0].price();
+        let planet_price = run.shop.as_ref().unwrap().items[1].price();
+
+        // The planet moves up to slot 0 once the joker is bought
+        assert!(run.buy_shop_item(0));
+        assert!(run.buy_shop_item(0));
+        assert_eq!(
+            run.purchases,
+            vec![
+                Purchase {
+                    name: "Joker".to_string(),
+                    price: joker_price,
+                    ante: 1,
+                },
+                Purchase {
+                    name: "Jupiter".to_string(),
+                    price: planet_price,
+                    ante: 1,
+                },
+            ]
+        );
+        assert_eq!(run.money, 50 - joker_price - planet_price);
+    }
+
+    #[test]
+    fn test_buying_pack_opens_it() {
+        let mut run = RunState::with_seed(21);
+        run.money = 10;
+        run.shop = Some(Shop {
+            items: vec![ShopItem::PackItem(BoosterPack::Standard)],
+            reroll_cost: 5,
+            free_items: Vec::new(),
+        });
+
+        assert!(run.buy_shop_item(0));
+        assert_eq!(run.money, 6);
+        assert_eq!(run.pack_options.len(), 3);
+        assert!(run.pick_from_pack(2));
+        assert_eq!(run.full_deck().len(), 53);
+    }
+
+    #[test]
+    fn test_grabber_voucher_adds_hand_at_start_blind() {
+        let mut run = RunState::with_seed(8);
+        run.money = 50;
+        run.shop = Some(Shop {
+            items: vec![ShopItem::VoucherItem(Voucher::Grabber)],
+            reroll_cost: 5,
+            free_items: Vec::new(),
+        });
+
+        assert!(run.buy_shop_item(0));
+        assert_eq!(run.money, 40);
+        assert_eq!(run.vouchers, vec![Voucher::Grabber]);
+
+        run.start_blind();
+        assert_eq!(run.hands_remaining, 5);
+        assert_eq!(run.discards_remaining, 3);
+    }
+
+    #[test]
+    fn test_skipping_grants_a_seeded_tag() {
+        let skip = || {
+            let mut run = RunState::with_seed(11);
+            run.skip_blind();
+            run
+        };
+        let run = skip();
+        let tag = run.skip_tags[0].expect("skipping grants a tag");
+        assert_eq!(skip().skip_tags[0], Some(tag));
+        assert_eq!(run.skip_tags[1], None);
+        assert_eq!(run.blind_outcomes[0], BlindOutcome::Skipped);
+    }
+
+    #[test]
+    fn test_economy_tag_doubles_money_up_to_the_cap() {
+        let mut run = RunState::with_seed(3);
+        run.money = 12;
+        run.apply_tag(Tag::Economy);
+        assert_eq!(run.money, 24);
+        run.money = 70;
+        run.apply_tag(Tag::Economy);
+        assert_eq!(run.money, 70 + Tag::ECONOMY_CAP);
+        assert!(run.pending_tags.is_empty());
+    }
+
+    #[test]
+    fn test_shop_tags_wait_for_the_next_shop() {
+        let mut run = RunState::with_seed(3);
+        run.apply_tag(Tag::Voucher);
+        run.apply_tag(Tag::Uncommon);
+        assert_eq!(run.pending_tags, vec![Tag::Voucher, Tag::Uncommon]);
+
+        run.start_blind();
+        run.beat_blind();
+        assert!(run.pending_tags.is_empty());
+        let shop = run.shop.as_ref().unwrap();
+        assert_eq!(shop.items.iter().filter(|i| i.is_voucher()).count(), 2);
+        let free = shop.free_items[0];
+        let ShopItem::JokerItem(joker) = &shop.items[free] else {
+            panic!("Uncommon Tag adds a joker");
+        };
+        assert_eq!(
+            joker.joker_type.rarity(),
+            crate::joker::JokerRarity::Uncommon
+        );
+
+        // Free even when broke
+        run.money = 0;
+        assert!(run.buy_shop_item(free));
+        assert_eq!(run.money, 0);
+        assert!(run.shop.as_ref().unwrap().free_items.is_empty());
+    }
+
+    #[test]
+    fn test_bought_voucher_is_not_offered_again() {
+        let mut run = RunState::with_seed(8);
+        for voucher in [
+            Voucher::Overstock,
+            Voucher::ClearanceSale,
+            Voucher::Wasteful,
+            Voucher::SeedMoney,
+            Voucher::MoneyTree,
+            Voucher::RerollSurplus,
+            Voucher::RerollGlut,
+            Voucher::Telescope,
+            Voucher::Observatory,
+            Voucher::DirectorsCut,
+            Voucher::Retcon,
+            Voucher::CrystalBall,
+        ] {
+            run.redeem_voucher(voucher);
+        }
+        assert_eq!(run.shop_slots, 3);
+
+        let config = run.shop_config();
+        let shop = Shop::generate(&mut run.rng, 1, &config, &run.vouchers);
+        // Three card items, then the voucher, then the pack
+        assert_eq!(shop.items.len(), 5);
+        assert!(matches!(
+            shop.items[3],
+            ShopItem::VoucherItem(Voucher::Grabber)
+        ));
+
+        run.redeem_voucher(Voucher::Grabber);
+        let config = run.shop_config();
+        let shop = Shop::generate(&mut run.rng, 1, &config, &run.vouchers);
+        assert_eq!(shop.voucher_index(), None);
+    }
+
+    #[test]
+    fn test_undo_discard_restores_hand_once_per_round() {
+        let mut run = RunState::with_seed(12);
+        run.start_blind();
+        let hand_before = run.hand.clone();
+        let draw_pile_before = run.deck.draw_pile().to_vec();
+
+        run.toggle_select(0);
+        run.toggle_select(3);
+        run.discard_selected();
+        run.use_discard();
+        assert_ne!(run.hand, hand_before);
+
+        assert!(run.undo());
+        assert_eq!(run.hand, hand_before);
+        assert_eq!(run.deck.draw_pile(), draw_pile_before.as_slice());
+        assert_eq!(run.selected_indices, vec![0, 3]);
+        assert_eq!(run.discards_remaining, 3);
+
+        // Only one undo per round
+        run.discard_selected();
+        assert!(!run.can_undo());
+        assert!(!run.undo());
+
+        run.start_blind();
+        run.toggle_select(0);
+        run.play_selected();
+        assert!(run.can_undo());
+    }
+
+    #[test]
+    fn test_ride_the_bus_scales_until_a_face_card_scores() {
+        let mut run = RunState::with_seed(21);
+        run.jokers.push(Joker::new(JokerType::RideTheBus));
+        run.start_blind();
+
+        for expected_mult in [2, 3, 4] {
+            run.hand[0] = PlayingCard::new(Rank::Five, Suit::Clubs);
+            let result = play_first_card(&mut run);
+            // High Card base mult 1 + this hand's bus bonus
+            assert_eq!(result.total_mult, expected_mult);
+        }
+        assert_eq!(run.jokers[0].counter, 3);
+        assert_eq!(
+            run.jokers[0].live_preview(run.money).as_deref(),
+            Some("Currently +3 Mult")
+        );
+
+        run.hand[0] = PlayingCard::new(Rank::King, Suit::Clubs);
+        let result = play_first_card(&mut run);
+        assert_eq!(result.total_mult, 1);
+        assert_eq!(run.jokers[0].counter, 0);
+    }
+
+    #[test]
+    fn test_times_played_counts_each_played_hand() {
+        let mut run = RunState::with_seed(21);
+        run.start_blind();
+        assert_eq!(run.times_played(PokerHand::HighCard), 0);
+        assert!(run.is_most_played(PokerHand::HighCard));
+
+        play_first_card(&mut run);
+        play_first_card(&mut run);
+        assert_eq!(run.times_played(PokerHand::HighCard), 2);
+        assert_eq!(run.times_played(PokerHand::Pair), 0);
+        assert!(!run.is_most_played(PokerHand::Pair));
+    }
+
+    #[test]
+    fn test_obelisk_scales_until_the_most_played_hand_is_played() {
+        let mut run = RunState::with_seed(21);
+        run.jokers.push(Joker::new(JokerType::Obelisk));
+        run.hands_remaining = 6;
+        run.start_blind();
+        let obelisk_x = |result: &ScoreResult| {
+            result.steps.iter().find_map(|step| match step {
+                ScoreStep::JokerXMult { x_mult, .. } => Some(*x_mult),
+                _ => None,
+            })
+        };
+        let play_pair = |run: &mut RunState| {
+            run.hand[0] = PlayingCard::new(Rank::Four, Suit::Clubs);
+            run.hand[1] = PlayingCard::new(Rank::Four, Suit::Hearts);
+            run.selected_indices = vec![0, 1];
+            let played = run.play_selected();
+            let result = run.score_played(&played);
+            run.use_hand();
+            run.resolve_played_hand(&played, &result);
+            run.draw_to_hand_size();
+            result
+        };
+
+        // First hand of the run is its most played: nothing to gain
+        run.hand[0] = PlayingCard::new(Rank::Five, Suit::Clubs);
+        assert_eq!(obelisk_x(&play_first_card(&mut run)), None);
+        run.draw_to_hand_size();
+        run.hand[0] = PlayingCard::new(Rank::Five, Suit::Clubs);
+        play_first_card(&mut run);
+        run.draw_to_hand_size();
+
+        // Pairs trail High Card (2 plays) for two hands, then tie it and reset
+        assert_eq!(obelisk_x(&play_pair(&mut run)), Some(1.2));
+        let preview = {
+            run.hand[0] = PlayingCard::new(Rank::Four, Suit::Clubs);
+            run.hand[1] = PlayingCard::new(Rank::Four, Suit::Hearts);
+            run.selected_indices = vec![0, 1];
+            run.preview_selected().unwrap()
+        };
+        assert_eq!(obelisk_x(&preview), Some(1.4));
+        assert_eq!(run.jokers[0].counter, 1);
+        assert_eq!(obelisk_x(&play_pair(&mut run)), Some(1.4));
+        assert_eq!(
+            run.jokers[0].live_preview(run.money).as_deref(),
+            Some("Currently X1.4 Mult")
+        );
+        assert_eq!(obelisk_x(&play_pair(&mut run)), None);
+        assert_eq!(run.jokers[0].counter, 0);
+    }
+
+    #[test]
+    fn test_perkeo_copies_a_consumable_as_negative_on_leaving_shop() {
+        assert!(JokerType::ALL.contains(&JokerType::Perkeo));
+        assert_eq!(
+            JokerType::Perkeo.rarity(),
+            crate::joker::JokerRarity::Legendary
+        );
+        assert_eq!(JokerType::Perkeo.price(), 20);
+
+        let mut run = RunState::with_seed(8);
+        run.jokers.push(Joker::new(JokerType::Perkeo));
+        run.consumables.push(Consumable::tarot(TarotCard::TheSun));
+        run.consumables.push(Consumable::planet(PlanetCard::Venus));
+        run.start_blind();
+        run.beat_blind();
+        run.leave_shop();
+
+        assert_eq!(run.consumables.len(), 3);
+        assert!(run.consumables[2].negative);
+        // The copy doesn't take a slot
+        assert_eq!(run.consumable_slots_used(), 2);
+        assert!(!run.has_consumable_room());
+    }
+
+    #[test]
+    fn test_affordable_shop_items_follow_money() {
+        let mut run = RunState::with_seed(3);
+        run.start_blind();
+        run.beat_blind();
+        let cheapest = run
+            .shop
+            .as_ref()
+            .unwrap()
+            .items
+            .iter()
+            .map(|item| item.price_with_discount(run.shop_discount_percent))
+            .min()
+            .unwrap();
+
+        run.money = cheapest;
+        assert!(run.affordable_shop_items() >= 1);
+        run.money = cheapest - 1;
+        assert_eq!(run.affordable_shop_items(), 0);
+    }
+
+    #[test]
+    fn test_deck_type_sets_cards_and_round_bonuses() {
+        let mut red = RunState::with_seed_and_deck(5, DeckType::Red);
+        red.start_blind();
+        assert_eq!((red.hands_remaining, red.discards_remaining), (4, 4));
+
+        let mut blue = RunState::with_seed_and_deck(5, DeckType::Blue);
+        blue.start_blind();
+        assert_eq!((blue.hands_remaining, blue.discards_remaining), (5, 3));
+
+        let abandoned = RunState::with_seed_and_deck(5, DeckType::Abandoned);
+        assert_eq!(abandoned.full_deck().len(), 40);
+        assert!(abandoned
+            .recap_card_lines()
+            .iter()
+            .any(|l| l == "Deck: Abandoned Deck"));
+    }
+
+    #[test]
+    fn test_new_shop_resets_reroll_cost_to_base() {
+        let mut run = RunState::with_seed(8);
+        run.start_blind();
+        run.beat_blind();
+        run.money = 100;
+        assert_eq!(run.shop.as_ref().unwrap().reroll_cost, 5);
+        assert!(run.reroll_shop());
+        assert!(run.reroll_shop());
+        assert_eq!(run.shop.as_ref().unwrap().reroll_cost, 7);
+
+        run.redeem_voucher(Voucher::RerollSurplus);
+        assert_eq!(run.reroll_base, 3);
+        assert_eq!(run.shop.as_ref().unwrap().reroll_cost, 5);
+
+        run.leave_shop();
+        run.start_blind();
+        run.beat_blind();
+        assert_eq!(run.shop.as_ref().unwrap().reroll_cost, 3);
+    }
+
+    #[test]
+    fn test_observatory_gives_x1_5_for_a_held_planet_of_the_played_hand() {
+        let mut run = RunState::with_seed(8);
+        run.start_blind();
+        run.consumables
+            .push(Consumable::planet(PlanetCard::Mercury));
+        run.consumables
+            .push(Consumable::planet(PlanetCard::Jupiter));
+        run.hand[0] = PlayingCard::new(Rank::Four, Suit::Clubs);
+        run.hand[1] = PlayingCard::new(Rank::Four, Suit::Hearts);
+        run.selected_indices = vec![0, 1];
+        let plain = run.preview_selected().unwrap();
+
+        run.redeem_voucher(Voucher::Telescope);
+        run.redeem_voucher(Voucher::Observatory);
+        assert_eq!(run.observatory_planets(PokerHand::Pair), vec![0]);
+        let preview = run.preview_selected().unwrap();
+        let played = run.play_selected();
+        let result = run.score_played(&played);
+
+        for result in [&preview, &result] {
+            assert!(matches!(
+                result.steps.last(),
+                Some(ScoreStep::PlanetXMult {
+                    consumable_index: 0,
+                    x_mult
+                }) if *x_mult == 1.5
+            ));
+            // Pair base 2 mult X1.5
+            assert_eq!(result.total_mult, 3);
+            assert_eq!(result.total_chips, plain.total_chips);
+        }
+        // Jupiter is for Flushes
+        assert_eq!(run.observatory_planets(PokerHand::Flush), vec![1]);
+        assert!(run.observatory_planets(PokerHand::HighCard).is_empty());
+    }
+
+    #[test]
+    fn test_telescope_puts_the_most_played_planet_in_the_first_celestial_pack() {
+        let mut run = RunState::with_seed(8);
+        run.record_hand(PokerHand::Flush, 0);
+        run.record_hand(PokerHand::Flush, 0);
+        run.record_hand(PokerHand::Pair, 0);
+        run.redeem_voucher(Voucher::Telescope);
+
+        let options = run.open_pack(BoosterPack::Celestial);
+        assert!(matches!(
+            &options[0],
+            PackOption::Consumable(c)
+                if c.consumable_type == ConsumableType::Planet(PlanetCard::Jupiter)
+        ));
+        assert!(run.telescope_used);
+
+        // Only the first Celestial pack of a shop
+        run.hands_remaining = 4;
+        run.beat_blind();
+        assert!(!run.telescope_used);
+    }
+
+    #[test]
+    fn test_directors_cut_rerolls_the_boss_once_per_ante() {
+        let mut run = RunState::with_seed(4);
+        run.money = 30;
+        assert!(!run.reroll_boss());
+        assert!(run.boss_reroll_problem().is_some());
+
+        run.redeem_voucher(Voucher::DirectorsCut);
+        let before = run.boss_blind;
+        assert!(run.reroll_boss());
+        assert_ne!(run.boss_blind, before);
+        assert_eq!(run.money, 30 - BOSS_REROLL_COST);
+
+        // Once per ante without Retcon
+        assert!(!run.reroll_boss());
+        assert_eq!(run.money, 20);
+
+        run.redeem_voucher(Voucher::Retcon);
+        assert!(run.reroll_boss());
+        assert!(run.reroll_boss());
+        assert_eq!(run.money, 0);
+        assert!(!run.reroll_boss());
+    }
+
+    #[test]
+    fn test_boss_cannot_be_rerolled_once_it_is_up() {
+        let mut run = RunState::with_seed(4);
+        run.money = 30;
+        run.redeem_voucher(Voucher::DirectorsCut);
+        run.skip_blind();
+        run.skip_blind();
+        assert_eq!(run.blind_outcomes[2], BlindOutcome::Active);
+
+        // Skip tags may have paid out, so compare against the money now
+        let (boss, money) = (run.boss_blind, run.money);
+        assert!(!run.reroll_boss());
+        assert_eq!((run.boss_blind, run.money), (boss, money));
+    }
+
+    #[test]
+    fn test_money_tree_needs_seed_money_first() {
+        let mut run = RunState::with_seed(8);
+        for voucher in [
+            Voucher::Overstock,
+            Voucher::ClearanceSale,
+            Voucher::Grabber,
+            Voucher::Wasteful,
+            Voucher::RerollSurplus,
+            Voucher::RerollGlut,
+            Voucher::Telescope,
+            Voucher::Observatory,
+            Voucher::DirectorsCut,
+            Voucher::Retcon,
+            Voucher::CrystalBall,
+        ] {
+            run.redeem_voucher(voucher);
+        }
+        let config = run.shop_config();
+        let shop = Shop::generate(&mut run.rng, 1, &config, &run.vouchers);
+        let voucher = shop.voucher_index().map(|i| &shop.items[i]);
+        assert!(matches!(
+            voucher,
+            Some(ShopItem::VoucherItem(Voucher::SeedMoney))
+        ));
+    }
+
+    #[test]
+    fn test_interest_cap_raises_interest() {
+        let mut run = RunState::with_seed(8);
+        run.start_blind();
+        run.money = 60;
+        assert_eq!(run.calculate_reward_breakdown().interest, 5);
+
+        run.interest_cap = 10;
+        let breakdown = run.calculate_reward_breakdown();
+        assert_eq!(breakdown.interest, 10);
+        assert_eq!(breakdown.interest_cap, 10);
+
+        run.redeem_voucher(Voucher::MoneyTree);
+        run.money = 200;
+        assert_eq!(run.calculate_reward_breakdown().interest, 20);
+    }
+
+    #[test]
+    fn test_higher_stakes_raise_targets_and_lower_interest() {
+        let mut white = RunState::with_seed(8);
+        let mut gold = RunState::with_seed(8).with_stake(Stake::Gold);
+        for ante in 2..=8 {
+            let target = |stake| blind::score_target(ante, &BlindType::Big, stake);
+            assert!(target(Stake::Green) > target(Stake::White));
+            assert!(target(Stake::Purple) > target(Stake::Green));
+        }
+        // Ante 1 is the same at every stake
+        assert_eq!(gold.score_target, white.score_target);
+        white.ante = 3;
+        gold.ante = 3;
+        assert!(gold.blind_score_target(2) > white.blind_score_target(2));
+
+        let cash_out = |run: RunState| {
+            let mut run = run;
+            run.start_blind();
+            run.money = 60;
+            run.calculate_reward_breakdown()
+        };
+        let (white, gold) = (cash_out(white), cash_out(gold));
+        assert_eq!(white.interest, 5);
+        assert_eq!(gold.interest, 3);
+        assert_eq!(gold.interest_cap, 3);
+        // Red Stake and up: the Small Blind pays nothing
+        assert_eq!(white.blind_reward, 3);
+        assert_eq!(gold.blind_reward, 0);
+    }
+
+    #[test]
+    fn test_stake_rules_stack() {
+        let mut run = RunState::with_seed(3).with_stake(Stake::Gold);
+        run.start_blind();
+        assert_eq!(run.discards_remaining, 2);
+        assert_eq!(run.hand.len(), 7);
+        assert_eq!(run.reroll_base, default_reroll_base() + 1);
+
+        // Blue Stake's missing discard doesn't carry over to Red Stake
+        let mut run = RunState::with_seed(3).with_stake(Stake::Red);
+        run.start_blind();
+        assert_eq!(run.discards_remaining, 3);
+        assert_eq!(run.hand.len(), 8);
+
+        let restarted = RunState::with_seed(3).with_stake(Stake::Purple).restarted();
+        assert_eq!(restarted.stake, Stake::Purple);
+    }
+
+    #[test]
+    fn test_clearance_sale_discounts_purchases() {
+        let mut run = RunState::with_seed(8);
+        run.redeem_voucher(Voucher::ClearanceSale);
+        run.money = 10;
+        run.shop = Some(Shop {
+            items: vec![ShopItem::ConsumableItem(Consumable::tarot(
+                TarotCard::TheHermit,
+            ))],
+            reroll_cost: 5,
+            free_items: Vec::new(),
+        });
+
+        assert!(run.buy_shop_item(0));
+        // $3 at 25% off rounds down to $2
+        assert_eq!(run.money, 8);
+    }
+
+    #[test]
+    fn test_suit_tarots_convert_selected_cards() {
+        let cases = [
+            (TarotCard::TheStar, Suit::Diamonds),
+            (TarotCard::TheMoon, Suit::Clubs),
+            (TarotCard::TheSun, Suit::Hearts),
+            (TarotCard::TheWorld, Suit::Spades),
+        ];
+        for (tarot, suit) in cases {
+            let mut run = RunState::with_seed(3);
+            run.start_blind();
+            let untouched = run.hand[3];
+            run.consumables.push(Consumable::tarot(tarot));
+            run.selected_indices = vec![0, 1, 2];
+
+            assert!(run.use_tarot(0));
+            assert!(run.hand[..3].iter().all(|c| c.suit == suit));
+            assert_eq!(run.hand[3], untouched);
+            assert!(run.consumables.is_empty());
+        }
+    }
+
+    #[test]
+    fn test_suit_tarot_rejects_too_many_cards() {
+        let mut run = RunState::with_seed(3);
+        run.start_blind();
+        run.consumables.push(Consumable::tarot(TarotCard::TheSun));
+        run.selected_indices = vec![0, 1, 2, 3];
+
+        assert!(!run.use_tarot(0));
+        assert_eq!(run.consumables.len(), 1);
+    }
+
+    #[test]
+    fn test_death_copies_right_card_onto_left() {
+        let mut run = RunState::with_seed(3);
+        run.start_blind();
+        run.hand[4].enhancement = Some(Enhancement::Steel);
+        let right = run.hand[4];
+        run.consumables.push(Consumable::tarot(TarotCard::Death));
+        run.selected_indices = vec![4, 1];
+
+        assert!(run.use_tarot(0));
+        assert_eq!(run.hand[1], right);
+    }
+
+    #[test]
+    fn test_fool_copies_last_used_consumable() {
+        let mut run = RunState::with_seed(3);
+        run.start_blind();
+        run.consumables.push(Consumable::tarot(TarotCard::TheFool));
+        // Nothing used yet, so The Fool can't be used
+        assert!(!run.use_tarot(0));
+
+        run.consumables.push(Consumable::planet(PlanetCard::Venus));
+        assert!(run.use_planet(1));
+        assert!(run.use_tarot(0));
+        assert_eq!(
+            run.consumables[0].consumable_type,
+            ConsumableType::Planet(PlanetCard::Venus)
+        );
+    }
+
+    #[test]
+    fn test_talisman_adds_gold_seal_to_selected_card() {
+        let mut run = RunState::with_seed(11);
+        run.start_blind();
+        run.hand[2].seal = None;
+        run.consumables
+            .push(Consumable::spectral(SpectralCard::Talisman));
+
+        // Needs exactly one selected card
+        assert!(!run.use_spectral(0));
+        run.selected_indices = vec![2];
+        assert!(run.use_spectral(0));
+        assert_eq!(run.hand[2].seal, Some(Seal::Gold));
+        assert!(run.consumables.is_empty());
+    }
+
+    #[test]
+    fn test_aura_adds_foil_edition_to_selected_card() {
+        let mut run = RunState::with_seed(11);
+        run.start_blind();
+        run.consumables
+            .push(Consumable::spectral(SpectralCard::AuraFoil));
+        run.selected_indices = vec![0];
+
+        assert!(run.use_spectral(0));
+        assert_eq!(run.hand[0].edition, Edition::Foil);
+        assert!(run.hand[1..].iter().all(|c| c.edition == Edition::Base));
+    }
+
+    #[test]
+    fn test_grim_replaces_a_card_with_enhanced_aces() {
+        let mut run = RunState::with_seed(11);
+        run.start_blind();
+        let before = run.hand.len();
+        run.consumables
+            .push(Consumable::spectral(SpectralCard::Grim));
+
+        assert!(run.use_spectral(0));
+        assert_eq!(run.hand.len(), before + 1);
+        let added = &run.hand[before - 1..];
+        assert!(added
+            .iter()
+            .all(|c| c.rank == Rank::Ace && c.enhancement.is_some()));
+        assert_eq!(run.full_deck().len(), 53);
+    }
+
+    #[test]
+    fn test_familiar_in_the_shop_adds_its_cards_to_the_deck() {
+        let mut run = RunState::with_seed(11);
+        run.start_blind();
+        run.beat_blind();
+        run.consumables
+            .push(Consumable::spectral(SpectralCard::Familiar));
+
+        assert!(run.use_spectral(0));
+        assert_eq!(run.full_deck().len(), 55);
+        run.leave_shop();
+        run.start_blind();
+        assert_eq!(run.full_deck().len(), 55);
+    }
+
+    #[test]
+    fn test_purple_seal_discard_creates_tarot() {
+        let mut run = RunState::with_seed(5);
+        run.start_blind();
+        run.hand[0].seal = Some(Seal::Purple);
+        run.hand[0].debuffed = false;
+        run.selected_indices = vec![0];
+
+        run.discard_selected();
+        assert_eq!(run.consumables.len(), 1);
+        assert!(matches!(
+            run.consumables[0].consumable_type,
+            ConsumableType::Tarot(_)
+        ));
+    }
+
+    #[test]
+    fn test_recap_card_includes_seed_and_ante() {
+        let mut run = RunState::with_seed(424242);
+        run.ante = 3;
+        run.jokers.push(Joker::new(JokerType::Scholar));
+        run.jokers
+            .push(Joker::new(JokerType::Joker).with_edition(Edition::Foil));
+        run.add_score(120);
+        run.record_hand(PokerHand::Pair, 120);
+        run.add_score(80);
+        run.record_hand(PokerHand::HighCard, 80);
+
+        let lines = run.recap_card_lines();
+        assert!(lines.iter().any(|l| l == "Seed: 424242"));
+        assert!(lines.iter().any(|l| l == "Ante reached: 3"));
+        assert!(lines.iter().any(|l| l.contains("Pair (120 chips)")));
+        assert!(lines.iter().any(|l| l == "Total score: 200"));
+        assert!(lines.iter().any(|l| l == "  Scholar"));
+        assert!(lines.iter().any(|l| l == "  Foil Joker"));
+    }
+
+    #[test]
+    fn test_stats_count_hands_by_type() {
+        let mut run = RunState::with_seed(8);
+        run.start_blind();
+        for (hand, score) in [
+            (PokerHand::Pair, 60),
+            (PokerHand::Flush, 300),
+            (PokerHand::Pair, 90),
+            (PokerHand::Pair, 40),
+        ] {
+            run.use_hand();
+            run.add_score(score);
+            run.record_hand(hand, score);
+        }
+        run.use_discard();
+
+        assert_eq!(run.stats.hands_played, 4);
+        assert_eq!(run.stats.discards_used, 1);
+        assert_eq!(run.stats.hand_counts[&PokerHand::Pair], 3);
+        assert_eq!(run.stats.hand_counts[&PokerHand::Flush], 1);
+        assert_eq!(run.stats.most_played_hand(), Some((PokerHand::Pair, 3)));
+    }
+
+    #[test]
+    fn test_stats_card_shows_best_hand_and_ante_reached() {
+        let mut run = RunState::with_seed(8);
+        for score in [120, 450, 80] {
+            run.record_hand(PokerHand::Pair, score);
+        }
+        // Died on the first blind of ante 3, without beating anything there
+        run.ante = 3;
+
+        let lines = run.stats_card_lines();
+        assert!(lines.contains(&"Best hand score: 450".to_string()));
+        assert!(lines.contains(&"Highest ante: 3".to_string()));
+    }
+
+    #[test]
+    fn test_most_played_tie_goes_to_higher_hand() {
+        let mut run = RunState::with_seed(8);
+        run.record_hand(PokerHand::Flush, 10);
+        run.record_hand(PokerHand::Pair, 10);
+        assert_eq!(run.stats.most_played_hand(), Some((PokerHand::Flush, 1)));
+    }
+
+    #[test]
+    fn test_same_seed_string_gives_same_run() {
+        let a = RunState::with_seed_string("bala1234");
+        let b = RunState::with_seed_string("BALA-1234");
+
+        assert_eq!(a.seed, b.seed);
+        assert_eq!(a.deck.draw_pile(), b.deck.draw_pile());
+        assert_eq!(a.boss_blind, b.boss_blind);
+        assert_eq!(a.seed_label(), "BALA1234");
+
+        let c = RunState::with_seed_string("BALA1235");
+        assert_ne!(a.deck.draw_pile(), c.deck.draw_pile());
+    }
+
+    #[test]
+    fn test_same_seed_draws_the_same_hands() {
+        let mut a = RunState::with_seed(42);
+        let mut b = RunState::with_seed(42);
+        a.start_blind();
+        b.start_blind();
+        assert_eq!(a.hand, b.hand);
+
+        let next = a.deck.peek(3);
+        assert_eq!(next, b.deck.peek(3));
+        assert_eq!(a.deck.draw(3), next);
+    }
+
+    #[test]
+    fn test_empty_seed_string_falls_back_to_random_seed() {
+        let run = RunState::with_seed_string("  -- ");
+        assert_eq!(run.seed_string, None);
+        assert_eq!(run.seed_label(), run.seed.to_string());
+    }
+
+    #[test]
+    fn test_recap_shows_typed_seed() {
+        let run = RunState::with_seed_string("ABCDEFGHIJ");
+        assert!(run.recap_card_lines().iter().any(|l| l == "Seed: ABCDEFGH"));
+    }
+
+    #[test]
+    fn test_use_consumable_planet_levels_hand() {
+        let mut run = RunState::with_seed(2);
+        run.consumables
+            .push(Consumable::planet(PlanetCard::Mercury));
+        let before = run.hand_levels.get_level(&PokerHand::Pair);
+
+        assert_eq!(run.consumable_use_problem(0), None);
+        assert!(run.use_consumable(0));
+        assert_eq!(run.hand_levels.get_level(&PokerHand::Pair), before + 1);
+        assert!(run.consumables.is_empty());
+    }
+
+    #[test]
+    fn test_tarot_with_wrong_selection_gives_feedback() {
+        let mut run = RunState::with_seed(2);
+        run.start_blind();
+        run.consumables.push(Consumable::tarot(TarotCard::Death));
+        run.selected_indices = vec![0];
+
+        assert_eq!(
+            run.consumable_use_problem(0).as_deref(),
+            Some("Death needs 2 cards selected")
+        );
+        assert!(!run.use_consumable(0));
+        assert_eq!(run.consumables.len(), 1);
+
+        run.selected_indices = vec![0, 1];
+        assert_eq!(run.consumable_use_problem(0), None);
+        assert!(run.use_consumable(0));
+    }
+
+    #[test]
+    fn test_sell_consumable_refunds_half_price() {
+        let mut run = RunState::with_seed(2);
+        run.money = 0;
+        run.consumables.push(Consumable::planet(PlanetCard::Pluto));
+        run.consumables
+            .push(Consumable::spectral(SpectralCard::Grim));
+
+        assert!(run.sell_consumable(1));
+        assert_eq!(run.consumables.len(), 1);
+        assert_eq!(run.money, 2);
+
+        assert!(run.sell_consumable(0));
+        assert!(run.consumables.is_empty());
+        assert_eq!(run.money, 3);
+
+        assert!(!run.sell_consumable(0));
+        assert_eq!(run.money, 3);
+    }
+
+    #[test]
+    fn test_sell_all_jokers_returns_the_summed_sell_values() {
+        let mut run = RunState::with_seed(2);
+        run.money = 5;
+        run.jokers.push(Joker::new(JokerType::Joker));
+        run.jokers.push(Joker::new(JokerType::Fibonacci));
+        run.jokers[1].bonus_sell = 3;
+        let expected: u32 = run.jokers.iter().map(|j| j.total_sell_value()).sum();
+
+        assert_eq!(run.sell_all_jokers(), expected);
+        assert!(run.jokers.is_empty());
+        assert_eq!(run.money, 5 + expected);
+        assert_eq!(run.sell_all_jokers(), 0);
+    }
+
+    #[test]
+    fn test_max_selected_allows_six_cards() {
+        let mut run = RunState::with_seed(3);
+        run.start_blind();
+        for i in 0..6 {
+            run.toggle_select(i);
+        }
+        assert_eq!(run.selected_indices.len(), 5);
+
+        run.max_selected = 6;
+        run.toggle_select(5);
+        run.toggle_select(6);
+        assert_eq!(run.selected_indices.len(), 6);
+        assert!(run.can_play());
+    }
+
+    #[test]
+    fn test_the_wall_doubles_boss_target() {
+        let mut run = RunState::with_seed(1);
+        run.ante = 3;
+        run.boss_blind = BossBlind::TheHook;
+        let normal = run.blind_score_target(2);
+        run.boss_blind = BossBlind::TheWall;
+
+        // Blind select shows the boosted target before the blind starts
+        assert_eq!(run.blind_score_target(2), normal * 2);
+        assert_eq!(
+            blind::score_target(3, &BlindType::Boss(BossBlind::TheWall), Stake::White),
+            blind::score_target(3, &BlindType::Boss(BossBlind::TheHook), Stake::White) * 2
+        );
+    }
+
+    #[test]
+    fn test_practice_boss_starts_at_requested_boss() {
+        let mut run = RunState::practice_boss(3, 4, BossBlind::TheWall);
+
+        assert_eq!(run.ante, 4);
+        assert_eq!(run.boss_blind, BossBlind::TheWall);
+        assert_eq!(run.blind_type, BlindType::Boss(BossBlind::TheWall));
+        assert_eq!(run.ante_phase, AntePhase::Playing);
+        assert_eq!(run.blind_outcomes[2], BlindOutcome::Active);
+        assert_eq!(run.hand.len(), run.hand_size as usize);
+        assert_eq!(
+            run.score_target,
+            blind::score_target(4, &BlindType::Boss(BossBlind::TheWall), Stake::White)
+        );
+        run.toggle_select(0);
+        assert!(run.can_play());
+
+        // Same seed, same opening hand
+        let again = RunState::practice_boss(3, 4, BossBlind::TheWall);
+        assert_eq!(run.hand, again.hand);
+        assert_eq!(run.money, PracticeSetup::for_ante(4).money);
+    }
+
+    #[test]
+    fn test_the_fish_draws_face_down_after_a_play() {
+        let mut run = RunState::practice_boss(3, 1, BossBlind::TheFish);
+        assert!(run.hand.iter().all(|c| !c.face_down));
+
+        // Discard draws stay face up
+        run.toggle_select(0);
+        run.discard_selected();
+        assert!(run.hand.iter().all(|c| !c.face_down));
+
+        run.toggle_select(0);
+        run.toggle_select(1);
+        let played = run.play_selected();
+        run.draw_to_hand_size();
+        assert!(played.iter().all(|c| !c.face_down));
+        let hidden = run.hand.iter().filter(|c| c.face_down).count();
+        assert_eq!(hidden, 2);
+        assert!(run.hand[..run.hand.len() - 2].iter().all(|c| !c.face_down));
+
+        // The next play reveals them
+        run.toggle_select(0);
+        run.play_selected();
+        assert!(run.hand.iter().all(|c| !c.face_down));
+    }
+
+    #[test]
+    fn test_the_manacle_shrinks_hand_until_blind_beaten() {
+        let mut run = RunState::practice_boss(3, 1, BossBlind::TheManacle);
+        assert_eq!(run.hand_size, 7);
+        assert_eq!(run.hand.len(), 7);
+
+        run.toggle_select(0);
+        run.play_selected();
+        run.draw_to_hand_size();
+        assert_eq!(run.hand.len(), 7);
+
+        run.beat_blind();
+        assert_eq!(run.hand_size, 8);
+    }
+
+    #[test]
+    fn test_round_lost_only_without_hands_and_below_target() {
+        let mut run = RunState::with_seed(6);
+        run.start_blind();
+        assert!(!run.round_lost());
+
+        run.hands_remaining = 0;
+        run.round_score = run.score_target - 1;
+        assert!(run.round_lost());
+        assert!(run
+            .outcome_line()
+            .starts_with("Ran out of hands on Small Blind"));
+
+        run.add_score(1);
+        assert!(run.blind_beaten());
+        assert!(!run.round_lost());
+    }
+
+    #[test]
+    fn test_restarted_run_keeps_seed_and_deck() {
+        let mut run = RunState::with_seed_string_and_deck("BALA1234", DeckType::Red);
+        let fresh = RunState::with_seed_string_and_deck("BALA1234", DeckType::Red);
+        run.start_blind();
+        run.money = 99;
+
+        let restarted = run.restarted();
+        assert_eq!(restarted.seed_label(), "BALA1234");
+        assert_eq!(restarted.deck_type, DeckType::Red);
+        assert_eq!(restarted.money, fresh.money);
+        assert_eq!(restarted.full_deck(), fresh.full_deck());
+        assert_eq!(restarted.boss_blind, fresh.boss_blind);
+    }
+
+    #[test]
+    fn test_move_joker_reorders_jokers() {
+        let mut run = RunState::with_seed(1);
+        run.jokers = [JokerType::Joker, JokerType::Blueprint, JokerType::Mime]
+            .into_iter()
+            .map(Joker::new)
+            .collect();
+
+        assert!(run.move_joker(0, 2));
+        let order: Vec<JokerType> = run.jokers.iter().map(|j| j.joker_type).collect();
+        assert_eq!(
+            order,
+            vec![JokerType::Blueprint, JokerType::Mime, JokerType::Joker]
+        );
+
+        assert!(!run.move_joker(0, 3));
+        assert_eq!(run.jokers[0].joker_type, JokerType::Blueprint);
+    }
+
+    #[test]
+    fn test_full_deck_order_is_deterministic() {
+        let mut run = RunState::with_seed(21);
+        run.start_blind();
+        let at_start = run.full_deck();
+        assert_eq!(at_start.len(), 52);
+        assert_eq!(at_start[0], PlayingCard::new(Rank::Ace, Suit::Spades));
+        assert_eq!(at_start[51], PlayingCard::new(Rank::Two, Suit::Clubs));
+
+        // Playing, discarding and drawing move cards around but not the order
+        run.selected_indices = vec![0, 1];
+        run.discard_selected();
+        run.draw_to_hand_size();
+        play_first_card(&mut run);
+        run.draw_to_hand_size();
+        assert_eq!(run.full_deck(), at_start);
+
+        let mut other = RunState::with_seed(99);
+        other.start_blind();
+        assert_eq!(other.full_deck(), at_start);
+    }
+
+    #[test]
+    fn test_preview_of_a_pair_includes_joker_mult() {
+        let mut run = RunState::with_seed(3);
+        run.start_blind();
+        run.hand[0] = PlayingCard::new(Rank::Four, Suit::Clubs);
+        run.hand[1] = PlayingCard::new(Rank::Four, Suit::Hearts);
+        run.selected_indices = vec![0, 1];
+
+        let plain = run.preview_selected().unwrap();
+        assert_eq!(plain.hand_type, PokerHand::Pair);
+        assert_eq!((plain.total_chips, plain.total_mult), (18, 2));
+
+        run.jokers.push(Joker::new(JokerType::Joker));
+        let rng_before = run.rng.clone();
+        let preview = run.preview_selected().unwrap();
+        assert_eq!((preview.total_chips, preview.total_mult), (18, 6));
+        assert_eq!(preview.final_score, 108);
+        // Previewing never advances the run RNG
+        assert_eq!(run.rng, rng_before);
+    }
+
+    #[test]
+    fn test_score_log_keeps_each_played_hand() {
+        let mut run = RunState::with_seed(3);
+        run.start_blind();
+        run.jokers.push(Joker::new(JokerType::Joker));
+        let first = play_first_card(&mut run);
+        run.draw_to_hand_size();
+        let second = play_first_card(&mut run);
+
+        assert_eq!(run.score_log.len(), 2);
+        assert_eq!(run.score_log[0].result.final_score, first.final_score);
+        assert_eq!(run.score_log[1].result.final_score, second.final_score);
+        let lines = run.score_log[1].contribution_lines();
+        assert!(lines[0].starts_with("High Card"));
+        assert!(lines.iter().any(|l| l == "Joker: +4 mult"));
+    }
+
+    #[test]
+    fn test_score_log_is_bounded() {
+        let mut run = RunState::with_seed(3);
+        run.start_blind();
+        run.hands_remaining = SCORE_LOG_LEN as u8 + 5;
+        for _ in 0..SCORE_LOG_LEN + 3 {
+            play_first_card(&mut run);
+            run.draw_to_hand_size();
+        }
+        assert_eq!(run.score_log.len(), SCORE_LOG_LEN);
+    }
+
+    #[test]
+    fn test_last_hand_shortfall_is_the_gap_left_after_the_selection() {
+        let mut run = RunState::with_seed(5);
+        run.start_blind();
+        run.hand[0] = PlayingCard::new(Rank::King, Suit::Spades);
+        run.hand[1] = PlayingCard::new(Rank::King, Suit::Hearts);
+        run.selected_indices = vec![0, 1];
+        // Pair of Kings: (10 + 20) X 2 = 60
+        assert_eq!(run.preview_selected().unwrap().final_score, 60);
+        run.score_target = 300;
+        run.round_score = 200;
+
+        // Not the last hand yet
+        assert_eq!(run.last_hand_shortfall(), None);
+
+        run.hands_remaining = 1;
+        assert_eq!(run.last_hand_shortfall(), Some(40));
+        run.round_score = 240;
+        assert_eq!(run.last_hand_shortfall(), None);
+
+        run.selected_indices.clear();
+        run.round_score = 0;
+        assert_eq!(run.last_hand_shortfall(), None);
+    }
+
+    #[test]
+    fn test_the_tooth_costs_a_dollar_per_card_played() {
+        let mut run = boss_run(BossBlind::TheTooth);
+        run.money = 10;
+        run.selected_indices = vec![0, 1, 2, 3];
+        run.play_selected();
+        assert_eq!(run.money, 6);
+
+        run.money = 2;
+        run.selected_indices = vec![0, 1, 2, 3];
+        run.play_selected();
+        assert_eq!(run.money, 0);
+    }
+
+    #[test]
+    fn test_bootstraps_under_the_tooth_counts_money_after_the_toll() {
+        let mut run = boss_run(BossBlind::TheTooth);
+        run.jokers.push(Joker::new(JokerType::Bootstraps));
+        run.money = 10;
+        run.selected_indices = vec![0];
+        let preview = run.preview_selected().unwrap();
+
+        let played = run.play_selected();
+        let result = run.score_played(&played);
+        // $9 after the toll: +2 Mult, not the +4 that $10 would give
+        assert!(result
+            .steps
+            .iter()
+            .any(|s| matches!(s, ScoreStep::JokerMult { mult: 2, .. })));
+        assert_eq!(preview.final_score, result.final_score);
+    }
+
+    #[test]
+    fn test_the_arm_scores_the_played_hand_one_level_lower() {
+        let mut run = boss_run(BossBlind::TheArm);
+        run.hand_levels.level_up(PokerHand::Pair);
+        run.hand_levels.level_up(PokerHand::Pair);
+        run.hand[0] = PlayingCard::new(Rank::Four, Suit::Clubs);
+        run.hand[1] = PlayingCard::new(Rank::Four, Suit::Hearts);
+        run.selected_indices = vec![0, 1];
+        let played = run.play_selected();
+        let result = run.score_played(&played);
+
+        // Level 3 Pair scores as level 2, plus the two Fours' chips
+        let mut level_two = HandLevels::new();
+        level_two.level_up(PokerHand::Pair);
+        assert_eq!(
+            result.total_chips,
+            level_two.chips_for(&PokerHand::Pair) + 8
+        );
+        assert_eq!(result.total_mult, level_two.mult_for(&PokerHand::Pair));
+        // Only this hand's scoring is affected
+        assert_eq!(run.hand_levels.get_level(&PokerHand::Pair), 3);
+        // A level-1 hand stays at level 1
+        assert_eq!(
+            run.scoring_levels(PokerHand::Flush)
+                .get_level(&PokerHand::Flush),
+            1
+        );
+    }
+
+    #[test]
+    fn test_the_plant_debuffs_only_face_cards() {
+        let mut run = boss_run(BossBlind::ThePlant);
+        run.hand[0] = PlayingCard::new(Rank::Jack, Suit::Clubs);
+        run.hand[1] = PlayingCard::new(Rank::Queen, Suit::Hearts);
+        run.hand[2] = PlayingCard::new(Rank::King, Suit::Spades);
+        run.hand[3] = PlayingCard::new(Rank::Ten, Suit::Diamonds);
+        run.hand[4] = PlayingCard::new(Rank::Ace, Suit::Clubs);
+        run.apply_boss_debuffs();
+
+        assert!(run.hand[..3].iter().all(|c| c.debuffed));
+        assert!(!run.hand[3].debuffed && !run.hand[4].debuffed);
+    }
+
+    #[test]
+    fn test_the_eye_blocks_repeating_a_hand_type() {
+        let mut run = boss_run(BossBlind::TheEye);
+        run.hand[0] = PlayingCard::new(Rank::King, Suit::Clubs);
+        run.hand[1] = PlayingCard::new(Rank::King, Suit::Hearts);
+        run.selected_indices = vec![0, 1];
+        assert!(run.can_play());
+        play_selection(&mut run, &[0, 1]);
+        assert_eq!(run.played_hand_types, vec![PokerHand::Pair]);
+
+        run.hand[0] = PlayingCard::new(Rank::Five, Suit::Clubs);
+        run.hand[1] = PlayingCard::new(Rank::Five, Suit::Spades);
+        run.selected_indices = vec![0, 1];
+        assert!(!run.can_play());
+        run.selected_indices = vec![0];
+        assert!(run.can_play());
+
+        // A new round forgets what was played
+        run.start_blind();
+        assert!(run.played_hand_types.is_empty());
+    }
+
+    #[test]
+    fn test_the_mouth_allows_only_the_first_hand_type() {
+        let mut run = boss_run(BossBlind::TheMouth);
+        run.hand[0] = PlayingCard::new(Rank::Two, Suit::Clubs);
+        play_selection(&mut run, &[0]);
+
+        run.hand[0] = PlayingCard::new(Rank::Nine, Suit::Clubs);
+        run.hand[1] = PlayingCard::new(Rank::Nine, Suit::Hearts);
+        run.selected_indices = vec![0, 1];
+        assert!(!run.can_play());
+        run.selected_indices = vec![0];
+        assert!(run.can_play());
+    }
+
+    #[test]
+    fn test_the_needle_still_allows_discards() {
+        let mut run = boss_run(BossBlind::TheNeedle);
+        assert_eq!(run.hands_remaining, 1);
+        run.selected_indices = vec![0];
+        assert!(run.can_discard());
+        run.discard_selected();
+        assert_eq!(run.hands_remaining, 1);
+    }
+
+    #[test]
+    fn test_practice_boss_with_custom_loadout() {
+        let setup = PracticeSetup {
+            money: 50,
+            jokers: vec![JokerType::Joker, JokerType::SteelJoker],
+        };
+        let run = RunState::practice_boss_with(3, 2, BossBlind::TheNeedle, setup);
+
+        assert_eq!(run.money, 50);
+        assert_eq!(run.jokers.len(), 2);
+        assert_eq!(run.hands_remaining, 1);
+    }
+
+    #[test]
+    fn test_sandbox_run_uses_the_built_deck() {
+        let mut setup = SandboxSetup::new();
+        let mut cards = Vec::new();
+        for (i, rank) in Rank::ALL.iter().take(10).enumerate() {
+            let mut card = PlayingCard::new(*rank, Suit::ALL[i % 4]);
+            if i % 3 == 0 {
+                card.enhancement = Some(Enhancement::Glass);
+            }
+            if i == 4 {
+                card.seal = Some(Seal::Red);
+                card.edition = Edition::Foil;
+            }
+            setup.deck.add_card(card);
+            cards.push(card);
+        }
+        setup.jokers = vec![Joker::new(JokerType::Fibonacci)];
+        let mut run = RunState::sandbox(5, setup);
+
+        cards.sort_by_key(PlayingCard::display_order_key);
+        assert_eq!(run.full_deck(), cards);
+        assert_eq!(run.jokers.len(), 1);
+
+        run.start_blind();
+        assert_eq!(run.hand.len(), 8);
+        assert_eq!(run.full_deck(), cards);
+    }
+
+    #[test]
+    fn test_small_sandbox_deck_loses_once_the_cards_run_out() {
+        let mut setup = SandboxSetup::new();
+        for rank in Rank::ALL.iter().take(10) {
+            setup.deck.add_card(PlayingCard::new(*rank, Suit::Spades));
+        }
+        let mut run = RunState::sandbox(5, setup);
+        run.start_blind();
+
+        for _ in 0..2 {
+            assert!(!run.round_lost());
+            run.selected_indices = (0..5).collect();
+            run.play_selected();
+            run.use_hand();
+            run.draw_to_hand_size();
+        }
+        assert!(run.hand.is_empty());
+        assert!(run.hands_remaining > 0);
+        assert!(run.round_lost());
+    }
+
+    #[test]
+    fn test_sandbox_drops_jokers_past_the_slot_limit() {
+        let mut setup = SandboxSetup::standard();
+        setup.jokers = vec![Joker::new(JokerType::Joker); 6];
+        setup
+            .jokers
+            .push(Joker::new(JokerType::Joker).with_edition(Edition::Negative));
+        let run = RunState::sandbox(5, setup);
+
+        assert_eq!(run.jokers.len(), 6);
+        assert_eq!(run.full_deck().len(), 52);
+    }
+
+    #[test]
+    fn test_describe_text_includes_blind_target_and_hand() {
+        let mut run = RunState::with_seed(7);
+        run.start_blind();
+        let text = run.describe_text();
+
+        assert!(text.contains("Small Blind"));
+        assert!(text.contains(&format!("Target: {} chips", run.score_target)));
+        for card in &run.hand {
+            let name = format!("{} of {}", card.rank.name(), card.suit.name());
+            assert!(text.contains(&name), "missing {}", name);
+        }
+    }
+
+    #[test]
+    fn test_in_round_save_restores_hand_and_selection() {
+        let mut run = RunState::with_seed(11);
+        run.start_blind();
+        run.toggle_select(1);
+        run.toggle_select(4);
+        run.use_discard();
+        run.discard_selected();
+        run.toggle_select(0);
+        run.toggle_select(2);
+        run.use_hand();
+
+        let path = temp_save_path("in-round");
+        run.save_to_path(&path).unwrap();
+        let mut loaded = RunState::load_from_path(&path).unwrap();
+        std::fs::remove_file(&path).ok();
+
+        assert_eq!(loaded.ante_phase, AntePhase::Playing);
+        assert_eq!(loaded.hand, run.hand);
+        assert_eq!(loaded.selected_indices, run.selected_indices);
+        assert_eq!(loaded.hands_remaining, run.hands_remaining);
+        assert_eq!(loaded.discards_remaining, run.discards_remaining);
+        assert_eq!(loaded.deck.draw_pile(), run.deck.draw_pile());
+
+        // The RNG resumes at the same position
+        assert_eq!(loaded.rng.gen::<u64>(), run.rng.gen::<u64>());
+    }
+    #[test]
+    fn test_save_round_trip_preserves_all_fields() {
+        let mut run = RunState::with_seed(77);
+        run.jokers.push(Joker::new(JokerType::Bootstraps));
+        run.consumables
+            .push(Consumable::planet(PlanetCard::Jupiter));
+        run.redeem_voucher(Voucher::Grabber);
+        run.hand_levels.level_up(PokerHand::Flush);
+        run.start_blind();
+        run.hand[0].seal = Some(Seal::Red);
+        run.toggle_select(0);
+
+        let path = temp_save_path("round-trip");
+        run.save_to_path(&path).unwrap();
+        let mut loaded = RunState::load_from_path(&path).unwrap();
+        std::fs::remove_file(&path).ok();
+
+        let as_value = |r: &RunState| {
+            let mut value = serde_json::to_value(r).unwrap();
+            value.as_object_mut().unwrap().remove("rng");
+            value
+        };
+        assert_eq!(as_value(&loaded), as_value(&run));
+
+        // Subsequent draws reproduce exactly
+        assert_eq!(loaded.rng.gen::<u64>(), run.rng.gen::<u64>());
+    }
+
+    #[test]
+    fn test_endless_targets_keep_scaling_past_ante_8() {
+        for blind_type in [
+            BlindType::Small,
+            BlindType::Big,
+            BlindType::Boss(BossBlind::TheHook),
+        ] {
+            assert!(
+                blind::score_target(9, &blind_type, Stake::White)
+                    > blind::score_target(8, &blind_type, Stake::White)
+            );
+        }
+        assert_eq!(blind::ante_base_chips(9, Stake::White), 110_000);
+        let targets: Vec<u64> = (9..=15)
+            .map(|ante| blind::ante_base_chips(ante, Stake::White))
+            .collect();
+        assert!(targets.windows(2).all(|w| w[0] < w[1]));
+        // From ante 16 the curve outgrows u64 and saturates instead of overflowing
+        assert_eq!(blind::ante_base_chips(16, Stake::White), u64::MAX);
+        assert_eq!(blind::ante_base_chips(u8::MAX, Stake::White), u64::MAX);
+        assert_eq!(
+            blind::score_target(u8::MAX, &BlindType::Boss(BossBlind::TheWall), Stake::White),
+            u64::MAX
+        );
+    }
+
+    #[test]
+    fn test_endless_mode_plays_on_past_ante_8() {
+        let mut run = RunState::practice_boss(5, WINNING_ANTE, BossBlind::TheHook);
+        run.beat_blind();
+        assert!(run.run_won());
+
+        run.continue_endless();
+        assert!(!run.run_won());
+        let ante_8_target = run.score_target;
+        run.leave_shop();
+        assert_eq!(run.ante, 9);
+        assert!(run.score_target > ante_8_target);
+        assert!(!run.run_won());
+
+        // Beating ante 9's boss moves on to ante 10
+        for _ in 0..3 {
+            run.start_blind();
+            run.beat_blind();
+            assert!(!run.run_won());
+            run.leave_shop();
+        }
+        assert_eq!(run.ante, 10);
+        assert_eq!(run.ante_reached(), 10);
+    }
+}
@@ -1,8 +1,17 @@
 use std::collections::HashMap;
 
-use crate::card::PlayingCard;
-use crate::hand::{detect_hand, PokerHand};
-use crate::joker::{evaluate_joker, Joker, JokerContext, JokerEffect, JokerType};
+use rand::{Rng, RngCore};
+use serde::{Deserialize, Serialize};
+
+use crate::card::{Edition, PlayingCard};
+use crate::format::format_number;
+use crate::hand::{detect_hand, detect_hand_with, HandRules, PokerHand};
+use crate::joker::{
+    copy_target_index, evaluate_joker, held_retriggers, Joker, JokerContext, JokerEffect, JokerType,
+};
+
+/// X-Mult each held Planet gives its own hand with the Observatory voucher
+pub const OBSERVATORY_X_MULT: f64 = 1.5;
 
 /// A single step in the scoring process, used for animation.
 /// Each step represents one visual "beat" in the scoring sequence.
@@ -20,12 +29,34 @@ pub enum ScoreStep {
     CardMult { card_index: usize, mult: u64 },
     /// A played card applies multiplicative mult (glass/polychrome)
     CardXMult { card_index: usize, x_mult: f64 },
+    /// A played card's edition: Foil chips, Holographic mult or Polychrome x-mult
+    CardEdition { card_index: usize, edition: Edition },
+    /// A card held in hand applies multiplicative mult (Steel)
+    HeldCardXMult { held_index: usize, x_mult: f64 },
+    /// A Lucky card hit its 1 in 5 roll for bonus mult
+    LuckyMult { card_index: usize, mult: u64 },
+    /// A Lucky card hit its 1 in 15 roll for bonus money
+    LuckyMoney { card_index: usize, money: u32 },
+    /// A Red seal scores the card again
+    SealRetrigger { card_index: usize },
+    /// A joker (Hack) scores the card again
+    JokerRetrigger {
+        joker_index: usize,
+        card_index: usize,
+    },
+    /// A played card pays money when scored (Gold seal)
+    CardMoney { card_index: usize, money: u32 },
     /// A joker adds flat chips (global effect, not per-card)
     JokerChips { joker_index: usize, chips: u64 },
     /// A joker adds flat mult (global effect, not per-card)
     JokerMult { joker_index: usize, mult: u64 },
     /// A joker applies multiplicative mult
     JokerXMult { joker_index: usize, x_mult: f64 },
+    /// A held Planet card for the played hand applies multiplicative mult (Observatory)
+    PlanetXMult {
+        consumable_index: usize,
+        x_mult: f64,
+    },
     /// A joker adds chips triggered by a specific card (per-card effect)
     JokerCardChips {
         joker_index: usize,
@@ -38,6 +69,17 @@ pub enum ScoreStep {
         card_index: usize,
         mult: u64,
     },
+    /// A joker applies multiplicative mult triggered by a specific card
+    JokerCardXMult {
+        joker_index: usize,
+        card_index: usize,
+        x_mult: f64,
+    },
+    /// A joker's edition: Foil chips, Holographic mult or Polychrome x-mult
+    JokerEdition {
+        joker_index: usize,
+        edition: Edition,
+    },
 }
 
 impl ScoreStep {
@@ -48,15 +90,32 @@ impl ScoreStep {
             ScoreStep::CardChips { chips, .. } => format!("+{}", chips),
             ScoreStep::CardMult { mult, .. } => format!("+{}", mult),
             ScoreStep::CardXMult { x_mult, .. } => format!("X{}", x_mult),
+            ScoreStep::CardEdition { edition, .. } | ScoreStep::JokerEdition { edition, .. } => {
+                match edition {
+                    Edition::Base | Edition::Negative => String::new(),
+                    Edition::Foil => format!("+{}", edition.chips()),
+                    Edition::Holographic => format!("+{}", edition.mult()),
+                    Edition::Polychrome => format!("X{}", edition.x_mult()),
+                }
+            }
+            ScoreStep::HeldCardXMult { x_mult, .. } => format!("X{}", x_mult),
+            ScoreStep::LuckyMult { mult, .. } => format!("+{} Mult!", mult),
+            ScoreStep::LuckyMoney { money, .. } => format!("+${}", money),
+            ScoreStep::SealRetrigger { .. } | ScoreStep::JokerRetrigger { .. } => {
+                "Again!".to_string()
+            }
+            ScoreStep::CardMoney { money, .. } => format!("+${}", money),
             ScoreStep::JokerChips { chips, .. } => format!("+{}", chips),
             ScoreStep::JokerMult { mult, .. } => format!("+{}", mult),
             ScoreStep::JokerXMult { x_mult, .. } => format!("X{}", x_mult),
+            ScoreStep::PlanetXMult { x_mult, .. } => format!("X{}", x_mult),
             ScoreStep::JokerCardChips { chips, .. } => format!("+{}", chips),
             ScoreStep::JokerCardMult { mult, .. } => format!("+{}", mult),
+            ScoreStep::JokerCardXMult { x_mult, .. } => format!("X{}", x_mult),
         }
     }
 
-    /// Returns the color category for the popup: "chips", "mult", or "xmult"
+    /// Returns the color category for the popup: "chips", "mult", "xmult", or "money"
     pub fn popup_kind(&self) -> &'static str {
         match self {
             ScoreStep::BaseHand { .. } => "chips",
@@ -64,9 +123,24 @@ impl ScoreStep {
             | ScoreStep::JokerChips { .. }
             | ScoreStep::JokerCardChips { .. } => "chips",
             ScoreStep::CardMult { .. }
+            | ScoreStep::LuckyMult { .. }
+            | ScoreStep::SealRetrigger { .. }
+            | ScoreStep::JokerRetrigger { .. }
             | ScoreStep::JokerMult { .. }
             | ScoreStep::JokerCardMult { .. } => "mult",
-            ScoreStep::CardXMult { .. } | ScoreStep::JokerXMult { .. } => "xmult",
+            ScoreStep::CardXMult { .. }
+            | ScoreStep::HeldCardXMult { .. }
+            | ScoreStep::JokerXMult { .. }
+            | ScoreStep::PlanetXMult { .. }
+            | ScoreStep::JokerCardXMult { .. } => "xmult",
+            ScoreStep::CardMoney { .. } | ScoreStep::LuckyMoney { .. } => "money",
+            ScoreStep::CardEdition { edition, .. } | ScoreStep::JokerEdition { edition, .. } => {
+                match edition {
+                    Edition::Base | Edition::Foil | Edition::Negative => "chips",
+                    Edition::Holographic => "mult",
+                    Edition::Polychrome => "xmult",
+                }
+            }
         }
     }
 }
@@ -81,10 +155,146 @@ pub struct ScoreResult {
     pub total_chips: u64,
     pub total_mult: u64,
     pub final_score: u64,
+    /// Money paid out while scoring (Gold seals), applied when the hand resolves
+    pub money_earned: u32,
 }
 
-/// Hand level state: tracks the level of each poker hand
+/// A scored hand kept for the score log, with the cards and jokers its
+/// steps point into
 #[derive(Debug, Clone)]
+pub struct ScoredHand {
+    pub played: Vec<PlayingCard>,
+    pub jokers: Vec<JokerType>,
+    pub result: ScoreResult,
+}
+
+impl ScoredHand {
+    /// "Pair  K♠ K♥  20 X 2 = 40"
+    pub fn summary_line(&self) -> String {
+        let cards: Vec<String> = self.played.iter().map(|c| c.to_string()).collect();
+        format!(
+            "{}  {}  {} X {} = {}",
+            self.result.hand_type,
+            cards.join(" "),
+            format_number(self.result.total_chips),
+            format_number(self.result.total_mult),
+            format_number(self.result.final_score)
+        )
+    }
+
+    /// One line per scoring step, in the order they applied
+    pub fn contribution_lines(&self) -> Vec<String> {
+        let card = |i: &usize| {
+            self.played
+                .get(*i)
+                .map_or_else(|| "?".to_string(), |c| c.to_string())
+        };
+        let joker = |i: &usize| self.jokers.get(*i).map_or("?", |j| j.name());
+        self.result
+            .steps
+            .iter()
+            .map(|step| match step {
+                ScoreStep::BaseHand {
+                    hand_type,
+                    chips,
+                    mult,
+                } => format!("{}: {} chips, {} mult", hand_type, chips, mult),
+                ScoreStep::CardChips { card_index, chips } => {
+                    format!("{}: +{} chips", card(card_index), chips)
+                }
+                ScoreStep::CardMult { card_index, mult } => {
+                    format!("{}: +{} mult", card(card_index), mult)
+                }
+                ScoreStep::CardXMult { card_index, x_mult } => {
+                    format!("{}: X{} mult", card(card_index), x_mult)
+                }
+                ScoreStep::CardEdition {
+                    card_index,
+                    edition,
+                } => format!(
+                    "{} {}: {}",
+                    card(card_index),
+                    edition.name(),
+                    step.popup_text()
+                ),
+                ScoreStep::HeldCardXMult { x_mult, .. } => {
+                    format!("Held card: X{} mult", x_mult)
+                }
+                ScoreStep::LuckyMult { card_index, mult } => {
+                    format!("{} Lucky: +{} mult", card(card_index), mult)
+                }
+                ScoreStep::LuckyMoney { card_index, money } => {
+                    format!("{} Lucky: +${}", card(card_index), money)
+                }
+                ScoreStep::SealRetrigger { card_index } => {
+                    format!("{} Red seal: again", card(card_index))
+                }
+                ScoreStep::JokerRetrigger {
+                    joker_index,
+                    card_index,
+                } => format!("{}: {} again", joker(joker_index), card(card_index)),
+                ScoreStep::CardMoney { card_index, money } => {
+                    format!("{} Gold seal: +${}", card(card_index), money)
+                }
+                ScoreStep::JokerChips { joker_index, chips } => {
+                    format!("{}: +{} chips", joker(joker_index), chips)
+                }
+                ScoreStep::JokerMult { joker_index, mult } => {
+                    format!("{}: +{} mult", joker(joker_index), mult)
+                }
+                ScoreStep::JokerXMult {
+                    joker_index,
+                    x_mult,
+                } => format!("{}: X{} mult", joker(joker_index), x_mult),
+                ScoreStep::PlanetXMult { x_mult, .. } => {
+                    format!("Observatory: X{} mult", x_mult)
+                }
+                ScoreStep::JokerCardChips {
+                    joker_index,
+                    card_index,
+                    chips,
+                } => format!(
+                    "{} ({}): +{} chips",
+                    joker(joker_index),
+                    card(card_index),
+                    chips
+                ),
+                ScoreStep::JokerCardMult {
+                    joker_index,
+                    card_index,
+                    mult,
+                } => format!(
+                    "{} ({}): +{} mult",
+                    joker(joker_index),
+                    card(card_index),
+                    mult
+                ),
+                ScoreStep::JokerCardXMult {
+                    joker_index,
+                    card_index,
+                    x_mult,
+                } => format!(
+                    "{} ({}): X{} mult",
+                    joker(joker_index),
+                    card(card_index),
+                    x_mult
+                ),
+                ScoreStep::JokerEdition {
+                    joker_index,
+                    edition,
+                } => format!(
+                    "{} {}: {}",
+                    joker(joker_index),
+                    edition.name(),
+                    step.popup_text()
+                ),
+            })
+            .collect()
+    }
+}
+
+/// Hand level state: tracks the level of each poker hand
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct HandLevels {
     levels: HashMap<PokerHand, u8>,
 }
@@ -113,6 +323,12 @@ impl HandLevels {
         *entry += 1;
     }
 
+    /// Drop `hand` one level, never below level 1
+    pub fn level_down(&mut self, hand: PokerHand) {
+        let entry = self.levels.entry(hand).or_insert(1);
+        *entry = entry.saturating_sub(1).max(1);
+    }
+
     /// Get chips for a hand at its current level
     pub fn chips_for(&self, hand: &PokerHand) -> u64 {
         let level = self.get_level(hand);
@@ -127,7 +343,8 @@ impl HandLevels {
 }
 
 /// Calculate the score for a set of played cards.
-/// This is the core scoring function without joker effects.
+/// This is the core scoring function without joker effects; chance-based
+/// effects (Lucky cards) never trigger here.
 pub fn calculate_score(played_cards: &[PlayingCard], hand_levels: &HandLevels) -> ScoreResult {
     let hand_result = detect_hand(played_cards);
     let hand_type = hand_result.hand_type;
@@ -149,56 +366,21 @@ pub fn calculate_score(played_cards: &[PlayingCard], hand_levels: &HandLevels) -
     let mut total_mult_f: f64 = base_mult as f64;
 
     // Step 2: Process each scoring card
-    for &idx in &scoring_indices {
-        let card = &played_cards[idx];
-
-        // Card chip value
-        let card_chips = card.chip_value();
-        if card_chips > 0 {
-            steps.push(ScoreStep::CardChips {
-                card_index: idx,
-                chips: card_chips,
-            });
-            total_chips += card_chips;
-        }
-
-        // Card flat mult bonus
-        let card_mult = card.mult_bonus();
-        if card_mult > 0 {
-            steps.push(ScoreStep::CardMult {
-                card_index: idx,
-                mult: card_mult,
-            });
-            total_mult_f += card_mult as f64;
-        }
-
-        // Card multiplicative mult
-        let card_x_mult = card.x_mult();
-        if (card_x_mult - 1.0).abs() > f64::EPSILON {
-            steps.push(ScoreStep::CardXMult {
-                card_index: idx,
-                x_mult: card_x_mult,
-            });
-            total_mult_f *= card_x_mult;
-        }
-    }
-
-    // Also process cards that always score (Stone cards) but aren't in the hand
-    for (idx, card) in played_cards.iter().enumerate() {
-        if card.always_scores() && !scoring_indices.contains(&idx) {
-            let card_chips = card.chip_value();
-            if card_chips > 0 {
-                steps.push(ScoreStep::CardChips {
-                    card_index: idx,
-                    chips: card_chips,
-                });
-                total_chips += card_chips;
-            }
-        }
-    }
+    let mut money_earned = 0;
+    let no_joker_retriggers = vec![Vec::new(); played_cards.len()];
+    score_played_cards(
+        played_cards,
+        &scoring_indices,
+        &no_joker_retriggers,
+        &mut total_chips,
+        &mut total_mult_f,
+        &mut money_earned,
+        &mut steps,
+        None,
+    );
 
     let total_mult = total_mult_f.ceil() as u64;
-    let final_score = total_chips * total_mult;
+    let final_score = total_chips.saturating_mul(total_mult);
 
     ScoreResult {
         hand_type,
@@ -207,19 +389,57 @@ pub fn calculate_score(played_cards: &[PlayingCard], hand_levels: &HandLevels) -
         total_chips,
         total_mult,
         final_score,
+        money_earned,
     }
 }
 
+/// The rest of the round as it stands when a hand is played, for scoring
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ScoringContext<'a> {
+    /// Cards left in hand (Steel cards, held-card jokers)
+    pub held_cards: &'a [PlayingCard],
+    pub discards_remaining: u8,
+    /// Money once the hand is played (after The Tooth's toll) but before
+    /// anything it earns, for money-scaling jokers
+    pub money: u32,
+    /// Consumable slots of Planets for the played hand that Observatory
+    /// turns into X1.5 Mult (empty without it)
+    pub held_planets: &'a [usize],
+}
+
 /// Calculate the score with joker effects applied.
-/// This is the full scoring pipeline used during gameplay.
-pub fn calculate_score_with_jokers(
+/// This is the full scoring pipeline used during gameplay. Lucky card rolls
+/// draw from `rng` (the run RNG) so a seeded run always scores the same.
+pub fn calculate_score_with_jokers<R: Rng>(
     played_cards: &[PlayingCard],
     hand_levels: &HandLevels,
     jokers: &[Joker],
-    held_cards: &[PlayingCard],
-    discards_remaining: u8,
+    round: &ScoringContext,
+    rng: &mut R,
 ) -> ScoreResult {
-    let hand_result = detect_hand(played_cards);
+    score_with_jokers(played_cards, hand_levels, jokers, round, Some(rng))
+}
+
+/// Projected score for a hand before it is played: the same pipeline as
+/// `calculate_score_with_jokers`, but Lucky cards never roll and no RNG is touched
+pub fn preview(
+    played_cards: &[PlayingCard],
+    hand_levels: &HandLevels,
+    jokers: &[Joker],
+    round: &ScoringContext,
+) -> ScoreResult {
+    score_with_jokers(played_cards, hand_levels, jokers, round, None)
+}
+
+fn score_with_jokers(
+    played_cards: &[PlayingCard],
+    hand_levels: &HandLevels,
+    jokers: &[Joker],
+    round: &ScoringContext,
+    rng: Option<&mut dyn RngCore>,
+) -> ScoreResult {
+    let held_cards = round.held_cards;
+    let hand_result = detect_hand_with(played_cards, HandRules::from_jokers(jokers));
     let hand_type = hand_result.hand_type;
     let scoring_indices = hand_result.scoring_indices;
 
@@ -238,75 +458,99 @@ pub fn calculate_score_with_jokers(
     let mut total_chips = base_chips;
     let mut total_mult_f: f64 = base_mult as f64;
 
-    // Step 2: Process each scoring card
-    for &idx in &scoring_indices {
-        let card = &played_cards[idx];
+    // Jokers are evaluated up front: their effects only depend on the cards,
+    // and retrigger jokers (Hack) must be known before any card scores
+    let ctx = JokerContext {
+        played_cards,
+        scoring_indices: &scoring_indices,
+        hand_type,
+        held_cards,
+        discards_remaining: round.discards_remaining,
+        num_played: played_cards.len(),
+        first_scored_index: scoring_indices.iter().min().copied(),
+        pareidolia: jokers.iter().any(|j| j.joker_type == JokerType::Pareidolia),
+        money: round.money,
+    };
+    let effects: Vec<JokerEffect> = jokers
+        .iter()
+        .enumerate()
+        .map(|(ji, joker)| {
+            let copied = if joker.joker_type.is_copier() {
+                copy_target_index(jokers, ji).map(|i| &jokers[i])
+            } else {
+                None
+            };
+            evaluate_joker(joker, &ctx, copied)
+        })
+        .collect();
 
-        let card_chips = card.chip_value();
-        if card_chips > 0 {
-            steps.push(ScoreStep::CardChips {
-                card_index: idx,
-                chips: card_chips,
-            });
-            total_chips += card_chips;
-        }
+    let joker_retriggers = joker_retriggers(&effects, played_cards.len());
+    let triggers = trigger_counts(played_cards, &scoring_indices, &joker_retriggers);
 
-        let card_mult = card.mult_bonus();
-        if card_mult > 0 {
-            steps.push(ScoreStep::CardMult {
-                card_index: idx,
-                mult: card_mult,
-            });
-            total_mult_f += card_mult as f64;
-        }
+    // Step 2: Process each scoring card, once per trigger
+    let mut money_earned = 0;
+    score_played_cards(
+        played_cards,
+        &scoring_indices,
+        &joker_retriggers,
+        &mut total_chips,
+        &mut total_mult_f,
+        &mut money_earned,
+        &mut steps,
+        rng,
+    );
 
-        let card_x_mult = card.x_mult();
-        if (card_x_mult - 1.0).abs() > f64::EPSILON {
-            steps.push(ScoreStep::CardXMult {
-                card_index: idx,
-                x_mult: card_x_mult,
+    // Step 3: Cards held in hand (Steel), Red seals and Mime retrigger them too
+    let mime_retriggers = held_retriggers(jokers);
+    for (idx, card) in held_cards.iter().enumerate() {
+        let x_mult = card.held_x_mult();
+        if (x_mult - 1.0).abs() <= f64::EPSILON {
+            continue;
+        }
+        for _ in 0..=card.seal_retriggers() + mime_retriggers {
+            steps.push(ScoreStep::HeldCardXMult {
+                held_index: idx,
+                x_mult,
             });
-            total_mult_f *= card_x_mult;
+            total_mult_f *= x_mult;
         }
     }
 
-    // Stone cards that always score
-    for (idx, card) in played_cards.iter().enumerate() {
-        if card.always_scores() && !scoring_indices.contains(&idx) {
-            let card_chips = card.chip_value();
-            if card_chips > 0 {
-                steps.push(ScoreStep::CardChips {
-                    card_index: idx,
-                    chips: card_chips,
-                });
-                total_chips += card_chips;
-            }
+    // Step 4: Apply joker effects left-to-right; per-card effects repeat
+    // for every trigger of their card. A joker's edition applies after its effect.
+    for (ji, effect) in effects.into_iter().enumerate() {
+        apply_joker_effect(
+            effect,
+            ji,
+            &triggers,
+            &mut total_chips,
+            &mut total_mult_f,
+            &mut steps,
+        );
+        let edition = jokers[ji].edition;
+        if edition.scores() {
+            steps.push(ScoreStep::JokerEdition {
+                joker_index: ji,
+                edition,
+            });
+            total_chips = total_chips.saturating_add(edition.chips());
+            total_mult_f += edition.mult() as f64;
+            total_mult_f *= edition.x_mult();
         }
     }
 
-    // Step 3: Process jokers left-to-right
-    let ctx = JokerContext {
-        played_cards,
-        scoring_indices: &scoring_indices,
-        hand_type,
-        held_cards,
-        discards_remaining,
-        num_played: played_cards.len(),
-    };
-
-    for (ji, joker) in jokers.iter().enumerate() {
-        let next_type = if joker.joker_type == JokerType::Blueprint {
-            jokers.get(ji + 1).map(|j| j.joker_type)
-        } else {
-            None
-        };
-
-        let effect = evaluate_joker(joker, &ctx, next_type);
-        apply_joker_effect(effect, ji, &mut total_chips, &mut total_mult_f, &mut steps);
+    // Step 5: Observatory, once per held Planet for this hand
+    for &consumable_index in round.held_planets {
+        steps.push(ScoreStep::PlanetXMult {
+            consumable_index,
+            x_mult: OBSERVATORY_X_MULT,
+        });
+        total_mult_f *= OBSERVATORY_X_MULT;
     }
 
+    // Float-to-int casts saturate, so huge xMult chains clamp at u64::MAX
     let total_mult = total_mult_f.max(1.0).ceil() as u64;
-    let final_score = total_chips * total_mult;
+    let final_score = total_chips.saturating_mul(total_mult);
 
     ScoreResult {
         hand_type,
@@ -315,12 +559,166 @@ pub fn calculate_score_with_jokers(
         total_chips,
         total_mult,
         final_score,
+        money_earned,
+    }
+}
+
+/// For each played card, the jokers (by index) that retrigger it, in joker order
+fn joker_retriggers(effects: &[JokerEffect], num_played: usize) -> Vec<Vec<usize>> {
+    let mut retriggers = vec![Vec::new(); num_played];
+    for (ji, effect) in effects.iter().enumerate() {
+        if let JokerEffect::Retrigger { card_indices } = effect {
+            for &ci in card_indices {
+                if let Some(sources) = retriggers.get_mut(ci) {
+                    sources.push(ji);
+                }
+            }
+        }
+    }
+    retriggers
+}
+
+/// How many times each played card scores: once, plus one per Red seal and
+/// retrigger joker. Non-scoring cards never trigger.
+fn trigger_counts(
+    played_cards: &[PlayingCard],
+    scoring_indices: &[usize],
+    joker_retriggers: &[Vec<usize>],
+) -> Vec<usize> {
+    let mut triggers = vec![0; played_cards.len()];
+    for &idx in scoring_indices {
+        triggers[idx] = 1 + played_cards[idx].seal_retriggers() + joker_retriggers[idx].len();
+    }
+    triggers
+}
+
+/// Score every scoring card (Stone cards are always among them).
+/// Each card scores once, then again for a Red seal and for every joker that
+/// retriggers it, repeating all of its steps. Without an `rng`, Lucky cards don't roll.
+#[allow(clippy::too_many_arguments)]
+fn score_played_cards(
+    played_cards: &[PlayingCard],
+    scoring_indices: &[usize],
+    joker_retriggers: &[Vec<usize>],
+    total_chips: &mut u64,
+    total_mult_f: &mut f64,
+    money_earned: &mut u32,
+    steps: &mut Vec<ScoreStep>,
+    mut rng: Option<&mut dyn RngCore>,
+) {
+    for &idx in scoring_indices {
+        let card = &played_cards[idx];
+        let retriggers =
+            (0..card.seal_retriggers())
+                .map(|_| ScoreStep::SealRetrigger { card_index: idx })
+                .chain(joker_retriggers[idx].iter().map(|&joker_index| {
+                    ScoreStep::JokerRetrigger {
+                        joker_index,
+                        card_index: idx,
+                    }
+                }));
+        for retrigger in std::iter::once(None).chain(retriggers.map(Some)) {
+            if let Some(step) = retrigger {
+                steps.push(step);
+            }
+            score_card(idx, card, total_chips, total_mult_f, money_earned, steps);
+            if let Some(rng) = rng.as_deref_mut() {
+                roll_lucky(idx, card, rng, total_mult_f, money_earned, steps);
+            }
+        }
     }
 }
 
+/// One trigger of a single card: chips, flat mult, x-mult, edition, then seal money
+fn score_card(
+    idx: usize,
+    card: &PlayingCard,
+    total_chips: &mut u64,
+    total_mult_f: &mut f64,
+    money_earned: &mut u32,
+    steps: &mut Vec<ScoreStep>,
+) {
+    let card_chips = card.enhanced_chip_value();
+    if card_chips > 0 {
+        steps.push(ScoreStep::CardChips {
+            card_index: idx,
+            chips: card_chips,
+        });
+        *total_chips = total_chips.saturating_add(card_chips);
+    }
+
+    let card_mult = card.enhancement_mult();
+    if card_mult > 0 {
+        steps.push(ScoreStep::CardMult {
+            card_index: idx,
+            mult: card_mult,
+        });
+        *total_mult_f += card_mult as f64;
+    }
+
+    let card_x_mult = card.enhancement_x_mult();
+    if (card_x_mult - 1.0).abs() > f64::EPSILON {
+        steps.push(ScoreStep::CardXMult {
+            card_index: idx,
+            x_mult: card_x_mult,
+        });
+        *total_mult_f *= card_x_mult;
+    }
+
+    if card.edition.scores() && !card.debuffed {
+        let edition = card.edition;
+        steps.push(ScoreStep::CardEdition {
+            card_index: idx,
+            edition,
+        });
+        *total_chips = total_chips.saturating_add(edition.chips());
+        *total_mult_f += edition.mult() as f64;
+        *total_mult_f *= edition.x_mult();
+    }
+
+    let money = card.seal_money();
+    if money > 0 {
+        steps.push(ScoreStep::CardMoney {
+            card_index: idx,
+            money,
+        });
+        *money_earned += money;
+    }
+}
+
+/// Lucky card: independent 1 in 5 for +20 Mult and 1 in 15 for +$20
+fn roll_lucky(
+    idx: usize,
+    card: &PlayingCard,
+    rng: &mut dyn RngCore,
+    total_mult_f: &mut f64,
+    money_earned: &mut u32,
+    steps: &mut Vec<ScoreStep>,
+) {
+    if !card.is_lucky() {
+        return;
+    }
+    if rng.gen_ratio(1, 5) {
+        steps.push(ScoreStep::LuckyMult {
+            card_index: idx,
+            mult: 20,
+        });
+        *total_mult_f += 20.0;
+    }
+    if rng.gen_ratio(1, 15) {
+        steps.push(ScoreStep::LuckyMoney {
+            card_index: idx,
+            money: 20,
+        });
+        *money_earned += 20;
+    }
+}
+
+/// Apply one joker's effect. Per-card effects repeat `triggers[card]` times.
 fn apply_joker_effect(
     effect: JokerEffect,
     joker_index: usize,
+    triggers: &[usize],
     total_chips: &mut u64,
     total_mult_f: &mut f64,
     steps: &mut Vec<ScoreStep>,
@@ -331,7 +729,7 @@ fn apply_joker_effect(
                 joker_index,
                 chips: c,
             });
-            *total_chips += c;
+            *total_chips = total_chips.saturating_add(c);
         }
         JokerEffect::AddMult(m) => {
             steps.push(ScoreStep::JokerMult {
@@ -347,18 +745,30 @@ fn apply_joker_effect(
             });
             *total_mult_f *= x;
         }
+        JokerEffect::XMultOnCard { card_index, x_mult } => {
+            for _ in 0..card_triggers(triggers, card_index) {
+                steps.push(ScoreStep::JokerCardXMult {
+                    joker_index,
+                    card_index,
+                    x_mult,
+                });
+                *total_mult_f *= x_mult;
+            }
+        }
         JokerEffect::AddChipsPerCard {
             card_indices,
             chips_each,
         } => {
             // Emit individual steps per card for animation (each card lights up)
             for &ci in &card_indices {
-                steps.push(ScoreStep::JokerCardChips {
-                    joker_index,
-                    card_index: ci,
-                    chips: chips_each,
-                });
-                *total_chips += chips_each;
+                for _ in 0..card_triggers(triggers, ci) {
+                    steps.push(ScoreStep::JokerCardChips {
+                        joker_index,
+                        card_index: ci,
+                        chips: chips_each,
+                    });
+                    *total_chips = total_chips.saturating_add(chips_each);
+                }
             }
         }
         JokerEffect::AddMultPerCard {
@@ -367,12 +777,14 @@ fn apply_joker_effect(
         } => {
             // Emit individual steps per card for animation
             for &ci in &card_indices {
-                steps.push(ScoreStep::JokerCardMult {
-                    joker_index,
-                    card_index: ci,
-                    mult: mult_each,
-                });
-                *total_mult_f += mult_each as f64;
+                for _ in 0..card_triggers(triggers, ci) {
+                    steps.push(ScoreStep::JokerCardMult {
+                        joker_index,
+                        card_index: ci,
+                        mult: mult_each,
+                    });
+                    *total_mult_f += mult_each as f64;
+                }
             }
         }
         JokerEffect::AddChipsAndMultPerCard {
@@ -382,38 +794,152 @@ fn apply_joker_effect(
         } => {
             // Emit individual chip+mult steps per card for animation
             for &ci in &card_indices {
-                steps.push(ScoreStep::JokerCardChips {
-                    joker_index,
-                    card_index: ci,
-                    chips: chips_each,
-                });
-                *total_chips += chips_each;
-                steps.push(ScoreStep::JokerCardMult {
-                    joker_index,
-                    card_index: ci,
-                    mult: mult_each,
-                });
-                *total_mult_f += mult_each as f64;
+                for _ in 0..card_triggers(triggers, ci) {
+                    steps.push(ScoreStep::JokerCardChips {
+                        joker_index,
+                        card_index: ci,
+                        chips: chips_each,
+                    });
+                    *total_chips = total_chips.saturating_add(chips_each);
+                    steps.push(ScoreStep::JokerCardMult {
+                        joker_index,
+                        card_index: ci,
+                        mult: mult_each,
+                    });
+                    *total_mult_f += mult_each as f64;
+                }
             }
         }
-        JokerEffect::Retrigger { card_indices: _ } => {
-            // Retrigger: re-score the card chips/mult (simplified)
-            // Full retrigger would re-evaluate, but for MVP we skip
+        JokerEffect::Retrigger { .. } => {
+            // Already counted in `triggers` and replayed while scoring cards
         }
         JokerEffect::None => {}
     }
 }
 
+/// Times a card-triggered joker effect fires for `card_index` (at least once)
+fn card_triggers(triggers: &[usize], card_index: usize) -> usize {
+    triggers.get(card_index).copied().unwrap_or(1).max(1)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::card::{Rank::*, Suit::*};
+    use crate::joker::copy_target;
     use crate::PlayingCard;
+    use rand::SeedableRng;
+    use rand_chacha::ChaCha12Rng;
 
     fn c(rank: crate::Rank, suit: crate::Suit) -> PlayingCard {
         PlayingCard::new(rank, suit)
     }
 
+    fn rng() -> ChaCha12Rng {
+        ChaCha12Rng::seed_from_u64(0)
+    }
+
+    /// Nothing held, three discards left and no money
+    fn round() -> ScoringContext<'static> {
+        ScoringContext {
+            discards_remaining: 3,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_chained_blueprints_copy_the_duo() {
+        let cards = vec![c(King, Spades), c(King, Hearts), c(Five, Clubs)];
+        let levels = HandLevels::new();
+        let jokers = vec![
+            Joker::new(JokerType::Blueprint),
+            Joker::new(JokerType::Blueprint),
+            Joker::new(JokerType::TheDuo),
+        ];
+        let result = calculate_score_with_jokers(&cards, &levels, &jokers, &round(), &mut rng());
+
+        let x_mults: Vec<(usize, f64)> = result
+            .steps
+            .iter()
+            .filter_map(|s| match s {
+                ScoreStep::JokerXMult {
+                    joker_index,
+                    x_mult,
+                } => Some((*joker_index, *x_mult)),
+                _ => None,
+            })
+            .collect();
+        assert_eq!(x_mults, vec![(0, 2.0), (1, 2.0), (2, 2.0)]);
+        // Pair base 2 mult, doubled three times
+        assert_eq!(result.total_mult, 16);
+    }
+
+    #[test]
+    fn test_trailing_blueprints_copy_nothing() {
+        let jokers = vec![
+            Joker::new(JokerType::Joker),
+            Joker::new(JokerType::Blueprint),
+            Joker::new(JokerType::Blueprint),
+        ];
+        assert_eq!(copy_target(&jokers, 1), None);
+        assert_eq!(copy_target(&jokers, 2), None);
+    }
+
+    #[test]
+    fn test_brainstorm_copies_leftmost_joker() {
+        let cards = vec![c(King, Spades), c(Five, Hearts)];
+        let levels = HandLevels::new();
+        let jokers = vec![
+            Joker::new(JokerType::Joker),
+            Joker::new(JokerType::Banner),
+            Joker::new(JokerType::Brainstorm),
+        ];
+        let result = calculate_score_with_jokers(
+            &cards,
+            &levels,
+            &jokers,
+            &ScoringContext {
+                discards_remaining: 0,
+                ..Default::default()
+            },
+            &mut rng(),
+        );
+
+        assert!(result.steps.iter().any(|s| matches!(
+            s,
+            ScoreStep::JokerMult {
+                joker_index: 2,
+                mult: 4
+            }
+        )));
+        // High Card base 1 mult + 4 (Joker) + 4 (Brainstorm)
+        assert_eq!(result.total_mult, 9);
+    }
+
+    #[test]
+    fn test_copier_loops_copy_nothing() {
+        let jokers = vec![
+            Joker::new(JokerType::Brainstorm),
+            Joker::new(JokerType::Joker),
+        ];
+        assert_eq!(copy_target(&jokers, 0), None);
+
+        let jokers = vec![
+            Joker::new(JokerType::Blueprint),
+            Joker::new(JokerType::Brainstorm),
+        ];
+        assert_eq!(copy_target(&jokers, 0), None);
+        assert_eq!(copy_target(&jokers, 1), None);
+
+        // Brainstorm on a leftmost Blueprint copies what the Blueprint copies
+        let jokers = vec![
+            Joker::new(JokerType::Blueprint),
+            Joker::new(JokerType::TheDuo),
+            Joker::new(JokerType::Brainstorm),
+        ];
+        assert_eq!(copy_target(&jokers, 2), Some(JokerType::TheDuo));
+    }
+
     #[test]
     fn test_pair_scoring() {
         let cards = vec![c(King, Spades), c(King, Hearts), c(Five, Clubs)];
@@ -502,4 +1028,398 @@ mod tests {
         assert_eq!(result.total_mult, 4);
         assert_eq!(result.final_score, 320);
     }
+
+    #[test]
+    fn test_red_seal_scores_card_twice() {
+        let king = c(King, Spades).with_seal(crate::card::Seal::Red);
+        let result = calculate_score(&[king], &HandLevels::new());
+
+        // Base: 5 chips, King scored twice: +10 +10
+        assert_eq!(result.total_chips, 25);
+        let king_chip_steps = result
+            .steps
+            .iter()
+            .filter(|s| matches!(s, ScoreStep::CardChips { card_index: 0, .. }))
+            .count();
+        assert_eq!(king_chip_steps, 2);
+        assert!(result
+            .steps
+            .iter()
+            .any(|s| matches!(s, ScoreStep::SealRetrigger { card_index: 0 })));
+    }
+
+    #[test]
+    fn test_hack_and_red_seal_stack_retriggers() {
+        let four = c(Four, Clubs).with_seal(crate::card::Seal::Red);
+        let jokers = vec![Joker::new(JokerType::Hack)];
+        let result =
+            calculate_score_with_jokers(&[four], &HandLevels::new(), &jokers, &round(), &mut rng());
+
+        // Base: 5 chips, Four scored three times: +4 +4 +4
+        assert_eq!(result.total_chips, 17);
+        let four_chip_steps = result
+            .steps
+            .iter()
+            .filter(|s| matches!(s, ScoreStep::CardChips { card_index: 0, .. }))
+            .count();
+        assert_eq!(four_chip_steps, 3);
+        assert!(result.steps.iter().any(|s| matches!(
+            s,
+            ScoreStep::JokerRetrigger {
+                joker_index: 0,
+                card_index: 0
+            }
+        )));
+    }
+
+    #[test]
+    fn test_card_joker_effects_follow_retriggers() {
+        let ace = c(Ace, Hearts).with_seal(crate::card::Seal::Red);
+        let jokers = vec![Joker::new(JokerType::Scholar)];
+        let result =
+            calculate_score_with_jokers(&[ace], &HandLevels::new(), &jokers, &round(), &mut rng());
+
+        // Base 5 + Ace 11 twice + Scholar 20 twice
+        assert_eq!(result.total_chips, 67);
+        // Base 1 + Scholar 4 twice
+        assert_eq!(result.total_mult, 9);
+    }
+
+    #[test]
+    fn test_fibonacci_triggers_per_fibonacci_rank() {
+        let cards = vec![
+            c(Ace, Hearts),
+            c(Two, Spades),
+            c(Three, Clubs),
+            c(Four, Diamonds),
+            c(Five, Hearts),
+        ];
+        let jokers = vec![Joker::new(JokerType::Fibonacci)];
+        let result =
+            calculate_score_with_jokers(&cards, &HandLevels::new(), &jokers, &round(), &mut rng());
+
+        let triggered: Vec<(usize, u64)> = result
+            .steps
+            .iter()
+            .filter_map(|step| match step {
+                ScoreStep::JokerCardMult {
+                    card_index, mult, ..
+                } => Some((*card_index, *mult)),
+                _ => None,
+            })
+            .collect();
+        // The 4 at index 3 is skipped
+        assert_eq!(triggered, vec![(0, 8), (1, 8), (2, 8), (4, 8)]);
+        assert_eq!(result.total_mult, 4 + 4 * 8);
+    }
+
+    #[test]
+    fn test_shortcut_scores_a_gapped_straight() {
+        let cards = vec![
+            c(Three, Hearts),
+            c(Five, Spades),
+            c(Seven, Clubs),
+            c(Nine, Diamonds),
+            c(Jack, Hearts),
+        ];
+        let score = |jokers: &[Joker]| {
+            calculate_score_with_jokers(&cards, &HandLevels::new(), jokers, &round(), &mut rng())
+        };
+
+        assert_eq!(score(&[]).hand_type, PokerHand::HighCard);
+        let result = score(&[Joker::new(JokerType::Shortcut)]);
+        assert_eq!(result.hand_type, PokerHand::Straight);
+        assert_eq!(result.scoring_indices, vec![0, 1, 2, 3, 4]);
+    }
+
+    #[test]
+    fn test_gold_seal_pays_when_scored() {
+        let ace = c(Ace, Hearts).with_seal(crate::card::Seal::Gold);
+        let result =
+            calculate_score_with_jokers(&[ace], &HandLevels::new(), &[], &round(), &mut rng());
+
+        assert_eq!(result.money_earned, 3);
+        assert!(result
+            .steps
+            .iter()
+            .any(|s| matches!(s, ScoreStep::CardMoney { money: 3, .. })));
+    }
+
+    #[test]
+    fn test_glass_card_x_mult() {
+        let glass = c(Ace, Spades).with_enhancement(crate::card::Enhancement::Glass);
+        let result = calculate_score(&[glass], &HandLevels::new());
+
+        // High card: 5 + 11 chips, 1 mult x1.5 -> ceil(1.5) = 2
+        assert!(result.steps.iter().any(|s| matches!(
+            s,
+            ScoreStep::CardXMult { card_index: 0, x_mult } if (*x_mult - 1.5).abs() < f64::EPSILON
+        )));
+        assert_eq!(result.final_score, 16 * 2);
+    }
+
+    #[test]
+    fn test_red_sealed_glass_applies_twice() {
+        let glass = c(Ace, Spades)
+            .with_enhancement(crate::card::Enhancement::Glass)
+            .with_seal(crate::card::Seal::Red);
+        let mut levels = HandLevels::new();
+        levels.level_up(PokerHand::HighCard); // 2 mult so x2.25 stays exact: 4.5 -> 5
+
+        let result = calculate_score(&[glass], &levels);
+        let x_steps = result
+            .steps
+            .iter()
+            .filter(|s| matches!(s, ScoreStep::CardXMult { .. }))
+            .count();
+        assert_eq!(x_steps, 2);
+        assert_eq!(result.total_mult, 5);
+    }
+
+    #[test]
+    fn test_held_steel_cards_stack_x_mult() {
+        let steel = c(Queen, Clubs).with_enhancement(crate::card::Enhancement::Steel);
+        let held = vec![steel, steel, c(Two, Hearts)];
+        let mut levels = HandLevels::new();
+        for _ in 0..3 {
+            levels.level_up(PokerHand::HighCard); // 4 base mult
+        }
+
+        let result = calculate_score_with_jokers(
+            &[c(Ace, Spades)],
+            &levels,
+            &[],
+            &ScoringContext {
+                held_cards: &held,
+                ..round()
+            },
+            &mut rng(),
+        );
+
+        let held_steps: Vec<usize> = result
+            .steps
+            .iter()
+            .filter_map(|s| match s {
+                ScoreStep::HeldCardXMult { held_index, .. } => Some(*held_index),
+                _ => None,
+            })
+            .collect();
+        assert_eq!(held_steps, vec![0, 1]);
+        // 4 mult x1.5 x1.5 = x2.25
+        assert_eq!(result.total_mult, 9);
+    }
+
+    #[test]
+    fn test_mime_retriggers_held_steel_card() {
+        let steel = c(King, Clubs).with_enhancement(crate::card::Enhancement::Steel);
+        let mut levels = HandLevels::new();
+        for _ in 0..3 {
+            levels.level_up(PokerHand::HighCard); // 4 base mult
+        }
+        let jokers = vec![Joker::new(JokerType::Mime)];
+
+        let result = calculate_score_with_jokers(
+            &[c(Ace, Spades)],
+            &levels,
+            &jokers,
+            &ScoringContext {
+                held_cards: &[steel],
+                ..round()
+            },
+            &mut rng(),
+        );
+
+        let held_steps = result
+            .steps
+            .iter()
+            .filter(|s| matches!(s, ScoreStep::HeldCardXMult { held_index: 0, .. }))
+            .count();
+        assert_eq!(held_steps, 2);
+        // 4 mult x1.5 x1.5 = x2.25
+        assert_eq!(result.total_mult, 9);
+    }
+
+    #[test]
+    fn test_huge_mult_saturates_instead_of_overflowing() {
+        // 1.5^120 is far beyond u64::MAX
+        let jokers = vec![Joker::new(JokerType::Joker).with_edition(Edition::Polychrome); 120];
+        let result = calculate_score_with_jokers(
+            &[c(Ace, Spades)],
+            &HandLevels::new(),
+            &jokers,
+            &round(),
+            &mut rng(),
+        );
+        assert_eq!(result.total_mult, u64::MAX);
+        assert_eq!(result.final_score, u64::MAX);
+
+        let mut run = crate::run::RunState::with_seed(1);
+        run.add_score(result.final_score);
+        run.add_score(result.final_score);
+        assert_eq!(run.round_score, u64::MAX);
+    }
+
+    fn lucky_steps(seed: u64) -> Vec<ScoreStep> {
+        let lucky = c(Seven, Diamonds).with_enhancement(crate::card::Enhancement::Lucky);
+        let mut rng = ChaCha12Rng::seed_from_u64(seed);
+        calculate_score_with_jokers(&[lucky], &HandLevels::new(), &[], &round(), &mut rng)
+            .steps
+            .into_iter()
+            .filter(|s| {
+                matches!(
+                    s,
+                    ScoreStep::LuckyMult { .. } | ScoreStep::LuckyMoney { .. }
+                )
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_lucky_card_rolls_are_seeded() {
+        let seeds: Vec<u64> = (0..200).collect();
+        let mult_hit = seeds
+            .iter()
+            .find(|&&s| {
+                lucky_steps(s)
+                    .iter()
+                    .any(|st| matches!(st, ScoreStep::LuckyMult { .. }))
+            })
+            .copied()
+            .expect("some seed hits the 1 in 5");
+        let money_hit = seeds
+            .iter()
+            .find(|&&s| {
+                lucky_steps(s)
+                    .iter()
+                    .any(|st| matches!(st, ScoreStep::LuckyMoney { .. }))
+            })
+            .copied()
+            .expect("some seed hits the 1 in 15");
+        let miss = seeds
+            .iter()
+            .find(|&&s| lucky_steps(s).is_empty())
+            .copied()
+            .expect("some seed misses both");
+
+        // Same seed, same outcome
+        assert!(matches!(
+            lucky_steps(mult_hit).as_slice(),
+            [ScoreStep::LuckyMult { mult: 20, .. }, ..]
+        ));
+        assert!(lucky_steps(money_hit)
+            .iter()
+            .any(|s| matches!(s, ScoreStep::LuckyMoney { money: 20, .. })));
+        assert!(lucky_steps(miss).is_empty());
+    }
+
+    #[test]
+    fn test_lucky_card_never_rolls_without_rng() {
+        let lucky = c(Seven, Diamonds).with_enhancement(crate::card::Enhancement::Lucky);
+        let result = calculate_score(&[lucky], &HandLevels::new());
+        assert_eq!(result.money_earned, 0);
+        assert_eq!(result.total_mult, 1);
+    }
+
+    #[test]
+    fn test_stone_card_adds_fifty_chips() {
+        let stone = c(Two, Hearts).with_enhancement(crate::card::Enhancement::Stone);
+        let result = calculate_score(&[c(Nine, Clubs), stone], &HandLevels::new());
+
+        // High card 5 + Nine 9 + Stone 50 (no rank chips)
+        assert_eq!(result.total_chips, 64);
+        assert!(result.steps.iter().any(|s| matches!(
+            s,
+            ScoreStep::CardChips {
+                card_index: 1,
+                chips: 50
+            }
+        )));
+    }
+
+    fn edition_result(edition: Edition) -> ScoreResult {
+        let card = c(Ten, Spades).with_edition(edition);
+        let mut levels = HandLevels::new();
+        levels.level_up(PokerHand::HighCard); // 2 base mult so x1.5 stays whole
+        calculate_score(&[card], &levels)
+    }
+
+    #[test]
+    fn test_card_edition_contributions() {
+        // High card level 2: 15 chips, 2 mult; Ten adds 10 chips
+        let base = edition_result(Edition::Base);
+        assert_eq!((base.total_chips, base.total_mult), (25, 2));
+        assert!(!base
+            .steps
+            .iter()
+            .any(|s| matches!(s, ScoreStep::CardEdition { .. })));
+
+        let foil = edition_result(Edition::Foil);
+        assert_eq!((foil.total_chips, foil.total_mult), (75, 2));
+
+        let holo = edition_result(Edition::Holographic);
+        assert_eq!((holo.total_chips, holo.total_mult), (25, 12));
+
+        let poly = edition_result(Edition::Polychrome);
+        assert_eq!((poly.total_chips, poly.total_mult), (25, 3));
+        assert!(poly.steps.iter().any(|s| matches!(
+            s,
+            ScoreStep::CardEdition {
+                card_index: 0,
+                edition: Edition::Polychrome
+            }
+        )));
+    }
+
+    #[test]
+    fn test_joker_editions_apply_after_joker_effect() {
+        // Joker: +4 mult; Foil Joker adds 50 chips, Polychrome Joker x1.5
+        let jokers = vec![
+            Joker::new(JokerType::Joker).with_edition(Edition::Foil),
+            Joker::new(JokerType::Joker).with_edition(Edition::Polychrome),
+            Joker::new(JokerType::Joker).with_edition(Edition::Negative),
+        ];
+        let result = calculate_score_with_jokers(
+            &[c(Ten, Spades)],
+            &HandLevels::new(),
+            &jokers,
+            &round(),
+            &mut rng(),
+        );
+
+        // Base 5 + Ten 10 + Foil 50; mult (1 + 4 + 4) * 1.5 + 4 = 17.5
+        assert_eq!(result.total_chips, 65);
+        assert_eq!(result.total_mult, 18);
+        let edition_steps: Vec<usize> = result
+            .steps
+            .iter()
+            .filter_map(|s| match s {
+                ScoreStep::JokerEdition { joker_index, .. } => Some(*joker_index),
+                _ => None,
+            })
+            .collect();
+        assert_eq!(edition_steps, vec![0, 1]);
+    }
+
+    #[test]
+    fn test_debuffed_card_still_forms_the_hand_but_scores_nothing() {
+        let debuffed_king = c(King, Diamonds)
+            .with_enhancement(crate::card::Enhancement::Bonus)
+            .with_edition(Edition::Foil)
+            .debuffed();
+
+        let alone = calculate_score(&[debuffed_king], &HandLevels::new());
+        assert_eq!(alone.total_chips, 5); // High Card base only
+
+        let cards = vec![c(King, Spades), debuffed_king];
+        let result = calculate_score(&cards, &HandLevels::new());
+        assert_eq!(result.hand_type, PokerHand::Pair);
+        assert_eq!(result.scoring_indices, vec![0, 1]);
+        assert_eq!((result.total_chips, result.total_mult), (20, 2));
+
+        // Per-card jokers skip it too
+        let jokers = vec![Joker::new(JokerType::GreedyJoker)];
+        let result =
+            calculate_score_with_jokers(&cards, &HandLevels::new(), &jokers, &round(), &mut rng());
+        assert_eq!(result.total_mult, 2);
+    }
 }
@@ -1,20 +1,29 @@
 use rand::Rng;
+use serde::{Deserialize, Serialize};
 
-use crate::consumable::{Consumable, PlanetCard, TarotCard};
-use crate::joker::{Joker, JokerType};
+use crate::card::{Edition, Enhancement, PlayingCard, Rank, Suit};
+use crate::consumable::{Consumable, ConsumableType, ConsumableWeights, TarotCard};
+use crate::joker::{Joker, JokerRarity, JokerType, RarityWeights};
+use crate::pack::BoosterPack;
+use crate::run::RunState;
+use crate::voucher::Voucher;
 
 /// An item available in the shop
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub enum ShopItem {
     JokerItem(Joker),
     ConsumableItem(Consumable),
+    VoucherItem(Voucher),
+    PackItem(BoosterPack),
 }
 
 impl ShopItem {
     pub fn name(&self) -> String {
         match self {
-            ShopItem::JokerItem(j) => j.joker_type.name().to_string(),
+            ShopItem::JokerItem(j) => j.name(),
             ShopItem::ConsumableItem(c) => c.consumable_type.name().to_string(),
+            ShopItem::VoucherItem(v) => v.name().to_string(),
+            ShopItem::PackItem(p) => p.name().to_string(),
         }
     }
 
@@ -22,66 +31,489 @@ impl ShopItem {
         match self {
             ShopItem::JokerItem(j) => j.joker_type.description().to_string(),
             ShopItem::ConsumableItem(c) => c.consumable_type.description(),
+            ShopItem::VoucherItem(v) => v.description().to_string(),
+            ShopItem::PackItem(p) => p.description().to_string(),
         }
     }
 
     pub fn price(&self) -> u32 {
         match self {
-            ShopItem::JokerItem(j) => j.joker_type.price(),
+            ShopItem::JokerItem(j) => j.price(),
             ShopItem::ConsumableItem(c) => c.consumable_type.price(),
+            ShopItem::VoucherItem(v) => v.price(),
+            ShopItem::PackItem(p) => p.price(),
+        }
+    }
+
+    pub fn is_voucher(&self) -> bool {
+        matches!(self, ShopItem::VoucherItem(_))
+    }
+
+    pub fn is_pack(&self) -> bool {
+        matches!(self, ShopItem::PackItem(_))
+    }
+
+    /// Price after a percentage discount (e.g. Clearance Sale), rounded down
+    pub fn price_with_discount(&self, discount_percent: u32) -> u32 {
+        self.price() * 100u32.saturating_sub(discount_percent) / 100
+    }
+
+    /// What this item would work with in the current run, for inspect highlights
+    pub fn synergy_targets(&self, run: &RunState) -> SynergyInfo {
+        let mut info = SynergyInfo::default();
+        match self {
+            ShopItem::JokerItem(joker) => joker_synergy(joker.joker_type, run, &mut info),
+            ShopItem::ConsumableItem(c) => match c.consumable_type {
+                ConsumableType::Planet(planet) => {
+                    let hand = planet.hand_type();
+                    let level = run.hand_levels.get_level(&hand);
+                    info.notes.push(format!("{} is level {}", hand, level));
+                }
+                ConsumableType::Tarot(TarotCard::TheChariot) => {
+                    info.joker_indices = owned_indices(run, |t| t == JokerType::SteelJoker);
+                }
+                ConsumableType::Tarot(_) | ConsumableType::Spectral(_) => {}
+            },
+            ShopItem::VoucherItem(_) | ShopItem::PackItem(_) => {}
+        }
+        info
+    }
+}
+
+/// Owned jokers and deck cards a shop item interacts with
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SynergyInfo {
+    /// Indices into `RunState::jokers` to highlight
+    pub joker_indices: Vec<usize>,
+    /// Number of cards in the full deck the item cares about
+    pub card_count: Option<usize>,
+    /// Short explanation lines for the inspect popup
+    pub notes: Vec<String>,
+}
+
+fn owned_indices(run: &RunState, pred: impl Fn(JokerType) -> bool) -> Vec<usize> {
+    run.jokers
+        .iter()
+        .enumerate()
+        .filter(|(_, j)| pred(j.joker_type))
+        .map(|(i, _)| i)
+        .collect()
+}
+
+fn count_cards(run: &RunState, pred: impl Fn(&PlayingCard) -> bool) -> usize {
+    run.full_deck().iter().filter(|c| pred(c)).count()
+}
+
+fn joker_synergy(joker_type: JokerType, run: &RunState, info: &mut SynergyInfo) {
+    let pareidolia = run
+        .jokers
+        .iter()
+        .any(|j| j.joker_type == JokerType::Pareidolia);
+
+    let suit = match joker_type {
+        JokerType::GreedyJoker => Some(Suit::Diamonds),
+        JokerType::LustyJoker => Some(Suit::Hearts),
+        JokerType::WrathfulJoker => Some(Suit::Spades),
+        JokerType::GluttonousJoker => Some(Suit::Clubs),
+        _ => None,
+    };
+    if let Some(suit) = suit {
+        let count = count_cards(run, |c| c.is_suit(suit));
+        info.card_count = Some(count);
+        info.notes
+            .push(format!("{} {} in deck", count, suit.name()));
+    }
+
+    match joker_type {
+        JokerType::SteelJoker => {
+            let count = count_cards(run, |c| c.enhancement == Some(Enhancement::Steel));
+            info.card_count = Some(count);
+            info.notes.push(format!("{} Steel cards in deck", count));
+        }
+        JokerType::Scholar => {
+            let count = count_cards(run, |c| !c.is_stone() && c.rank == Rank::Ace);
+            info.card_count = Some(count);
+            info.notes.push(format!("{} Aces in deck", count));
+        }
+        JokerType::Hack => {
+            let count = count_cards(run, |c| {
+                !c.is_stone() && matches!(c.rank, Rank::Two | Rank::Three | Rank::Four | Rank::Five)
+            });
+            info.card_count = Some(count);
+            info.notes
+                .push(format!("{} cards ranked 2-5 in deck", count));
+        }
+        JokerType::Blackboard => {
+            let count = count_cards(run, |c| matches!(c.suit, Suit::Spades | Suit::Clubs));
+            info.card_count = Some(count);
+            info.notes.push(format!("{} Spades/Clubs in deck", count));
+        }
+        JokerType::Photograph => {
+            let count = count_cards(run, |c| c.is_face_with(pareidolia));
+            info.card_count = Some(count);
+            info.notes.push(format!("{} face cards in deck", count));
+            info.joker_indices = owned_indices(run, |t| t == JokerType::Pareidolia);
+        }
+        JokerType::Pareidolia => {
+            info.joker_indices = owned_indices(run, |t| t == JokerType::Photograph);
+        }
+        // Bought into the rightmost slot, it has nothing to its right to copy
+        JokerType::Blueprint => {
+            info.notes
+                .push("Copies nothing until moved left of another joker".to_string());
+        }
+        JokerType::Brainstorm => {
+            if let Some(first) = run.jokers.first().filter(|j| !j.joker_type.is_copier()) {
+                info.joker_indices = vec![0];
+                info.notes
+                    .push(format!("Would copy {}", first.joker_type.name()));
+            }
+        }
+        _ => {}
+    }
+
+    // A bought joker lands in the rightmost slot, so every Blueprint in the
+    // trailing run of Blueprints would end up copying it
+    if joker_type != JokerType::Blueprint {
+        let trailing = run
+            .jokers
+            .iter()
+            .rev()
+            .take_while(|j| j.joker_type == JokerType::Blueprint)
+            .count();
+        if trailing > 0 {
+            for idx in run.jokers.len() - trailing..run.jokers.len() {
+                if !info.joker_indices.contains(&idx) {
+                    info.joker_indices.push(idx);
+                }
+            }
+            info.notes.push("Blueprint would copy this".to_string());
+        }
+    }
+}
+
+/// Size and odds of a generated shop. The default is the standard shop.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ShopConfig {
+    /// Card items per shop (Overstock adds one)
+    pub item_slots: usize,
+    /// Chance that a card item is a joker rather than a consumable
+    pub joker_chance: f32,
+    /// Relative odds of each consumable kind
+    pub consumable_weights: ConsumableWeights,
+    /// Relative odds of each joker rarity; `None` keeps every non-Legendary
+    /// joker equally likely, with Legendaries at 0.3% (see `JokerType::random`)
+    pub rarity_weights: Option<RarityWeights>,
+}
+
+impl Default for ShopConfig {
+    fn default() -> Self {
+        Self {
+            item_slots: 2,
+            joker_chance: 0.70,
+            consumable_weights: ConsumableWeights::SHOP,
+            rarity_weights: None,
+        }
+    }
+}
+
+impl ShopConfig {
+    /// Roll one card item: a joker with a random edition, or a consumable
+    fn random_item<R: Rng>(&self, rng: &mut R, ante: u8) -> ShopItem {
+        let roll: f32 = rng.gen();
+        if roll < self.joker_chance {
+            let joker_type = match &self.rarity_weights {
+                Some(weights) => JokerType::random_weighted(rng, weights, ante),
+                None => JokerType::random(rng),
+            };
+            ShopItem::JokerItem(Joker::new(joker_type).with_edition(roll_joker_edition(rng)))
+        } else {
+            ShopItem::ConsumableItem(Consumable::new(ConsumableType::random(
+                rng,
+                &self.consumable_weights,
+            )))
         }
     }
 }
 
 /// The shop state
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Shop {
     pub items: Vec<ShopItem>,
     pub reroll_cost: u32,
+    /// Indices into `items` that cost nothing (Uncommon Tag jokers)
+    #[serde(default)]
+    pub free_items: Vec<usize>,
 }
 
 impl Shop {
-    pub fn generate<R: Rng>(rng: &mut R, _ante: u8) -> Self {
-        let mut items = Vec::new();
-
-        // Generate 2 items: ~70% joker, ~15% planet, ~15% tarot
-        for _ in 0..2 {
-            let roll: f32 = rng.gen();
-            if roll < 0.70 {
-                // Random joker
-                let idx = rng.gen_range(0..JokerType::ALL.len());
-                items.push(ShopItem::JokerItem(Joker::new(JokerType::ALL[idx])));
-            } else if roll < 0.85 {
-                // Random planet
-                let idx = rng.gen_range(0..PlanetCard::COMMON.len());
-                items.push(ShopItem::ConsumableItem(Consumable::planet(
-                    PlanetCard::COMMON[idx],
-                )));
-            } else {
-                // Random tarot
-                let idx = rng.gen_range(0..TarotCard::ALL.len());
-                items.push(ShopItem::ConsumableItem(Consumable::tarot(
-                    TarotCard::ALL[idx],
-                )));
+    /// Generate `config.item_slots` random items, plus one voucher not yet in
+    /// `owned_vouchers` and one booster pack
+    pub fn generate<R: Rng>(
+        rng: &mut R,
+        ante: u8,
+        config: &ShopConfig,
+        owned_vouchers: &[Voucher],
+    ) -> Self {
+        let items: Vec<ShopItem> = (0..config.item_slots)
+            .map(|_| config.random_item(rng, ante))
+            .collect();
+        let mut shop = Self {
+            items,
+            reroll_cost: 5,
+            free_items: Vec::new(),
+        };
+        shop.add_voucher(rng, owned_vouchers);
+
+        let idx = rng.gen_range(0..BoosterPack::ALL.len());
+        shop.items.push(ShopItem::PackItem(BoosterPack::ALL[idx]));
+        shop
+    }
+
+    /// Offer one more voucher that is neither owned nor already on offer, placed
+    /// after any other voucher. Returns false if none is left to offer.
+    pub fn add_voucher<R: Rng>(&mut self, rng: &mut R, owned_vouchers: &[Voucher]) -> bool {
+        let on_offer: Vec<Voucher> = self
+            .items
+            .iter()
+            .filter_map(|item| match item {
+                ShopItem::VoucherItem(v) => Some(*v),
+                _ => None,
+            })
+            .collect();
+        let offered: Vec<Voucher> = Voucher::ALL
+            .into_iter()
+            .filter(|v| !owned_vouchers.contains(v) && !on_offer.contains(v))
+            .filter(|v| v.requires().is_none_or(|r| owned_vouchers.contains(&r)))
+            .collect();
+        if offered.is_empty() {
+            return false;
+        }
+        let idx = rng.gen_range(0..offered.len());
+        let at = self
+            .items
+            .iter()
+            .position(ShopItem::is_pack)
+            .unwrap_or(self.items.len());
+        self.insert_item(at, ShopItem::VoucherItem(offered[idx]));
+        true
+    }
+
+    /// Add a free Uncommon joker after the other card items (Uncommon Tag)
+    pub fn add_free_uncommon_joker<R: Rng>(&mut self, rng: &mut R) {
+        let joker = Joker::new(JokerType::random_with_rarity(rng, JokerRarity::Uncommon));
+        let at = self
+            .items
+            .iter()
+            .position(|item| item.is_voucher() || item.is_pack())
+            .unwrap_or(self.items.len());
+        self.insert_item(at, ShopItem::JokerItem(joker));
+        self.free_items.push(at);
+    }
+
+    /// Insert at `at`, keeping free item indices pointing at the same items
+    fn insert_item(&mut self, at: usize, item: ShopItem) {
+        self.items.insert(at, item);
+        for free in &mut self.free_items {
+            if *free >= at {
+                *free += 1;
             }
         }
+    }
 
-        Self {
-            items,
-            reroll_cost: 5,
+    /// What the item at `index` costs after a percentage discount
+    pub fn price(&self, index: usize, discount_percent: u32) -> Option<u32> {
+        let item = self.items.get(index)?;
+        if self.free_items.contains(&index) {
+            Some(0)
+        } else {
+            Some(item.price_with_discount(discount_percent))
         }
     }
 
-    pub fn reroll<R: Rng>(&mut self, rng: &mut R, ante: u8) {
-        *self = Self::generate(rng, ante);
+    /// Replace the card items; an unbought voucher or pack stays until the shop closes
+    pub fn reroll<R: Rng>(&mut self, rng: &mut R, ante: u8, config: &ShopConfig) {
+        let kept: Vec<ShopItem> = self
+            .items
+            .iter()
+            .filter(|item| item.is_voucher() || item.is_pack())
+            .cloned()
+            .collect();
+        self.items = (0..config.item_slots)
+            .map(|_| config.random_item(rng, ante))
+            .collect();
+        self.items.extend(kept);
+        // Free jokers are card items, so they go with the reroll
+        self.free_items.clear();
         self.reroll_cost += 1; // Stays incremented
     }
 
+    /// Start rerolls at `base` instead of the default $5
+    pub fn with_reroll_cost(mut self, base: u32) -> Self {
+        self.reroll_cost = base;
+        self
+    }
+
+    /// Index of the voucher in `items`, if one is on offer
+    pub fn voucher_index(&self) -> Option<usize> {
+        self.items.iter().position(ShopItem::is_voucher)
+    }
+
     pub fn buy(&mut self, index: usize) -> Option<ShopItem> {
         if index < self.items.len() {
+            self.free_items.retain(|&free| free != index);
+            for free in &mut self.free_items {
+                if *free > index {
+                    *free -= 1;
+                }
+            }
             Some(self.items.remove(index))
         } else {
             None
         }
     }
 }
+
+/// Shop jokers: 4% Foil, 1.4% Holographic, 0.3% Polychrome, 0.3% Negative
+fn roll_joker_edition<R: Rng>(rng: &mut R) -> Edition {
+    let roll: f32 = rng.gen();
+    if roll < 0.04 {
+        Edition::Foil
+    } else if roll < 0.054 {
+        Edition::Holographic
+    } else if roll < 0.057 {
+        Edition::Polychrome
+    } else if roll < 0.06 {
+        Edition::Negative
+    } else {
+        Edition::Base
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_steel_joker_synergy_counts_steel_cards() {
+        let mut run = RunState::with_seed(7);
+        let item = ShopItem::JokerItem(Joker::new(JokerType::SteelJoker));
+        assert_eq!(item.synergy_targets(&run).card_count, Some(0));
+
+        let steel = PlayingCard::new(Rank::King, Suit::Hearts).with_enhancement(Enhancement::Steel);
+        run.hand.push(steel);
+        run.hand.push(steel);
+
+        let info = item.synergy_targets(&run);
+        assert_eq!(info.card_count, Some(2));
+        assert!(info.notes.iter().any(|n| n.contains("2 Steel")));
+    }
+
+    #[test]
+    fn test_bought_blueprint_copies_nothing() {
+        let mut run = RunState::with_seed(7);
+        run.jokers.push(Joker::new(JokerType::Joker));
+        run.jokers.push(Joker::new(JokerType::Blueprint));
+
+        let info = ShopItem::JokerItem(Joker::new(JokerType::Blueprint)).synergy_targets(&run);
+        assert!(info.joker_indices.is_empty());
+        assert!(info.notes.iter().any(|n| n.contains("Copies nothing")));
+
+        // An owned Blueprint at the end would copy whatever is bought next
+        let info = ShopItem::JokerItem(Joker::new(JokerType::Scholar)).synergy_targets(&run);
+        assert_eq!(info.joker_indices, vec![1]);
+    }
+
+    fn seeded_shop(seed: u64, ante: u8, config: &ShopConfig) -> Shop {
+        use rand::SeedableRng;
+        let mut rng = rand_chacha::ChaCha12Rng::seed_from_u64(seed);
+        Shop::generate(&mut rng, ante, config, &[])
+    }
+
+    fn item_names(shop: &Shop) -> Vec<String> {
+        shop.items.iter().map(ShopItem::name).collect()
+    }
+
+    /// What the standard shop rolls from seed 42 at ante 1
+    const EXPECTED_SEED_42: [&str; 4] = ["Photograph", "Obelisk", "Seed Money", "Standard Pack"];
+
+    #[test]
+    fn test_seeded_shop_is_deterministic() {
+        let config = ShopConfig::default();
+        let shop = seeded_shop(42, 1, &config);
+        assert_eq!(item_names(&shop), EXPECTED_SEED_42);
+        assert_eq!(item_names(&seeded_shop(42, 1, &config)), EXPECTED_SEED_42);
+    }
+
+    #[test]
+    fn test_overstock_config_adds_a_card_item() {
+        let standard = seeded_shop(42, 1, &ShopConfig::default());
+        let overstock = ShopConfig {
+            item_slots: 3,
+            ..ShopConfig::default()
+        };
+        let boosted = seeded_shop(42, 1, &overstock);
+        assert_eq!(boosted.items.len(), standard.items.len() + 1);
+        assert!(boosted.voucher_index().is_some());
+        assert!(boosted.items.last().is_some_and(ShopItem::is_pack));
+    }
+
+    #[test]
+    fn test_config_sets_the_joker_and_rarity_odds() {
+        let all_rare = ShopConfig {
+            item_slots: 6,
+            joker_chance: 1.0,
+            rarity_weights: Some(RarityWeights {
+                common: 0,
+                uncommon: 0,
+                rare: 1,
+                legendary: 0,
+                uncommon_per_ante: 0,
+                rare_per_ante: 0,
+            }),
+            ..ShopConfig::default()
+        };
+        let shop = seeded_shop(3, 1, &all_rare);
+        assert!(shop.items[..6]
+            .iter()
+            .all(|item| matches!(item, ShopItem::JokerItem(j) if j.joker_type.rarity() == JokerRarity::Rare)));
+
+        let no_jokers = ShopConfig {
+            joker_chance: 0.0,
+            ..all_rare
+        };
+        let shop = seeded_shop(3, 1, &no_jokers);
+        assert!(shop.items[..6]
+            .iter()
+            .all(|item| matches!(item, ShopItem::ConsumableItem(_))));
+    }
+
+    #[test]
+    fn test_rarity_weights_grow_with_the_ante() {
+        let weights = RarityWeights {
+            common: 70,
+            uncommon: 25,
+            rare: 5,
+            legendary: 0,
+            uncommon_per_ante: 2,
+            rare_per_ante: 1,
+        };
+        assert_eq!(weights.at_ante(1), weights);
+        let ante_4 = weights.at_ante(4);
+        assert_eq!((ante_4.common, ante_4.uncommon, ante_4.rare), (70, 31, 8));
+    }
+
+    #[test]
+    fn test_shop_joker_edition_raises_its_price() {
+        let joker = Joker::new(JokerType::Scholar).with_edition(Edition::Polychrome);
+        assert_eq!(joker.price(), JokerType::Scholar.price() + 5);
+
+        let shop = Shop {
+            items: vec![ShopItem::JokerItem(joker.clone())],
+            reroll_cost: 5,
+            free_items: Vec::new(),
+        };
+        assert_eq!(shop.price(0, 0), Some(joker.price()));
+        assert!(shop.items[0].name().starts_with("Polychrome "));
+    }
+}
@@ -0,0 +1,107 @@
+use serde::{Deserialize, Serialize};
+
+/// Difficulty chosen for a run. Each stake keeps every rule of the stakes below it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Default, Serialize, Deserialize)]
+pub enum Stake {
+    #[default]
+    White,
+    Red,
+    Green,
+    Black,
+    Blue,
+    Purple,
+    Orange,
+    Gold,
+}
+
+impl Stake {
+    pub const ALL: [Stake; 8] = [
+        Stake::White,
+        Stake::Red,
+        Stake::Green,
+        Stake::Black,
+        Stake::Blue,
+        Stake::Purple,
+        Stake::Orange,
+        Stake::Gold,
+    ];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Stake::White => "White Stake",
+            Stake::Red => "Red Stake",
+            Stake::Green => "Green Stake",
+            Stake::Black => "Black Stake",
+            Stake::Blue => "Blue Stake",
+            Stake::Purple => "Purple Stake",
+            Stake::Orange => "Orange Stake",
+            Stake::Gold => "Gold Stake",
+        }
+    }
+
+    /// The rule this stake adds on top of the ones below it
+    pub fn description(&self) -> &'static str {
+        match self {
+            Stake::White => "Base difficulty",
+            Stake::Red => "Small Blind gives no reward money",
+            Stake::Green => "Required score scales faster for each Ante",
+            Stake::Black => "Interest capped $2 lower",
+            Stake::Blue => "-1 discard every round",
+            Stake::Purple => "Required score scales even faster",
+            Stake::Orange => "Shop rerolls start $1 higher",
+            Stake::Gold => "-1 hand size",
+        }
+    }
+
+    /// Which ante score table applies: 1 (White, Red), 2 (Green to Blue) or 3 (Purple up)
+    pub fn score_scaling(&self) -> u8 {
+        if *self >= Stake::Purple {
+            3
+        } else if *self >= Stake::Green {
+            2
+        } else {
+            1
+        }
+    }
+
+    /// Whether beating the Small Blind pays its reward money
+    pub fn pays_small_blind(&self) -> bool {
+        *self < Stake::Red
+    }
+
+    /// Dollars taken off the interest cap
+    pub fn interest_cap_penalty(&self) -> u32 {
+        if *self >= Stake::Black {
+            2
+        } else {
+            0
+        }
+    }
+
+    /// Discards taken away every round
+    pub fn discard_penalty(&self) -> u8 {
+        u8::from(*self >= Stake::Blue)
+    }
+
+    /// Dollars added to the starting reroll cost of every shop
+    pub fn reroll_penalty(&self) -> u32 {
+        u32::from(*self >= Stake::Orange)
+    }
+
+    /// Cards taken off the hand size
+    pub fn hand_size_penalty(&self) -> u8 {
+        u8::from(*self >= Stake::Gold)
+    }
+
+    /// Next stake in `ALL`, wrapping around (`forward = false` goes back)
+    pub fn cycle(&self, forward: bool) -> Self {
+        let len = Self::ALL.len();
+        let idx = Self::ALL.iter().position(|s| s == self).unwrap_or(0);
+        let next = if forward {
+            (idx + 1) % len
+        } else {
+            (idx + len - 1) % len
+        };
+        Self::ALL[next]
+    }
+}
@@ -0,0 +1,46 @@
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+
+/// Tags are rewards for skipping a blind. Some pay out at once, the rest
+/// wait for the next shop.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum Tag {
+    Economy,  // Double your money (max +$40), right away
+    Voucher,  // Next shop offers an extra voucher
+    Uncommon, // Next shop has a free Uncommon joker
+}
+
+impl Tag {
+    pub const ALL: [Tag; 3] = [Tag::Economy, Tag::Voucher, Tag::Uncommon];
+
+    /// Most money an Economy tag can add
+    pub const ECONOMY_CAP: u32 = 40;
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Tag::Economy => "Economy Tag",
+            Tag::Voucher => "Voucher Tag",
+            Tag::Uncommon => "Uncommon Tag",
+        }
+    }
+
+    pub fn description(&self) -> &'static str {
+        match self {
+            Tag::Economy => "Doubles your money (max +$40)",
+            Tag::Voucher => "Adds a voucher to the next shop",
+            Tag::Uncommon => "Next shop has a free Uncommon Joker",
+        }
+    }
+
+    /// Whether the tag waits for the next shop instead of paying out at once
+    pub fn applies_in_shop(&self) -> bool {
+        !matches!(self, Tag::Economy)
+    }
+}
+
+impl fmt::Display for Tag {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
@@ -0,0 +1,98 @@
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+
+/// Vouchers are one-time shop purchases that permanently modify the run
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum Voucher {
+    Overstock,     // +1 card slot in the shop
+    ClearanceSale, // 25% off everything in the shop
+    Grabber,       // +1 hand every round
+    Wasteful,      // +1 discard every round
+    SeedMoney,     // Interest cap raised to $10
+    MoneyTree,     // Interest cap raised to $20 (needs Seed Money)
+    RerollSurplus, // Rerolls cost $2 less
+    RerollGlut,    // Rerolls cost another $2 less (needs Reroll Surplus)
+    Telescope,     // First Celestial pack each shop has the most played hand's Planet
+    Observatory,   // Held Planets give X1.5 Mult for their hand (needs Telescope)
+    DirectorsCut,  // Reroll the boss blind once per ante
+    Retcon,        // Reroll the boss blind any number of times (needs Director's Cut)
+    CrystalBall,   // +1 consumable slot
+}
+
+impl Voucher {
+    pub const ALL: [Voucher; 13] = [
+        Voucher::Overstock,
+        Voucher::ClearanceSale,
+        Voucher::Grabber,
+        Voucher::Wasteful,
+        Voucher::SeedMoney,
+        Voucher::MoneyTree,
+        Voucher::RerollSurplus,
+        Voucher::RerollGlut,
+        Voucher::Telescope,
+        Voucher::Observatory,
+        Voucher::DirectorsCut,
+        Voucher::Retcon,
+        Voucher::CrystalBall,
+    ];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Voucher::Overstock => "Overstock",
+            Voucher::ClearanceSale => "Clearance Sale",
+            Voucher::Grabber => "Grabber",
+            Voucher::Wasteful => "Wasteful",
+            Voucher::SeedMoney => "Seed Money",
+            Voucher::MoneyTree => "Money Tree",
+            Voucher::RerollSurplus => "Reroll Surplus",
+            Voucher::RerollGlut => "Reroll Glut",
+            Voucher::Telescope => "Telescope",
+            Voucher::Observatory => "Observatory",
+            Voucher::DirectorsCut => "Director's Cut",
+            Voucher::Retcon => "Retcon",
+            Voucher::CrystalBall => "Crystal Ball",
+        }
+    }
+
+    pub fn description(&self) -> &'static str {
+        match self {
+            Voucher::Overstock => "+1 card slot in the shop",
+            Voucher::ClearanceSale => "All shop items are 25% off",
+            Voucher::Grabber => "+1 hand every round",
+            Voucher::Wasteful => "+1 discard every round",
+            Voucher::SeedMoney => "Raise the interest cap to $10",
+            Voucher::MoneyTree => "Raise the interest cap to $20",
+            Voucher::RerollSurplus => "Rerolls cost $2 less",
+            Voucher::RerollGlut => "Rerolls cost another $2 less",
+            Voucher::Telescope => {
+                "First Celestial Pack each shop has your most played hand's Planet"
+            }
+            Voucher::Observatory => "Held Planet cards give X1.5 Mult for their hand",
+            Voucher::DirectorsCut => "Reroll the Boss Blind once per Ante, $10",
+            Voucher::Retcon => "Reroll the Boss Blind any number of times, $10 each",
+            Voucher::CrystalBall => "+1 consumable slot",
+        }
+    }
+
+    /// Voucher that must be owned before this one is offered
+    pub fn requires(&self) -> Option<Voucher> {
+        match self {
+            Voucher::MoneyTree => Some(Voucher::SeedMoney),
+            Voucher::RerollGlut => Some(Voucher::RerollSurplus),
+            Voucher::Observatory => Some(Voucher::Telescope),
+            Voucher::Retcon => Some(Voucher::DirectorsCut),
+            _ => None,
+        }
+    }
+
+    pub fn price(&self) -> u32 {
+        10
+    }
+}
+
+impl fmt::Display for Voucher {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
@@ -100,15 +100,29 @@ impl Widget for ActionButtonsWidget {
             play_rect,
             &format!("Play Hand ({})", self.hands_remaining),
             self.can_play,
-            Theme::CHIPS_COLOR,
-            Theme::DIM_TEXT,
+            Theme::chips_color(),
+            Theme::dim_text(),
         );
 
         // === Sort Rank button ===
-        render_button(buf, sort_rank_rect, "Rank", true, Theme::GOLD, Theme::GOLD);
+        render_button(
+            buf,
+            sort_rank_rect,
+            "Rank",
+            true,
+            Theme::gold(),
+            Theme::gold(),
+        );
 
         // === Sort Suit button ===
-        render_button(buf, sort_suit_rect, "Suit", true, Theme::GOLD, Theme::GOLD);
+        render_button(
+            buf,
+            sort_suit_rect,
+            "Suit",
+            true,
+            Theme::gold(),
+            Theme::gold(),
+        );
 
         // === Discard button ===
         render_button(
@@ -116,8 +130,8 @@ impl Widget for ActionButtonsWidget {
             discard_rect,
             &format!("Discard ({})", self.discards_remaining),
             self.can_discard,
-            Theme::MULT_COLOR,
-            Theme::DIM_TEXT,
+            Theme::mult_color(),
+            Theme::dim_text(),
         );
     }
 }
@@ -142,10 +156,10 @@ fn render_button(
     let border_style = Style::default().fg(color);
     let text_style = if enabled {
         Style::default()
-            .fg(Theme::BRIGHT_TEXT)
+            .fg(Theme::bright_text())
             .add_modifier(Modifier::BOLD)
     } else {
-        Style::default().fg(Theme::DIM_TEXT)
+        Style::default().fg(Theme::dim_text())
     };
 
     // Top border
@@ -19,15 +19,14 @@ impl BackgroundWidget {
 impl Widget for BackgroundWidget {
     fn render(self, area: Rect, buf: &mut Buffer) {
         let cycle = (self.tick % 360) as f64;
+        let (base_r, base_g, base_b) = match Theme::felt() {
+            Color::Rgb(r, g, b) => (r, g, b),
+            _ => (22, 22, 52),
+        };
 
         for y in area.top()..area.bottom() {
             for x in area.left()..area.right() {
-                // Base felt color with subtle variation
-                let base_r = 22u8;
-                let base_g = 22u8;
-                let base_b = 52u8;
-
-                // Subtle wave pattern
+                // Subtle wave pattern over the felt color
                 let wave = ((x as f64 * 0.3 + y as f64 * 0.5 + cycle * 0.02).sin() * 4.0) as i16;
 
                 let r = (base_r as i16 + wave).clamp(0, 255) as u8;
@@ -77,7 +76,7 @@ impl Widget for FrameWidget {
         // Top and bottom decorative lines
         for x in area.left()..area.right() {
             let t = (x as f64 / area.width as f64 + cycle * 0.005).sin().abs();
-            let color = interpolate_color(Theme::FELT, Theme::GOLD, (t * 0.3) as f32);
+            let color = interpolate_color(Theme::felt(), Theme::gold(), (t * 0.3) as f32);
 
             let top_cell = buf.cell_mut((x, area.top()));
             if let Some(cell) = top_cell {
@@ -95,7 +94,7 @@ impl Widget for FrameWidget {
         // Left and right borders
         for y in area.top()..area.bottom() {
             let t = (y as f64 / area.height as f64 + cycle * 0.005).sin().abs();
-            let color = interpolate_color(Theme::FELT, Theme::GOLD, (t * 0.3) as f32);
+            let color = interpolate_color(Theme::felt(), Theme::gold(), (t * 0.3) as f32);
 
             let left_cell = buf.cell_mut((area.left(), y));
             if let Some(cell) = left_cell {
@@ -111,7 +110,7 @@ impl Widget for FrameWidget {
         }
 
         // Corners with gold accent
-        let corner_style = Style::default().fg(Theme::GOLD);
+        let corner_style = Style::default().fg(Theme::gold());
         set_cell(buf, area.left(), area.top(), "\u{256d}", corner_style);
         set_cell(
             buf,
@@ -1,5 +1,8 @@
 use balatrust_core::blind::{self, BlindType, BossBlind};
+use balatrust_core::format::format_number;
 use balatrust_core::run::BlindOutcome;
+use balatrust_core::stake::Stake;
+use balatrust_core::tag::Tag;
 use ratatui::buffer::Buffer;
 use ratatui::layout::{Constraint, Layout, Rect};
 use ratatui::style::{Modifier, Style};
@@ -15,6 +18,10 @@ pub struct BlindSelectWidget {
     pub boss: BossBlind,
     pub cursor: usize, // 0=small, 1=big, 2=boss
     pub outcomes: [BlindOutcome; 3],
+    /// Tag won by skipping each blind, shown on skipped cards
+    pub skip_tags: [Option<Tag>; 3],
+    /// Stake the run is played at (sets targets and rewards)
+    pub stake: Stake,
 }
 
 impl BlindSelectWidget {
@@ -24,9 +31,21 @@ impl BlindSelectWidget {
             boss,
             cursor,
             outcomes,
+            skip_tags: [None; 3],
+            stake: Stake::default(),
         }
     }
 
+    pub fn stake(mut self, stake: Stake) -> Self {
+        self.stake = stake;
+        self
+    }
+
+    pub fn skip_tags(mut self, skip_tags: [Option<Tag>; 3]) -> Self {
+        self.skip_tags = skip_tags;
+        self
+    }
+
     /// Get the Rect for a specific blind card given the panel area.
     pub fn card_rect(area: Rect, index: usize) -> Rect {
         if area.width < 30 || area.height < 8 {
@@ -105,27 +124,43 @@ impl Widget for BlindSelectWidget {
                 height: col.height,
             };
             let is_cursor = self.cursor == i;
-            render_blind_card(*blind, padded, buf, is_cursor, self.ante, self.outcomes[i]);
+            // Small/Big cards preview the boss still to come
+            let coming_boss = Some(self.boss).filter(|_| blind.can_skip());
+            render_blind_card(
+                *blind,
+                padded,
+                buf,
+                is_cursor,
+                self.ante,
+                self.stake,
+                self.outcomes[i],
+                self.skip_tags[i],
+                coming_boss,
+            );
         }
     }
 }
 
+#[allow(clippy::too_many_arguments)]
 fn render_blind_card(
     blind: BlindType,
     area: Rect,
     buf: &mut Buffer,
     is_cursor: bool,
     ante: u8,
+    stake: Stake,
     outcome: BlindOutcome,
+    skip_tag: Option<Tag>,
+    coming_boss: Option<BossBlind>,
 ) {
     if area.width < 10 || area.height < 6 {
         return;
     }
 
     let (base_color, blind_name) = match &blind {
-        BlindType::Small => (Theme::SMALL_BLIND, "Small Blind"),
-        BlindType::Big => (Theme::BIG_BLIND, "Big Blind"),
-        BlindType::Boss(boss) => (Theme::BOSS_BLIND, boss_name(boss)),
+        BlindType::Small => (Theme::small_blind(), "Small Blind"),
+        BlindType::Big => (Theme::big_blind(), "Big Blind"),
+        BlindType::Boss(boss) => (Theme::boss_blind(), boss_name(boss)),
     };
 
     let is_active = outcome == BlindOutcome::Active;
@@ -134,11 +169,11 @@ fn render_blind_card(
 
     // Choose border style based on state
     let border_color = if is_cursor && is_active {
-        Theme::CARD_SELECTED
+        Theme::card_selected()
     } else if is_active {
         base_color
     } else {
-        Theme::DIM_TEXT
+        Theme::dim_text()
     };
 
     let border_style = Style::default().fg(border_color);
@@ -174,7 +209,7 @@ fn render_blind_card(
                     Rect::new(inner.x, y, inner.width, 3),
                     buf,
                     "Select",
-                    Theme::GOLD,
+                    Theme::gold(),
                 );
                 y += 3;
 
@@ -184,22 +219,24 @@ fn render_blind_card(
                         Rect::new(inner.x, y, inner.width, 3),
                         buf,
                         "Skip",
-                        Theme::MUTED_TEXT,
+                        Theme::muted_text(),
                     );
                     y += 3;
                 }
             }
         }
         BlindOutcome::Skipped => {
-            let label = "SKIPPED";
+            // The tag won by skipping, if any
+            let (label, color) = match skip_tag {
+                Some(tag) => (tag.name(), Theme::gold()),
+                None => ("SKIPPED", Theme::dim_text()),
+            };
             let x = inner.x + (max_w as u16).saturating_sub(label.len() as u16) / 2;
             buf.set_string(
                 x,
                 y,
                 label,
-                Style::default()
-                    .fg(Theme::DIM_TEXT)
-                    .add_modifier(Modifier::BOLD),
+                Style::default().fg(color).add_modifier(Modifier::BOLD),
             );
             y += 2;
         }
@@ -211,7 +248,7 @@ fn render_blind_card(
                 y,
                 label,
                 Style::default()
-                    .fg(Theme::MONEY_COLOR)
+                    .fg(Theme::money_color())
                     .add_modifier(Modifier::BOLD),
             );
             y += 2;
@@ -224,7 +261,7 @@ fn render_blind_card(
                 y,
                 label,
                 Style::default()
-                    .fg(Theme::DIM_TEXT)
+                    .fg(Theme::dim_text())
                     .add_modifier(Modifier::BOLD),
             );
             y += 2;
@@ -235,7 +272,7 @@ fn render_blind_card(
     if y < inner.bottom() {
         let sep: String = "\u{2500}".repeat(max_w);
         let sep_color = if is_dimmed || is_upcoming {
-            Theme::DIM_TEXT
+            Theme::dim_text()
         } else {
             base_color
         };
@@ -247,7 +284,7 @@ fn render_blind_card(
     if y < inner.bottom() {
         let name_style = if is_dimmed || is_upcoming {
             Style::default()
-                .fg(Theme::DIM_TEXT)
+                .fg(Theme::dim_text())
                 .add_modifier(Modifier::BOLD)
         } else {
             Style::default().fg(base_color).add_modifier(Modifier::BOLD)
@@ -267,7 +304,7 @@ fn render_blind_card(
             BlindType::Boss(_) => "\u{2605}", // ★
         };
         let icon_style = if is_dimmed || is_upcoming {
-            Style::default().fg(Theme::DIM_TEXT)
+            Style::default().fg(Theme::dim_text())
         } else {
             Style::default().fg(base_color).add_modifier(Modifier::BOLD)
         };
@@ -281,9 +318,9 @@ fn render_blind_card(
         let desc = boss.description();
         let lines = word_wrap(desc, max_w);
         let desc_style = if is_dimmed || is_upcoming {
-            Style::default().fg(Theme::DIM_TEXT)
+            Style::default().fg(Theme::dim_text())
         } else {
-            Style::default().fg(Theme::MULT_COLOR)
+            Style::default().fg(Theme::mult_color())
         };
         for line in &lines {
             if y >= inner.bottom() {
@@ -302,9 +339,9 @@ fn render_blind_card(
     if y < inner.bottom() {
         let label = "Score at least";
         let label_style = if is_dimmed || is_upcoming {
-            Style::default().fg(Theme::DIM_TEXT)
+            Style::default().fg(Theme::dim_text())
         } else {
-            Style::default().fg(Theme::MUTED_TEXT)
+            Style::default().fg(Theme::muted_text())
         };
         let x = inner.x + (max_w as u16).saturating_sub(label.len() as u16) / 2;
         buf.set_string(x, y, label, label_style);
@@ -312,15 +349,15 @@ fn render_blind_card(
     }
 
     if y < inner.bottom() {
-        let target = blind::score_target(ante, &blind);
+        let target = blind::score_target(ante, &blind, stake);
         let target_str = format_number(target);
         let target_style = if is_dimmed || is_upcoming {
             Style::default()
-                .fg(Theme::DIM_TEXT)
+                .fg(Theme::dim_text())
                 .add_modifier(Modifier::BOLD)
         } else {
             Style::default()
-                .fg(Theme::CHIPS_COLOR)
+                .fg(Theme::chips_color())
                 .add_modifier(Modifier::BOLD)
         };
         let x = inner.x + (max_w as u16).saturating_sub(target_str.len() as u16) / 2;
@@ -330,13 +367,17 @@ fn render_blind_card(
 
     // ═══ 6. Reward ═══
     if y < inner.bottom() {
-        let reward = blind.reward();
-        let dollars: String = "$".repeat(reward as usize);
+        let reward = blind.reward_at(stake);
+        let dollars: String = if reward == 0 {
+            "none".to_string()
+        } else {
+            "$".repeat(reward as usize)
+        };
         let reward_style = if is_dimmed || is_upcoming {
-            Style::default().fg(Theme::DIM_TEXT)
+            Style::default().fg(Theme::dim_text())
         } else {
             Style::default()
-                .fg(Theme::MONEY_COLOR)
+                .fg(Theme::money_color())
                 .add_modifier(Modifier::BOLD)
         };
         let label = format!("Reward: {}", dollars);
@@ -354,7 +395,27 @@ fn render_blind_card(
                     break;
                 }
                 let x = inner.x + (max_w as u16).saturating_sub(line.len() as u16) / 2;
-                buf.set_string(x, y, line, Style::default().fg(Theme::GOLD));
+                buf.set_string(x, y, line, Style::default().fg(Theme::gold()));
+                y += 1;
+            }
+        }
+    }
+
+    // ═══ 8. Coming Up Boss Preview (small/big only, until they're done) ═══
+    if let Some(boss) = coming_boss.filter(|_| !is_dimmed) {
+        let mut lines = vec![format!("Coming up: {}", boss_name(&boss))];
+        lines.extend(word_wrap(boss.description(), max_w));
+        if y + lines.len() as u16 <= inner.bottom() {
+            for (i, line) in lines.iter().enumerate() {
+                let style = if i == 0 {
+                    Style::default()
+                        .fg(Theme::dim_text())
+                        .add_modifier(Modifier::BOLD)
+                } else {
+                    Style::default().fg(Theme::dim_text())
+                };
+                let x = inner.x + (max_w as u16).saturating_sub(line.len() as u16) / 2;
+                buf.set_stringn(x, y, line, max_w, style);
                 y += 1;
             }
         }
@@ -372,7 +433,7 @@ fn render_blind_card(
                 stamp_y,
                 stamp,
                 Style::default()
-                    .fg(Theme::DIM_TEXT)
+                    .fg(Theme::dim_text())
                     .add_modifier(Modifier::BOLD),
             );
         }
@@ -481,24 +542,13 @@ fn boss_name(boss: &BossBlind) -> &'static str {
         BossBlind::TheGoad => "The Goad",
         BossBlind::TheWindow => "The Window",
         BossBlind::TheHead => "The Head",
-    }
-}
-
-fn format_number(n: u64) -> String {
-    if n >= 1_000_000 {
-        format!("{:.1}M", n as f64 / 1_000_000.0)
-    } else if n >= 1_000 {
-        let s = n.to_string();
-        let mut result = String::new();
-        for (i, c) in s.chars().rev().enumerate() {
-            if i > 0 && i % 3 == 0 {
-                result.push(',');
-            }
-            result.push(c);
-        }
-        result.chars().rev().collect()
-    } else {
-        n.to_string()
+        BossBlind::TheFish => "The Fish",
+        BossBlind::TheManacle => "The Manacle",
+        BossBlind::TheEye => "The Eye",
+        BossBlind::TheMouth => "The Mouth",
+        BossBlind::ThePlant => "The Plant",
+        BossBlind::TheArm => "The Arm",
+        BossBlind::TheTooth => "The Tooth",
     }
 }
 
@@ -524,3 +574,59 @@ fn word_wrap(text: &str, max_width: usize) -> Vec<String> {
 
     lines
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Text inside `rect`, one trimmed row per line, blank rows dropped
+    fn card_text(buf: &Buffer, rect: Rect) -> String {
+        (rect.y..rect.bottom())
+            .map(|y| {
+                (rect.x..rect.right())
+                    .map(|x| buf[(x, y)].symbol())
+                    .collect::<String>()
+                    .trim()
+                    .to_string()
+            })
+            .filter(|row| !row.is_empty())
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
+
+    #[test]
+    fn test_small_blind_previews_the_upcoming_boss() {
+        let boss = BossBlind::TheTooth;
+        let outcomes = [
+            BlindOutcome::Active,
+            BlindOutcome::Upcoming,
+            BlindOutcome::Upcoming,
+        ];
+        let area = Rect::new(0, 0, 120, 40);
+        let mut buf = Buffer::empty(area);
+        BlindSelectWidget::new(1, boss, 0, outcomes).render(area, &mut buf);
+
+        for index in [0, 1] {
+            let text = card_text(&buf, BlindSelectWidget::card_rect(area, index));
+            assert!(text.contains("Coming up: The Tooth"), "{}", text);
+            assert!(text.contains(boss.description()), "{}", text);
+        }
+    }
+
+    #[test]
+    fn test_finished_blinds_drop_the_boss_preview() {
+        let outcomes = [
+            BlindOutcome::Beaten,
+            BlindOutcome::Skipped,
+            BlindOutcome::Active,
+        ];
+        let area = Rect::new(0, 0, 120, 40);
+        let mut buf = Buffer::empty(area);
+        BlindSelectWidget::new(1, BossBlind::TheTooth, 2, outcomes).render(area, &mut buf);
+
+        for index in [0, 1] {
+            let text = card_text(&buf, BlindSelectWidget::card_rect(area, index));
+            assert!(!text.contains("Coming up"), "{}", text);
+        }
+    }
+}
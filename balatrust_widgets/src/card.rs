@@ -9,16 +9,23 @@ use crate::theme::Theme;
 /// Width and height of a card in terminal cells
 pub const CARD_WIDTH: u16 = 13;
 pub const CARD_HEIGHT: u16 = 11;
+/// Size of a compact card (corner rank and suit only) for small terminals
+pub const COMPACT_CARD_WIDTH: u16 = 7;
+pub const COMPACT_CARD_HEIGHT: u16 = 5;
 
 /// A visual playing card widget
 pub struct CardWidget {
     pub card: PlayingCard,
     pub selected: bool,
     pub highlighted: bool,
+    /// Part of the suggested best play (green border)
+    pub suggested: bool,
     pub face_down: bool,
     pub dimmed: bool,
     /// True when this card is actively being scored (bright glow border)
     pub scoring: bool,
+    /// Draw at `COMPACT_CARD_WIDTH` x `COMPACT_CARD_HEIGHT` without pips
+    pub compact: bool,
 }
 
 impl CardWidget {
@@ -27,9 +34,11 @@ impl CardWidget {
             card,
             selected: false,
             highlighted: false,
+            suggested: false,
             face_down: false,
             dimmed: false,
             scoring: false,
+            compact: false,
         }
     }
 
@@ -43,6 +52,11 @@ impl CardWidget {
         self
     }
 
+    pub fn suggested(mut self, suggested: bool) -> Self {
+        self.suggested = suggested;
+        self
+    }
+
     pub fn face_down(mut self, face_down: bool) -> Self {
         self.face_down = face_down;
         self
@@ -58,33 +72,62 @@ impl CardWidget {
         self
     }
 
+    pub fn compact(mut self, compact: bool) -> Self {
+        self.compact = compact;
+        self
+    }
+
+    /// (width, height) this card draws at
+    pub fn size(&self) -> (u16, u16) {
+        if self.compact {
+            (COMPACT_CARD_WIDTH, COMPACT_CARD_HEIGHT)
+        } else {
+            (CARD_WIDTH, CARD_HEIGHT)
+        }
+    }
+
     fn suit_color(&self) -> ratatui::style::Color {
         if self.dimmed || self.card.debuffed {
-            Theme::DIM_TEXT
-        } else if self.card.suit.is_red() {
-            Theme::RED_SUIT
+            Theme::dim_text()
         } else {
-            Theme::BLACK_SUIT
+            Theme::suit_color(self.card.suit)
         }
     }
 
     fn border_color(&self) -> ratatui::style::Color {
         if self.scoring {
-            Theme::BRIGHT_TEXT
+            Theme::bright_text()
         } else if self.selected {
-            Theme::CARD_SELECTED
+            Theme::card_selected()
         } else if self.highlighted {
-            Theme::GOLD
+            Theme::gold()
+        } else if self.suggested {
+            Theme::uncommon()
         } else {
-            Theme::CARD_BORDER
+            Theme::card_border()
         }
     }
 
+    fn enhancement_indicator(&self) -> Option<(&str, ratatui::style::Color)> {
+        use balatrust_core::card::Enhancement;
+        self.card.enhancement.map(|enh| match enh {
+            Enhancement::Bonus => ("+", Theme::chips_color()),
+            Enhancement::Mult => ("x", Theme::mult_color()),
+            Enhancement::Wild => ("W", Theme::legendary()),
+            Enhancement::Glass => ("G", Theme::bright_text()),
+            Enhancement::Steel => ("S", Theme::dim_text()),
+            Enhancement::Stone => ("O", Theme::muted_text()),
+            Enhancement::Gold => ("$", Theme::gold()),
+            Enhancement::Lucky => ("L", Theme::money_color()),
+        })
+    }
+
     fn edition_indicator(&self) -> Option<(&str, ratatui::style::Color)> {
         match self.card.edition {
-            Edition::Foil => Some(("F", Theme::CHIPS_COLOR)),
-            Edition::Holographic => Some(("H", Theme::MULT_COLOR)),
-            Edition::Polychrome => Some(("P", Theme::LEGENDARY)),
+            Edition::Foil => Some(("F", Theme::chips_color())),
+            Edition::Holographic => Some(("H", Theme::mult_color())),
+            Edition::Polychrome => Some(("P", Theme::legendary())),
+            Edition::Negative => Some(("N", Theme::bright_text())),
             Edition::Base => None,
         }
     }
@@ -92,9 +135,11 @@ impl CardWidget {
 
 impl Widget for CardWidget {
     fn render(self, area: Rect, buf: &mut Buffer) {
-        if area.width < CARD_WIDTH || area.height < CARD_HEIGHT {
+        let (width, height) = self.size();
+        if area.width < width || area.height < height {
             return;
         }
+        let area = Rect::new(area.x, area.y, width, height);
 
         let border_color = self.border_color();
         let border_style = Style::default().fg(border_color);
@@ -103,11 +148,15 @@ impl Widget for CardWidget {
             render_face_down(area, buf, border_style);
             return;
         }
+        if self.compact {
+            self.render_compact(area, buf, border_style);
+            return;
+        }
 
         let suit_color = self.suit_color();
         let rank_style = Style::default().fg(suit_color).add_modifier(Modifier::BOLD);
         let suit_style = Style::default().fg(suit_color);
-        let separator_style = Style::default().fg(Theme::CARD_FACE_DIM);
+        let separator_style = Style::default().fg(Theme::card_face_dim());
 
         let rank = self.card.rank.short_name();
         let suit_ch = self.card.suit.symbol();
@@ -134,6 +183,10 @@ impl Widget for CardWidget {
         buf.set_string(area.x + 1, area.y + 1, rank, rank_style);
         let suit_offset: u16 = if rank.len() > 1 { 2 } else { 1 };
         buf.set_string(area.x + 1 + suit_offset, area.y + 1, &suit_str, suit_style);
+        let letter = Theme::suit_letter(self.card.suit).map(|l| l.to_string());
+        if let Some(letter) = &letter {
+            buf.set_string(area.x + 2 + suit_offset, area.y + 1, letter, suit_style);
+        }
 
         // ─── Bottom-right: suit + rank (mirrored) ──────────────────
 
@@ -146,6 +199,9 @@ impl Widget for CardWidget {
         }
         let br_suit_x = area.x + CARD_WIDTH - 2 - rank.len() as u16;
         buf.set_string(br_suit_x, br_y, &suit_str, suit_style);
+        if let Some(letter) = &letter {
+            buf.set_string(br_suit_x - 1, br_y, letter, suit_style);
+        }
 
         // ─── Pip area ──────────────────────────────────────────────
         //
@@ -175,7 +231,7 @@ impl Widget for CardWidget {
         };
 
         let face_letter_style = Style::default().fg(suit_color).add_modifier(Modifier::BOLD);
-        let face_dim_style = Style::default().fg(Theme::CARD_FACE_DIM);
+        let face_dim_style = Style::default().fg(Theme::card_face_dim());
 
         match self.card.rank {
             // ── Ace: single centered pip ─────────────────────────
@@ -308,94 +364,119 @@ impl Widget for CardWidget {
 
         // ─── Enhancement indicator (bottom-left) ────────────────────
 
-        if let Some(enh) = &self.card.enhancement {
-            let (symbol, color) = match enh {
-                balatrust_core::card::Enhancement::Bonus => ("+", Theme::CHIPS_COLOR),
-                balatrust_core::card::Enhancement::Mult => ("x", Theme::MULT_COLOR),
-                balatrust_core::card::Enhancement::Wild => ("W", Theme::LEGENDARY),
-                balatrust_core::card::Enhancement::Glass => ("G", Theme::BRIGHT_TEXT),
-                balatrust_core::card::Enhancement::Steel => ("S", Theme::DIM_TEXT),
-                balatrust_core::card::Enhancement::Stone => ("O", Theme::MUTED_TEXT),
-                balatrust_core::card::Enhancement::Gold => ("$", Theme::GOLD),
-                balatrust_core::card::Enhancement::Lucky => ("L", Theme::MONEY_COLOR),
-            };
+        if let Some((symbol, color)) = self.enhancement_indicator() {
             let enh_style = Style::default().fg(color);
             buf.set_string(area.x + 1, area.y + CARD_HEIGHT - 2, symbol, enh_style);
         }
     }
 }
 
+impl CardWidget {
+    /// Compact layout: rank and suit in opposite corners, one center pip,
+    /// edition top-right and enhancement bottom-left
+    fn render_compact(&self, area: Rect, buf: &mut Buffer, border_style: Style) {
+        let suit_color = self.suit_color();
+        let rank_style = Style::default().fg(suit_color).add_modifier(Modifier::BOLD);
+        let suit_style = Style::default().fg(suit_color);
+        let rank = self.card.rank.short_name();
+        let suit_str = self.card.suit.symbol().to_string();
+        let rank_w = rank.len() as u16;
+
+        draw_border(area, buf, border_style, self.selected);
+
+        buf.set_string(area.x + 1, area.y + 1, rank, rank_style);
+        buf.set_string(area.x + 1 + rank_w, area.y + 1, &suit_str, suit_style);
+        if let Some(letter) = Theme::suit_letter(self.card.suit) {
+            let letter = letter.to_string();
+            buf.set_string(area.x + 2 + rank_w, area.y + 1, letter, suit_style);
+        }
+        buf.set_string(area.x + area.width / 2, area.y + 2, &suit_str, suit_style);
+        let br_y = area.y + area.height - 2;
+        let br_x = area.x + area.width - 1 - rank_w;
+        buf.set_string(br_x - 1, br_y, &suit_str, suit_style);
+        buf.set_string(br_x, br_y, rank, rank_style);
+
+        if let Some((indicator, color)) = self.edition_indicator() {
+            let ind_style = Style::default().fg(color).add_modifier(Modifier::BOLD);
+            buf.set_string(area.x + area.width - 2, area.y + 1, indicator, ind_style);
+        }
+        if let Some((symbol, color)) = self.enhancement_indicator() {
+            buf.set_string(area.x + 1, br_y, symbol, Style::default().fg(color));
+        }
+    }
+}
+
 /// Draw the card border and fill interior with spaces
 fn draw_border(area: Rect, buf: &mut Buffer, border_style: Style, selected: bool) {
     if selected {
         // Double-line border for selected cards
         buf.set_string(area.x, area.y, "\u{2554}", border_style);
-        for x in 1..CARD_WIDTH - 1 {
+        for x in 1..area.width - 1 {
             buf.set_string(area.x + x, area.y, "\u{2550}", border_style);
         }
-        buf.set_string(area.x + CARD_WIDTH - 1, area.y, "\u{2557}", border_style);
+        buf.set_string(area.x + area.width - 1, area.y, "\u{2557}", border_style);
 
-        for y in 1..CARD_HEIGHT - 1 {
+        for y in 1..area.height - 1 {
             buf.set_string(area.x, area.y + y, "\u{2551}", border_style);
             buf.set_string(
-                area.x + CARD_WIDTH - 1,
+                area.x + area.width - 1,
                 area.y + y,
                 "\u{2551}",
                 border_style,
             );
-            for x in 1..CARD_WIDTH - 1 {
+            for x in 1..area.width - 1 {
                 buf.set_string(area.x + x, area.y + y, " ", Style::default());
             }
         }
 
-        buf.set_string(area.x, area.y + CARD_HEIGHT - 1, "\u{255a}", border_style);
-        for x in 1..CARD_WIDTH - 1 {
+        buf.set_string(area.x, area.y + area.height - 1, "\u{255a}", border_style);
+        for x in 1..area.width - 1 {
             buf.set_string(
                 area.x + x,
-                area.y + CARD_HEIGHT - 1,
+                area.y + area.height - 1,
                 "\u{2550}",
                 border_style,
             );
         }
         buf.set_string(
-            area.x + CARD_WIDTH - 1,
-            area.y + CARD_HEIGHT - 1,
+            area.x + area.width - 1,
+            area.y + area.height - 1,
             "\u{255d}",
             border_style,
         );
     } else {
         // Rounded corners for normal cards
         buf.set_string(area.x, area.y, "\u{256d}", border_style);
-        for x in 1..CARD_WIDTH - 1 {
+        for x in 1..area.width - 1 {
             buf.set_string(area.x + x, area.y, "\u{2500}", border_style);
         }
-        buf.set_string(area.x + CARD_WIDTH - 1, area.y, "\u{256e}", border_style);
+        buf.set_string(area.x + area.width - 1, area.y, "\u{256e}", border_style);
 
-        for y in 1..CARD_HEIGHT - 1 {
+        for y in 1..area.height - 1 {
             buf.set_string(area.x, area.y + y, "\u{2502}", border_style);
             buf.set_string(
-                area.x + CARD_WIDTH - 1,
+                area.x + area.width - 1,
                 area.y + y,
                 "\u{2502}",
                 border_style,
             );
-            for x in 1..CARD_WIDTH - 1 {
+            for x in 1..area.width - 1 {
                 buf.set_string(area.x + x, area.y + y, " ", Style::default());
             }
         }
 
-        buf.set_string(area.x, area.y + CARD_HEIGHT - 1, "\u{2570}", border_style);
-        for x in 1..CARD_WIDTH - 1 {
+        buf.set_string(area.x, area.y + area.height - 1, "\u{2570}", border_style);
+        for x in 1..area.width - 1 {
             buf.set_string(
                 area.x + x,
-                area.y + CARD_HEIGHT - 1,
+                area.y + area.height - 1,
                 "\u{2500}",
                 border_style,
             );
         }
         buf.set_string(
-            area.x + CARD_WIDTH - 1,
-            area.y + CARD_HEIGHT - 1,
+            area.x + area.width - 1,
+            area.y + area.height - 1,
             "\u{256f}",
             border_style,
         );
@@ -403,19 +484,19 @@ fn draw_border(area: Rect, buf: &mut Buffer, border_style: Style, selected: bool
 }
 
 fn render_face_down(area: Rect, buf: &mut Buffer, border_style: Style) {
-    let fill_style = Style::default().fg(Theme::CARD_BACK);
+    let fill_style = Style::default().fg(Theme::card_back());
 
     // Top border
     buf.set_string(area.x, area.y, "\u{256d}", border_style);
-    for x in 1..CARD_WIDTH - 1 {
+    for x in 1..area.width - 1 {
         buf.set_string(area.x + x, area.y, "\u{2500}", border_style);
     }
-    buf.set_string(area.x + CARD_WIDTH - 1, area.y, "\u{256e}", border_style);
+    buf.set_string(area.x + area.width - 1, area.y, "\u{256e}", border_style);
 
     // Fill with pattern
-    for y in 1..CARD_HEIGHT - 1 {
+    for y in 1..area.height - 1 {
         buf.set_string(area.x, area.y + y, "\u{2502}", border_style);
-        for x in 1..CARD_WIDTH - 1 {
+        for x in 1..area.width - 1 {
             let pattern = if (x + y) % 2 == 0 {
                 "\u{2593}"
             } else {
@@ -424,7 +505,7 @@ fn render_face_down(area: Rect, buf: &mut Buffer, border_style: Style) {
             buf.set_string(area.x + x, area.y + y, pattern, fill_style);
         }
         buf.set_string(
-            area.x + CARD_WIDTH - 1,
+            area.x + area.width - 1,
             area.y + y,
             "\u{2502}",
             border_style,
@@ -432,19 +513,64 @@ fn render_face_down(area: Rect, buf: &mut Buffer, border_style: Style) {
     }
 
     // Bottom border
-    buf.set_string(area.x, area.y + CARD_HEIGHT - 1, "\u{2570}", border_style);
-    for x in 1..CARD_WIDTH - 1 {
+    buf.set_string(area.x, area.y + area.height - 1, "\u{2570}", border_style);
+    for x in 1..area.width - 1 {
         buf.set_string(
             area.x + x,
-            area.y + CARD_HEIGHT - 1,
+            area.y + area.height - 1,
             "\u{2500}",
             border_style,
         );
     }
     buf.set_string(
-        area.x + CARD_WIDTH - 1,
-        area.y + CARD_HEIGHT - 1,
+        area.x + area.width - 1,
+        area.y + area.height - 1,
         "\u{256f}",
         border_style,
     );
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use balatrust_core::card::Suit;
+    use ratatui::style::Color;
+
+    /// (suit glyph + letter, suit color) in the top-left corner of a King
+    fn corner(suit: Suit, compact: bool) -> (String, Color) {
+        let area = Rect::new(0, 0, CARD_WIDTH, CARD_HEIGHT);
+        let mut buf = Buffer::empty(area);
+        CardWidget::new(PlayingCard::new(Rank::King, suit))
+            .compact(compact)
+            .render(area, &mut buf);
+        let glyph = format!("{}{}", buf[(2, 1)].symbol(), buf[(3, 1)].symbol());
+        (glyph, buf[(2, 1)].fg)
+    }
+
+    #[test]
+    fn test_accessible_suits_are_all_distinct() {
+        Theme::set_accessible_suits(true);
+        let full: Vec<(String, Color)> = Suit::ALL.iter().map(|&s| corner(s, false)).collect();
+        let compact: Vec<(String, Color)> = Suit::ALL.iter().map(|&s| corner(s, true)).collect();
+        Theme::set_accessible_suits(false);
+
+        for looks in [&full, &compact] {
+            for (i, a) in looks.iter().enumerate() {
+                for b in &looks[i + 1..] {
+                    assert_ne!(a.0, b.0);
+                    assert_ne!(a.1, b.1);
+                }
+            }
+        }
+        assert_eq!(full[0].0, "\u{2660}S");
+        assert_eq!(full[2].0, "\u{2666}D");
+    }
+
+    #[test]
+    fn test_suits_share_red_and_black_by_default() {
+        let hearts = corner(Suit::Hearts, false);
+        let diamonds = corner(Suit::Diamonds, false);
+        assert_eq!(hearts.1, diamonds.1);
+        assert_eq!(hearts.0, "\u{2665} ");
+    }
+}
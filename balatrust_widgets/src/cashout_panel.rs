@@ -1,3 +1,4 @@
+use balatrust_core::format::format_number;
 use balatrust_core::run::RewardBreakdown;
 use ratatui::buffer::Buffer;
 use ratatui::layout::{Constraint, Layout, Rect};
@@ -13,6 +14,8 @@ pub struct CashOutPanel {
     pub blind_name: String,
     pub score_target: u64,
     pub breakdown: RewardBreakdown,
+    /// Lines shown so far (blind reward first, then earnings); the rest are hidden
+    pub revealed: usize,
 }
 
 impl CashOutPanel {
@@ -21,9 +24,27 @@ impl CashOutPanel {
             blind_name,
             score_target,
             breakdown,
+            revealed: usize::MAX,
         }
     }
 
+    /// Only show the lines a `CashOutTally` has counted so far
+    pub fn tally(mut self, tally: &CashOutTally) -> Self {
+        self.revealed = tally.revealed();
+        self
+    }
+
+    /// Money shown on the button: the sum of the revealed lines
+    fn shown_total(&self) -> u32 {
+        if self.revealed == usize::MAX {
+            return self.breakdown.total;
+        }
+        tally_amounts(&self.breakdown)
+            .iter()
+            .take(self.revealed)
+            .sum()
+    }
+
     /// Calculate the rect for the "Cash Out" button given the widget area.
     /// The button is in the top portion of the inner panel.
     pub fn cashout_button_rect(area: Rect) -> Rect {
@@ -93,7 +114,7 @@ impl Widget for CashOutPanel {
         let block = Block::default()
             .borders(Borders::ALL)
             .border_type(BorderType::Double)
-            .border_style(Style::default().fg(Theme::CARD_SELECTED))
+            .border_style(Style::default().fg(Theme::card_selected()))
             .padding(Padding::new(2, 2, 1, 1));
 
         let inner = block.inner(panel);
@@ -141,9 +162,9 @@ impl CashOutPanel {
             return;
         }
 
-        let border_style = Style::default().fg(Theme::GOLD);
+        let border_style = Style::default().fg(Theme::gold());
         let text_style = Style::default()
-            .fg(Theme::GOLD)
+            .fg(Theme::gold())
             .add_modifier(Modifier::BOLD);
 
         // Top border
@@ -162,7 +183,7 @@ impl CashOutPanel {
         let padding: String = " ".repeat(inner_w);
         buf.set_string(btn_area.x + 1, y, &padding, Style::default());
 
-        let label = format!("Cash Out: ${}", self.breakdown.total);
+        let label = format!("Cash Out: ${}", self.shown_total());
         let label_display: String = label.chars().take(inner_w).collect();
         let label_x =
             btn_area.x + 1 + (inner_w as u16).saturating_sub(label_display.len() as u16) / 2;
@@ -181,7 +202,7 @@ impl CashOutPanel {
     }
 
     fn render_blind_reward_line(&self, area: Rect, buf: &mut Buffer) {
-        if area.width < 10 {
+        if area.width < 10 || self.revealed == 0 {
             return;
         }
 
@@ -190,13 +211,13 @@ impl CashOutPanel {
             Span::styled(
                 format!(" {} ", self.blind_name),
                 Style::default()
-                    .fg(Theme::BRIGHT_TEXT)
+                    .fg(Theme::bright_text())
                     .add_modifier(Modifier::BOLD),
             ),
             Span::styled(
                 format!("\u{2265}{}", format_number(self.score_target)),
                 Style::default()
-                    .fg(Theme::MULT_COLOR)
+                    .fg(Theme::mult_color())
                     .add_modifier(Modifier::BOLD),
             ),
             // Right-align the dollar signs
@@ -211,7 +232,7 @@ impl CashOutPanel {
                         + dollars.len()
                 ),
                 Style::default()
-                    .fg(Theme::MULT_COLOR)
+                    .fg(Theme::mult_color())
                     .add_modifier(Modifier::BOLD),
             ),
         ]);
@@ -227,7 +248,12 @@ impl CashOutPanel {
             .chars()
             .take(area.width as usize)
             .collect();
-        buf.set_string(area.x, area.y, &dots, Style::default().fg(Theme::DIM_TEXT));
+        buf.set_string(
+            area.x,
+            area.y,
+            &dots,
+            Style::default().fg(Theme::dim_text()),
+        );
     }
 
     fn render_earnings_breakdown(&self, area: Rect, buf: &mut Buffer) {
@@ -235,18 +261,22 @@ impl CashOutPanel {
             return;
         }
 
-        let mut y = area.y;
-
         // Each earning line: amount (blue) | description (white) | dollar signs (red)
-        let lines = self.build_earnings_lines();
+        let lines = earnings_lines(&self.breakdown);
+        let shown = self.revealed.saturating_sub(1);
 
-        for (amount, description, dollar_count) in &lines {
+        for (y, (amount, description, dollar_count)) in (area.y..).zip(lines.iter().take(shown)) {
             if y >= area.bottom() {
                 break;
             }
 
             let dollars: String = "$".repeat(*dollar_count as usize);
-            let amount_str = format!("{}", amount);
+            // Lines that pay nothing now (Egg) leave the amount blank
+            let amount_str = if *amount > 0 {
+                amount.to_string()
+            } else {
+                String::new()
+            };
 
             // Left: amount in blue
             buf.set_string(
@@ -254,7 +284,7 @@ impl CashOutPanel {
                 y,
                 &amount_str,
                 Style::default()
-                    .fg(Theme::CHIPS_COLOR)
+                    .fg(Theme::chips_color())
                     .add_modifier(Modifier::BOLD),
             );
 
@@ -269,7 +299,7 @@ impl CashOutPanel {
                 desc_x,
                 y,
                 &desc_display,
-                Style::default().fg(Theme::MUTED_TEXT),
+                Style::default().fg(Theme::muted_text()),
             );
 
             // Right: dollar signs in red
@@ -279,63 +309,169 @@ impl CashOutPanel {
                 y,
                 &dollars,
                 Style::default()
-                    .fg(Theme::MULT_COLOR)
+                    .fg(Theme::mult_color())
                     .add_modifier(Modifier::BOLD),
             );
-
-            y += 1;
         }
     }
+}
 
-    fn build_earnings_lines(&self) -> Vec<(u32, String, u32)> {
-        let mut lines = Vec::new();
-        let bd = &self.breakdown;
-
-        // Remaining hands bonus
-        if bd.hands_bonus > 0 {
-            lines.push((
-                bd.hands_bonus,
-                format!("Remaining hands ($1 each)",),
-                bd.hands_bonus,
-            ));
-        }
+/// Earning lines below the blind reward: (amount, description, dollar count)
+fn earnings_lines(bd: &RewardBreakdown) -> Vec<(u32, String, u32)> {
+    let mut lines = Vec::new();
+
+    // Remaining hands bonus
+    if bd.hands_bonus > 0 {
+        lines.push((
+            bd.hands_bonus,
+            "Remaining hands ($1 each)".to_string(),
+            bd.hands_bonus,
+        ));
+    }
+
+    // Interest
+    if bd.interest > 0 {
+        lines.push((
+            bd.interest,
+            format!("Interest: $1 per $5 held (max {})", bd.interest_cap),
+            bd.interest,
+        ));
+    }
+
+    // Economy jokers, one line each; Egg's sell value growth pays nothing now
+    for payout in &bd.joker_payouts {
+        let description = if payout.sell_value > 0 {
+            format!("{} (+${} sell value)", payout.name, payout.sell_value)
+        } else {
+            payout.name.to_string()
+        };
+        lines.push((payout.money, description, payout.money));
+    }
+
+    // Gold cards held in hand
+    if bd.gold_cards_bonus > 0 {
+        lines.push((
+            bd.gold_cards_bonus,
+            "Gold cards held ($3 each)".to_string(),
+            bd.gold_cards_bonus,
+        ));
+    }
+
+    lines
+}
+
+/// Amounts in the order the tally counts them: blind reward, then each earning line
+fn tally_amounts(bd: &RewardBreakdown) -> Vec<u32> {
+    std::iter::once(bd.blind_reward)
+        .chain(earnings_lines(bd).into_iter().map(|(amount, _, _)| amount))
+        .collect()
+}
 
-        // Interest
-        if bd.interest > 0 {
-            lines.push((
-                bd.interest,
-                format!("Interest: $1 per $5 held (max 5)"),
-                bd.interest,
-            ));
+// ─── Tally Animation ─────────────────────────────────────────────────
+
+/// Ticks between two lines of the tally
+const TICKS_PER_LINE: u8 = 10;
+
+/// Reveals the cash-out lines one at a time, adding each to the running total
+#[derive(Debug, Clone)]
+pub struct CashOutTally {
+    amounts: Vec<u32>,
+    revealed: usize,
+    timer: u8,
+}
+
+impl CashOutTally {
+    pub fn new(breakdown: &RewardBreakdown) -> Self {
+        Self {
+            amounts: tally_amounts(breakdown),
+            revealed: 0,
+            timer: TICKS_PER_LINE,
         }
+    }
 
-        // Golden Joker bonus
-        if bd.golden_joker_bonus > 0 {
-            lines.push((
-                bd.golden_joker_bonus,
-                "Golden Joker".to_string(),
-                bd.golden_joker_bonus,
-            ));
+    /// Advance one tick, revealing the next line when its timer runs out
+    pub fn tick(&mut self) {
+        if self.is_done() {
+            return;
+        }
+        if self.timer == 0 {
+            self.revealed += 1;
+            self.timer = TICKS_PER_LINE;
+        } else {
+            self.timer -= 1;
         }
+    }
+
+    /// Reveal every line at once
+    pub fn skip(&mut self) {
+        self.revealed = self.amounts.len();
+    }
 
-        lines
+    pub fn is_done(&self) -> bool {
+        self.revealed >= self.amounts.len()
+    }
+
+    /// Number of lines revealed so far
+    pub fn revealed(&self) -> usize {
+        self.revealed
+    }
+
+    /// Money counted so far
+    pub fn total(&self) -> u32 {
+        self.amounts.iter().take(self.revealed).sum()
     }
 }
 
-fn format_number(n: u64) -> String {
-    if n >= 1_000_000 {
-        format!("{:.1}M", n as f64 / 1_000_000.0)
-    } else if n >= 1_000 {
-        let s = n.to_string();
-        let mut result = String::new();
-        for (i, c) in s.chars().rev().enumerate() {
-            if i > 0 && i % 3 == 0 {
-                result.push(',');
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use balatrust_core::run::JokerPayout;
+
+    fn breakdown() -> RewardBreakdown {
+        RewardBreakdown {
+            blind_reward: 5,
+            hands_bonus: 2,
+            hands_remaining: 2,
+            interest: 3,
+            interest_cap: 5,
+            money_held: 15,
+            joker_payouts: vec![JokerPayout {
+                joker_index: 0,
+                name: "Golden Joker",
+                money: 4,
+                sell_value: 0,
+            }],
+            economy_joker_bonus: 4,
+            gold_cards_bonus: 0,
+            total: 14,
+        }
+    }
+
+    #[test]
+    fn test_tally_reveals_lines_one_at_a_time() {
+        let mut tally = CashOutTally::new(&breakdown());
+        assert_eq!(tally.total(), 0);
+
+        let mut totals = Vec::new();
+        while !tally.is_done() {
+            let before = tally.revealed();
+            tally.tick();
+            if tally.revealed() != before {
+                totals.push(tally.total());
             }
-            result.push(c);
         }
-        result.chars().rev().collect()
-    } else {
-        n.to_string()
+
+        // Blind reward, hands, interest, Golden Joker; no Gold cards line
+        assert_eq!(totals, vec![5, 7, 10, 14]);
+        assert_eq!(tally.total(), breakdown().total);
+    }
+
+    #[test]
+    fn test_tally_skip_reaches_total() {
+        let mut tally = CashOutTally::new(&breakdown());
+        tally.tick();
+        tally.skip();
+        assert!(tally.is_done());
+        assert_eq!(tally.total(), breakdown().total);
     }
 }
@@ -14,6 +14,8 @@ const SLOT_HEIGHT: u16 = 5;
 pub struct ConsumableSlotsWidget<'a> {
     pub consumables: &'a [Consumable],
     pub max_consumables: u8,
+    /// Slot under the cursor, drawn highlighted with its sell value
+    pub selected: Option<usize>,
 }
 
 impl<'a> ConsumableSlotsWidget<'a> {
@@ -21,8 +23,40 @@ impl<'a> ConsumableSlotsWidget<'a> {
         Self {
             consumables,
             max_consumables,
+            selected: None,
         }
     }
+
+    pub fn selected(mut self, selected: Option<usize>) -> Self {
+        self.selected = selected;
+        self
+    }
+
+    /// Slots to draw: `max_consumables` plus one per Negative copy
+    pub fn slot_count(consumables: &[Consumable], max_consumables: u8) -> u8 {
+        let free = consumables.iter().filter(|c| !c.takes_slot()).count();
+        (max_consumables as usize + free).max(consumables.len()) as u8
+    }
+
+    /// Rects of each slot that fits in `area` (for mouse hit-testing)
+    pub fn slot_rects(area: Rect, max_consumables: u8) -> Vec<Rect> {
+        if area.width < 6 || area.height < 3 {
+            return Vec::new();
+        }
+
+        let slot_w = SLOT_WIDTH.min(area.width);
+        let slot_x = area.x + area.width.saturating_sub(slot_w) / 2;
+        let mut y = area.y + 1; // below the counter header
+        let mut rects = Vec::new();
+        for _ in 0..max_consumables {
+            if y + SLOT_HEIGHT > area.bottom() {
+                break;
+            }
+            rects.push(Rect::new(slot_x, y, slot_w, SLOT_HEIGHT));
+            y += SLOT_HEIGHT + 1; // +1 gap between slots
+        }
+        rects
+    }
 }
 
 impl<'a> Widget for ConsumableSlotsWidget<'a> {
@@ -31,49 +65,48 @@ impl<'a> Widget for ConsumableSlotsWidget<'a> {
             return;
         }
 
-        let mut y = area.y;
-
         // Counter header: "0/2"
-        let counter = format!("{}/{}", self.consumables.len(), self.max_consumables);
+        let used = self.consumables.iter().filter(|c| c.takes_slot()).count();
+        let counter = format!("{}/{}", used, self.max_consumables);
         let x = area.x + area.width.saturating_sub(counter.len() as u16) / 2;
         buf.set_string(
             x,
-            y,
+            area.y,
             &counter,
             Style::default()
-                .fg(Theme::MUTED_TEXT)
+                .fg(Theme::muted_text())
                 .add_modifier(Modifier::BOLD),
         );
-        y += 1;
 
         // Render each slot
-        for i in 0..self.max_consumables as usize {
-            if y + SLOT_HEIGHT > area.bottom() {
-                break;
-            }
-
-            let slot_w = SLOT_WIDTH.min(area.width);
-            let slot_x = area.x + area.width.saturating_sub(slot_w) / 2;
-            let slot_area = Rect::new(slot_x, y, slot_w, SLOT_HEIGHT);
-
+        let slots = Self::slot_rects(
+            area,
+            Self::slot_count(self.consumables, self.max_consumables),
+        );
+        for (i, slot_area) in slots.into_iter().enumerate() {
             if let Some(consumable) = self.consumables.get(i) {
-                render_consumable_card(consumable, slot_area, buf);
+                render_consumable_card(consumable, slot_area, buf, self.selected == Some(i));
             } else {
                 render_empty_slot(slot_area, buf);
             }
-
-            y += SLOT_HEIGHT + 1; // +1 gap between slots
         }
     }
 }
 
-fn render_consumable_card(consumable: &Consumable, area: Rect, buf: &mut Buffer) {
+fn render_consumable_card(consumable: &Consumable, area: Rect, buf: &mut Buffer, selected: bool) {
     let color = match consumable.consumable_type {
-        ConsumableType::Planet(_) => Theme::CHIPS_COLOR,
-        ConsumableType::Tarot(_) => Theme::LEGENDARY,
+        ConsumableType::Planet(_) => Theme::chips_color(),
+        ConsumableType::Tarot(_) => Theme::legendary(),
+        ConsumableType::Spectral(_) => Theme::spectral(),
     };
 
-    let border_style = Style::default().fg(color);
+    let border_style = if selected {
+        Style::default()
+            .fg(Theme::card_selected())
+            .add_modifier(Modifier::BOLD)
+    } else {
+        Style::default().fg(color)
+    };
 
     // Top border
     buf.set_string(area.x, area.y, "\u{256d}", border_style);
@@ -114,14 +147,16 @@ fn render_consumable_card(consumable: &Consumable, area: Rect, buf: &mut Buffer)
         area.y + 1,
         &display_name,
         Style::default()
-            .fg(Theme::BRIGHT_TEXT)
+            .fg(Theme::bright_text())
             .add_modifier(Modifier::BOLD),
     );
 
     // Type label
     let type_label = match consumable.consumable_type {
+        _ if consumable.negative => "Negative",
         ConsumableType::Planet(_) => "Planet",
         ConsumableType::Tarot(_) => "Tarot",
+        ConsumableType::Spectral(_) => "Spectral",
     };
     let type_display: String = type_label.chars().take(max_len).collect();
     let type_x = area.x + 1 + (max_len as u16).saturating_sub(type_display.len() as u16) / 2;
@@ -131,10 +166,23 @@ fn render_consumable_card(consumable: &Consumable, area: Rect, buf: &mut Buffer)
         &type_display,
         Style::default().fg(color),
     );
+
+    // Sell value under the cursor
+    if selected {
+        let sell = format!("Sell ${}", consumable.consumable_type.sell_value());
+        let sell_display: String = sell.chars().take(max_len).collect();
+        let sell_x = area.x + 1 + (max_len as u16).saturating_sub(sell_display.len() as u16) / 2;
+        buf.set_string(
+            sell_x,
+            area.y + 3,
+            &sell_display,
+            Style::default().fg(Theme::money_color()),
+        );
+    }
 }
 
 fn render_empty_slot(area: Rect, buf: &mut Buffer) {
-    let style = Style::default().fg(Theme::DIM_TEXT);
+    let style = Style::default().fg(Theme::dim_text());
 
     // Top border
     buf.set_string(area.x, area.y, "\u{256d}", style);
@@ -1,6 +1,7 @@
 use std::collections::HashMap;
 
 use balatrust_core::card::{Edition, Enhancement, PlayingCard, Rank, Seal, Suit};
+use balatrust_core::deck::Deck;
 use ratatui::buffer::Buffer;
 use ratatui::layout::Rect;
 use ratatui::style::{Modifier, Style};
@@ -28,6 +29,31 @@ const RANKS_HIGH_TO_LOW: [Rank; 13] = [
 
 const SUITS_ORDER: [Suit; 4] = [Suit::Spades, Suit::Hearts, Suit::Clubs, Suit::Diamonds];
 
+/// Where the owned cards are right now
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct PileCounts {
+    pub draw: usize,
+    pub discard: usize,
+    /// Played this round, set aside until the next one
+    pub played: usize,
+    pub hand: usize,
+}
+
+impl PileCounts {
+    pub fn new(deck: &Deck, hand: usize) -> Self {
+        Self {
+            draw: deck.remaining(),
+            discard: deck.discard_count(),
+            played: deck.played_count(),
+            hand,
+        }
+    }
+
+    pub fn total(&self) -> usize {
+        self.draw + self.discard + self.played + self.hand
+    }
+}
+
 // ═══════════════════════════════════════════════════════════════════════
 // DeckPreviewWidget — small stacked-card icon for the right sidebar
 // ═══════════════════════════════════════════════════════════════════════
@@ -35,16 +61,12 @@ const SUITS_ORDER: [Suit; 4] = [Suit::Spades, Suit::Hearts, Suit::Clubs, Suit::D
 /// A small clickable deck preview that looks like a stack of cards.
 /// Renders in the right sidebar bottom area.
 pub struct DeckPreviewWidget {
-    pub total_cards: usize,
-    pub remaining: usize,
+    pub piles: PileCounts,
 }
 
 impl DeckPreviewWidget {
-    pub fn new(total_cards: usize, remaining: usize) -> Self {
-        Self {
-            total_cards,
-            remaining,
-        }
+    pub fn new(piles: PileCounts) -> Self {
+        Self { piles }
     }
 
     /// Return the rect where the preview is rendered (for hit-testing).
@@ -72,8 +94,8 @@ impl Widget for DeckPreviewWidget {
         let x = area.x + area.width.saturating_sub(w) / 2;
         let y = area.y;
 
-        let border_style = Style::default().fg(Theme::CARD_BORDER);
-        let back_style = Style::default().fg(Theme::CARD_BACK);
+        let border_style = Style::default().fg(Theme::card_border());
+        let back_style = Style::default().fg(Theme::card_back());
 
         // Draw 3 offset card backs to create a "stack" effect
         // Card 3 (bottom of stack, offset +2,+1)
@@ -87,8 +109,8 @@ impl Widget for DeckPreviewWidget {
         // Card 1 (top, front)
         draw_mini_card_front(x, y, w, h, buf);
 
-        // Count below the stack
-        let count_str = format!("{}/{}", self.remaining, self.total_cards);
+        // Count below the stack, then the discard pile when there's room
+        let count_str = format!("{}/{}", self.piles.draw, self.piles.total());
         let cx = area.x + area.width.saturating_sub(count_str.len() as u16) / 2;
         let cy = y + h;
         if cy < area.bottom() {
@@ -97,10 +119,20 @@ impl Widget for DeckPreviewWidget {
                 cy,
                 &count_str,
                 Style::default()
-                    .fg(Theme::MUTED_TEXT)
+                    .fg(Theme::muted_text())
                     .add_modifier(Modifier::BOLD),
             );
         }
+        let discard_str = format!("Discard {}", self.piles.discard);
+        let dx = area.x + area.width.saturating_sub(discard_str.len() as u16) / 2;
+        if cy + 1 < area.bottom() && discard_str.len() as u16 <= area.width {
+            buf.set_string(
+                dx,
+                cy + 1,
+                &discard_str,
+                Style::default().fg(Theme::dim_text()),
+            );
+        }
     }
 }
 
@@ -144,7 +176,7 @@ fn draw_mini_card_back(
 }
 
 fn draw_mini_card_front(x: u16, y: u16, w: u16, h: u16, buf: &mut Buffer) {
-    let border = Style::default().fg(Theme::GOLD);
+    let border = Style::default().fg(Theme::gold());
 
     // Top border
     buf.set_string(x, y, "\u{256d}", border);
@@ -179,7 +211,7 @@ fn draw_mini_card_front(x: u16, y: u16, w: u16, h: u16, buf: &mut Buffer) {
         ly,
         label,
         Style::default()
-            .fg(Theme::GOLD)
+            .fg(Theme::gold())
             .add_modifier(Modifier::BOLD),
     );
 }
@@ -192,6 +224,22 @@ fn draw_mini_card_front(x: u16, y: u16, w: u16, h: u16, buf: &mut Buffer) {
 pub struct DeckOverlayWidget<'a> {
     pub cards: &'a [PlayingCard],
     pub selected_card: Option<usize>,
+    /// Grid lines scrolled past at the top
+    pub scroll: u16,
+    /// Only cards matching this are shown (see `card_matches_filter`)
+    pub filter: &'a str,
+    /// Whether the filter is being typed into
+    pub filter_editing: bool,
+    /// Draw, discard and hand counts for the info panel, if known
+    pub piles: Option<PileCounts>,
+}
+
+/// One line of the card grid. A suit whose cards don't fit on one line wraps
+/// onto more lines; only its first line carries the suit symbol.
+struct GridLine {
+    suit: Suit,
+    show_suit: bool,
+    cards: Vec<(usize, PlayingCard)>,
 }
 
 /// Precomputed stats about the deck for the info panel
@@ -248,41 +296,124 @@ impl<'a> DeckOverlayWidget<'a> {
         Self {
             cards,
             selected_card,
+            scroll: 0,
+            filter: "",
+            filter_editing: false,
+            piles: None,
         }
     }
 
-    /// Compute the card grid rects for hit-testing.
+    /// Start the card grid `offset` lines down
+    pub fn scroll(mut self, offset: u16) -> Self {
+        self.scroll = offset;
+        self
+    }
+
+    /// Only show cards matching `filter`
+    pub fn filter(mut self, filter: &'a str, editing: bool) -> Self {
+        self.filter = filter;
+        self.filter_editing = editing;
+        self
+    }
+
+    /// Show where the cards are (draw pile, discard pile, hand) in the info panel
+    pub fn piles(mut self, piles: Option<PileCounts>) -> Self {
+        self.piles = piles;
+        self
+    }
+
+    /// Compute the card grid rects for hit-testing, for cards matching `filter`.
     /// Returns a vec of (global_card_index, Rect) for each card cell rendered.
-    pub fn card_cell_rects(area: Rect, cards: &[PlayingCard]) -> Vec<(usize, Rect)> {
-        let overlay = Self::overlay_rect(area);
-        if overlay.width < 20 || overlay.height < 10 {
+    pub fn card_cell_rects(
+        area: Rect,
+        cards: &[PlayingCard],
+        filter: &str,
+        scroll: u16,
+    ) -> Vec<(usize, Rect)> {
+        let Some(grid_area) = Self::grid_area_for(area) else {
             return Vec::new();
+        };
+        let cell_w = Self::cell_width(grid_area);
+
+        let mut result = Vec::new();
+        for (y, line) in Self::visible_lines(grid_area, cards, filter, scroll) {
+            let Some(line) = line else { continue };
+            let mut x = grid_area.x + 2; // offset for suit symbol
+            for &(global_idx, _card) in &line.cards {
+                result.push((global_idx, Rect::new(x, y, cell_w, 1)));
+                x += cell_w;
+            }
         }
+        result
+    }
+
+    /// Furthest the card grid can scroll for this screen area, deck and filter
+    pub fn max_scroll(area: Rect, cards: &[PlayingCard], filter: &str) -> u16 {
+        let Some(grid_area) = Self::grid_area_for(area) else {
+            return 0;
+        };
+        let lines = Self::grid_lines(grid_area, cards, filter).len() as u16;
+        lines.saturating_sub(grid_area.height)
+    }
 
+    /// Card grid area within the screen, if the overlay is big enough to show one
+    fn grid_area_for(area: Rect) -> Option<Rect> {
+        let overlay = Self::overlay_rect(area);
+        if overlay.width < 20 || overlay.height < 10 {
+            return None;
+        }
         let inner = Self::inner_rect(overlay);
-        let grid_area = Self::grid_area(inner);
+        if inner.width < 10 || inner.height < 4 {
+            return None;
+        }
+        Some(Self::grid_area(inner))
+    }
 
-        let mut result = Vec::new();
-        let cards_by_suit = Self::group_by_suit(cards);
+    /// All grid lines, top to bottom. `None` is the blank gap between suits.
+    fn grid_lines(grid_area: Rect, cards: &[PlayingCard], filter: &str) -> Vec<Option<GridLine>> {
+        let cards_by_suit = Self::group_by_suit(cards, filter);
         let cell_w = Self::cell_width(grid_area);
+        let per_line = (grid_area.width.saturating_sub(2) / cell_w).max(1) as usize;
 
-        let mut y = grid_area.y;
-        for &suit in &SUITS_ORDER {
-            if y + 1 > grid_area.bottom() {
-                break;
+        let mut lines = Vec::new();
+        for (i, &suit) in SUITS_ORDER.iter().enumerate() {
+            if i > 0 {
+                lines.push(None);
             }
             let suit_cards = cards_by_suit.get(&suit).cloned().unwrap_or_default();
-            let mut x = grid_area.x + 2; // offset for suit symbol
-            for &(global_idx, _card) in &suit_cards {
-                if x + cell_w > grid_area.right() {
-                    break;
-                }
-                result.push((global_idx, Rect::new(x, y, cell_w, 1)));
-                x += cell_w;
+            if suit_cards.is_empty() {
+                lines.push(Some(GridLine {
+                    suit,
+                    show_suit: true,
+                    cards: Vec::new(),
+                }));
+                continue;
+            }
+            for (j, chunk) in suit_cards.chunks(per_line).enumerate() {
+                lines.push(Some(GridLine {
+                    suit,
+                    show_suit: j == 0,
+                    cards: chunk.to_vec(),
+                }));
             }
-            y += 2; // row + gap
         }
-        result
+        lines
+    }
+
+    /// Grid lines that fit in `grid_area` after scrolling, with their row
+    fn visible_lines(
+        grid_area: Rect,
+        cards: &[PlayingCard],
+        filter: &str,
+        scroll: u16,
+    ) -> Vec<(u16, Option<GridLine>)> {
+        (grid_area.y..grid_area.bottom())
+            .zip(
+                Self::grid_lines(grid_area, cards, filter)
+                    .into_iter()
+                    .skip(scroll as usize),
+            )
+            .collect()
     }
 
     /// Get the back button rect for hit-testing
@@ -333,17 +464,22 @@ impl<'a> DeckOverlayWidget<'a> {
         // Each card cell is ~4 chars (e.g. "A\u{2660} "), at least 3
         let available = grid_area.width.saturating_sub(2); // suit label
         let per_card = available / 13;
-        per_card.max(3).min(5)
+        per_card.clamp(3, 5)
     }
 
-    fn group_by_suit(cards: &[PlayingCard]) -> HashMap<Suit, Vec<(usize, PlayingCard)>> {
+    fn group_by_suit(
+        cards: &[PlayingCard],
+        filter: &str,
+    ) -> HashMap<Suit, Vec<(usize, PlayingCard)>> {
         let mut map: HashMap<Suit, Vec<(usize, PlayingCard)>> = HashMap::new();
         for (i, card) in cards.iter().enumerate() {
-            map.entry(card.suit).or_default().push((i, *card));
+            if card_matches_filter(card, filter) {
+                map.entry(card.suit).or_default().push((i, *card));
+            }
         }
         // Sort each suit's cards by rank high to low
         for cards_vec in map.values_mut() {
-            cards_vec.sort_by(|a, b| rank_sort_key(b.1.rank).cmp(&rank_sort_key(a.1.rank)));
+            cards_vec.sort_by_key(|c| std::cmp::Reverse(rank_sort_key(c.1.rank)));
         }
         map
     }
@@ -353,15 +489,6 @@ fn rank_sort_key(rank: Rank) -> u8 {
     rank as u8
 }
 
-fn suit_color(suit: Suit) -> ratatui::style::Color {
-    match suit {
-        Suit::Spades => Theme::BLACK_SUIT,
-        Suit::Hearts => Theme::RED_SUIT,
-        Suit::Clubs => Theme::BLACK_SUIT,
-        Suit::Diamonds => Theme::RED_SUIT,
-    }
-}
-
 fn suit_symbol(suit: Suit) -> &'static str {
     match suit {
         Suit::Spades => "\u{2660}",
@@ -371,6 +498,36 @@ fn suit_symbol(suit: Suit) -> &'static str {
     }
 }
 
+/// Suit symbol, followed by its letter when accessible suits are on ("♠S")
+fn suit_label(suit: Suit) -> String {
+    match Theme::suit_letter(suit) {
+        Some(letter) => format!("{}{}", suit_symbol(suit), letter),
+        None => suit_symbol(suit).to_string(),
+    }
+}
+
+/// Whether `card` matches the deck viewer filter (case-insensitive).
+/// A rank symbol must match exactly ("K", "10"); rank, suit, enhancement,
+/// seal and edition names match by prefix ("hea", "steel"). Empty matches all.
+pub fn card_matches_filter(card: &PlayingCard, filter: &str) -> bool {
+    let filter = filter.trim().to_lowercase();
+    if filter.is_empty() {
+        return true;
+    }
+    if card.rank.short_name().to_lowercase() == filter {
+        return true;
+    }
+    let mut names = vec![card.rank.name(), card.suit.name()];
+    names.extend(card.enhancement.map(|e| e.name()));
+    names.extend(card.seal.map(|s| s.name()));
+    if card.edition != Edition::Base {
+        names.push(card.edition.name());
+    }
+    names
+        .iter()
+        .any(|name| name.to_lowercase().starts_with(&filter))
+}
+
 impl<'a> Widget for DeckOverlayWidget<'a> {
     fn render(self, area: Rect, buf: &mut Buffer) {
         let overlay = Self::overlay_rect(area);
@@ -382,7 +539,7 @@ impl<'a> Widget for DeckOverlayWidget<'a> {
         Clear.render(overlay, buf);
 
         // Draw outer border
-        let border_style = Style::default().fg(Theme::CARD_SELECTED);
+        let border_style = Style::default().fg(Theme::card_selected());
         draw_overlay_border(overlay, buf, border_style);
 
         // Title badge: "Full Deck" centered at top
@@ -393,7 +550,7 @@ impl<'a> Widget for DeckOverlayWidget<'a> {
             overlay.y,
             title,
             Style::default()
-                .fg(Theme::MULT_COLOR)
+                .fg(Theme::mult_color())
                 .add_modifier(Modifier::BOLD),
         );
 
@@ -404,7 +561,7 @@ impl<'a> Widget for DeckOverlayWidget<'a> {
                 arrow_x,
                 overlay.y + 1,
                 "\u{25bc}",
-                Style::default().fg(Theme::MULT_COLOR),
+                Style::default().fg(Theme::mult_color()),
             );
         }
 
@@ -454,15 +611,61 @@ impl<'a> DeckOverlayWidget<'a> {
             y,
             &total_str,
             Style::default()
-                .fg(Theme::BRIGHT_TEXT)
+                .fg(Theme::bright_text())
                 .add_modifier(Modifier::BOLD),
         );
-        y += 2;
+        y += 1;
+
+        // Pile counts, with the played cards only once there are some
+        if let Some(piles) = self.piles {
+            let pile_style = Style::default().fg(Theme::muted_text());
+            let draw_line = format!("Draw {} · Discard {}", piles.draw, piles.discard);
+            buf.set_stringn(area.x, y, &draw_line, area.width as usize, pile_style);
+            let mut hand_line = format!("Hand {}", piles.hand);
+            if piles.played > 0 {
+                hand_line.push_str(&format!(" · Played {}", piles.played));
+            }
+            buf.set_stringn(area.x, y + 1, &hand_line, area.width as usize, pile_style);
+            y += 2;
+        }
+
+        // Active filter and match count, or a hint to start one
+        if self.filter.is_empty() && !self.filter_editing {
+            buf.set_string(
+                area.x,
+                y,
+                "[/] Filter",
+                Style::default().fg(Theme::dim_text()),
+            );
+            y += 2;
+        } else {
+            let cursor = if self.filter_editing { "_" } else { "" };
+            let filter_line = format!("/{}{}", self.filter, cursor);
+            buf.set_stringn(
+                area.x,
+                y,
+                &filter_line,
+                area.width as usize,
+                Style::default().fg(Theme::card_selected()),
+            );
+            let matches = self
+                .cards
+                .iter()
+                .filter(|c| card_matches_filter(c, self.filter))
+                .count();
+            buf.set_string(
+                area.x,
+                y + 1,
+                format!("{} matching", matches),
+                Style::default().fg(Theme::muted_text()),
+            );
+            y += 3;
+        }
 
         // Rank counts
-        let label_style = Style::default().fg(Theme::MUTED_TEXT);
+        let label_style = Style::default().fg(Theme::muted_text());
         let count_style = Style::default()
-            .fg(Theme::BRIGHT_TEXT)
+            .fg(Theme::bright_text())
             .add_modifier(Modifier::BOLD);
 
         for &(rank, count) in &stats.rank_counts {
@@ -471,7 +674,7 @@ impl<'a> DeckOverlayWidget<'a> {
             }
             let rank_str = format!("{:>2}", rank.short_name());
             buf.set_string(area.x, y, &rank_str, label_style);
-            buf.set_string(area.x + 3, y, &format!("{}", count), count_style);
+            buf.set_string(area.x + 3, y, format!("{}", count), count_style);
             y += 1;
         }
 
@@ -486,7 +689,7 @@ impl<'a> DeckOverlayWidget<'a> {
             y,
             "Base Cards",
             Style::default()
-                .fg(Theme::GOLD)
+                .fg(Theme::gold())
                 .add_modifier(Modifier::BOLD),
         );
         y += 1;
@@ -497,7 +700,12 @@ impl<'a> DeckOverlayWidget<'a> {
                 "Enh:{} Seal:{} Ed:{}",
                 stats.enhanced_count, stats.sealed_count, stats.edition_count
             );
-            buf.set_string(area.x, y, &enh_line, Style::default().fg(Theme::MUTED_TEXT));
+            buf.set_string(
+                area.x,
+                y,
+                &enh_line,
+                Style::default().fg(Theme::muted_text()),
+            );
             y += 1;
         }
 
@@ -513,17 +721,17 @@ impl<'a> DeckOverlayWidget<'a> {
             }
             let mut x = area.x;
             for &(suit, count) in pair {
-                let sym = suit_symbol(suit);
-                let color = suit_color(suit);
-                buf.set_string(x, y, sym, Style::default().fg(color));
-                x += 1;
+                let label = suit_label(suit);
+                let color = Theme::suit_color(suit);
+                buf.set_string(x, y, &label, Style::default().fg(color));
+                x += label.chars().count() as u16;
                 let cnt = format!("{:<4}", count);
                 buf.set_string(
                     x,
                     y,
                     &cnt,
                     Style::default()
-                        .fg(Theme::BRIGHT_TEXT)
+                        .fg(Theme::bright_text())
                         .add_modifier(Modifier::BOLD),
                 );
                 x += 5;
@@ -537,28 +745,22 @@ impl<'a> DeckOverlayWidget<'a> {
             return;
         }
 
-        let cards_by_suit = Self::group_by_suit(self.cards);
         let cell_w = Self::cell_width(area);
+        let visible = Self::visible_lines(area, self.cards, self.filter, self.scroll);
 
-        let mut y = area.y;
-        for &suit in &SUITS_ORDER {
-            if y + 1 > area.bottom() {
-                break;
-            }
+        for (y, line) in visible {
+            let Some(line) = line else { continue };
+            let suit = line.suit;
 
             // Suit label
             let sym = suit_symbol(suit);
-            let color = suit_color(suit);
-            buf.set_string(y.min(area.y), y, "", Style::default()); // noop positioning
-            buf.set_string(area.x, y, sym, Style::default().fg(color));
+            let color = Theme::suit_color(suit);
+            if line.show_suit {
+                buf.set_string(area.x, y, suit_label(suit), Style::default().fg(color));
+            }
 
-            let suit_cards = cards_by_suit.get(&suit).cloned().unwrap_or_default();
             let mut x = area.x + 2;
-            for &(global_idx, card) in suit_cards.iter() {
-                if x + cell_w > area.right() {
-                    break;
-                }
-
+            for &(global_idx, card) in line.cards.iter() {
                 let is_selected = self.selected_card == Some(global_idx);
                 let rank_str = card.rank.short_name();
                 let cell_str = format!("{}{}", rank_str, sym);
@@ -566,37 +768,36 @@ impl<'a> DeckOverlayWidget<'a> {
                 // Determine style based on card properties
                 let base_style = if is_selected {
                     Style::default()
-                        .fg(Theme::CARD_SELECTED)
+                        .fg(Theme::card_selected())
                         .add_modifier(Modifier::BOLD | Modifier::UNDERLINED)
                 } else if card.debuffed {
-                    Style::default().fg(Theme::DIM_TEXT)
+                    Style::default().fg(Theme::dim_text())
                 } else if card.enhancement.is_some() {
                     let enh_color = match card.enhancement {
-                        Some(Enhancement::Bonus) => Theme::CHIPS_COLOR,
-                        Some(Enhancement::Mult) => Theme::MULT_COLOR,
-                        Some(Enhancement::Wild) => Theme::LEGENDARY,
-                        Some(Enhancement::Glass) => Theme::BRIGHT_TEXT,
-                        Some(Enhancement::Steel) => Theme::DIM_TEXT,
-                        Some(Enhancement::Stone) => Theme::MUTED_TEXT,
-                        Some(Enhancement::Gold) => Theme::GOLD,
-                        Some(Enhancement::Lucky) => Theme::MONEY_COLOR,
+                        Some(Enhancement::Bonus) => Theme::chips_color(),
+                        Some(Enhancement::Mult) => Theme::mult_color(),
+                        Some(Enhancement::Wild) => Theme::legendary(),
+                        Some(Enhancement::Glass) => Theme::bright_text(),
+                        Some(Enhancement::Steel) => Theme::dim_text(),
+                        Some(Enhancement::Stone) => Theme::muted_text(),
+                        Some(Enhancement::Gold) => Theme::gold(),
+                        Some(Enhancement::Lucky) => Theme::money_color(),
                         None => color,
                     };
                     Style::default().fg(enh_color).add_modifier(Modifier::BOLD)
                 } else if card.edition != Edition::Base {
-                    let ed_style = match card.edition {
+                    match card.edition {
                         Edition::Foil => Style::default()
-                            .fg(Theme::CHIPS_COLOR)
+                            .fg(Theme::chips_color())
                             .add_modifier(Modifier::BOLD),
                         Edition::Holographic => Style::default()
-                            .fg(Theme::MULT_COLOR)
+                            .fg(Theme::mult_color())
                             .add_modifier(Modifier::ITALIC),
                         Edition::Polychrome => Style::default()
-                            .fg(Theme::LEGENDARY)
+                            .fg(Theme::legendary())
                             .add_modifier(Modifier::BOLD),
-                        Edition::Base => Style::default().fg(color),
-                    };
-                    ed_style
+                        Edition::Base | Edition::Negative => Style::default().fg(color),
+                    }
                 } else {
                     Style::default().fg(color)
                 };
@@ -614,11 +815,11 @@ impl<'a> DeckOverlayWidget<'a> {
 
                 if !seal_char.is_empty() {
                     let seal_color = match card.seal {
-                        Some(Seal::Gold) => Theme::GOLD,
-                        Some(Seal::Red) => Theme::MULT_COLOR,
-                        Some(Seal::Blue) => Theme::CHIPS_COLOR,
-                        Some(Seal::Purple) => Theme::LEGENDARY,
-                        None => Theme::DIM_TEXT,
+                        Some(Seal::Gold) => Theme::gold(),
+                        Some(Seal::Red) => Theme::mult_color(),
+                        Some(Seal::Blue) => Theme::chips_color(),
+                        Some(Seal::Purple) => Theme::legendary(),
+                        None => Theme::dim_text(),
                     };
                     let seal_x = x + cell_str.len() as u16;
                     if seal_x < area.right() {
@@ -628,8 +829,17 @@ impl<'a> DeckOverlayWidget<'a> {
 
                 x += cell_w;
             }
+        }
 
-            y += 2; // row + gap between suits
+        // Scroll hints when grid lines are hidden above or below
+        let total = Self::grid_lines(area, self.cards, self.filter).len() as u16;
+        let hint_x = area.right().saturating_sub(1);
+        let hint_style = Style::default().fg(Theme::muted_text());
+        if self.scroll > 0 {
+            buf.set_string(hint_x, area.y, "\u{25b2}", hint_style);
+        }
+        if self.scroll + area.height < total {
+            buf.set_string(hint_x, area.bottom() - 1, "\u{25bc}", hint_style);
         }
     }
 
@@ -651,13 +861,13 @@ impl<'a> DeckOverlayWidget<'a> {
             (
                 name,
                 Style::default()
-                    .fg(Theme::BRIGHT_TEXT)
+                    .fg(Theme::bright_text())
                     .add_modifier(Modifier::BOLD),
             ),
             (
                 chips,
                 Style::default()
-                    .fg(Theme::CHIPS_COLOR)
+                    .fg(Theme::chips_color())
                     .add_modifier(Modifier::BOLD),
             ),
         ];
@@ -666,7 +876,7 @@ impl<'a> DeckOverlayWidget<'a> {
             lines.push((
                 format!("+{} Mult", card.mult_bonus()),
                 Style::default()
-                    .fg(Theme::MULT_COLOR)
+                    .fg(Theme::mult_color())
                     .add_modifier(Modifier::BOLD),
             ));
         }
@@ -675,51 +885,29 @@ impl<'a> DeckOverlayWidget<'a> {
             lines.push((
                 format!("x{:.1} Mult", card.x_mult()),
                 Style::default()
-                    .fg(Theme::XMULT_COLOR)
+                    .fg(Theme::xmult_color())
                     .add_modifier(Modifier::BOLD),
             ));
         }
 
-        if let Some(enh) = &card.enhancement {
-            let enh_name = match enh {
-                Enhancement::Bonus => "Bonus",
-                Enhancement::Mult => "Mult",
-                Enhancement::Wild => "Wild",
-                Enhancement::Glass => "Glass",
-                Enhancement::Steel => "Steel",
-                Enhancement::Stone => "Stone",
-                Enhancement::Gold => "Gold",
-                Enhancement::Lucky => "Lucky",
-            };
+        if let Some(enh) = card.enhancement {
             lines.push((
-                format!("Enhancement: {}", enh_name),
-                Style::default().fg(Theme::GOLD),
+                format!("Enhancement: {}", enh.name()),
+                Style::default().fg(Theme::gold()),
             ));
         }
 
         if card.edition != Edition::Base {
-            let ed_name = match card.edition {
-                Edition::Foil => "Foil",
-                Edition::Holographic => "Holographic",
-                Edition::Polychrome => "Polychrome",
-                Edition::Base => "",
-            };
             lines.push((
-                format!("Edition: {}", ed_name),
-                Style::default().fg(Theme::LEGENDARY),
+                format!("Edition: {}", card.edition.name()),
+                Style::default().fg(Theme::legendary()),
             ));
         }
 
-        if let Some(seal) = &card.seal {
-            let seal_name = match seal {
-                Seal::Gold => "Gold",
-                Seal::Red => "Red",
-                Seal::Blue => "Blue",
-                Seal::Purple => "Purple",
-            };
+        if let Some(seal) = card.seal {
             lines.push((
-                format!("Seal: {}", seal_name),
-                Style::default().fg(Theme::GOLD),
+                format!("Seal: {}", seal.name()),
+                Style::default().fg(Theme::gold()),
             ));
         }
 
@@ -727,7 +915,7 @@ impl<'a> DeckOverlayWidget<'a> {
             lines.push((
                 "DEBUFFED".to_string(),
                 Style::default()
-                    .fg(Theme::DIM_TEXT)
+                    .fg(Theme::dim_text())
                     .add_modifier(Modifier::BOLD),
             ));
         }
@@ -756,7 +944,7 @@ impl<'a> DeckOverlayWidget<'a> {
         Clear.render(popup_area, buf);
 
         // Border
-        let border_style = Style::default().fg(suit_color(card.suit));
+        let border_style = Style::default().fg(Theme::suit_color(card.suit));
         buf.set_string(popup_area.x, popup_area.y, "\u{256d}", border_style);
         for dx in 1..popup_w.saturating_sub(1) {
             buf.set_string(popup_area.x + dx, popup_area.y, "\u{2500}", border_style);
@@ -866,9 +1054,9 @@ fn render_back_button(area: Rect, buf: &mut Buffer) {
     }
 
     let btn_style = Style::default()
-        .fg(Theme::BRIGHT_TEXT)
+        .fg(Theme::bright_text())
         .add_modifier(Modifier::BOLD);
-    let bg_style = Style::default().fg(Theme::GOLD);
+    let bg_style = Style::default().fg(Theme::gold());
 
     // Top border of button
     let top_y = area.y;
@@ -921,6 +1109,22 @@ pub struct DeckViewerState {
     pub back_rect: Rect,
     /// Cached deck preview rect (right sidebar)
     pub preview_rect: Rect,
+    /// Card grid lines scrolled past
+    pub scroll: u16,
+    /// Cached screen area the overlay was last rendered into (for scroll clamping)
+    pub screen_area: Rect,
+    /// Text the card grid is filtered by (see `card_matches_filter`)
+    pub filter: String,
+    /// Whether typed keys go into `filter` (started with '/')
+    pub filter_editing: bool,
+    /// Pile counts from the last preview render, shown in the overlay
+    pub piles: Option<PileCounts>,
+}
+
+impl Default for DeckViewerState {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 impl DeckViewerState {
@@ -932,13 +1136,23 @@ impl DeckViewerState {
             card_rects: Vec::new(),
             back_rect: Rect::default(),
             preview_rect: Rect::default(),
+            scroll: 0,
+            screen_area: Rect::default(),
+            filter: String::new(),
+            filter_editing: false,
+            piles: None,
         }
     }
 
-    /// Open the deck viewer with fresh deck data
-    pub fn open(&mut self, full_deck: Vec<PlayingCard>) {
+    /// Open the deck viewer on a sorted snapshot of `full_deck`. The snapshot
+    /// doesn't change while open, so `selected_card` keeps pointing at the same card.
+    pub fn open(&mut self, mut full_deck: Vec<PlayingCard>) {
+        full_deck.sort_by_key(PlayingCard::display_order_key);
         self.cached_deck = full_deck;
         self.selected_card = None;
+        self.scroll = 0;
+        self.filter.clear();
+        self.filter_editing = false;
         self.open = true;
     }
 
@@ -946,6 +1160,16 @@ impl DeckViewerState {
     pub fn close(&mut self) {
         self.open = false;
         self.selected_card = None;
+        self.filter_editing = false;
+    }
+
+    /// Replace the filter, going back to the top of the (now shorter) grid
+    pub fn set_filter(&mut self, filter: &str) {
+        self.filter = filter.to_string();
+        self.selected_card = None;
+        self.scroll = 0;
+        self.card_rects =
+            DeckOverlayWidget::card_cell_rects(self.screen_area, &self.cached_deck, filter, 0);
     }
 
     /// Toggle open/close
@@ -959,14 +1183,9 @@ impl DeckViewerState {
 
     /// Render the preview in the right sidebar bottom area.
     /// Returns the preview rect for hit-testing.
-    pub fn render_preview(
-        &mut self,
-        frame: &mut ratatui::Frame,
-        area: Rect,
-        total_cards: usize,
-        remaining: usize,
-    ) {
-        let widget = DeckPreviewWidget::new(total_cards, remaining);
+    pub fn render_preview(&mut self, frame: &mut ratatui::Frame, area: Rect, piles: PileCounts) {
+        self.piles = Some(piles);
+        let widget = DeckPreviewWidget::new(piles);
         self.preview_rect = DeckPreviewWidget::hit_rect(area);
         frame.render_widget(widget, area);
     }
@@ -977,8 +1196,22 @@ impl DeckViewerState {
             return;
         }
 
-        let widget = DeckOverlayWidget::new(&self.cached_deck, self.selected_card);
-        self.card_rects = DeckOverlayWidget::card_cell_rects(screen_area, &self.cached_deck);
+        self.screen_area = screen_area;
+        self.scroll = self.scroll.min(DeckOverlayWidget::max_scroll(
+            screen_area,
+            &self.cached_deck,
+            &self.filter,
+        ));
+        let widget = DeckOverlayWidget::new(&self.cached_deck, self.selected_card)
+            .scroll(self.scroll)
+            .filter(&self.filter, self.filter_editing)
+            .piles(self.piles);
+        self.card_rects = DeckOverlayWidget::card_cell_rects(
+            screen_area,
+            &self.cached_deck,
+            &self.filter,
+            self.scroll,
+        );
         self.back_rect = DeckOverlayWidget::back_button_rect(screen_area);
         frame.render_widget(widget, screen_area);
     }
@@ -990,7 +1223,44 @@ impl DeckViewerState {
             return false;
         }
 
+        if self.filter_editing {
+            match code {
+                crossterm::event::KeyCode::Char(c) => {
+                    let filter = format!("{}{}", self.filter, c);
+                    self.set_filter(&filter);
+                }
+                crossterm::event::KeyCode::Backspace => {
+                    let mut filter = self.filter.clone();
+                    filter.pop();
+                    self.set_filter(&filter);
+                }
+                crossterm::event::KeyCode::Esc => {
+                    self.filter_editing = false;
+                    self.set_filter("");
+                }
+                crossterm::event::KeyCode::Enter => self.filter_editing = false,
+                _ => {}
+            }
+            return true;
+        }
+
         match code {
+            crossterm::event::KeyCode::Char('/') => {
+                self.filter_editing = true;
+                true
+            }
+            crossterm::event::KeyCode::Backspace if !self.filter.is_empty() => {
+                self.filter_editing = true;
+                let mut filter = self.filter.clone();
+                filter.pop();
+                self.set_filter(&filter);
+                true
+            }
+            // Esc clears an active filter before closing
+            crossterm::event::KeyCode::Esc if !self.filter.is_empty() => {
+                self.set_filter("");
+                true
+            }
             crossterm::event::KeyCode::Esc
             | crossterm::event::KeyCode::Char('v')
             | crossterm::event::KeyCode::Char('V')
@@ -998,10 +1268,45 @@ impl DeckViewerState {
                 self.close();
                 true
             }
+            crossterm::event::KeyCode::Up | crossterm::event::KeyCode::Char('k') => {
+                self.scroll_by(-1);
+                true
+            }
+            crossterm::event::KeyCode::Down | crossterm::event::KeyCode::Char('j') => {
+                self.scroll_by(1);
+                true
+            }
             _ => true, // Consume all keys when overlay is open
         }
     }
 
+    /// Handle a mouse wheel event while the overlay is open.
+    /// Returns true if the event was consumed.
+    pub fn handle_mouse_scroll(&mut self, kind: crossterm::event::MouseEventKind) -> bool {
+        if !self.open {
+            return false;
+        }
+        match kind {
+            crossterm::event::MouseEventKind::ScrollUp => self.scroll_by(-1),
+            crossterm::event::MouseEventKind::ScrollDown => self.scroll_by(1),
+            _ => return false,
+        }
+        true
+    }
+
+    /// Move the card grid by `delta` lines, clamped to the content, and
+    /// refresh the hit-test rects to match
+    fn scroll_by(&mut self, delta: i32) {
+        let max = DeckOverlayWidget::max_scroll(self.screen_area, &self.cached_deck, &self.filter);
+        self.scroll = (self.scroll as i32 + delta).clamp(0, max as i32) as u16;
+        self.card_rects = DeckOverlayWidget::card_cell_rects(
+            self.screen_area,
+            &self.cached_deck,
+            &self.filter,
+            self.scroll,
+        );
+    }
+
     /// Handle a mouse click while overlay or preview is active.
     /// Returns Some(true) if the overlay was interacted with (consumed),
     /// Some(false) if the preview was clicked (caller should open),
@@ -1054,3 +1359,151 @@ impl DeckViewerState {
         None
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crossterm::event::MouseEventKind;
+
+    /// Two standard decks: every suit wraps onto two grid lines
+    fn big_deck() -> Vec<PlayingCard> {
+        let mut cards = Vec::new();
+        for _ in 0..2 {
+            for &suit in &SUITS_ORDER {
+                for &rank in &RANKS_HIGH_TO_LOW {
+                    cards.push(PlayingCard::new(rank, suit));
+                }
+            }
+        }
+        cards
+    }
+
+    fn open_viewer(area: Rect) -> DeckViewerState {
+        let mut state = DeckViewerState::new();
+        state.open(big_deck());
+        state.screen_area = area;
+        state
+    }
+
+    fn buffer_text(buf: &Buffer) -> String {
+        buf.content().iter().map(|c| c.symbol()).collect()
+    }
+
+    #[test]
+    fn test_overlay_shows_pile_counts() {
+        let area = Rect::new(0, 0, 90, 30);
+        let piles = PileCounts {
+            draw: 40,
+            discard: 4,
+            played: 0,
+            hand: 8,
+        };
+        let mut buf = Buffer::empty(area);
+        DeckOverlayWidget::new(&big_deck(), None)
+            .piles(Some(piles))
+            .render(area, &mut buf);
+        let text = buffer_text(&buf);
+        assert!(text.contains("Draw 40 · Discard 4"));
+        assert!(text.contains("Hand 8"));
+        assert!(!text.contains("Played"));
+    }
+
+    #[test]
+    fn test_preview_shows_draw_and_discard_piles() {
+        let area = Rect::new(0, 0, 12, 7);
+        let piles = PileCounts {
+            draw: 30,
+            discard: 6,
+            played: 5,
+            hand: 8,
+        };
+        let mut buf = Buffer::empty(area);
+        DeckPreviewWidget::new(piles).render(area, &mut buf);
+        let text = buffer_text(&buf);
+        assert!(text.contains("30/49"));
+        assert!(text.contains("Discard 6"));
+    }
+
+    #[test]
+    fn test_scroll_clamps_past_bottom() {
+        let area = Rect::new(0, 0, 80, 14);
+        let max = DeckOverlayWidget::max_scroll(area, &big_deck(), "");
+        assert!(max > 0);
+
+        let mut state = open_viewer(area);
+        for _ in 0..(max + 10) {
+            state.handle_mouse_scroll(MouseEventKind::ScrollDown);
+        }
+        assert_eq!(state.scroll, max);
+
+        state.handle_mouse_scroll(MouseEventKind::ScrollUp);
+        assert_eq!(state.scroll, max - 1);
+    }
+
+    #[test]
+    fn test_card_rects_shift_with_scroll() {
+        let area = Rect::new(0, 0, 80, 14);
+        let cards = big_deck();
+        let top = DeckOverlayWidget::card_cell_rects(area, &cards, "", 0);
+        let scrolled = DeckOverlayWidget::card_cell_rects(area, &cards, "", 3);
+
+        // Spades wrap onto two lines, then a gap: the first Heart moves up to the top row
+        let grid_y = top[0].1.y;
+        let (idx, rect) = top
+            .iter()
+            .find(|(_, r)| r.y == grid_y + 3)
+            .copied()
+            .expect("fourth line visible");
+        let moved = scrolled
+            .iter()
+            .find(|(i, _)| *i == idx)
+            .map(|(_, r)| *r)
+            .expect("card still visible");
+        assert_eq!(moved.y, rect.y - 3);
+        assert_eq!(moved.x, rect.x);
+        assert!(!scrolled.iter().any(|(i, _)| *i == top[0].0));
+    }
+
+    #[test]
+    fn test_filter_by_enhancement_keeps_only_matching_cells() {
+        let area = Rect::new(0, 0, 80, 30);
+        let mut cards = big_deck();
+        for i in [3, 20, 41] {
+            cards[i].enhancement = Some(Enhancement::Steel);
+        }
+        cards[7].enhancement = Some(Enhancement::Stone);
+
+        let mut state = DeckViewerState::new();
+        state.open(cards);
+        state.screen_area = area;
+        state.handle_key(crossterm::event::KeyCode::Char('/'));
+        for c in "Steel".chars() {
+            state.handle_key(crossterm::event::KeyCode::Char(c));
+        }
+
+        // Indices point into the sorted snapshot taken on open
+        let shown: Vec<usize> = state.card_rects.iter().map(|(i, _)| *i).collect();
+        assert_eq!(shown.len(), 3);
+        assert!(shown
+            .iter()
+            .all(|&i| state.cached_deck[i].enhancement == Some(Enhancement::Steel)));
+
+        // Esc stops typing and clears, the next Esc closes
+        state.handle_key(crossterm::event::KeyCode::Esc);
+        assert!(state.filter.is_empty() && state.open);
+        assert!(state.card_rects.len() > 3);
+        state.handle_key(crossterm::event::KeyCode::Esc);
+        assert!(!state.open);
+    }
+
+    #[test]
+    fn test_filter_matches_rank_symbol_exactly() {
+        let king = PlayingCard::new(Rank::King, Suit::Hearts);
+        let ten = PlayingCard::new(Rank::Ten, Suit::Spades);
+        assert!(card_matches_filter(&king, "k"));
+        assert!(card_matches_filter(&king, "hearts"));
+        assert!(!card_matches_filter(&ten, "k"));
+        assert!(card_matches_filter(&ten, "10"));
+        assert!(!card_matches_filter(&ten, "1"));
+    }
+}
@@ -3,14 +3,17 @@ use ratatui::buffer::Buffer;
 use ratatui::layout::Rect;
 use ratatui::widgets::Widget;
 
-use crate::card::{CardWidget, CARD_HEIGHT, CARD_WIDTH};
+use crate::card::{CardWidget, CARD_HEIGHT, CARD_WIDTH, COMPACT_CARD_HEIGHT, COMPACT_CARD_WIDTH};
 
 /// Widget that renders a fan of cards (the player's hand).
 /// Selected cards are rendered one row higher to show "raised" effect.
+/// Falls back to compact cards when full-size ones don't fit the area.
 pub struct HandWidget<'a> {
     pub cards: &'a [PlayingCard],
     pub selected_indices: &'a [usize],
     pub cursor: Option<usize>,
+    /// Cards of the suggested best play, outlined but not selected
+    pub suggested: &'a [usize],
     pub spacing: u16,
 }
 
@@ -20,6 +23,7 @@ impl<'a> HandWidget<'a> {
             cards,
             selected_indices,
             cursor: None,
+            suggested: &[],
             spacing: 1,
         }
     }
@@ -29,18 +33,41 @@ impl<'a> HandWidget<'a> {
         self
     }
 
+    pub fn suggested(mut self, suggested: &'a [usize]) -> Self {
+        self.suggested = suggested;
+        self
+    }
+
     pub fn spacing(mut self, spacing: u16) -> Self {
         self.spacing = spacing;
         self
     }
 
-    /// Calculate the total width needed for the hand
+    /// Calculate the total width needed for the hand at full card size
     pub fn total_width(&self) -> u16 {
+        self.width_for(CARD_WIDTH)
+    }
+
+    fn width_for(&self, card_width: u16) -> u16 {
         let n = self.cards.len() as u16;
         if n == 0 {
             return 0;
         }
-        n * CARD_WIDTH + (n - 1) * self.spacing
+        n * card_width + (n - 1) * self.spacing
+    }
+
+    /// Whether `area` is too narrow or short for full-size cards
+    pub fn is_compact(&self, area: Rect) -> bool {
+        self.total_width() > area.width || area.height < CARD_HEIGHT + 1
+    }
+
+    /// (width, height) of each card when laid out in `area`
+    pub fn card_size(&self, area: Rect) -> (u16, u16) {
+        if self.is_compact(area) {
+            (COMPACT_CARD_WIDTH, COMPACT_CARD_HEIGHT)
+        } else {
+            (CARD_WIDTH, CARD_HEIGHT)
+        }
     }
 
     /// Get the Rect for a specific card given the hand area
@@ -49,24 +76,26 @@ impl<'a> HandWidget<'a> {
             return None;
         }
 
-        let total_w = self.total_width();
+        let (card_w, card_h) = self.card_size(area);
+        let total_w = self.width_for(card_w);
         let start_x = area.x + area.width.saturating_sub(total_w) / 2;
 
-        let x = start_x + (card_index as u16) * (CARD_WIDTH + self.spacing);
+        let x = start_x + (card_index as u16) * (card_w + self.spacing);
         let is_selected = self.selected_indices.contains(&card_index);
 
         // Selected cards render 1 row higher
         let y = if is_selected { area.y } else { area.y + 1 };
 
-        Some(Rect::new(x, y, CARD_WIDTH, CARD_HEIGHT))
+        Some(Rect::new(x, y, card_w, card_h))
     }
 }
 
 impl<'a> Widget for HandWidget<'a> {
     fn render(self, area: Rect, buf: &mut Buffer) {
-        if area.height < CARD_HEIGHT + 1 {
+        if area.height < COMPACT_CARD_HEIGHT + 1 {
             return;
         }
+        let compact = self.is_compact(area);
 
         for (i, card) in self.cards.iter().enumerate() {
             if let Some(card_area) = self.card_rect(area, i) {
@@ -81,9 +110,60 @@ impl<'a> Widget for HandWidget<'a> {
                 CardWidget::new(*card)
                     .selected(is_selected)
                     .highlighted(is_cursor && !is_selected)
+                    .suggested(self.suggested.contains(&i))
                     .dimmed(card.debuffed)
+                    .face_down(card.face_down)
+                    .compact(compact)
                     .render(card_area, buf);
             }
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use balatrust_core::card::{Rank, Suit};
+
+    fn eight_cards() -> Vec<PlayingCard> {
+        Rank::ALL[..8]
+            .iter()
+            .map(|&rank| PlayingCard::new(rank, Suit::Spades))
+            .collect()
+    }
+
+    #[test]
+    fn test_wide_area_uses_full_size_cards() {
+        let cards = eight_cards();
+        let widget = HandWidget::new(&cards, &[]);
+        let area = Rect::new(0, 0, 120, CARD_HEIGHT + 1);
+        assert!(!widget.is_compact(area));
+        let rect = widget.card_rect(area, 0).unwrap();
+        assert_eq!((rect.width, rect.height), (CARD_WIDTH, CARD_HEIGHT));
+    }
+
+    #[test]
+    fn test_narrow_area_lays_out_compact_cards_that_fit() {
+        let cards = eight_cards();
+        let widget = HandWidget::new(&cards, &[]);
+        let area = Rect::new(5, 0, 70, CARD_HEIGHT + 1);
+        assert!(widget.total_width() > area.width);
+        assert!(widget.is_compact(area));
+
+        let first = widget.card_rect(area, 0).unwrap();
+        let last = widget.card_rect(area, cards.len() - 1).unwrap();
+        assert_eq!(
+            (first.width, first.height),
+            (COMPACT_CARD_WIDTH, COMPACT_CARD_HEIGHT)
+        );
+        assert!(first.x >= area.x && last.right() <= area.right());
+
+        // Every card is drawn: each top-left corner holds a border glyph
+        let mut buf = Buffer::empty(area);
+        widget.render(area, &mut buf);
+        for i in 0..cards.len() {
+            let rect = HandWidget::new(&cards, &[]).card_rect(area, i).unwrap();
+            assert_eq!(buf[(rect.x, rect.y)].symbol(), "\u{256d}");
+        }
+    }
+}
@@ -0,0 +1,261 @@
+use ratatui::buffer::Buffer;
+use ratatui::layout::{Alignment, Rect};
+use ratatui::style::{Modifier, Style};
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{Block, BorderType, Borders, Clear, Padding, Widget};
+
+use crate::theme::Theme;
+
+const OVERLAY_WIDTH: u16 = 64;
+/// Width of the key column
+const KEY_WIDTH: usize = 14;
+
+// ═══════════════════════════════════════════════════════════════════════
+// Key bindings per screen
+// ═══════════════════════════════════════════════════════════════════════
+
+/// Which screen's key bindings the help overlay lists
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HelpContext {
+    Play,
+    Shop,
+    BlindSelect,
+    DeckViewer,
+}
+
+impl HelpContext {
+    pub fn title(&self) -> &'static str {
+        match self {
+            HelpContext::Play => "Round Controls",
+            HelpContext::Shop => "Shop Controls",
+            HelpContext::BlindSelect => "Blind Select Controls",
+            HelpContext::DeckViewer => "Deck Viewer Controls",
+        }
+    }
+
+    /// (key, description) pairs, in the order they are listed
+    pub fn bindings(&self) -> &'static [(&'static str, &'static str)] {
+        match self {
+            HelpContext::Play => &[
+                ("\u{2190}\u{2192} / h l", "Move hand cursor"),
+                ("Space / \u{2191} / k", "Toggle selected card"),
+                ("Enter / p", "Play selected cards"),
+                ("d", "Discard selected cards"),
+                ("s / t", "Sort hand by rank / suit"),
+                ("a / c", "Select up to the play limit / clear"),
+                ("1-9", "Toggle the card at that hand position"),
+                ("Tab", "Pick the next consumable slot"),
+                ("u", "Use the picked consumable"),
+                ("z", "Undo the last play or discard (once per round)"),
+                ("i", "Run Info: poker hand levels"),
+                ("g", "Score log: recent hands, step by step"),
+                ("v", "Deck viewer"),
+                ("q / Esc", "Quit the run (asks first)"),
+                ("?", "This help"),
+            ],
+            HelpContext::Shop => &[
+                ("Tab", "Switch focus: items / jokers / consumables"),
+                ("\u{2190}\u{2192} / h l", "Move cursor"),
+                ("Enter / Space", "Inspect, or use the selected consumable"),
+                ("b", "Buy the focused item"),
+                ("u", "Use the selected consumable"),
+                ("r", "Reroll the shop"),
+                ("s", "Sell the selected joker or consumable"),
+                ("X", "Sell every joker (asks first)"),
+                ("< >", "Move the selected joker left / right"),
+                ("n", "Next round"),
+                ("i", "Run Info: poker hand levels"),
+                ("p", "Purchase log: everything bought this run"),
+                ("v", "Deck viewer"),
+                ("q / Esc", "Quit the run (asks first)"),
+                ("?", "This help"),
+            ],
+            HelpContext::BlindSelect => &[
+                ("\u{2190}\u{2192} / h l", "Move between blinds"),
+                ("Enter / Space", "Start the blind"),
+                ("s", "Skip the blind (Small / Big)"),
+                ("r", "Reroll the boss (Director's Cut / Retcon)"),
+                ("i", "Run Info: poker hand levels"),
+                ("v", "Deck viewer"),
+                ("q / Esc", "Quit the run (asks first)"),
+                ("?", "This help"),
+            ],
+            HelpContext::DeckViewer => &[
+                ("Mouse wheel", "Scroll the card grid"),
+                ("/", "Filter by rank, suit, enhancement, seal or edition"),
+                ("Backspace", "Edit the filter"),
+                ("Enter", "Stop typing the filter"),
+                ("Esc / v", "Clear the filter, then close"),
+                ("Click", "Show a card's details"),
+                ("?", "This help"),
+            ],
+        }
+    }
+}
+
+// ═══════════════════════════════════════════════════════════════════════
+// HelpOverlayWidget — full list of key bindings
+// ═══════════════════════════════════════════════════════════════════════
+
+/// Centered overlay listing every key binding of one screen
+pub struct HelpOverlayWidget<'a> {
+    pub title: &'a str,
+    pub bindings: &'a [(&'a str, &'a str)],
+}
+
+impl<'a> HelpOverlayWidget<'a> {
+    pub fn new(title: &'a str, bindings: &'a [(&'a str, &'a str)]) -> Self {
+        Self { title, bindings }
+    }
+
+    pub fn for_context(context: HelpContext) -> HelpOverlayWidget<'static> {
+        HelpOverlayWidget::new(context.title(), context.bindings())
+    }
+
+    /// One line per binding, then a blank line and the close hint
+    pub fn lines(&self) -> Vec<Line<'a>> {
+        let mut lines: Vec<Line> = self
+            .bindings
+            .iter()
+            .map(|(key, description)| {
+                Line::from(vec![
+                    Span::styled(
+                        format!("{:<width$}", key, width = KEY_WIDTH),
+                        Style::default().fg(Theme::gold()),
+                    ),
+                    Span::styled(*description, Style::default().fg(Theme::bright_text())),
+                ])
+            })
+            .collect();
+        lines.push(Line::default());
+        lines.push(
+            Line::from(Span::styled(
+                "[Esc/?] Close",
+                Style::default().fg(Theme::dim_text()),
+            ))
+            .alignment(Alignment::Center),
+        );
+        lines
+    }
+
+    fn overlay_rect(&self, area: Rect) -> Rect {
+        // Border + lines
+        let h = (self.bindings.len() as u16 + 4).min(area.height);
+        let w = OVERLAY_WIDTH.min(area.width);
+        let x = area.x + area.width.saturating_sub(w) / 2;
+        let y = area.y + area.height.saturating_sub(h) / 2;
+        Rect::new(x, y, w, h)
+    }
+}
+
+impl<'a> Widget for HelpOverlayWidget<'a> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let overlay = self.overlay_rect(area);
+        if overlay.width < 30 || overlay.height < 5 {
+            return;
+        }
+
+        Clear.render(overlay, buf);
+
+        let title = Line::from(Span::styled(
+            format!(" {} ", self.title),
+            Style::default()
+                .fg(Theme::gold())
+                .add_modifier(Modifier::BOLD),
+        ));
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .border_type(BorderType::Double)
+            .border_style(Style::default().fg(Theme::card_selected()))
+            .title(title)
+            .title_alignment(Alignment::Center)
+            .padding(Padding::horizontal(1));
+        let inner = block.inner(overlay);
+        block.render(overlay, buf);
+
+        for (i, line) in self.lines().iter().enumerate() {
+            let y = inner.y + i as u16;
+            if y >= inner.bottom() {
+                break;
+            }
+            let line_area = Rect::new(inner.x, y, inner.width, 1);
+            line.render(line_area, buf);
+        }
+    }
+}
+
+// ═══════════════════════════════════════════════════════════════════════
+// HelpState — open/close state shared by the in-run screens
+// ═══════════════════════════════════════════════════════════════════════
+
+/// Whether the help overlay is showing, and for which screen
+#[derive(Debug, Default)]
+pub struct HelpState {
+    pub open: Option<HelpContext>,
+}
+
+impl HelpState {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Render the overlay if open. Call last so it sits above every other overlay.
+    pub fn render_overlay(&self, frame: &mut ratatui::Frame, screen_area: Rect) {
+        if let Some(context) = self.open {
+            frame.render_widget(HelpOverlayWidget::for_context(context), screen_area);
+        }
+    }
+
+    /// Handle a key event: `?` opens the help for `context`; while open, Esc or `?`
+    /// closes it. Returns true if the event was consumed.
+    pub fn handle_key(&mut self, code: crossterm::event::KeyCode, context: HelpContext) -> bool {
+        use crossterm::event::KeyCode;
+        if self.open.is_some() {
+            if matches!(code, KeyCode::Esc | KeyCode::Char('?')) {
+                self.open = None;
+            }
+            return true; // Consume all keys when overlay is open
+        }
+        if code == KeyCode::Char('?') {
+            self.open = Some(context);
+            return true;
+        }
+        false
+    }
+
+    /// Any click closes the overlay. Returns true if the click was consumed.
+    pub fn handle_mouse_click(&mut self) -> bool {
+        self.open.take().is_some()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_one_line_per_binding_plus_close_hint() {
+        for context in [
+            HelpContext::Play,
+            HelpContext::Shop,
+            HelpContext::BlindSelect,
+            HelpContext::DeckViewer,
+        ] {
+            let widget = HelpOverlayWidget::for_context(context);
+            assert_eq!(widget.lines().len(), context.bindings().len() + 2);
+        }
+        assert_eq!(HelpContext::BlindSelect.bindings().len(), 8);
+    }
+
+    #[test]
+    fn test_question_mark_toggles_and_esc_closes() {
+        use crossterm::event::KeyCode;
+        let mut help = HelpState::new();
+        assert!(!help.handle_key(KeyCode::Char('x'), HelpContext::Shop));
+        assert!(help.handle_key(KeyCode::Char('?'), HelpContext::Shop));
+        assert_eq!(help.open, Some(HelpContext::Shop));
+        assert!(help.handle_key(KeyCode::Char('x'), HelpContext::Shop));
+        assert!(help.handle_key(KeyCode::Esc, HelpContext::Shop));
+        assert_eq!(help.open, None);
+    }
+}
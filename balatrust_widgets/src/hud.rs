@@ -57,16 +57,16 @@ impl Widget for HudWidget {
         // Play Hand button
         let play_style = if self.can_play {
             Style::default()
-                .fg(Theme::BRIGHT_TEXT)
+                .fg(Theme::bright_text())
                 .add_modifier(Modifier::BOLD)
         } else {
-            Style::default().fg(Theme::DIM_TEXT)
+            Style::default().fg(Theme::dim_text())
         };
         let play_line = Line::from(vec![
             Span::styled(
                 "[P]",
                 Style::default()
-                    .fg(Theme::GOLD)
+                    .fg(Theme::gold())
                     .add_modifier(Modifier::BOLD),
             ),
             Span::styled("lay Hand", play_style),
@@ -76,16 +76,16 @@ impl Widget for HudWidget {
         // Discard button
         let disc_style = if self.can_discard {
             Style::default()
-                .fg(Theme::BRIGHT_TEXT)
+                .fg(Theme::bright_text())
                 .add_modifier(Modifier::BOLD)
         } else {
-            Style::default().fg(Theme::DIM_TEXT)
+            Style::default().fg(Theme::dim_text())
         };
         let disc_line = Line::from(vec![
             Span::styled(
                 "[D]",
                 Style::default()
-                    .fg(Theme::GOLD)
+                    .fg(Theme::gold())
                     .add_modifier(Modifier::BOLD),
             ),
             Span::styled("iscard", disc_style),
@@ -94,11 +94,11 @@ impl Widget for HudWidget {
 
         // Hands remaining
         let hands_line = Line::from(vec![
-            Span::styled("Hands: ", Style::default().fg(Theme::MUTED_TEXT)),
+            Span::styled("Hands: ", Style::default().fg(Theme::muted_text())),
             Span::styled(
                 format!("{}", self.hands),
                 Style::default()
-                    .fg(Theme::CHIPS_COLOR)
+                    .fg(Theme::chips_color())
                     .add_modifier(Modifier::BOLD),
             ),
         ]);
@@ -106,11 +106,11 @@ impl Widget for HudWidget {
 
         // Discards remaining
         let discs_line = Line::from(vec![
-            Span::styled("Discards: ", Style::default().fg(Theme::MUTED_TEXT)),
+            Span::styled("Discards: ", Style::default().fg(Theme::muted_text())),
             Span::styled(
                 format!("{}", self.discards),
                 Style::default()
-                    .fg(Theme::MULT_COLOR)
+                    .fg(Theme::mult_color())
                     .add_modifier(Modifier::BOLD),
             ),
         ]);
@@ -121,13 +121,13 @@ impl Widget for HudWidget {
             Span::styled(
                 format!("${}", self.money),
                 Style::default()
-                    .fg(Theme::MONEY_COLOR)
+                    .fg(Theme::money_color())
                     .add_modifier(Modifier::BOLD),
             ),
-            Span::styled("  Deck:", Style::default().fg(Theme::MUTED_TEXT)),
+            Span::styled("  Deck:", Style::default().fg(Theme::muted_text())),
             Span::styled(
                 format!("{}", self.deck_remaining),
-                Style::default().fg(Theme::BRIGHT_TEXT),
+                Style::default().fg(Theme::bright_text()),
             ),
         ]);
         buf.set_line(chunks[4].x, chunks[4].y, &money_line, chunks[4].width);
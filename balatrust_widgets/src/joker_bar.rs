@@ -1,7 +1,8 @@
+use balatrust_core::card::Edition;
 use balatrust_core::joker::{Joker, JokerRarity};
 use ratatui::buffer::Buffer;
 use ratatui::layout::Rect;
-use ratatui::style::{Modifier, Style};
+use ratatui::style::{Color, Modifier, Style};
 use ratatui::widgets::Widget;
 
 use crate::theme::Theme;
@@ -9,6 +10,14 @@ use crate::theme::Theme;
 pub const JOKER_WIDTH: u16 = 13;
 pub const JOKER_HEIGHT: u16 = 5;
 
+/// How a joker card's border is drawn
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct JokerBorder {
+    pub color: Color,
+    /// Double-line border instead of the rounded one
+    pub double: bool,
+}
+
 /// Widget showing the joker bar (horizontal row of owned jokers)
 pub struct JokerBarWidget<'a> {
     pub jokers: &'a [Joker],
@@ -16,6 +25,8 @@ pub struct JokerBarWidget<'a> {
     pub selected: Option<usize>,
     /// Index of the joker currently "activated" (pulsing during scoring animation)
     pub activated: Option<usize>,
+    /// Jokers to outline as synergy targets (e.g. while inspecting a shop item)
+    pub highlighted: &'a [usize],
 }
 
 impl<'a> JokerBarWidget<'a> {
@@ -25,6 +36,7 @@ impl<'a> JokerBarWidget<'a> {
             max_jokers,
             selected: None,
             activated: None,
+            highlighted: &[],
         }
     }
 
@@ -38,14 +50,57 @@ impl<'a> JokerBarWidget<'a> {
         self
     }
 
+    pub fn highlighted(mut self, highlighted: &'a [usize]) -> Self {
+        self.highlighted = highlighted;
+        self
+    }
+
+    /// Slots to draw: `max_jokers`, plus one per Negative joker
+    pub fn slot_count(&self) -> usize {
+        let free = self.jokers.iter().filter(|j| !j.takes_slot()).count();
+        (self.max_jokers as usize + free).max(self.jokers.len())
+    }
+
+    /// Border for the joker at `index`. The scoring glow wins over selection,
+    /// so a joker that is both shows only the glow; selection wins over a
+    /// synergy highlight.
+    pub fn border_for(&self, index: usize) -> JokerBorder {
+        if self.activated == Some(index) {
+            // Bright white glow while it fires during scoring
+            JokerBorder {
+                color: Theme::bright_text(),
+                double: false,
+            }
+        } else if self.selected == Some(index) {
+            JokerBorder {
+                color: Theme::card_selected(),
+                double: true,
+            }
+        } else if self.highlighted.contains(&index) {
+            // Synergy target of the inspected shop item
+            JokerBorder {
+                color: Theme::gold(),
+                double: false,
+            }
+        } else {
+            JokerBorder {
+                color: self
+                    .jokers
+                    .get(index)
+                    .map_or(Theme::dim_text(), |j| rarity_color(j.joker_type.rarity())),
+                double: false,
+            }
+        }
+    }
+
     /// Get the Rect for a specific joker given the bar area
     pub fn joker_rect(&self, area: Rect, joker_index: usize) -> Option<Rect> {
-        if joker_index >= self.max_jokers as usize {
+        if joker_index >= self.slot_count() {
             return None;
         }
 
         let spacing = 1u16;
-        let total_slots = self.max_jokers as u16;
+        let total_slots = self.slot_count() as u16;
         let total_width = total_slots * JOKER_WIDTH + (total_slots.saturating_sub(1)) * spacing;
         let start_x = area.x + area.width.saturating_sub(total_width) / 2;
 
@@ -61,11 +116,11 @@ impl<'a> Widget for JokerBarWidget<'a> {
         }
 
         let spacing = 1u16;
-        let total_slots = self.max_jokers as u16;
+        let total_slots = self.slot_count() as u16;
         let total_width = total_slots * JOKER_WIDTH + (total_slots.saturating_sub(1)) * spacing;
         let start_x = area.x + area.width.saturating_sub(total_width) / 2;
 
-        for i in 0..self.max_jokers as usize {
+        for i in 0..self.slot_count() {
             let x = start_x + (i as u16) * (JOKER_WIDTH + spacing);
             let card_area = Rect::new(x, area.y, JOKER_WIDTH, JOKER_HEIGHT);
 
@@ -74,9 +129,7 @@ impl<'a> Widget for JokerBarWidget<'a> {
             }
 
             if let Some(joker) = self.jokers.get(i) {
-                let is_selected = self.selected == Some(i);
-                let is_activated = self.activated == Some(i);
-                render_joker_card(joker, card_area, buf, is_selected, is_activated);
+                render_joker_card(joker, card_area, buf, self.border_for(i));
             } else {
                 render_empty_slot(card_area, buf);
             }
@@ -84,25 +137,21 @@ impl<'a> Widget for JokerBarWidget<'a> {
     }
 }
 
-fn render_joker_card(joker: &Joker, area: Rect, buf: &mut Buffer, selected: bool, activated: bool) {
-    let rarity_color = match joker.joker_type.rarity() {
-        JokerRarity::Common => Theme::COMMON,
-        JokerRarity::Uncommon => Theme::UNCOMMON,
-        JokerRarity::Rare => Theme::RARE,
-        JokerRarity::Legendary => Theme::LEGENDARY,
-    };
+fn rarity_color(rarity: JokerRarity) -> Color {
+    match rarity {
+        JokerRarity::Common => Theme::common(),
+        JokerRarity::Uncommon => Theme::uncommon(),
+        JokerRarity::Rare => Theme::rare(),
+        JokerRarity::Legendary => Theme::legendary(),
+    }
+}
 
-    let border_color = if activated {
-        Theme::BRIGHT_TEXT // Bright white glow when activated during scoring
-    } else if selected {
-        Theme::CARD_SELECTED
-    } else {
-        rarity_color
-    };
-    let border_style = Style::default().fg(border_color);
+fn render_joker_card(joker: &Joker, area: Rect, buf: &mut Buffer, border: JokerBorder) {
+    let rarity_color = rarity_color(joker.joker_type.rarity());
+    let border_style = Style::default().fg(border.color);
 
     // Draw border
-    if selected {
+    if border.double {
         buf.set_string(area.x, area.y, "\u{2554}", border_style); // ╔
         for x in 1..area.width - 1 {
             buf.set_string(area.x + x, area.y, "\u{2550}", border_style);
@@ -183,7 +232,7 @@ fn render_joker_card(joker: &Joker, area: Rect, buf: &mut Buffer, selected: bool
         area.y + 1,
         &display_name,
         Style::default()
-            .fg(Theme::BRIGHT_TEXT)
+            .fg(Theme::bright_text())
             .add_modifier(Modifier::BOLD),
     );
 
@@ -197,24 +246,33 @@ fn render_joker_card(joker: &Joker, area: Rect, buf: &mut Buffer, selected: bool
         Style::default().fg(rarity_color),
     );
 
-    // Rarity label
-    let rarity_str = match joker.joker_type.rarity() {
-        JokerRarity::Common => "Common",
-        JokerRarity::Uncommon => "Uncommon",
-        JokerRarity::Rare => "Rare",
-        JokerRarity::Legendary => "Legend",
+    // Rarity label, or the edition when the joker has one
+    let (label, label_color) = match joker.edition {
+        Edition::Base => (
+            match joker.joker_type.rarity() {
+                JokerRarity::Common => "Common",
+                JokerRarity::Uncommon => "Uncommon",
+                JokerRarity::Rare => "Rare",
+                JokerRarity::Legendary => "Legend",
+            },
+            Theme::dim_text(),
+        ),
+        Edition::Foil => ("Foil", Theme::chips_color()),
+        Edition::Holographic => ("Holographic", Theme::mult_color()),
+        Edition::Polychrome => ("Polychrome", Theme::legendary()),
+        Edition::Negative => ("Negative", Theme::bright_text()),
     };
-    let rarity_display: String = rarity_str.chars().take(max_len).collect();
+    let label_display: String = label.chars().take(max_len).collect();
     buf.set_string(
         area.x + 1,
         area.y + 3,
-        &rarity_display,
-        Style::default().fg(Theme::DIM_TEXT),
+        &label_display,
+        Style::default().fg(label_color),
     );
 }
 
 fn render_empty_slot(area: Rect, buf: &mut Buffer) {
-    let style = Style::default().fg(Theme::DIM_TEXT);
+    let style = Style::default().fg(Theme::dim_text());
 
     buf.set_string(area.x, area.y, "\u{256d}", style);
     for x in 1..area.width - 1 {
@@ -246,3 +304,72 @@ fn render_empty_slot(area: Rect, buf: &mut Buffer) {
     let x = area.x + (area.width.saturating_sub(label.len() as u16)) / 2;
     buf.set_string(x, area.y + 2, label, style);
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use balatrust_core::joker::JokerType;
+
+    use crate::theme::ColorScheme;
+
+    #[test]
+    fn test_scoring_glow_wins_over_selection() {
+        let jokers = [Joker::new(JokerType::Joker), Joker::new(JokerType::Scholar)];
+        let bar = JokerBarWidget::new(&jokers, 5)
+            .selected(Some(0))
+            .activated(Some(0));
+
+        let both = bar.border_for(0);
+        assert_eq!(both.color, Theme::bright_text());
+        assert!(!both.double);
+
+        // Rendered with the glow, not the selection's double border
+        let area = Rect::new(0, 0, 40, JOKER_HEIGHT);
+        let mut buf = Buffer::empty(area);
+        bar.render(area, &mut buf);
+        let corner = bar_corner(&buf, &jokers, area);
+        assert_eq!(corner.symbol(), "\u{256d}");
+        assert_eq!(corner.fg, Theme::bright_text());
+    }
+
+    #[test]
+    fn test_selection_shows_once_scoring_ends() {
+        let jokers = [Joker::new(JokerType::Joker)];
+        let bar = JokerBarWidget::new(&jokers, 5).selected(Some(0));
+        assert_eq!(
+            bar.border_for(0),
+            JokerBorder {
+                color: Theme::card_selected(),
+                double: true,
+            }
+        );
+    }
+
+    #[test]
+    fn test_border_follows_the_color_scheme() {
+        let jokers = [Joker::new(JokerType::Joker)];
+        let area = Rect::new(0, 0, 40, JOKER_HEIGHT);
+        let corner_under = |scheme: ColorScheme| {
+            Theme::set_scheme(scheme);
+            let mut buf = Buffer::empty(area);
+            JokerBarWidget::new(&jokers, 5)
+                .selected(Some(0))
+                .render(area, &mut buf);
+            bar_corner(&buf, &jokers, area).fg
+        };
+
+        let dark = corner_under(ColorScheme::Dark);
+        let light = corner_under(ColorScheme::Light);
+        Theme::set_scheme(ColorScheme::Dark);
+
+        assert_eq!(dark, Theme::DARK.card_selected);
+        assert_eq!(light, Theme::LIGHT.card_selected);
+        assert_ne!(dark, light);
+    }
+
+    /// Top-left corner cell of the first joker
+    fn bar_corner<'b>(buf: &'b Buffer, jokers: &[Joker], area: Rect) -> &'b ratatui::buffer::Cell {
+        let rect = JokerBarWidget::new(jokers, 5).joker_rect(area, 0).unwrap();
+        &buf[(rect.x, rect.y)]
+    }
+}
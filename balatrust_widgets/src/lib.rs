@@ -6,11 +6,15 @@ pub mod cashout_panel;
 pub mod consumable_slots;
 pub mod deck_viewer;
 pub mod hand;
+pub mod help;
 pub mod hud;
 pub mod joker_bar;
 pub mod played_cards;
 pub mod popup;
+pub mod purchase_log;
+pub mod run_info;
 pub mod score_display;
+pub mod score_log;
 pub mod score_popup;
 pub mod shop_panel;
 pub mod sidebar;
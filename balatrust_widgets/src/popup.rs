@@ -52,14 +52,14 @@ impl Widget for PopupWidget {
         let title_line = Line::from(Span::styled(
             format!(" {} ", self.title),
             Style::default()
-                .fg(Theme::GOLD)
+                .fg(Theme::gold())
                 .add_modifier(Modifier::BOLD),
         ));
 
         let block = Block::default()
             .borders(Borders::ALL)
             .border_type(BorderType::Double)
-            .border_style(Style::default().fg(Theme::CARD_SELECTED))
+            .border_style(Style::default().fg(Theme::card_selected()))
             .title(title_line)
             .title_alignment(Alignment::Center)
             .padding(Padding::uniform(1));
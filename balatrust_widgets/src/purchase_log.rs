@@ -0,0 +1,230 @@
+use balatrust_core::run::Purchase;
+use ratatui::buffer::Buffer;
+use ratatui::layout::{Alignment, Rect};
+use ratatui::style::{Modifier, Style};
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{Block, BorderType, Borders, Clear, Padding, Widget};
+
+use crate::theme::Theme;
+
+const OVERLAY_WIDTH: u16 = 48;
+const OVERLAY_HEIGHT: u16 = 20;
+
+// ═══════════════════════════════════════════════════════════════════════
+// PurchaseLogWidget — everything bought in the shop this run
+// ═══════════════════════════════════════════════════════════════════════
+
+/// Centered overlay listing the run's shop purchases, newest first, under
+/// a total of the money spent
+pub struct PurchaseLogWidget<'a> {
+    pub purchases: &'a [Purchase],
+    pub scroll: usize,
+}
+
+impl<'a> PurchaseLogWidget<'a> {
+    pub fn new(purchases: &'a [Purchase], scroll: usize) -> Self {
+        Self { purchases, scroll }
+    }
+
+    /// Every line of the log before scrolling
+    pub fn lines(&self) -> Vec<Line<'static>> {
+        if self.purchases.is_empty() {
+            return vec![Line::from(Span::styled(
+                "Nothing bought yet",
+                Style::default().fg(Theme::dim_text()),
+            ))];
+        }
+        let spent: u32 = self.purchases.iter().map(|p| p.price).sum();
+        let mut lines = vec![
+            Line::from(Span::styled(
+                format!("{} items, ${} spent", self.purchases.len(), spent),
+                Style::default()
+                    .fg(Theme::gold())
+                    .add_modifier(Modifier::BOLD),
+            )),
+            Line::default(),
+        ];
+        for purchase in self.purchases.iter().rev() {
+            lines.push(Line::from(vec![
+                Span::styled(
+                    format!("Ante {:<3}", purchase.ante),
+                    Style::default().fg(Theme::muted_text()),
+                ),
+                Span::styled(
+                    format!("${:<4}", purchase.price),
+                    Style::default().fg(Theme::money_color()),
+                ),
+                Span::styled(
+                    purchase.name.clone(),
+                    Style::default().fg(Theme::bright_text()),
+                ),
+            ]));
+        }
+        lines
+    }
+
+    /// Furthest the log can scroll for this screen area
+    pub fn max_scroll(&self, area: Rect) -> usize {
+        // Top and bottom borders, then the footer row
+        let body_height = Self::overlay_rect(area).height.saturating_sub(3) as usize;
+        self.lines().len().saturating_sub(body_height)
+    }
+
+    fn overlay_rect(area: Rect) -> Rect {
+        let h = OVERLAY_HEIGHT.min(area.height);
+        let w = OVERLAY_WIDTH.min(area.width);
+        let x = area.x + area.width.saturating_sub(w) / 2;
+        let y = area.y + area.height.saturating_sub(h) / 2;
+        Rect::new(x, y, w, h)
+    }
+}
+
+impl<'a> Widget for PurchaseLogWidget<'a> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let overlay = Self::overlay_rect(area);
+        if overlay.width < 30 || overlay.height < 6 {
+            return;
+        }
+
+        Clear.render(overlay, buf);
+
+        let title = Line::from(Span::styled(
+            " Purchases ",
+            Style::default()
+                .fg(Theme::money_color())
+                .add_modifier(Modifier::BOLD),
+        ));
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .border_type(BorderType::Double)
+            .border_style(Style::default().fg(Theme::card_selected()))
+            .title(title)
+            .title_alignment(Alignment::Center)
+            .padding(Padding::horizontal(1));
+        let inner = block.inner(overlay);
+        block.render(overlay, buf);
+
+        // Last row is the footer
+        let body_height = inner.height.saturating_sub(1) as usize;
+        for (i, line) in self
+            .lines()
+            .iter()
+            .skip(self.scroll)
+            .take(body_height)
+            .enumerate()
+        {
+            buf.set_line(inner.x, inner.y + i as u16, line, inner.width);
+        }
+
+        let footer = "[\u{2191}\u{2193}] Scroll  [Esc] Close";
+        let fx = inner.x + inner.width.saturating_sub(footer.chars().count() as u16) / 2;
+        buf.set_string(
+            fx,
+            inner.bottom().saturating_sub(1),
+            footer,
+            Style::default().fg(Theme::dim_text()),
+        );
+    }
+}
+
+// ═══════════════════════════════════════════════════════════════════════
+// PurchaseLogState — open/close and scroll state for the shop screen
+// ═══════════════════════════════════════════════════════════════════════
+
+/// Whether the purchase log is showing, and how far it is scrolled
+#[derive(Debug, Default)]
+pub struct PurchaseLogState {
+    pub open: bool,
+    pub scroll: usize,
+}
+
+impl PurchaseLogState {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Open at the newest purchase, or close
+    pub fn toggle(&mut self) {
+        self.open = !self.open;
+        self.scroll = 0;
+    }
+
+    /// Render the overlay if open, first pulling the scroll back within the log
+    pub fn render_overlay(
+        &mut self,
+        frame: &mut ratatui::Frame,
+        screen_area: Rect,
+        purchases: &[Purchase],
+    ) {
+        if self.open {
+            let widget = PurchaseLogWidget::new(purchases, self.scroll);
+            self.scroll = self.scroll.min(widget.max_scroll(screen_area));
+            frame.render_widget(PurchaseLogWidget::new(purchases, self.scroll), screen_area);
+        }
+    }
+
+    /// Handle a key event while the overlay is open.
+    /// Returns true if the event was consumed.
+    pub fn handle_key(&mut self, code: crossterm::event::KeyCode) -> bool {
+        use crossterm::event::KeyCode;
+        if !self.open {
+            return false;
+        }
+        match code {
+            KeyCode::Esc | KeyCode::Enter | KeyCode::Char('p') | KeyCode::Char('P') => {
+                self.open = false;
+            }
+            KeyCode::Up | KeyCode::Char('k') => self.scroll = self.scroll.saturating_sub(1),
+            KeyCode::Down | KeyCode::Char('j') => self.scroll += 1,
+            _ => {}
+        }
+        true // Consume all keys when overlay is open
+    }
+
+    /// Any click closes the overlay. Returns true if the click was consumed.
+    pub fn handle_mouse_click(&mut self) -> bool {
+        let was_open = self.open;
+        self.open = false;
+        was_open
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn purchase(name: &str, price: u32, ante: u8) -> Purchase {
+        Purchase {
+            name: name.to_string(),
+            price,
+            ante,
+        }
+    }
+
+    #[test]
+    fn test_lines_total_the_spend_and_list_newest_first() {
+        let purchases = [purchase("Joker", 2, 1), purchase("Jupiter", 3, 2)];
+        let text: Vec<String> = PurchaseLogWidget::new(&purchases, 0)
+            .lines()
+            .iter()
+            .map(|l| l.to_string())
+            .collect();
+
+        assert_eq!(text[0], "2 items, $5 spent");
+        assert_eq!(text[1], "");
+        assert_eq!(text[2], "Ante 2  $3   Jupiter");
+        assert_eq!(text[3], "Ante 1  $2   Joker");
+        assert_eq!(text.len(), 4);
+    }
+
+    #[test]
+    fn test_max_scroll_stops_at_the_last_purchase() {
+        let area = Rect::new(0, 0, 80, 40);
+        let few = [purchase("Joker", 2, 1)];
+        assert_eq!(PurchaseLogWidget::new(&few, 0).max_scroll(area), 0);
+
+        // 30 purchases and 2 header lines, 17 body rows in a 20-row overlay
+        let many: Vec<Purchase> = (0..30).map(|_| purchase("Joker", 2, 1)).collect();
+        assert_eq!(PurchaseLogWidget::new(&many, 0).max_scroll(area), 15);
+    }
+}
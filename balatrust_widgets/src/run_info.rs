@@ -0,0 +1,237 @@
+use std::collections::HashMap;
+
+use balatrust_core::format::format_number;
+use balatrust_core::hand::PokerHand;
+use balatrust_core::scoring::HandLevels;
+use ratatui::buffer::Buffer;
+use ratatui::layout::{Alignment, Rect};
+use ratatui::style::{Modifier, Style};
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{Block, BorderType, Borders, Clear, Padding, Widget};
+
+use crate::theme::Theme;
+
+const OVERLAY_WIDTH: u16 = 58;
+
+// ═══════════════════════════════════════════════════════════════════════
+// RunInfoWidget — poker hand levels overlay
+// ═══════════════════════════════════════════════════════════════════════
+
+/// One poker hand's row in the Run Info table
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HandInfoRow {
+    pub hand: PokerHand,
+    pub level: u8,
+    pub chips: u64,
+    pub mult: u64,
+    pub played: u32,
+}
+
+impl HandInfoRow {
+    /// "chips X mult" as shown in the table
+    pub fn chips_mult_text(&self) -> String {
+        format!(
+            "{} X {}",
+            format_number(self.chips),
+            format_number(self.mult)
+        )
+    }
+}
+
+/// Rows for every poker hand, strongest first
+pub fn hand_info_rows(
+    hand_levels: &HandLevels,
+    hand_counts: &HashMap<PokerHand, u32>,
+) -> Vec<HandInfoRow> {
+    PokerHand::ALL
+        .iter()
+        .rev()
+        .map(|hand| HandInfoRow {
+            hand: *hand,
+            level: hand_levels.get_level(hand),
+            chips: hand_levels.chips_for(hand),
+            mult: hand_levels.mult_for(hand),
+            played: *hand_counts.get(hand).unwrap_or(&0),
+        })
+        .collect()
+}
+
+/// Centered overlay listing every poker hand's level, chips × mult and play count
+pub struct RunInfoWidget<'a> {
+    pub hand_levels: &'a HandLevels,
+    pub hand_counts: &'a HashMap<PokerHand, u32>,
+}
+
+impl<'a> RunInfoWidget<'a> {
+    pub fn new(hand_levels: &'a HandLevels, hand_counts: &'a HashMap<PokerHand, u32>) -> Self {
+        Self {
+            hand_levels,
+            hand_counts,
+        }
+    }
+
+    fn overlay_rect(area: Rect) -> Rect {
+        // Border + padding + header + rows + blank + footer
+        let h = (PokerHand::ALL.len() as u16 + 7).min(area.height);
+        let w = OVERLAY_WIDTH.min(area.width);
+        let x = area.x + area.width.saturating_sub(w) / 2;
+        let y = area.y + area.height.saturating_sub(h) / 2;
+        Rect::new(x, y, w, h)
+    }
+}
+
+impl<'a> Widget for RunInfoWidget<'a> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let overlay = Self::overlay_rect(area);
+        if overlay.width < 30 || overlay.height < 6 {
+            return;
+        }
+
+        Clear.render(overlay, buf);
+
+        let title = Line::from(Span::styled(
+            " Run Info ",
+            Style::default()
+                .fg(Theme::mult_color())
+                .add_modifier(Modifier::BOLD),
+        ));
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .border_type(BorderType::Double)
+            .border_style(Style::default().fg(Theme::card_selected()))
+            .title(title)
+            .title_alignment(Alignment::Center)
+            .padding(Padding::horizontal(1));
+        let inner = block.inner(overlay);
+        block.render(overlay, buf);
+
+        let dim = Style::default().fg(Theme::dim_text());
+        let header = format!(
+            "{:<16} {:>4}  {:>13}  {:>6}",
+            "Hand", "Lvl", "Chips X Mult", "Played"
+        );
+        buf.set_stringn(inner.x, inner.y, header, inner.width as usize, dim);
+
+        let rows = hand_info_rows(self.hand_levels, self.hand_counts);
+        for (i, row) in rows.iter().enumerate() {
+            let y = inner.y + 1 + i as u16;
+            if y >= inner.bottom().saturating_sub(1) {
+                break;
+            }
+            let name_style = if row.played > 0 {
+                Style::default().fg(Theme::bright_text())
+            } else {
+                dim
+            };
+            let level_style = if row.level > 1 {
+                Style::default()
+                    .fg(Theme::gold())
+                    .add_modifier(Modifier::BOLD)
+            } else {
+                dim
+            };
+            let line = Line::from(vec![
+                Span::styled(format!("{:<16} ", row.hand.to_string()), name_style),
+                Span::styled(format!("{:>4}  ", row.level), level_style),
+                Span::styled(
+                    format!("{:>13}  ", row.chips_mult_text()),
+                    Style::default().fg(Theme::chips_color()),
+                ),
+                Span::styled(format!("{:>6}", row.played), name_style),
+            ]);
+            buf.set_line(inner.x, y, &line, inner.width);
+        }
+
+        let footer = "[Esc] Close";
+        let fx = inner.x + inner.width.saturating_sub(footer.len() as u16) / 2;
+        buf.set_string(fx, inner.bottom().saturating_sub(1), footer, dim);
+    }
+}
+
+// ═══════════════════════════════════════════════════════════════════════
+// RunInfoState — open/close state shared by the in-run screens
+// ═══════════════════════════════════════════════════════════════════════
+
+/// Whether the Run Info overlay is showing
+#[derive(Debug, Default)]
+pub struct RunInfoState {
+    pub open: bool,
+}
+
+impl RunInfoState {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn toggle(&mut self) {
+        self.open = !self.open;
+    }
+
+    /// Render the overlay if open
+    pub fn render_overlay(
+        &self,
+        frame: &mut ratatui::Frame,
+        screen_area: Rect,
+        hand_levels: &HandLevels,
+        hand_counts: &HashMap<PokerHand, u32>,
+    ) {
+        if self.open {
+            frame.render_widget(RunInfoWidget::new(hand_levels, hand_counts), screen_area);
+        }
+    }
+
+    /// Handle a key event while the overlay is open.
+    /// Returns true if the event was consumed.
+    pub fn handle_key(&mut self, code: crossterm::event::KeyCode) -> bool {
+        if !self.open {
+            return false;
+        }
+        if matches!(
+            code,
+            crossterm::event::KeyCode::Esc
+                | crossterm::event::KeyCode::Enter
+                | crossterm::event::KeyCode::Char('i')
+                | crossterm::event::KeyCode::Char('I')
+        ) {
+            self.open = false;
+        }
+        true // Consume all keys when overlay is open
+    }
+
+    /// Any click closes the overlay. Returns true if the click was consumed.
+    pub fn handle_mouse_click(&mut self) -> bool {
+        let was_open = self.open;
+        self.open = false;
+        was_open
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn row_for(rows: &[HandInfoRow], hand: PokerHand) -> &HandInfoRow {
+        rows.iter().find(|r| r.hand == hand).unwrap()
+    }
+
+    #[test]
+    fn test_rows_show_chips_and_mult_per_level() {
+        let mut levels = HandLevels::new();
+        let counts = HashMap::from([(PokerHand::Pair, 3)]);
+
+        let rows = hand_info_rows(&levels, &counts);
+        assert_eq!(rows.len(), PokerHand::ALL.len());
+        assert_eq!(rows[0].hand, PokerHand::FlushFive);
+        let pair = row_for(&rows, PokerHand::Pair);
+        assert_eq!((pair.level, pair.played), (1, 3));
+        assert_eq!(pair.chips_mult_text(), "10 X 2");
+
+        levels.level_up(PokerHand::Pair);
+        levels.level_up(PokerHand::Pair);
+        let rows = hand_info_rows(&levels, &counts);
+        let pair = row_for(&rows, PokerHand::Pair);
+        assert_eq!(pair.level, 3);
+        assert_eq!(pair.chips_mult_text(), "40 X 4");
+        assert_eq!(row_for(&rows, PokerHand::Flush).played, 0);
+    }
+}
@@ -1,3 +1,4 @@
+use balatrust_core::format::format_number;
 use ratatui::buffer::Buffer;
 use ratatui::layout::Rect;
 use ratatui::style::{Modifier, Style};
@@ -41,7 +42,7 @@ impl Widget for ScoreDisplayWidget {
         let block = Block::default()
             .borders(Borders::ALL)
             .border_type(BorderType::Rounded)
-            .border_style(Style::default().fg(Theme::CARD_BORDER))
+            .border_style(Style::default().fg(Theme::card_border()))
             .padding(Padding::horizontal(1));
 
         let inner = block.inner(area);
@@ -59,12 +60,12 @@ impl Widget for ScoreDisplayWidget {
                 Span::styled(
                     &self.hand_name,
                     Style::default()
-                        .fg(Theme::BRIGHT_TEXT)
+                        .fg(Theme::bright_text())
                         .add_modifier(Modifier::BOLD),
                 ),
                 Span::styled(
                     format!(" Lv.{}", self.hand_level),
-                    Style::default().fg(Theme::GOLD),
+                    Style::default().fg(Theme::gold()),
                 ),
             ]);
             buf.set_line(inner.x, y, &hand_line, inner.width);
@@ -79,19 +80,19 @@ impl Widget for ScoreDisplayWidget {
             Span::styled(
                 format!("{}", self.chips),
                 Style::default()
-                    .fg(Theme::CHIPS_COLOR)
+                    .fg(Theme::chips_color())
                     .add_modifier(Modifier::BOLD),
             ),
             Span::styled(
                 " \u{00d7} ",
                 Style::default()
-                    .fg(Theme::BRIGHT_TEXT)
+                    .fg(Theme::bright_text())
                     .add_modifier(Modifier::BOLD),
             ),
             Span::styled(
                 format!("{}", self.mult),
                 Style::default()
-                    .fg(Theme::MULT_COLOR)
+                    .fg(Theme::mult_color())
                     .add_modifier(Modifier::BOLD),
             ),
         ]);
@@ -101,11 +102,11 @@ impl Widget for ScoreDisplayWidget {
         // Round score / target
         if y < inner.bottom() {
             let progress_line = Line::from(vec![
-                Span::styled("Score: ", Style::default().fg(Theme::MUTED_TEXT)),
+                Span::styled("Score: ", Style::default().fg(Theme::muted_text())),
                 Span::styled(
                     format_number(self.round_score),
                     Style::default()
-                        .fg(Theme::SCORE_COLOR)
+                        .fg(Theme::score_color())
                         .add_modifier(Modifier::BOLD),
                 ),
             ]);
@@ -115,10 +116,10 @@ impl Widget for ScoreDisplayWidget {
 
         if y < inner.bottom() {
             let target_line = Line::from(vec![
-                Span::styled("Target: ", Style::default().fg(Theme::MUTED_TEXT)),
+                Span::styled("Target: ", Style::default().fg(Theme::muted_text())),
                 Span::styled(
                     format_number(self.score_target),
-                    Style::default().fg(Theme::BRIGHT_TEXT),
+                    Style::default().fg(Theme::bright_text()),
                 ),
             ]);
             buf.set_line(inner.x, y, &target_line, inner.width);
@@ -143,31 +144,12 @@ impl Widget for ScoreDisplayWidget {
             bar.push(']');
 
             let bar_color = if progress >= 1.0 {
-                Theme::MONEY_COLOR
+                Theme::money_color()
             } else {
-                Theme::CHIPS_COLOR
+                Theme::chips_color()
             };
             let bar_style = Style::default().fg(bar_color);
             buf.set_string(inner.x, y, &bar, bar_style);
         }
     }
 }
-
-fn format_number(n: u64) -> String {
-    if n >= 1_000_000 {
-        format!("{:.1}M", n as f64 / 1_000_000.0)
-    } else if n >= 1_000 {
-        // Add comma separator
-        let s = n.to_string();
-        let mut result = String::new();
-        for (i, c) in s.chars().rev().enumerate() {
-            if i > 0 && i % 3 == 0 {
-                result.push(',');
-            }
-            result.push(c);
-        }
-        result.chars().rev().collect()
-    } else {
-        n.to_string()
-    }
-}
@@ -0,0 +1,208 @@
+use balatrust_core::scoring::ScoredHand;
+use ratatui::buffer::Buffer;
+use ratatui::layout::{Alignment, Rect};
+use ratatui::style::{Modifier, Style};
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{Block, BorderType, Borders, Clear, Padding, Widget};
+
+use crate::theme::Theme;
+
+const OVERLAY_WIDTH: u16 = 64;
+const OVERLAY_HEIGHT: u16 = 24;
+
+// ═══════════════════════════════════════════════════════════════════════
+// ScoreLogWidget — breakdown of recently scored hands
+// ═══════════════════════════════════════════════════════════════════════
+
+/// Centered overlay listing recent scored hands, newest first, each followed
+/// by its contributions in the order they applied
+pub struct ScoreLogWidget<'a> {
+    pub log: &'a [ScoredHand],
+    pub scroll: usize,
+}
+
+impl<'a> ScoreLogWidget<'a> {
+    pub fn new(log: &'a [ScoredHand], scroll: usize) -> Self {
+        Self { log, scroll }
+    }
+
+    /// Every line of the log before scrolling
+    pub fn lines(&self) -> Vec<Line<'static>> {
+        if self.log.is_empty() {
+            return vec![Line::from(Span::styled(
+                "No hands scored yet",
+                Style::default().fg(Theme::dim_text()),
+            ))];
+        }
+        let mut lines = Vec::new();
+        for (i, entry) in self.log.iter().rev().enumerate() {
+            if i > 0 {
+                lines.push(Line::default());
+            }
+            lines.push(Line::from(Span::styled(
+                entry.summary_line(),
+                Style::default()
+                    .fg(Theme::gold())
+                    .add_modifier(Modifier::BOLD),
+            )));
+            for contribution in entry.contribution_lines() {
+                lines.push(Line::from(Span::styled(
+                    format!("  {}", contribution),
+                    Style::default().fg(Theme::bright_text()),
+                )));
+            }
+        }
+        lines
+    }
+
+    fn overlay_rect(area: Rect) -> Rect {
+        let h = OVERLAY_HEIGHT.min(area.height);
+        let w = OVERLAY_WIDTH.min(area.width);
+        let x = area.x + area.width.saturating_sub(w) / 2;
+        let y = area.y + area.height.saturating_sub(h) / 2;
+        Rect::new(x, y, w, h)
+    }
+}
+
+impl<'a> Widget for ScoreLogWidget<'a> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let overlay = Self::overlay_rect(area);
+        if overlay.width < 30 || overlay.height < 6 {
+            return;
+        }
+
+        Clear.render(overlay, buf);
+
+        let title = Line::from(Span::styled(
+            " Score Log ",
+            Style::default()
+                .fg(Theme::chips_color())
+                .add_modifier(Modifier::BOLD),
+        ));
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .border_type(BorderType::Double)
+            .border_style(Style::default().fg(Theme::card_selected()))
+            .title(title)
+            .title_alignment(Alignment::Center)
+            .padding(Padding::horizontal(1));
+        let inner = block.inner(overlay);
+        block.render(overlay, buf);
+
+        // Last row is the footer
+        let body_height = inner.height.saturating_sub(1) as usize;
+        for (i, line) in self
+            .lines()
+            .iter()
+            .skip(self.scroll)
+            .take(body_height)
+            .enumerate()
+        {
+            buf.set_line(inner.x, inner.y + i as u16, line, inner.width);
+        }
+
+        let footer = "[\u{2191}\u{2193}] Scroll  [Esc] Close";
+        let fx = inner.x + inner.width.saturating_sub(footer.chars().count() as u16) / 2;
+        buf.set_string(
+            fx,
+            inner.bottom().saturating_sub(1),
+            footer,
+            Style::default().fg(Theme::dim_text()),
+        );
+    }
+}
+
+// ═══════════════════════════════════════════════════════════════════════
+// ScoreLogState — open/close and scroll state for the round screen
+// ═══════════════════════════════════════════════════════════════════════
+
+/// Whether the score log is showing, and how far it is scrolled
+#[derive(Debug, Default)]
+pub struct ScoreLogState {
+    pub open: bool,
+    pub scroll: usize,
+}
+
+impl ScoreLogState {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Open at the newest entry, or close
+    pub fn toggle(&mut self) {
+        self.open = !self.open;
+        self.scroll = 0;
+    }
+
+    /// Render the overlay if open
+    pub fn render_overlay(
+        &self,
+        frame: &mut ratatui::Frame,
+        screen_area: Rect,
+        log: &[ScoredHand],
+    ) {
+        if self.open {
+            frame.render_widget(ScoreLogWidget::new(log, self.scroll), screen_area);
+        }
+    }
+
+    /// Handle a key event while the overlay is open.
+    /// Returns true if the event was consumed.
+    pub fn handle_key(&mut self, code: crossterm::event::KeyCode) -> bool {
+        use crossterm::event::KeyCode;
+        if !self.open {
+            return false;
+        }
+        match code {
+            KeyCode::Esc | KeyCode::Enter | KeyCode::Char('g') | KeyCode::Char('G') => {
+                self.open = false;
+            }
+            KeyCode::Up | KeyCode::Char('k') => self.scroll = self.scroll.saturating_sub(1),
+            KeyCode::Down | KeyCode::Char('j') => self.scroll += 1,
+            _ => {}
+        }
+        true // Consume all keys when overlay is open
+    }
+
+    /// Any click closes the overlay. Returns true if the click was consumed.
+    pub fn handle_mouse_click(&mut self) -> bool {
+        let was_open = self.open;
+        self.open = false;
+        was_open
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use balatrust_core::card::{PlayingCard, Rank, Suit};
+    use balatrust_core::scoring::{calculate_score, HandLevels};
+
+    fn scored(cards: Vec<PlayingCard>) -> ScoredHand {
+        let result = calculate_score(&cards, &HandLevels::new());
+        ScoredHand {
+            played: cards,
+            jokers: Vec::new(),
+            result,
+        }
+    }
+
+    #[test]
+    fn test_lines_list_newest_hand_first() {
+        let high = scored(vec![PlayingCard::new(Rank::Ace, Suit::Spades)]);
+        let pair = scored(vec![
+            PlayingCard::new(Rank::King, Suit::Spades),
+            PlayingCard::new(Rank::King, Suit::Hearts),
+        ]);
+        let log = [high, pair];
+        let lines = ScoreLogWidget::new(&log, 0).lines();
+
+        let text: Vec<String> = lines.iter().map(|l| l.to_string()).collect();
+        assert!(text[0].starts_with("Pair"));
+        assert!(text[0].ends_with("= 60"));
+        // Base hand plus one chip step per King, a blank line, then the High Card
+        assert_eq!(text[4], "");
+        assert!(text[5].starts_with("High Card"));
+        assert_eq!(text.len(), 5 + 3);
+    }
+}
@@ -19,6 +19,7 @@ pub enum ScorePopupKind {
     Chips,
     Mult,
     XMult,
+    Money,
 }
 
 impl ScorePopup {
@@ -49,9 +50,10 @@ impl ScorePopup {
 
     fn color(&self) -> ratatui::style::Color {
         match self.kind {
-            ScorePopupKind::Chips => Theme::CHIPS_COLOR,
-            ScorePopupKind::Mult => Theme::MULT_COLOR,
-            ScorePopupKind::XMult => Theme::XMULT_COLOR,
+            ScorePopupKind::Chips => Theme::chips_color(),
+            ScorePopupKind::Mult => Theme::mult_color(),
+            ScorePopupKind::XMult => Theme::xmult_color(),
+            ScorePopupKind::Money => Theme::money_color(),
         }
     }
 }
@@ -83,7 +85,7 @@ impl Widget for ScorePopup {
             if cx < buf.area().right() && y < buf.area().bottom() && y >= buf.area().top() {
                 if let Some(cell) = buf.cell_mut((cx, y)) {
                     cell.set_symbol(" ");
-                    cell.set_bg(Theme::PANEL_BG);
+                    cell.set_bg(Theme::panel_bg());
                 }
             }
         }
@@ -22,12 +22,15 @@ const BUTTON_COL_WIDTH: u16 = 16;
 /// Renders the shop panel with:
 /// - Left column: "Next Round" and "Reroll $X" buttons
 /// - Right area: shop item cards with price tags
-/// - Bottom row: placeholder empty slots
+/// - Bottom row: the voucher and booster pack slots
 pub struct ShopPanelWidget<'a> {
     pub items: &'a [ShopItem],
     pub money: u32,
     pub reroll_cost: u32,
     pub selected_item: Option<usize>,
+    pub discount_percent: u32,
+    /// Item indices shown as free (Uncommon Tag jokers)
+    pub free_items: &'a [usize],
 }
 
 impl<'a> ShopPanelWidget<'a> {
@@ -42,9 +45,22 @@ impl<'a> ShopPanelWidget<'a> {
             money,
             reroll_cost,
             selected_item,
+            discount_percent: 0,
+            free_items: &[],
         }
     }
 
+    /// Percentage taken off every displayed price (Clearance Sale)
+    pub fn discount(mut self, discount_percent: u32) -> Self {
+        self.discount_percent = discount_percent;
+        self
+    }
+
+    pub fn free_items(mut self, free_items: &'a [usize]) -> Self {
+        self.free_items = free_items;
+        self
+    }
+
     // ─── Hit Testing ──────────────────────────────────────────────────
 
     /// Get the Rect for the "Next Round" button given the panel area.
@@ -83,26 +99,53 @@ impl<'a> ShopPanelWidget<'a> {
         buttons[2]
     }
 
-    /// Get the Rects for each shop item card given the panel area.
-    pub fn item_rects(area: Rect, item_count: usize) -> Vec<Rect> {
+    /// Get the Rects for each shop item given the panel area, indexed like `items`.
+    /// Card items sit in the top row; the voucher and pack sit in the bottom slots.
+    pub fn item_rects(area: Rect, items: &[ShopItem]) -> Vec<Rect> {
         let inner = Self::inner_rect(area);
         let cols = Layout::horizontal([Constraint::Length(BUTTON_COL_WIDTH), Constraint::Min(0)])
             .split(inner);
 
         let items_area = cols[1];
+        let card_count = items.iter().filter(|item| !is_bottom_item(item)).count();
+        let mut card_rects = Self::card_rects(items_area, card_count).into_iter();
+        let mut rects: Vec<Rect> = items
+            .iter()
+            .map(|item| {
+                if is_bottom_item(item) {
+                    Rect::default()
+                } else {
+                    card_rects.next().unwrap_or_default()
+                }
+            })
+            .collect();
+
+        let slots = bottom_slots(items);
+        for ((index, _), slot_rect) in slots
+            .iter()
+            .zip(Self::bottom_slot_rects(items_area, slots.len()))
+        {
+            if let Some(i) = index {
+                rects[*i] = slot_rect;
+            }
+        }
+        rects
+    }
+
+    /// Rects for `count` card items centered in the top row of the items area.
+    fn card_rects(items_area: Rect, count: usize) -> Vec<Rect> {
         let mut rects = Vec::new();
 
-        if item_count == 0 || items_area.width < ITEM_WIDTH {
+        if count == 0 || items_area.width < ITEM_WIDTH {
             return rects;
         }
 
         let spacing = 2u16;
-        let total_w =
-            item_count as u16 * ITEM_WIDTH + (item_count as u16).saturating_sub(1) * spacing;
+        let total_w = count as u16 * ITEM_WIDTH + (count as u16).saturating_sub(1) * spacing;
         let start_x = items_area.x + items_area.width.saturating_sub(total_w) / 2;
         let y = items_area.y + PRICE_TAG_HEIGHT;
 
-        for i in 0..item_count {
+        for i in 0..count {
             let x = start_x + i as u16 * (ITEM_WIDTH + spacing);
             let card_area = Rect::new(x, y, ITEM_WIDTH, ITEM_CARD_HEIGHT);
             if card_area.right() <= items_area.right() {
@@ -115,6 +158,40 @@ impl<'a> ShopPanelWidget<'a> {
         rects
     }
 
+    /// Rects for the bottom-row voucher and pack slots (below their price tag row).
+    /// A slot that doesn't fit is `Rect::default()`.
+    fn bottom_slot_rects(items_area: Rect, count: usize) -> Vec<Rect> {
+        let rows = Self::item_rows(items_area);
+        let mut slots = vec![Rect::default(); count];
+        if rows[2].height < PRICE_TAG_HEIGHT + 5 {
+            return slots;
+        }
+
+        let slot_w = ITEM_WIDTH;
+        let slot_h = 5u16.min(ITEM_CARD_HEIGHT);
+        let slot_spacing = 2u16;
+        let total_w = count as u16 * slot_w + (count as u16).saturating_sub(1) * slot_spacing;
+        let start_x = rows[2].x + rows[2].width.saturating_sub(total_w) / 2;
+
+        for (i, slot) in slots.iter_mut().enumerate() {
+            let sx = start_x + i as u16 * (slot_w + slot_spacing);
+            if sx + slot_w <= items_area.right() {
+                *slot = Rect::new(sx, rows[2].y + PRICE_TAG_HEIGHT, slot_w, slot_h);
+            }
+        }
+        slots
+    }
+
+    /// Split the items area into top row, separator and bottom row.
+    fn item_rows(items_area: Rect) -> std::rc::Rc<[Rect]> {
+        Layout::vertical([
+            Constraint::Length(PRICE_TAG_HEIGHT + ITEM_CARD_HEIGHT), // Items row
+            Constraint::Length(1),                                   // Separator
+            Constraint::Min(0),                                      // Bottom row (voucher, pack)
+        ])
+        .split(items_area)
+    }
+
     fn inner_rect(area: Rect) -> Rect {
         // Margins inside the outer panel border
         let h_margin = 1u16;
@@ -135,7 +212,7 @@ impl<'a> Widget for ShopPanelWidget<'a> {
         }
 
         // Outer panel border (double, red)
-        let border_style = Style::default().fg(Theme::MULT_COLOR);
+        let border_style = Style::default().fg(Theme::mult_color());
         self.render_outer_border(area, buf, border_style);
 
         let inner = Self::inner_rect(area);
@@ -203,7 +280,7 @@ impl<'a> ShopPanelWidget<'a> {
             buttons[0],
             buf,
             "Next Round",
-            Theme::MULT_COLOR,
+            Theme::mult_color(),
             true, // always available
         );
 
@@ -214,9 +291,9 @@ impl<'a> ShopPanelWidget<'a> {
             buf,
             &format!("Reroll ${}", self.reroll_cost),
             if can_reroll {
-                Theme::MONEY_COLOR
+                Theme::money_color()
             } else {
-                Theme::DIM_TEXT
+                Theme::dim_text()
             },
             can_reroll,
         );
@@ -273,56 +350,28 @@ impl<'a> ShopPanelWidget<'a> {
             return;
         }
 
-        // Split into top row (items) and bottom row (empty placeholder slots)
-        let rows = Layout::vertical([
-            Constraint::Length(PRICE_TAG_HEIGHT + ITEM_CARD_HEIGHT), // Items row
-            Constraint::Length(1),                                   // Separator
-            Constraint::Min(0),                                      // Bottom row (placeholders)
-        ])
-        .split(area);
-
-        // Render items in top row
-        if self.items.is_empty() {
+        // Split into top row (card items) and bottom row (voucher + pack slots)
+        let rows = Self::item_rows(area);
+
+        // Render card items in top row
+        let cards: Vec<(usize, &ShopItem)> = self
+            .items
+            .iter()
+            .enumerate()
+            .filter(|(_, item)| !is_bottom_item(item))
+            .collect();
+        if cards.is_empty() {
             let text = "No items - Reroll?";
             let x = rows[0].x + rows[0].width.saturating_sub(text.len() as u16) / 2;
             let y = rows[0].y + rows[0].height / 2;
-            buf.set_string(x, y, text, Style::default().fg(Theme::DIM_TEXT));
+            buf.set_string(x, y, text, Style::default().fg(Theme::dim_text()));
         } else {
-            let spacing = 2u16;
-            let count = self.items.len() as u16;
-            let total_w = count * ITEM_WIDTH + (count.saturating_sub(1)) * spacing;
-            let start_x = area.x + area.width.saturating_sub(total_w) / 2;
-
-            for (i, item) in self.items.iter().enumerate() {
-                let x = start_x + i as u16 * (ITEM_WIDTH + spacing);
-                if x + ITEM_WIDTH > area.right() {
+            let card_rects = Self::card_rects(area, cards.len());
+            for ((i, item), card_area) in cards.into_iter().zip(card_rects) {
+                if card_area.width == 0 {
                     break;
                 }
-
-                // Price tag above the card
-                let price_str = format!("${}", item.price());
-                let price_color = if self.money >= item.price() {
-                    Theme::MONEY_COLOR
-                } else {
-                    Theme::MULT_COLOR
-                };
-                let price_x = x + ITEM_WIDTH.saturating_sub(price_str.len() as u16) / 2;
-                buf.set_string(
-                    price_x,
-                    rows[0].y,
-                    &price_str,
-                    Style::default()
-                        .fg(price_color)
-                        .add_modifier(Modifier::BOLD),
-                );
-
-                // Card below the price tag
-                let card_area = Rect::new(
-                    x,
-                    rows[0].y + PRICE_TAG_HEIGHT,
-                    ITEM_WIDTH,
-                    ITEM_CARD_HEIGHT,
-                );
+                self.render_price_tag(i, item, card_area, buf);
                 let is_selected = self.selected_item == Some(i);
                 self.render_item_card(item, card_area, buf, is_selected);
             }
@@ -335,34 +384,64 @@ impl<'a> ShopPanelWidget<'a> {
                 rows[1].x + 1,
                 rows[1].y,
                 &sep,
-                Style::default().fg(Theme::DIM_TEXT),
+                Style::default().fg(Theme::dim_text()),
             );
         }
 
-        // Bottom row: 2 placeholder empty slots
-        if rows[2].height >= 5 {
-            let slot_w = ITEM_WIDTH;
-            let slot_h = 5u16.min(rows[2].height);
-            let slot_spacing = 2u16;
-            let total_w = 2 * slot_w + slot_spacing;
-            let start_x = rows[2].x + rows[2].width.saturating_sub(total_w) / 2;
-
-            for i in 0..2u16 {
-                let sx = start_x + i * (slot_w + slot_spacing);
-                if sx + slot_w > area.right() {
-                    break;
+        // Bottom row: voucher slot(s) + pack slot (empty once bought)
+        let slots = bottom_slots(self.items);
+        for ((index, label), slot_area) in
+            slots.iter().zip(Self::bottom_slot_rects(area, slots.len()))
+        {
+            if slot_area.width == 0 {
+                continue;
+            }
+            match index {
+                Some(i) => {
+                    let item = &self.items[*i];
+                    self.render_price_tag(*i, item, slot_area, buf);
+                    let is_selected = self.selected_item == Some(*i);
+                    self.render_item_card(item, slot_area, buf, is_selected);
                 }
-                let slot_area = Rect::new(sx, rows[2].y, slot_w, slot_h);
-                self.render_empty_slot(slot_area, buf, if i == 0 { "Voucher" } else { "Pack" });
+                None => self.render_empty_slot(slot_area, buf, label),
             }
         }
     }
 
+    /// Price tag in the row just above `card_area`
+    fn render_price_tag(&self, index: usize, item: &ShopItem, card_area: Rect, buf: &mut Buffer) {
+        let free = self.free_items.contains(&index);
+        let price = if free {
+            0
+        } else {
+            item.price_with_discount(self.discount_percent)
+        };
+        let price_str = if free {
+            "Free".to_string()
+        } else {
+            format!("${}", price)
+        };
+        let price_color = if self.money >= price {
+            Theme::money_color()
+        } else {
+            Theme::mult_color()
+        };
+        let price_x = card_area.x + ITEM_WIDTH.saturating_sub(price_str.len() as u16) / 2;
+        buf.set_string(
+            price_x,
+            card_area.y.saturating_sub(PRICE_TAG_HEIGHT),
+            &price_str,
+            Style::default()
+                .fg(price_color)
+                .add_modifier(Modifier::BOLD),
+        );
+    }
+
     fn render_item_card(&self, item: &ShopItem, area: Rect, buf: &mut Buffer, selected: bool) {
         let border_color = if selected {
-            Theme::CARD_SELECTED
+            Theme::card_selected()
         } else {
-            Theme::CARD_BORDER
+            Theme::card_border()
         };
         let border_style = Style::default().fg(border_color);
 
@@ -431,15 +510,18 @@ impl<'a> ShopPanelWidget<'a> {
         let display_name: String = name.chars().take(max_len).collect();
         let name_color = match item {
             ShopItem::JokerItem(j) => match j.joker_type.rarity() {
-                JokerRarity::Common => Theme::COMMON,
-                JokerRarity::Uncommon => Theme::UNCOMMON,
-                JokerRarity::Rare => Theme::RARE,
-                JokerRarity::Legendary => Theme::LEGENDARY,
+                JokerRarity::Common => Theme::common(),
+                JokerRarity::Uncommon => Theme::uncommon(),
+                JokerRarity::Rare => Theme::rare(),
+                JokerRarity::Legendary => Theme::legendary(),
             },
             ShopItem::ConsumableItem(c) => match c.consumable_type {
-                ConsumableType::Planet(_) => Theme::CHIPS_COLOR,
-                ConsumableType::Tarot(_) => Theme::LEGENDARY,
+                ConsumableType::Planet(_) => Theme::chips_color(),
+                ConsumableType::Tarot(_) => Theme::legendary(),
+                ConsumableType::Spectral(_) => Theme::spectral(),
             },
+            ShopItem::VoucherItem(_) => Theme::gold(),
+            ShopItem::PackItem(_) => Theme::money_color(),
         };
         buf.set_string(
             area.x + 1,
@@ -456,13 +538,16 @@ impl<'a> ShopPanelWidget<'a> {
                 ShopItem::ConsumableItem(c) => match c.consumable_type {
                     ConsumableType::Planet(_) => "Planet",
                     ConsumableType::Tarot(_) => "Tarot",
+                    ConsumableType::Spectral(_) => "Spectral",
                 },
+                ShopItem::VoucherItem(_) => "Voucher",
+                ShopItem::PackItem(_) => "Booster Pack",
             };
             buf.set_string(
                 area.x + 1,
                 y,
                 type_label,
-                Style::default().fg(Theme::DIM_TEXT),
+                Style::default().fg(Theme::dim_text()),
             );
             y += 1;
         }
@@ -475,7 +560,7 @@ impl<'a> ShopPanelWidget<'a> {
                 area.x + 1,
                 y,
                 &display_desc,
-                Style::default().fg(Theme::MUTED_TEXT),
+                Style::default().fg(Theme::muted_text()),
             );
             y += 1;
 
@@ -486,14 +571,14 @@ impl<'a> ShopPanelWidget<'a> {
                     area.x + 1,
                     y,
                     &second_line,
-                    Style::default().fg(Theme::MUTED_TEXT),
+                    Style::default().fg(Theme::muted_text()),
                 );
             }
         }
     }
 
     fn render_empty_slot(&self, area: Rect, buf: &mut Buffer, label: &str) {
-        let style = Style::default().fg(Theme::DIM_TEXT);
+        let style = Style::default().fg(Theme::dim_text());
 
         // Rounded border
         buf.set_string(area.x, area.y, "\u{256d}", style);
@@ -524,3 +609,31 @@ impl<'a> ShopPanelWidget<'a> {
         buf.set_string(x, area.y + area.height / 2, &display, style);
     }
 }
+
+/// Vouchers and packs sit in the bottom row; everything else is a card item
+fn is_bottom_item(item: &ShopItem) -> bool {
+    item.is_voucher() || item.is_pack()
+}
+
+/// Bottom-row slots left to right: every voucher on offer (or an empty "Voucher"
+/// slot), then the pack (or an empty "Pack" slot), as (item index, empty label)
+fn bottom_slots(items: &[ShopItem]) -> Vec<(Option<usize>, &'static str)> {
+    let mut slots = Vec::new();
+    for (vouchers, label) in [(true, "Voucher"), (false, "Pack")] {
+        let before = slots.len();
+        for (i, item) in items.iter().enumerate() {
+            let in_group = if vouchers {
+                item.is_voucher()
+            } else {
+                item.is_pack()
+            };
+            if in_group {
+                slots.push((Some(i), label));
+            }
+        }
+        if slots.len() == before {
+            slots.push((None, label));
+        }
+    }
+    slots
+}
@@ -1,11 +1,18 @@
+use balatrust_core::blind::BlindType;
+use balatrust_core::format::format_number;
+use balatrust_core::run::RunState;
+use balatrust_core::stake::Stake;
 use ratatui::buffer::Buffer;
 use ratatui::layout::{Constraint, Layout, Rect};
 use ratatui::style::{Modifier, Style};
 use ratatui::text::{Line, Span};
-use ratatui::widgets::{Block, BorderType, Borders, Widget};
+use ratatui::widgets::{Block, BorderType, Borders, Paragraph, Widget, Wrap};
 
 use crate::theme::Theme;
 
+/// Ticks between color flips of the pulsing boss banner (~0.5s at 30fps)
+const BOSS_PULSE_TICKS: u64 = 15;
+
 /// Left sidebar widget matching Balatro's original layout.
 /// Displays blind info, score, hand type, chips x mult, game info, money, and ante/round.
 pub struct SidebarWidget {
@@ -14,6 +21,12 @@ pub struct SidebarWidget {
     pub blind_color: ratatui::style::Color,
     pub score_target: u64,
     pub reward: u32,
+    /// Cash out if the blind were beaten right now, under the reward
+    pub projected_reward: Option<u32>,
+    /// Active boss's effect, shown under the banner as a reminder
+    pub boss_effect: Option<String>,
+    /// App tick, for the pulsing boss banner
+    pub tick: u64,
 
     // Score
     pub round_score: u64,
@@ -30,11 +43,17 @@ pub struct SidebarWidget {
 
     // Money
     pub money: u32,
+    /// Which way the shown money is moving (colors the value)
+    pub money_trend: MoneyTrend,
+    /// Joker / consumable slots and hand size, under hands and discards
+    pub slots: Option<SlotStats>,
 
     // Meta
     pub ante: u8,
     pub max_ante: u8,
     pub round_number: u8,
+    /// Stake shown under the ante, when set
+    pub stake: Option<Stake>,
 
     // Mode
     /// When true, shows a reduced sidebar (no blind banner, blind info, or hand type)
@@ -69,6 +88,9 @@ impl SidebarWidget {
             blind_color,
             score_target,
             reward,
+            projected_reward: None,
+            boss_effect: None,
+            tick: 0,
             round_score,
             hand_name,
             hand_level,
@@ -77,9 +99,12 @@ impl SidebarWidget {
             hands_remaining,
             discards_remaining,
             money,
+            money_trend: MoneyTrend::Steady,
+            slots: None,
             ante,
             max_ante,
             round_number,
+            stake: None,
             recap: false,
             shop: false,
             blind_select: false,
@@ -103,6 +128,46 @@ impl SidebarWidget {
         self.blind_select = blind_select;
         self
     }
+
+    /// Show joker / consumable slot use and hand size under hands and discards
+    pub fn slots(mut self, slots: SlotStats) -> Self {
+        self.slots = Some(slots);
+        self
+    }
+
+    /// Show the cash out `game` would get if its blind were beaten now
+    pub fn projected_reward(mut self, game: &RunState) -> Self {
+        self.projected_reward = Some(game.calculate_reward());
+        self
+    }
+
+    /// Remind the player of `game`'s boss effect under a pulsing banner (boss blinds only)
+    pub fn boss_effect(mut self, game: &RunState) -> Self {
+        self.boss_effect = match game.blind_type {
+            BlindType::Boss(boss) => Some(boss.description().to_string()),
+            _ => None,
+        };
+        self
+    }
+
+    /// Show the run's stake under the ante and round
+    pub fn stake(mut self, stake: Stake) -> Self {
+        self.stake = Some(stake);
+        self
+    }
+
+    /// Drive the boss banner pulse from the app tick
+    pub fn tick(mut self, tick: u64) -> Self {
+        self.tick = tick;
+        self
+    }
+
+    /// Show the tweened money value instead of `money`
+    pub fn money_display(mut self, display: MoneyDisplay) -> Self {
+        self.money = display.shown;
+        self.money_trend = display.trend;
+        self
+    }
 }
 
 impl Widget for SidebarWidget {
@@ -115,7 +180,7 @@ impl Widget for SidebarWidget {
         let outer_block = Block::default()
             .borders(Borders::ALL)
             .border_type(BorderType::Rounded)
-            .border_style(Style::default().fg(Theme::CARD_BORDER));
+            .border_style(Style::default().fg(Theme::card_border()));
 
         let inner = outer_block.inner(area);
         outer_block.render(area, buf);
@@ -185,29 +250,32 @@ impl Widget for SidebarWidget {
             self.render_meta(sections[6], buf);
         } else {
             // Normal mode: full sidebar
+            let boss_rows = if self.boss_effect.is_some() { 2 } else { 0 };
             let sections = Layout::vertical([
-                Constraint::Length(3), // Blind name banner
-                Constraint::Length(4), // Blind info (target + reward)
-                Constraint::Length(3), // Round score
-                Constraint::Length(2), // Hand type
-                Constraint::Length(3), // Chips x Mult display
-                Constraint::Length(1), // Separator
-                Constraint::Length(3), // Game info (hands + discards)
-                Constraint::Length(2), // Money
-                Constraint::Min(0),    // Spacer
-                Constraint::Length(2), // Ante / Round meta
+                Constraint::Length(3),         // Blind name banner
+                Constraint::Length(boss_rows), // Boss effect reminder
+                Constraint::Length(4),         // Blind info (target + reward)
+                Constraint::Length(3),         // Round score
+                Constraint::Length(2),         // Hand type
+                Constraint::Length(3),         // Chips x Mult display
+                Constraint::Length(1),         // Separator
+                Constraint::Length(3),         // Game info (hands + discards)
+                Constraint::Length(2),         // Money
+                Constraint::Min(0),            // Spacer
+                Constraint::Length(2),         // Ante / Round meta
             ])
             .split(inner);
 
             self.render_blind_banner(sections[0], buf);
-            self.render_blind_info(sections[1], buf);
-            self.render_round_score(sections[2], buf);
-            self.render_hand_type(sections[3], buf);
-            self.render_chips_mult(sections[4], buf);
-            self.render_separator(sections[5], buf);
-            self.render_game_info(sections[6], buf);
-            self.render_money(sections[7], buf);
-            self.render_meta(sections[9], buf);
+            self.render_boss_effect(sections[1], buf);
+            self.render_blind_info(sections[2], buf);
+            self.render_round_score(sections[3], buf);
+            self.render_hand_type(sections[4], buf);
+            self.render_chips_mult(sections[5], buf);
+            self.render_separator(sections[6], buf);
+            self.render_game_info(sections[7], buf);
+            self.render_money(sections[8], buf);
+            self.render_meta(sections[10], buf);
         }
     }
 }
@@ -233,7 +301,7 @@ impl SidebarWidget {
             padded.y + 1,
             line1,
             Style::default()
-                .fg(Theme::BRIGHT_TEXT)
+                .fg(Theme::bright_text())
                 .add_modifier(Modifier::BOLD),
         );
 
@@ -246,7 +314,7 @@ impl SidebarWidget {
                 padded.y + 2,
                 line2,
                 Style::default()
-                    .fg(Theme::BRIGHT_TEXT)
+                    .fg(Theme::bright_text())
                     .add_modifier(Modifier::BOLD),
             );
         }
@@ -257,7 +325,7 @@ impl SidebarWidget {
             return;
         }
 
-        let color = Theme::MULT_COLOR;
+        let color = Theme::mult_color();
         let padded = Rect::new(
             area.x + 1,
             area.y,
@@ -302,7 +370,7 @@ impl SidebarWidget {
             area.height,
         );
         let x = padded.x + padded.width.saturating_sub(text.len() as u16) / 2;
-        buf.set_string(x, padded.y, text, Style::default().fg(Theme::MUTED_TEXT));
+        buf.set_string(x, padded.y, text, Style::default().fg(Theme::muted_text()));
     }
 
     fn render_blind_banner(&self, area: Rect, buf: &mut Buffer) {
@@ -310,6 +378,14 @@ impl SidebarWidget {
             return;
         }
 
+        // A boss banner pulses between its color and danger red
+        let pulse_on = self.boss_effect.is_some() && (self.tick / BOSS_PULSE_TICKS) % 2 == 1;
+        let deco_color = if pulse_on {
+            Theme::mult_color()
+        } else {
+            self.blind_color
+        };
+
         // Fill background with blind color (dimmed)
         let bg_style = Style::default()
             .fg(self.blind_color)
@@ -327,12 +403,7 @@ impl SidebarWidget {
         // Top decoration line
         if padded.height >= 1 {
             let deco: String = "\u{2500}".repeat(padded.width as usize);
-            buf.set_string(
-                padded.x,
-                padded.y,
-                &deco,
-                Style::default().fg(self.blind_color),
-            );
+            buf.set_string(padded.x, padded.y, &deco, Style::default().fg(deco_color));
         }
 
         // Blind name (centered)
@@ -348,11 +419,32 @@ impl SidebarWidget {
                 padded.x,
                 padded.y + 2,
                 &deco,
-                Style::default().fg(self.blind_color),
+                Style::default().fg(deco_color),
             );
         }
     }
 
+    fn render_boss_effect(&self, area: Rect, buf: &mut Buffer) {
+        let Some(effect) = &self.boss_effect else {
+            return;
+        };
+        let padded = Rect::new(
+            area.x + 1,
+            area.y,
+            area.width.saturating_sub(2),
+            area.height,
+        );
+        Paragraph::new(effect.as_str())
+            .style(
+                Style::default()
+                    .fg(self.blind_color)
+                    .add_modifier(Modifier::ITALIC),
+            )
+            .alignment(ratatui::layout::Alignment::Center)
+            .wrap(Wrap { trim: true })
+            .render(padded, buf);
+    }
+
     fn render_blind_info(&self, area: Rect, buf: &mut Buffer) {
         let padded = Rect::new(
             area.x + 1,
@@ -366,11 +458,11 @@ impl SidebarWidget {
 
         // Target score
         let target_line = Line::from(vec![
-            Span::styled(" Target: ", Style::default().fg(Theme::MUTED_TEXT)),
+            Span::styled(" Target: ", Style::default().fg(Theme::muted_text())),
             Span::styled(
                 format_number(self.score_target),
                 Style::default()
-                    .fg(Theme::CHIPS_COLOR)
+                    .fg(Theme::chips_color())
                     .add_modifier(Modifier::BOLD),
             ),
         ]);
@@ -380,16 +472,28 @@ impl SidebarWidget {
         if padded.height >= 3 {
             let dollars: String = "$".repeat(self.reward as usize);
             let reward_line = Line::from(vec![
-                Span::styled(" Reward: ", Style::default().fg(Theme::MUTED_TEXT)),
+                Span::styled(" Reward: ", Style::default().fg(Theme::muted_text())),
                 Span::styled(
                     dollars,
                     Style::default()
-                        .fg(Theme::MONEY_COLOR)
+                        .fg(Theme::money_color())
                         .add_modifier(Modifier::BOLD),
                 ),
             ]);
             buf.set_line(padded.x, padded.y + 2, &reward_line, padded.width);
         }
+
+        // Projected cash out, counting hands left, interest and jokers
+        if let Some(projected) = self.projected_reward.filter(|_| padded.height >= 4) {
+            let projected_line = Line::from(vec![
+                Span::styled(" Cash out: ", Style::default().fg(Theme::muted_text())),
+                Span::styled(
+                    format!("${}", projected),
+                    Style::default().fg(Theme::money_color()),
+                ),
+            ]);
+            buf.set_line(padded.x, padded.y + 3, &projected_line, padded.width);
+        }
     }
 
     fn render_round_score(&self, area: Rect, buf: &mut Buffer) {
@@ -405,14 +509,14 @@ impl SidebarWidget {
 
         let label = Line::from(Span::styled(
             " Round Score",
-            Style::default().fg(Theme::MUTED_TEXT),
+            Style::default().fg(Theme::muted_text()),
         ));
         buf.set_line(padded.x, padded.y, &label, padded.width);
 
         let score_line = Line::from(Span::styled(
             format!(" {}", format_number(self.round_score)),
             Style::default()
-                .fg(Theme::SCORE_COLOR)
+                .fg(Theme::score_color())
                 .add_modifier(Modifier::BOLD),
         ));
         buf.set_line(padded.x, padded.y + 1, &score_line, padded.width);
@@ -433,12 +537,12 @@ impl SidebarWidget {
             Span::styled(
                 format!(" {}", self.hand_name),
                 Style::default()
-                    .fg(Theme::BRIGHT_TEXT)
+                    .fg(Theme::bright_text())
                     .add_modifier(Modifier::BOLD),
             ),
             Span::styled(
                 format!(" Lv.{}", self.hand_level),
-                Style::default().fg(Theme::GOLD),
+                Style::default().fg(Theme::gold()),
             ),
         ]);
         buf.set_line(padded.x, padded.y, &hand_line, padded.width);
@@ -477,7 +581,7 @@ impl SidebarWidget {
             y,
             &chips_box_str,
             Style::default()
-                .fg(Theme::CHIPS_COLOR)
+                .fg(Theme::chips_color())
                 .add_modifier(Modifier::BOLD),
         );
 
@@ -487,7 +591,7 @@ impl SidebarWidget {
             y,
             " \u{00d7} ",
             Style::default()
-                .fg(Theme::BRIGHT_TEXT)
+                .fg(Theme::bright_text())
                 .add_modifier(Modifier::BOLD),
         );
 
@@ -498,7 +602,7 @@ impl SidebarWidget {
             y,
             &mult_box_str,
             Style::default()
-                .fg(Theme::MULT_COLOR)
+                .fg(Theme::mult_color())
                 .add_modifier(Modifier::BOLD),
         );
     }
@@ -512,7 +616,7 @@ impl SidebarWidget {
             area.x + 1,
             area.y,
             &line,
-            Style::default().fg(Theme::DIM_TEXT),
+            Style::default().fg(Theme::dim_text()),
         );
     }
 
@@ -529,11 +633,11 @@ impl SidebarWidget {
 
         // Hands remaining
         let hands_line = Line::from(vec![
-            Span::styled(" Hands:    ", Style::default().fg(Theme::MUTED_TEXT)),
+            Span::styled(" Hands:    ", Style::default().fg(Theme::muted_text())),
             Span::styled(
                 format!("{}", self.hands_remaining),
                 Style::default()
-                    .fg(Theme::CHIPS_COLOR)
+                    .fg(Theme::chips_color())
                     .add_modifier(Modifier::BOLD),
             ),
         ]);
@@ -542,16 +646,44 @@ impl SidebarWidget {
         // Discards remaining
         if padded.height >= 2 {
             let disc_line = Line::from(vec![
-                Span::styled(" Discards: ", Style::default().fg(Theme::MUTED_TEXT)),
+                Span::styled(" Discards: ", Style::default().fg(Theme::muted_text())),
                 Span::styled(
                     format!("{}", self.discards_remaining),
                     Style::default()
-                        .fg(Theme::MULT_COLOR)
+                        .fg(Theme::mult_color())
                         .add_modifier(Modifier::BOLD),
                 ),
             ]);
             buf.set_line(padded.x, padded.y + 1, &disc_line, padded.width);
         }
+
+        // Slots and hand size
+        if let Some(slots) = self.slots.filter(|_| padded.height >= 3) {
+            let muted = Style::default().fg(Theme::muted_text());
+            let value = |full: bool| {
+                let color = if full {
+                    Theme::gold()
+                } else {
+                    Theme::bright_text()
+                };
+                Style::default().fg(color).add_modifier(Modifier::BOLD)
+            };
+            let slots_line = Line::from(vec![
+                Span::styled(" Jkr ", muted),
+                Span::styled(
+                    format!("{}/{}", slots.jokers, slots.max_jokers),
+                    value(slots.jokers >= slots.max_jokers),
+                ),
+                Span::styled("  Con ", muted),
+                Span::styled(
+                    format!("{}/{}", slots.consumables, slots.max_consumables),
+                    value(slots.consumables >= slots.max_consumables),
+                ),
+                Span::styled("  Hand ", muted),
+                Span::styled(format!("{}", slots.hand_size), value(false)),
+            ]);
+            buf.set_line(padded.x, padded.y + 2, &slots_line, padded.width);
+        }
     }
 
     fn render_money(&self, area: Rect, buf: &mut Buffer) {
@@ -565,11 +697,16 @@ impl SidebarWidget {
             return;
         }
 
+        let color = if self.money == 0 {
+            Theme::mult_color()
+        } else if self.money_trend == MoneyTrend::Rising {
+            Theme::gold()
+        } else {
+            Theme::money_color()
+        };
         let money_line = Line::from(Span::styled(
             format!(" ${}", self.money),
-            Style::default()
-                .fg(Theme::MONEY_COLOR)
-                .add_modifier(Modifier::BOLD),
+            Style::default().fg(color).add_modifier(Modifier::BOLD),
         ));
         buf.set_line(padded.x, padded.y, &money_line, padded.width);
     }
@@ -586,39 +723,296 @@ impl SidebarWidget {
         }
 
         let meta_line = Line::from(vec![
-            Span::styled(" Ante: ", Style::default().fg(Theme::MUTED_TEXT)),
+            Span::styled(" Ante: ", Style::default().fg(Theme::muted_text())),
             Span::styled(
                 format!("{}/{}", self.ante, self.max_ante),
                 Style::default()
-                    .fg(Theme::GOLD)
+                    .fg(Theme::gold())
                     .add_modifier(Modifier::BOLD),
             ),
-            Span::styled("  Round: ", Style::default().fg(Theme::MUTED_TEXT)),
+            Span::styled("  Round: ", Style::default().fg(Theme::muted_text())),
             Span::styled(
                 format!("{}", self.round_number),
                 Style::default()
-                    .fg(Theme::BRIGHT_TEXT)
+                    .fg(Theme::bright_text())
                     .add_modifier(Modifier::BOLD),
             ),
         ]);
         buf.set_line(padded.x, padded.y, &meta_line, padded.width);
+
+        if let Some(stake) = self.stake.filter(|_| padded.height > 1) {
+            let stake_line = Line::from(vec![
+                Span::styled(" Stake: ", Style::default().fg(Theme::muted_text())),
+                Span::styled(
+                    stake.name().trim_end_matches(" Stake"),
+                    Style::default().fg(Theme::bright_text()),
+                ),
+            ]);
+            buf.set_line(padded.x, padded.y + 1, &stake_line, padded.width);
+        }
     }
 }
 
-fn format_number(n: u64) -> String {
-    if n >= 1_000_000 {
-        format!("{:.1}M", n as f64 / 1_000_000.0)
-    } else if n >= 1_000 {
-        let s = n.to_string();
-        let mut result = String::new();
-        for (i, c) in s.chars().rev().enumerate() {
-            if i > 0 && i % 3 == 0 {
-                result.push(',');
-            }
-            result.push(c);
+// ═══════════════════════════════════════════════════════════════════════
+// SlotStats — joker / consumable slots and hand size
+// ═══════════════════════════════════════════════════════════════════════
+
+/// Slot use and hand size for the sidebar's stats row
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SlotStats {
+    pub jokers: usize,
+    pub max_jokers: usize,
+    pub consumables: usize,
+    pub max_consumables: usize,
+    pub hand_size: u8,
+}
+
+impl SlotStats {
+    /// Negative jokers and consumables don't count against their slots
+    pub fn from_run(game: &RunState) -> Self {
+        Self {
+            jokers: game.joker_slots_used(),
+            max_jokers: game.max_jokers as usize,
+            consumables: game.consumable_slots_used(),
+            max_consumables: game.max_consumables as usize,
+            hand_size: game.hand_size,
         }
-        result.chars().rev().collect()
+    }
+}
+
+// ═══════════════════════════════════════════════════════════════════════
+// MoneyDisplay — money counting toward its real value
+// ═══════════════════════════════════════════════════════════════════════
+
+/// Which way the shown money last moved
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum MoneyTrend {
+    #[default]
+    Steady,
+    Rising,
+    Falling,
+}
+
+/// Money as shown in the sidebar: counts up or down toward the real amount
+/// one `tick` at a time instead of snapping to it
+#[derive(Debug, Clone, Copy, Default)]
+pub struct MoneyDisplay {
+    pub shown: u32,
+    pub trend: MoneyTrend,
+}
+
+impl MoneyDisplay {
+    /// Advance one tick toward `target`
+    pub fn tick(&mut self, target: u32) {
+        self.trend = match self.shown.cmp(&target) {
+            std::cmp::Ordering::Less => MoneyTrend::Rising,
+            std::cmp::Ordering::Greater => MoneyTrend::Falling,
+            std::cmp::Ordering::Equal => MoneyTrend::Steady,
+        };
+        self.shown = tween_step(self.shown, target);
+    }
+
+    /// Show `amount` straight away, without counting toward it
+    pub fn snap(&mut self, amount: u32) {
+        self.shown = amount;
+        self.trend = MoneyTrend::Steady;
+    }
+}
+
+/// One step from `shown` toward `target`: a quarter of the gap (rounded up),
+/// so large changes finish in a few ticks and small ones count by $1
+pub fn tween_step(shown: u32, target: u32) -> u32 {
+    let step = shown.abs_diff(target).div_ceil(4);
+    if shown < target {
+        shown + step
     } else {
-        n.to_string()
+        shown - step
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use balatrust_core::consumable::{Consumable, TarotCard};
+    use balatrust_core::joker::{Joker, JokerType};
+
+    fn row_text(buf: &Buffer, area: Rect, y: u16) -> String {
+        (area.x..area.right())
+            .map(|x| buf[(x, y)].symbol())
+            .collect()
+    }
+
+    #[test]
+    fn test_slots_row_shows_slot_fractions_and_hand_size() {
+        let mut game = RunState::with_seed(1);
+        game.jokers.push(Joker::new(JokerType::Joker));
+        game.jokers.push(Joker::new(JokerType::Scholar));
+        game.consumables.push(Consumable::tarot(TarotCard::TheFool));
+        let slots = SlotStats::from_run(&game);
+        assert_eq!((slots.jokers, slots.max_jokers), (2, 5));
+        assert_eq!((slots.consumables, slots.max_consumables), (1, 2));
+
+        let sidebar = SidebarWidget::new(
+            String::new(),
+            Theme::small_blind(),
+            300,
+            3,
+            0,
+            String::new(),
+            1,
+            0,
+            0,
+            4,
+            3,
+            4,
+            1,
+            8,
+            1,
+        )
+        .recap(true)
+        .slots(slots);
+        let area = Rect::new(0, 0, 30, 24);
+        let mut buf = Buffer::empty(area);
+        sidebar.render(area, &mut buf);
+
+        let row = (area.y..area.bottom())
+            .map(|y| row_text(&buf, area, y))
+            .find(|row| row.contains("Jkr"))
+            .expect("stats row is rendered");
+        assert!(row.contains("Jkr 2/5  Con 1/2  Hand 8"), "{}", row);
+    }
+
+    #[test]
+    fn test_projected_reward_matches_the_cash_out() {
+        let mut game = RunState::with_seed(1);
+        game.money = 23;
+        game.jokers.push(Joker::new(JokerType::GoldenJoker));
+        game.start_blind();
+
+        let sidebar = |game: &RunState| {
+            SidebarWidget::new(
+                game.blind_type.name(),
+                Theme::small_blind(),
+                game.score_target,
+                game.blind_type.reward(),
+                0,
+                String::new(),
+                1,
+                0,
+                0,
+                game.hands_remaining,
+                game.discards_remaining,
+                game.money,
+                1,
+                8,
+                1,
+            )
+            .projected_reward(game)
+        };
+        let expected = game.calculate_reward();
+        // $3 blind + 4 hands + $4 interest + $4 Golden Joker
+        assert_eq!(expected, 15);
+        assert_eq!(sidebar(&game).projected_reward, Some(expected));
+
+        let area = Rect::new(0, 0, 30, 30);
+        let mut buf = Buffer::empty(area);
+        sidebar(&game).render(area, &mut buf);
+        let row = (area.y..area.bottom())
+            .map(|y| row_text(&buf, area, y))
+            .find(|row| row.contains("Cash out"))
+            .expect("projected reward is rendered");
+        assert!(row.contains("Cash out: $15"), "{}", row);
+
+        // Each hand used takes $1 off the projection
+        game.use_hand();
+        assert_eq!(sidebar(&game).projected_reward, Some(expected - 1));
+    }
+
+    fn round_sidebar(game: &RunState) -> SidebarWidget {
+        SidebarWidget::new(
+            game.blind_type.name(),
+            Theme::boss_blind(),
+            game.score_target,
+            game.blind_type.reward(),
+            0,
+            String::new(),
+            1,
+            0,
+            0,
+            game.hands_remaining,
+            game.discards_remaining,
+            game.money,
+            1,
+            8,
+            1,
+        )
+        .boss_effect(game)
+    }
+
+    fn sidebar_text(sidebar: SidebarWidget) -> String {
+        let area = Rect::new(0, 0, 30, 34);
+        let mut buf = Buffer::empty(area);
+        sidebar.render(area, &mut buf);
+        (area.y..area.bottom())
+            .map(|y| row_text(&buf, area, y))
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    #[test]
+    fn test_boss_blind_shows_its_effect() {
+        use balatrust_core::blind::BossBlind;
+
+        let mut game = RunState::with_seed(1);
+        game.start_blind();
+        assert!(round_sidebar(&game).boss_effect.is_none());
+
+        game.blind_type = BlindType::Boss(BossBlind::TheNeedle);
+        let text = sidebar_text(round_sidebar(&game));
+        assert!(
+            text.contains(BossBlind::TheNeedle.description()),
+            "{}",
+            text
+        );
+    }
+
+    #[test]
+    fn test_boss_banner_pulses_with_the_tick() {
+        use balatrust_core::blind::BossBlind;
+
+        let mut game = RunState::with_seed(1);
+        game.blind_type = BlindType::Boss(BossBlind::TheHook);
+        let deco_color = |tick: u64| {
+            let area = Rect::new(0, 0, 30, 34);
+            let mut buf = Buffer::empty(area);
+            round_sidebar(&game).tick(tick).render(area, &mut buf);
+            buf[(2, 1)].fg
+        };
+        assert_eq!(deco_color(0), Theme::boss_blind());
+        assert_eq!(deco_color(BOSS_PULSE_TICKS), Theme::mult_color());
+        assert_eq!(deco_color(BOSS_PULSE_TICKS * 2), Theme::boss_blind());
+    }
+
+    #[test]
+    fn test_tween_step_converges_to_target() {
+        assert_eq!(tween_step(10, 10), 10);
+        assert_eq!(tween_step(0, 1), 1);
+        assert_eq!(tween_step(0, 100), 25);
+        assert_eq!(tween_step(100, 0), 75);
+
+        let mut display = MoneyDisplay::default();
+        let mut ticks = 0;
+        while display.shown != 250 {
+            display.tick(250);
+            assert_eq!(display.trend, MoneyTrend::Rising);
+            ticks += 1;
+            assert!(ticks < 30, "stuck at {}", display.shown);
+        }
+        display.tick(250);
+        assert_eq!(display.trend, MoneyTrend::Steady);
+
+        display.tick(3);
+        assert_eq!(display.trend, MoneyTrend::Falling);
+        assert!(display.shown < 250 && display.shown > 3);
     }
 }
@@ -1,49 +1,415 @@
-use ratatui::style::Color;
+use std::cell::Cell;
 
-/// Balatro-inspired color theme for the TUI
-pub struct Theme;
+use balatrust_core::card::Suit;
+use balatrust_core::hand::PokerHand;
+use ratatui::style::Color;
+use serde::{Deserialize, Serialize};
 
-impl Theme {
+/// Every color the TUI draws with. `Theme::DARK` is the Balatro-inspired default;
+/// the active one is picked with `Theme::set_scheme` and read through the
+/// `Theme::gold()`-style accessors below.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Theme {
     // Backgrounds
-    pub const BG: Color = Color::Rgb(13, 17, 23);
-    pub const FELT: Color = Color::Rgb(22, 22, 52);
-    pub const PANEL_BG: Color = Color::Rgb(30, 30, 60);
+    pub bg: Color,
+    pub felt: Color,
+    pub panel_bg: Color,
 
     // Card colors
-    pub const CARD_FACE: Color = Color::Rgb(240, 240, 240);
-    pub const CARD_BORDER: Color = Color::Rgb(108, 117, 125);
-    pub const CARD_SELECTED: Color = Color::Rgb(255, 214, 10);
-    pub const CARD_BACK: Color = Color::Rgb(60, 60, 120);
+    pub card_face: Color,
+    pub card_border: Color,
+    pub card_selected: Color,
+    pub card_back: Color,
     /// Subtle dim color used for decorative interior elements on card faces
-    pub const CARD_FACE_DIM: Color = Color::Rgb(80, 80, 100);
+    pub card_face_dim: Color,
 
     // Suit colors
-    pub const RED_SUIT: Color = Color::Rgb(230, 57, 70);
-    pub const BLACK_SUIT: Color = Color::Rgb(224, 224, 224);
+    pub red_suit: Color,
+    pub black_suit: Color,
+    /// Diamonds and Clubs get their own colors with accessible suits on
+    pub diamond_suit: Color,
+    pub club_suit: Color,
 
     // Score colors
-    pub const CHIPS_COLOR: Color = Color::Rgb(76, 201, 240);
-    pub const MULT_COLOR: Color = Color::Rgb(230, 57, 70);
-    pub const XMULT_COLOR: Color = Color::Rgb(255, 100, 100);
-    pub const SCORE_COLOR: Color = Color::Rgb(255, 214, 10);
+    pub chips_color: Color,
+    pub mult_color: Color,
+    pub xmult_color: Color,
+    pub score_color: Color,
 
     // Money
-    pub const MONEY_COLOR: Color = Color::Rgb(6, 214, 160);
+    pub money_color: Color,
 
     // Joker rarities
-    pub const COMMON: Color = Color::Rgb(108, 117, 125);
-    pub const UNCOMMON: Color = Color::Rgb(6, 214, 160);
-    pub const RARE: Color = Color::Rgb(230, 57, 70);
-    pub const LEGENDARY: Color = Color::Rgb(114, 9, 183);
+    pub common: Color,
+    pub uncommon: Color,
+    pub rare: Color,
+    pub legendary: Color,
+
+    // Consumables
+    pub spectral: Color,
 
     // UI elements
-    pub const GOLD: Color = Color::Rgb(255, 183, 3);
-    pub const DIM_TEXT: Color = Color::Rgb(100, 100, 120);
-    pub const BRIGHT_TEXT: Color = Color::Rgb(255, 255, 255);
-    pub const MUTED_TEXT: Color = Color::Rgb(160, 160, 180);
+    pub gold: Color,
+    pub dim_text: Color,
+    pub bright_text: Color,
+    pub muted_text: Color,
 
     // Blind colors
-    pub const SMALL_BLIND: Color = Color::Rgb(76, 201, 240);
-    pub const BIG_BLIND: Color = Color::Rgb(255, 183, 3);
-    pub const BOSS_BLIND: Color = Color::Rgb(230, 57, 70);
+    pub small_blind: Color,
+    pub big_blind: Color,
+    pub boss_blind: Color,
+}
+
+/// Selectable color schemes (saved with the other settings)
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ColorScheme {
+    #[default]
+    Dark,
+    /// For terminals with a light background
+    Light,
+    HighContrast,
+    /// Okabe-Ito colors, told apart with red-green color blindness
+    Colorblind,
+}
+
+impl ColorScheme {
+    pub const ALL: [ColorScheme; 4] = [
+        ColorScheme::Dark,
+        ColorScheme::Light,
+        ColorScheme::HighContrast,
+        ColorScheme::Colorblind,
+    ];
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            ColorScheme::Dark => "Dark",
+            ColorScheme::Light => "Light",
+            ColorScheme::HighContrast => "High Contrast",
+            ColorScheme::Colorblind => "Colorblind",
+        }
+    }
+
+    pub fn theme(&self) -> &'static Theme {
+        match self {
+            ColorScheme::Dark => &Theme::DARK,
+            ColorScheme::Light => &Theme::LIGHT,
+            ColorScheme::HighContrast => &Theme::HIGH_CONTRAST,
+            ColorScheme::Colorblind => &Theme::COLORBLIND,
+        }
+    }
+
+    /// Next scheme in `ALL`, wrapping around (`forward = false` goes back)
+    pub fn cycle(&self, forward: bool) -> Self {
+        let len = Self::ALL.len();
+        let idx = Self::ALL.iter().position(|s| s == self).unwrap_or(0);
+        let next = if forward {
+            (idx + 1) % len
+        } else {
+            (idx + len - 1) % len
+        };
+        Self::ALL[next]
+    }
+}
+
+thread_local! {
+    /// Scheme the widgets draw with. Per thread, so tests can't repaint each other.
+    static ACTIVE_SCHEME: Cell<ColorScheme> = const { Cell::new(ColorScheme::Dark) };
+    /// Give every suit its own color and a letter, not just red / black
+    static ACCESSIBLE_SUITS: Cell<bool> = const { Cell::new(false) };
+}
+
+impl Theme {
+    pub const DARK: Theme = Theme {
+        bg: Color::Rgb(13, 17, 23),
+        felt: Color::Rgb(22, 22, 52),
+        panel_bg: Color::Rgb(30, 30, 60),
+        card_face: Color::Rgb(240, 240, 240),
+        card_border: Color::Rgb(108, 117, 125),
+        card_selected: Color::Rgb(255, 214, 10),
+        card_back: Color::Rgb(60, 60, 120),
+        card_face_dim: Color::Rgb(80, 80, 100),
+        red_suit: Color::Rgb(230, 57, 70),
+        black_suit: Color::Rgb(224, 224, 224),
+        diamond_suit: Color::Rgb(255, 160, 40),
+        club_suit: Color::Rgb(60, 200, 90),
+        chips_color: Color::Rgb(76, 201, 240),
+        mult_color: Color::Rgb(230, 57, 70),
+        xmult_color: Color::Rgb(255, 100, 100),
+        score_color: Color::Rgb(255, 214, 10),
+        money_color: Color::Rgb(6, 214, 160),
+        common: Color::Rgb(108, 117, 125),
+        uncommon: Color::Rgb(6, 214, 160),
+        rare: Color::Rgb(230, 57, 70),
+        legendary: Color::Rgb(114, 9, 183),
+        spectral: Color::Rgb(72, 149, 239),
+        gold: Color::Rgb(255, 183, 3),
+        dim_text: Color::Rgb(100, 100, 120),
+        bright_text: Color::Rgb(255, 255, 255),
+        muted_text: Color::Rgb(160, 160, 180),
+        small_blind: Color::Rgb(76, 201, 240),
+        big_blind: Color::Rgb(255, 183, 3),
+        boss_blind: Color::Rgb(230, 57, 70),
+    };
+
+    pub const LIGHT: Theme = Theme {
+        bg: Color::Rgb(250, 250, 245),
+        felt: Color::Rgb(232, 236, 228),
+        panel_bg: Color::Rgb(220, 224, 216),
+        card_face: Color::Rgb(255, 255, 255),
+        card_border: Color::Rgb(120, 120, 130),
+        card_selected: Color::Rgb(196, 132, 0),
+        card_back: Color::Rgb(90, 90, 170),
+        card_face_dim: Color::Rgb(200, 200, 210),
+        red_suit: Color::Rgb(200, 30, 45),
+        black_suit: Color::Rgb(30, 30, 40),
+        diamond_suit: Color::Rgb(0, 90, 200),
+        club_suit: Color::Rgb(0, 130, 60),
+        chips_color: Color::Rgb(0, 110, 190),
+        mult_color: Color::Rgb(200, 30, 45),
+        xmult_color: Color::Rgb(220, 60, 60),
+        score_color: Color::Rgb(176, 116, 0),
+        money_color: Color::Rgb(0, 135, 95),
+        common: Color::Rgb(110, 110, 120),
+        uncommon: Color::Rgb(0, 135, 95),
+        rare: Color::Rgb(200, 30, 45),
+        legendary: Color::Rgb(110, 20, 170),
+        spectral: Color::Rgb(30, 100, 200),
+        gold: Color::Rgb(176, 106, 0),
+        dim_text: Color::Rgb(140, 140, 150),
+        bright_text: Color::Rgb(15, 15, 25),
+        muted_text: Color::Rgb(80, 80, 95),
+        small_blind: Color::Rgb(0, 110, 190),
+        big_blind: Color::Rgb(176, 106, 0),
+        boss_blind: Color::Rgb(200, 30, 45),
+    };
+
+    pub const HIGH_CONTRAST: Theme = Theme {
+        bg: Color::Rgb(0, 0, 0),
+        felt: Color::Rgb(0, 0, 0),
+        panel_bg: Color::Rgb(20, 20, 20),
+        card_face: Color::Rgb(255, 255, 255),
+        card_border: Color::Rgb(200, 200, 200),
+        card_selected: Color::Rgb(255, 255, 0),
+        card_back: Color::Rgb(0, 0, 160),
+        card_face_dim: Color::Rgb(120, 120, 120),
+        red_suit: Color::Rgb(255, 60, 60),
+        black_suit: Color::Rgb(255, 255, 255),
+        diamond_suit: Color::Rgb(0, 160, 255),
+        club_suit: Color::Rgb(0, 255, 0),
+        chips_color: Color::Rgb(0, 200, 255),
+        mult_color: Color::Rgb(255, 60, 60),
+        xmult_color: Color::Rgb(255, 120, 120),
+        score_color: Color::Rgb(255, 255, 0),
+        money_color: Color::Rgb(0, 255, 128),
+        common: Color::Rgb(200, 200, 200),
+        uncommon: Color::Rgb(0, 255, 128),
+        rare: Color::Rgb(255, 60, 60),
+        legendary: Color::Rgb(255, 0, 255),
+        spectral: Color::Rgb(0, 160, 255),
+        gold: Color::Rgb(255, 200, 0),
+        dim_text: Color::Rgb(170, 170, 170),
+        bright_text: Color::Rgb(255, 255, 255),
+        muted_text: Color::Rgb(220, 220, 220),
+        small_blind: Color::Rgb(0, 200, 255),
+        big_blind: Color::Rgb(255, 200, 0),
+        boss_blind: Color::Rgb(255, 60, 60),
+    };
+
+    pub const COLORBLIND: Theme = Theme {
+        bg: Color::Rgb(13, 17, 23),
+        felt: Color::Rgb(22, 22, 52),
+        panel_bg: Color::Rgb(30, 30, 60),
+        card_face: Color::Rgb(240, 240, 240),
+        card_border: Color::Rgb(108, 117, 125),
+        card_selected: Color::Rgb(240, 228, 66),
+        card_back: Color::Rgb(60, 60, 120),
+        card_face_dim: Color::Rgb(80, 80, 100),
+        red_suit: Color::Rgb(213, 94, 0),
+        black_suit: Color::Rgb(224, 224, 224),
+        diamond_suit: Color::Rgb(0, 114, 178),
+        club_suit: Color::Rgb(240, 228, 66),
+        chips_color: Color::Rgb(86, 180, 233),
+        mult_color: Color::Rgb(213, 94, 0),
+        xmult_color: Color::Rgb(230, 130, 60),
+        score_color: Color::Rgb(240, 228, 66),
+        money_color: Color::Rgb(0, 158, 115),
+        common: Color::Rgb(108, 117, 125),
+        uncommon: Color::Rgb(0, 158, 115),
+        rare: Color::Rgb(213, 94, 0),
+        legendary: Color::Rgb(204, 121, 167),
+        spectral: Color::Rgb(0, 114, 178),
+        gold: Color::Rgb(230, 159, 0),
+        dim_text: Color::Rgb(100, 100, 120),
+        bright_text: Color::Rgb(255, 255, 255),
+        muted_text: Color::Rgb(160, 160, 180),
+        small_blind: Color::Rgb(86, 180, 233),
+        big_blind: Color::Rgb(230, 159, 0),
+        boss_blind: Color::Rgb(213, 94, 0),
+    };
+
+    /// Switch the scheme every widget draws with from now on
+    pub fn set_scheme(scheme: ColorScheme) {
+        ACTIVE_SCHEME.with(|active| active.set(scheme));
+    }
+
+    pub fn scheme() -> ColorScheme {
+        ACTIVE_SCHEME.with(Cell::get)
+    }
+
+    /// Colors of the active scheme
+    pub fn active() -> &'static Theme {
+        Self::scheme().theme()
+    }
+
+    /// Turn the four-color, lettered suits on or off
+    pub fn set_accessible_suits(on: bool) {
+        ACCESSIBLE_SUITS.with(|active| active.set(on));
+    }
+
+    pub fn accessible_suits() -> bool {
+        ACCESSIBLE_SUITS.with(Cell::get)
+    }
+
+    /// Color a suit is drawn in: red / black, or one color per suit when accessible
+    pub fn suit_color(suit: Suit) -> Color {
+        let theme = Self::active();
+        match suit {
+            Suit::Diamonds if Self::accessible_suits() => theme.diamond_suit,
+            Suit::Clubs if Self::accessible_suits() => theme.club_suit,
+            _ if suit.is_red() => theme.red_suit,
+            _ => theme.black_suit,
+        }
+    }
+
+    /// Suit letter to draw next to the symbol, when accessible suits are on
+    pub fn suit_letter(suit: Suit) -> Option<char> {
+        Self::accessible_suits().then(|| suit.letter())
+    }
+
+    /// Color for a hand type's name, brighter the stronger the hand
+    pub fn hand_color(hand: PokerHand) -> Color {
+        match hand {
+            PokerHand::HighCard => Self::dim_text(),
+            PokerHand::Pair | PokerHand::TwoPair => Self::common(),
+            PokerHand::ThreeOfAKind | PokerHand::Straight | PokerHand::Flush => Self::uncommon(),
+            PokerHand::FullHouse | PokerHand::FourOfAKind => Self::rare(),
+            PokerHand::StraightFlush => Self::gold(),
+            PokerHand::RoyalFlush
+            | PokerHand::FiveOfAKind
+            | PokerHand::FlushHouse
+            | PokerHand::FlushFive => Self::legendary(),
+        }
+    }
+
+    // ─── Active scheme colors ─────────────────────────────────────────
+
+    pub fn bg() -> Color {
+        Self::active().bg
+    }
+    pub fn felt() -> Color {
+        Self::active().felt
+    }
+    pub fn panel_bg() -> Color {
+        Self::active().panel_bg
+    }
+    pub fn card_face() -> Color {
+        Self::active().card_face
+    }
+    pub fn card_border() -> Color {
+        Self::active().card_border
+    }
+    pub fn card_selected() -> Color {
+        Self::active().card_selected
+    }
+    pub fn card_back() -> Color {
+        Self::active().card_back
+    }
+    pub fn card_face_dim() -> Color {
+        Self::active().card_face_dim
+    }
+    pub fn red_suit() -> Color {
+        Self::active().red_suit
+    }
+    pub fn black_suit() -> Color {
+        Self::active().black_suit
+    }
+    pub fn chips_color() -> Color {
+        Self::active().chips_color
+    }
+    pub fn mult_color() -> Color {
+        Self::active().mult_color
+    }
+    pub fn xmult_color() -> Color {
+        Self::active().xmult_color
+    }
+    pub fn score_color() -> Color {
+        Self::active().score_color
+    }
+    pub fn money_color() -> Color {
+        Self::active().money_color
+    }
+    pub fn common() -> Color {
+        Self::active().common
+    }
+    pub fn uncommon() -> Color {
+        Self::active().uncommon
+    }
+    pub fn rare() -> Color {
+        Self::active().rare
+    }
+    pub fn legendary() -> Color {
+        Self::active().legendary
+    }
+    pub fn spectral() -> Color {
+        Self::active().spectral
+    }
+    pub fn gold() -> Color {
+        Self::active().gold
+    }
+    pub fn dim_text() -> Color {
+        Self::active().dim_text
+    }
+    pub fn bright_text() -> Color {
+        Self::active().bright_text
+    }
+    pub fn muted_text() -> Color {
+        Self::active().muted_text
+    }
+    pub fn small_blind() -> Color {
+        Self::active().small_blind
+    }
+    pub fn big_blind() -> Color {
+        Self::active().big_blind
+    }
+    pub fn boss_blind() -> Color {
+        Self::active().boss_blind
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_hand_colors_rise_with_hand_strength() {
+        let theme = Theme::DARK;
+        let expected = [
+            (PokerHand::HighCard, theme.dim_text),
+            (PokerHand::Pair, theme.common),
+            (PokerHand::TwoPair, theme.common),
+            (PokerHand::ThreeOfAKind, theme.uncommon),
+            (PokerHand::Straight, theme.uncommon),
+            (PokerHand::Flush, theme.uncommon),
+            (PokerHand::FullHouse, theme.rare),
+            (PokerHand::FourOfAKind, theme.rare),
+            (PokerHand::StraightFlush, theme.gold),
+            (PokerHand::RoyalFlush, theme.legendary),
+            (PokerHand::FiveOfAKind, theme.legendary),
+            (PokerHand::FlushHouse, theme.legendary),
+            (PokerHand::FlushFive, theme.legendary),
+        ];
+        for (hand, color) in expected {
+            assert_eq!(Theme::hand_color(hand), color, "{}", hand);
+        }
+    }
 }